@@ -3,5 +3,5 @@ import type { HealthResponse } from '$lib/types/generated/HealthResponse';
 import { get } from '../base';
 
 export async function fetchHealthStatus(): Promise<ApiResult<HealthResponse>> {
-    return get<HealthResponse>('health');
+    return get<HealthResponse>('health/ready');
 }
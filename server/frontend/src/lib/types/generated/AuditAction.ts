@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type AuditAction = "auth.login" | "auth.login_failed" | "user.created" | "user.role_changed" | "user.deactivated" | "user.reactivated" | "user.deleted" | "board.created" | "board.imported" | "report.created" | "report.status_changed" | "report.issue_linked";
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AuditAction } from "./AuditAction";
+import type { AuditTargetType } from "./AuditTargetType";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+export type AuditEventResponse = { id: string, actor_id: string | null, action: AuditAction, target_type: AuditTargetType, target_id: string | null, ip_address: string | null, user_agent: string | null, changes: JsonValue, created_at: string, };
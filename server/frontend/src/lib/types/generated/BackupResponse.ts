@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Snapshot in the backup directory of the server. Archives also hold the uploaded files.
+ */
+export type BackupResponse = { file_name: string, size_bytes: number, created_at: string, includes_uploads: boolean, };
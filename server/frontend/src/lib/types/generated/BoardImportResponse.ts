@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Reporters and history actors are matched to existing users by email. Unmatched reporters are
+ * imported as anonymous reports, with their email kept as contact.
+ */
+export type BoardImportResponse = { dry_run: boolean, board_id: string, board_name: string, report_count: number, media_file_count: number, history_entry_count: number, matched_user_emails: Array<string>, unmatched_user_emails: Array<string>, warnings: Array<string>, };
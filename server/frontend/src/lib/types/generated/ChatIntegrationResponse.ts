@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ChatIntegrationResponse = { id: string, board_id: string, webhook_url: string, message_template: string | null, is_active: boolean, created_by: string, created_at: string, updated_at: string, };
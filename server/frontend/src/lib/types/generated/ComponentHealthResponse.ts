@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HealthStatus } from "./HealthStatus";
+
+export type ComponentHealthResponse = { name: string, status: HealthStatus, latency_ms: number, message: string | null, };
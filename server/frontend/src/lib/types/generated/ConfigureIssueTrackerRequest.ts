@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IssueTrackerProvider } from "./IssueTrackerProvider";
+
+export type ConfigureIssueTrackerRequest = { provider: IssueTrackerProvider, 
+/**
+ * Only needed for self-hosted GitHub Enterprise or GitLab instances.
+ */
+api_base_url: string | null, repository: string, access_token: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreateChatIntegrationRequest = { webhook_url: string, 
+/**
+ * Uses the default template when omitted.
+ */
+message_template: string | null, };
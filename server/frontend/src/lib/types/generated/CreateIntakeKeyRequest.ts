@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreateIntakeKeyRequest = { name: string, 
+/**
+ * Origins of the sites embedding the widget, e.g. `https://staging.example.com`.
+ */
+allowed_origins: Array<string>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EventType } from "./EventType";
+
+export type CreateWebhookRequest = { url: string, 
+/**
+ * Generated when omitted.
+ */
+secret: string | null, event_types: Array<EventType>, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebhookResponse } from "./WebhookResponse";
+
+/**
+ * Returned once on creation: the secret is not exposed afterwards.
+ */
+export type CreatedWebhookResponse = { webhook: WebhookResponse, secret: string, };
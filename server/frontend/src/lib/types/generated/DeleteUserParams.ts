@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UserDeletionMode } from "./UserDeletionMode";
+
+export type DeleteUserParams = { mode: UserDeletionMode, 
+/**
+ * Required when `mode` is `reassign`.
+ */
+reassign_to: string | null, };
@@ -1,3 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ComponentHealthResponse } from "./ComponentHealthResponse";
+import type { HealthStatus } from "./HealthStatus";
 
-export type HealthResponse = { status: string, message: string, };
+export type HealthResponse = { status: HealthStatus, message: string, 
+/**
+ * Checked dependencies, empty for the liveness check.
+ */
+components: Array<ComponentHealthResponse>, };
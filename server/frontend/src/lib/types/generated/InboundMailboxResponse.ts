@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Reports can be emailed to `address`, or posted as raw `.eml` files to `upload_url`.
+ */
+export type InboundMailboxResponse = { id: string, board_id: string, address: string, upload_url: string, created_by: string, created_at: string, updated_at: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The public key is meant to be embedded in the widget, along with the submission URL.
+ */
+export type IntakeKeyResponse = { id: string, board_id: string, name: string, public_key: string, allowed_origins: Array<string>, submission_url: string, is_active: boolean, created_by: string, created_at: string, updated_at: string, };
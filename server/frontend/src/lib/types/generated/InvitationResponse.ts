@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { UserRole } from "./UserRole";
+
+export type InvitationResponse = { id: string, email: string, role: UserRole, invited_by: string, expires_at: string, created_at: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IssueTrackerProvider } from "./IssueTrackerProvider";
+
+/**
+ * The access token is write-only and never returned. The webhook URL and secret are to be
+ * set on the tracker side to sync issue state back to the reports.
+ */
+export type IssueTrackerIntegrationResponse = { id: string, board_id: string, provider: IssueTrackerProvider, api_base_url: string, repository: string, webhook_url: string, webhook_secret: string, created_by: string, created_at: string, updated_at: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InvitationResponse } from "./InvitationResponse";
+
+/**
+ * Returned once on creation: the token cannot be retrieved afterwards.
+ */
+export type IssuedInvitationResponse = { invitation: InvitationResponse, token: string, };
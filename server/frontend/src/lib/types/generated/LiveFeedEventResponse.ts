@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EventType } from "./EventType";
+import type { ReportResponse } from "./ReportResponse";
+
+/**
+ * Data of a `report.*` event on the live feed.
+ */
+export type LiveFeedEventResponse = { id: string, event: EventType, board_id: string, occurred_at: string, report: ReportResponse, };
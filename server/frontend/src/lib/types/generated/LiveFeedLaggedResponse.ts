@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Data of a `lagged` event: events were dropped and the client should refetch.
+ */
+export type LiveFeedLaggedResponse = { skipped: bigint, };
@@ -1,4 +1,4 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { UserResponse } from "./UserResponse";
 
-export type LoginResponse = { access_token: string, token_type: string, user: UserResponse, };
+export type LoginResponse = { access_token: string, token_type: string, user: UserResponse, two_factor_setup_required: boolean, };
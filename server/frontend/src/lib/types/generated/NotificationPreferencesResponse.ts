@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type NotificationPreferencesResponse = { email_on_new_report: boolean, email_on_status_change: boolean, 
+/**
+ * Unset until the user changes the defaults.
+ */
+updated_at: string | null, };
@@ -1,4 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReportStatus } from "./ReportStatus";
 import type { ReportType } from "./ReportType";
 
-export type Report = { id: string, user_id: string, board_id: string, report_type: ReportType, title: string, description: string | null, file_path: string, thumbnail_file_path: string | null, url: string | null, browser_name: string | null, browser_version: string | null, os_name: string | null, created_at: string, updated_at: string, };
+export type Report = { id: string, user_id: string, board_id: string, report_type: ReportType, status: ReportStatus, title: string, description: string | null, file_path: string, thumbnail_file_path: string | null, url: string | null, browser_name: string | null, browser_version: string | null, os_name: string | null, 
+/**
+ * Left by anonymous submitters who want to be contacted back.
+ */
+contact_email: string | null, external_issue_key: string | null, external_issue_url: string | null, created_at: string, updated_at: string, };
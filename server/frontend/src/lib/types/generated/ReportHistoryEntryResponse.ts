@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReportChangeSource } from "./ReportChangeSource";
+
+export type ReportHistoryEntryResponse = { id: string, field: string, old_value: string | null, new_value: string | null, source: ReportChangeSource, actor_id: string | null, details: string | null, created_at: string, };
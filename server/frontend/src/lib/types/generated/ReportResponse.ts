@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReportStatus } from "./ReportStatus";
 import type { ReportType } from "./ReportType";
 
-export type ReportResponse = { id: string, board_id: string, title: string, report_type: ReportType, description: string | null, file_path: string, thumbnail_file_path: string | null, url: string | null, browser_name: string | null, browser_version: string | null, os_name: string | null, created_at: string, updated_at: string, };
+export type ReportResponse = { id: string, board_id: string, title: string, report_type: ReportType, status: ReportStatus, description: string | null, file_path: string, thumbnail_file_path: string | null, url: string | null, browser_name: string | null, browser_version: string | null, os_name: string | null, contact_email: string | null, external_issue_key: string | null, external_issue_url: string | null, created_at: string, updated_at: string, };
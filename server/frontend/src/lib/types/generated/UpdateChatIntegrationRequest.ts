@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UpdateChatIntegrationRequest = { webhook_url: string | null, 
+/**
+ * An empty template goes back to the default one.
+ */
+message_template: string | null, is_active: boolean | null, };
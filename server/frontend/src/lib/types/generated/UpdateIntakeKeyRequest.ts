@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UpdateIntakeKeyRequest = { name: string | null, allowed_origins: Array<string> | null, is_active: boolean | null, };
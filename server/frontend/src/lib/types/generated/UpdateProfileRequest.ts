@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type UpdateProfileRequest = { email: string | null, first_name: string | null, last_name: string | null, 
+/**
+ * Required when `email` changes.
+ */
+current_password: string | null, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EventType } from "./EventType";
+
+export type UpdateWebhookRequest = { url: string | null, secret: string | null, event_types: Array<EventType> | null, is_active: boolean | null, };
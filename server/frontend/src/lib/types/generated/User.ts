@@ -1,4 +1,4 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { UserRole } from "./UserRole";
 
-export type User = { id: string, email: string, first_name: string | null, last_name: string | null, role: UserRole, created_at: string, updated_at: string, };
+export type User = { id: string, email: string, first_name: string | null, last_name: string | null, avatar_url: string | null, role: UserRole, two_factor_enabled: boolean, is_active: boolean, deleted_at: string | null, created_at: string, updated_at: string, };
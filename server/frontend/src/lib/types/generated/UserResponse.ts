@@ -1,4 +1,4 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { UserRole } from "./UserRole";
 
-export type UserResponse = { id: string, email: string, first_name: string | null, last_name: string | null, role: UserRole, };
+export type UserResponse = { id: string, email: string, first_name: string | null, last_name: string | null, avatar_url: string | null, role: UserRole, two_factor_enabled: boolean, is_active: boolean, created_at: string, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EventType } from "./EventType";
+import type { WebhookDeliveryStatus } from "./WebhookDeliveryStatus";
+
+export type WebhookDeliveryResponse = { id: string, webhook_id: string, event_id: string, event_type: EventType, payload: string, status: WebhookDeliveryStatus, attempts: number, response_status: number | null, last_error: string | null, 
+/**
+ * When the next attempt is due, while the delivery is pending.
+ */
+next_attempt_at: string | null, created_at: string, updated_at: string, };
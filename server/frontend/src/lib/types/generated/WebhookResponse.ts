@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EventType } from "./EventType";
+
+export type WebhookResponse = { id: string, board_id: string, url: string, event_types: Array<EventType>, is_active: boolean, created_by: string, created_at: string, updated_at: string, };
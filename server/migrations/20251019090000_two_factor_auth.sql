@@ -0,0 +1,28 @@
+-- TWO-FACTOR AUTHENTICATION
+-- The TOTP secret is stored as soon as enrollment starts, but only takes effect once confirmed.
+ALTER TABLE users ADD COLUMN totp_secret TEXT;
+
+ALTER TABLE users ADD COLUMN totp_enabled INTEGER NOT NULL DEFAULT 0;
+
+-- RECOVERY CODES
+-- Single-use codes allowing a user to complete a login without their authenticator.
+CREATE TABLE
+    IF NOT EXISTS user_recovery_codes (
+        id TEXT PRIMARY KEY NOT NULL,
+        user_id TEXT NOT NULL,
+        code_hash TEXT NOT NULL,
+        used_at TEXT,
+        created_at TEXT NOT NULL,
+        FOREIGN KEY (user_id) REFERENCES users (id)
+    );
+
+CREATE INDEX idx_user_recovery_codes_user_id ON user_recovery_codes (user_id);
+
+-- SETTINGS
+-- Instance-wide settings editable by admins at runtime.
+CREATE TABLE
+    IF NOT EXISTS settings (
+        key TEXT PRIMARY KEY NOT NULL,
+        value TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    );
@@ -0,0 +1,6 @@
+-- USER MANAGEMENT
+-- Deactivated users keep their data but can no longer authenticate.
+ALTER TABLE users ADD COLUMN is_active INTEGER NOT NULL DEFAULT 1;
+
+-- Anonymized users are kept as tombstones so their boards and reports stay attributed to someone.
+ALTER TABLE users ADD COLUMN deleted_at TEXT;
@@ -0,0 +1,3 @@
+-- USER PROFILE
+-- Public URL of the avatar image, as returned by the storage backend.
+ALTER TABLE users ADD COLUMN avatar_url TEXT;
@@ -0,0 +1,17 @@
+-- USER INVITATIONS
+-- Only a hash of the invite token is stored; the token itself is shown once to the inviting admin.
+CREATE TABLE
+    IF NOT EXISTS user_invitations (
+        id TEXT PRIMARY KEY NOT NULL,
+        email TEXT NOT NULL,
+        role TEXT NOT NULL,
+        token_hash TEXT UNIQUE NOT NULL,
+        invited_by TEXT NOT NULL,
+        expires_at TEXT NOT NULL,
+        accepted_at TEXT,
+        revoked_at TEXT,
+        created_at TEXT NOT NULL,
+        FOREIGN KEY (invited_by) REFERENCES users (id)
+    );
+
+CREATE INDEX idx_user_invitations_email ON user_invitations (email);
@@ -0,0 +1,38 @@
+-- WEBHOOKS
+-- Per-board subscriptions. `event_types` is a comma-separated list such as `report.created,report.updated`.
+CREATE TABLE
+    IF NOT EXISTS webhooks (
+        id TEXT PRIMARY KEY NOT NULL,
+        board_id TEXT NOT NULL,
+        url TEXT NOT NULL,
+        secret TEXT NOT NULL,
+        event_types TEXT NOT NULL,
+        is_active INTEGER NOT NULL DEFAULT 1,
+        created_by TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        FOREIGN KEY (board_id) REFERENCES boards (id),
+        FOREIGN KEY (created_by) REFERENCES users (id)
+    );
+
+CREATE INDEX idx_webhooks_board_id ON webhooks (board_id);
+
+-- WEBHOOK DELIVERIES
+-- One row per event sent to a webhook; retries update the row in place.
+CREATE TABLE
+    IF NOT EXISTS webhook_deliveries (
+        id TEXT PRIMARY KEY NOT NULL,
+        webhook_id TEXT NOT NULL,
+        event_id TEXT NOT NULL,
+        event_type TEXT NOT NULL,
+        payload TEXT NOT NULL,
+        status TEXT NOT NULL,
+        attempts INTEGER NOT NULL DEFAULT 0,
+        response_status INTEGER,
+        last_error TEXT,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        FOREIGN KEY (webhook_id) REFERENCES webhooks (id)
+    );
+
+CREATE INDEX idx_webhook_deliveries_webhook_id ON webhook_deliveries (webhook_id);
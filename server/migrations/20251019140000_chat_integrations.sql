@@ -0,0 +1,18 @@
+-- CHAT INTEGRATIONS
+-- Slack/Mattermost-compatible incoming webhooks notified of new reports on a board.
+-- `message_template` overrides the default template from the configuration when set.
+CREATE TABLE
+    IF NOT EXISTS chat_integrations (
+        id TEXT PRIMARY KEY NOT NULL,
+        board_id TEXT NOT NULL,
+        webhook_url TEXT NOT NULL,
+        message_template TEXT,
+        is_active INTEGER NOT NULL DEFAULT 1,
+        created_by TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        FOREIGN KEY (board_id) REFERENCES boards (id),
+        FOREIGN KEY (created_by) REFERENCES users (id)
+    );
+
+CREATE INDEX idx_chat_integrations_board_id ON chat_integrations (board_id);
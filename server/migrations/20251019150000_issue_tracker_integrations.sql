@@ -0,0 +1,25 @@
+-- ISSUE TRACKER INTEGRATIONS
+-- At most one GitHub or GitLab repository per board, where reports can be exported as issues.
+CREATE TABLE
+    IF NOT EXISTS issue_tracker_integrations (
+        id TEXT PRIMARY KEY NOT NULL,
+        board_id TEXT NOT NULL UNIQUE,
+        provider TEXT NOT NULL,
+        api_base_url TEXT NOT NULL,
+        repository TEXT NOT NULL,
+        access_token TEXT NOT NULL,
+        created_by TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        FOREIGN KEY (board_id) REFERENCES boards (id),
+        FOREIGN KEY (created_by) REFERENCES users (id)
+    );
+
+-- Issue a report was exported to. The key reads `<repository>#<number>`.
+ALTER TABLE reports
+ADD COLUMN external_issue_key TEXT;
+
+ALTER TABLE reports
+ADD COLUMN external_issue_url TEXT;
+
+CREATE INDEX idx_reports_external_issue_key ON reports (external_issue_key);
@@ -0,0 +1,32 @@
+-- REPORT STATUS
+ALTER TABLE reports
+ADD COLUMN status TEXT NOT NULL DEFAULT 'Open';
+
+-- REPORT HISTORY
+-- Changes made to a report, by a user or synced from its tracker issue.
+CREATE TABLE
+    IF NOT EXISTS report_history (
+        id TEXT PRIMARY KEY NOT NULL,
+        report_id TEXT NOT NULL,
+        field TEXT NOT NULL,
+        old_value TEXT,
+        new_value TEXT,
+        source TEXT NOT NULL,
+        actor_id TEXT,
+        details TEXT,
+        created_at TEXT NOT NULL,
+        FOREIGN KEY (report_id) REFERENCES reports (id),
+        FOREIGN KEY (actor_id) REFERENCES users (id)
+    );
+
+CREATE INDEX idx_report_history_report_id ON report_history (report_id);
+
+-- Shared secret the tracker signs its webhooks with. Kept when the integration is reconfigured.
+ALTER TABLE issue_tracker_integrations
+ADD COLUMN webhook_secret TEXT NOT NULL DEFAULT '';
+
+UPDATE issue_tracker_integrations
+SET
+    webhook_secret = lower(hex(randomblob(24)))
+WHERE
+    webhook_secret = '';
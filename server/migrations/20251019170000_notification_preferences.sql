@@ -0,0 +1,10 @@
+-- NOTIFICATION PREFERENCES
+-- Users without a row get every notification.
+CREATE TABLE
+    IF NOT EXISTS notification_preferences (
+        user_id TEXT PRIMARY KEY NOT NULL,
+        email_on_new_report INTEGER NOT NULL DEFAULT 1,
+        email_on_status_change INTEGER NOT NULL DEFAULT 1,
+        updated_at TEXT NOT NULL,
+        FOREIGN KEY (user_id) REFERENCES users (id)
+    );
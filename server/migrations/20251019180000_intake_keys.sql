@@ -0,0 +1,54 @@
+-- INTAKE KEYS
+-- Public keys embedded in feedback widgets, letting anyone submit reports on a board from the listed
+-- origins. They are not secrets: they ship in the pages embedding the widget.
+-- `allowed_origins` is a comma-separated list of origins such as `https://staging.example.com`.
+CREATE TABLE
+    IF NOT EXISTS intake_keys (
+        id TEXT PRIMARY KEY NOT NULL,
+        board_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        public_key TEXT UNIQUE NOT NULL,
+        allowed_origins TEXT NOT NULL,
+        is_active INTEGER NOT NULL DEFAULT 1,
+        created_by TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        FOREIGN KEY (board_id) REFERENCES boards (id),
+        FOREIGN KEY (created_by) REFERENCES users (id)
+    );
+
+CREATE INDEX idx_intake_keys_board_id ON intake_keys (board_id);
+
+-- Address left by an anonymous submitter to be contacted back.
+ALTER TABLE reports
+ADD COLUMN contact_email TEXT;
+
+-- ANONYMOUS REPORTER
+-- Synthetic user the intake submissions are attributed to. Stored as an inactive tombstone without
+-- a password, so it can never sign in and stays out of the user listings.
+INSERT INTO
+    users (
+        id,
+        email,
+        password_hash,
+        first_name,
+        last_name,
+        role,
+        is_active,
+        deleted_at,
+        created_at,
+        updated_at
+    )
+VALUES
+    (
+        '00000000-0000-0000-0000-000000000000',
+        'anonymous@rebug.invalid',
+        '',
+        'Anonymous',
+        NULL,
+        'User',
+        0,
+        strftime ('%Y-%m-%dT%H:%M:%fZ', 'now'),
+        strftime ('%Y-%m-%dT%H:%M:%fZ', 'now'),
+        strftime ('%Y-%m-%dT%H:%M:%fZ', 'now')
+    );
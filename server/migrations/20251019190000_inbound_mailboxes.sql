@@ -0,0 +1,14 @@
+-- INBOUND MAILBOXES
+-- Email address of a board, `<token>@<inbound_email_domain>`. Messages sent to it are filed as reports.
+-- The token is the only secret, so it is regenerated when the address leaks.
+CREATE TABLE
+    IF NOT EXISTS inbound_mailboxes (
+        id TEXT PRIMARY KEY NOT NULL,
+        board_id TEXT UNIQUE NOT NULL,
+        token TEXT UNIQUE NOT NULL,
+        created_by TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL,
+        FOREIGN KEY (board_id) REFERENCES boards (id),
+        FOREIGN KEY (created_by) REFERENCES users (id)
+    );
@@ -0,0 +1,31 @@
+-- AUDIT EVENTS
+-- Append-only log of security-relevant and data-changing actions. Actors and targets are not foreign
+-- keys: events outlive the users, boards and reports they mention. `changes` holds a JSON object.
+CREATE TABLE
+    IF NOT EXISTS audit_events (
+        id TEXT PRIMARY KEY NOT NULL,
+        actor_id TEXT,
+        action TEXT NOT NULL,
+        target_type TEXT NOT NULL,
+        target_id TEXT,
+        ip_address TEXT,
+        user_agent TEXT,
+        changes TEXT NOT NULL,
+        created_at TEXT NOT NULL
+    );
+
+CREATE INDEX idx_audit_events_created_at ON audit_events (created_at);
+
+CREATE INDEX idx_audit_events_actor_id ON audit_events (actor_id);
+
+CREATE INDEX idx_audit_events_target ON audit_events (target_type, target_id);
+
+CREATE TRIGGER audit_events_no_update BEFORE UPDATE ON audit_events
+BEGIN
+    SELECT RAISE (ABORT, 'audit events are append-only');
+END;
+
+CREATE TRIGGER audit_events_no_delete BEFORE DELETE ON audit_events
+BEGIN
+    SELECT RAISE (ABORT, 'audit events are append-only');
+END;
@@ -0,0 +1,247 @@
+-- POSTGRESQL SCHEMA
+-- Same tables as the SQLite migrations up to `inbound_mailboxes`, with native types. New migrations
+-- are added to both directories.
+-- USERS
+CREATE TABLE
+    IF NOT EXISTS users (
+        id UUID PRIMARY KEY NOT NULL,
+        email TEXT UNIQUE NOT NULL,
+        password_hash TEXT NOT NULL,
+        first_name TEXT,
+        last_name TEXT,
+        avatar_url TEXT,
+        role TEXT NOT NULL,
+        totp_secret TEXT,
+        totp_enabled BOOLEAN NOT NULL DEFAULT FALSE,
+        is_active BOOLEAN NOT NULL DEFAULT TRUE,
+        deleted_at TIMESTAMPTZ,
+        created_at TIMESTAMPTZ NOT NULL,
+        updated_at TIMESTAMPTZ NOT NULL
+    );
+
+CREATE INDEX idx_users_email ON users (email);
+
+-- BOARDS
+CREATE TABLE
+    IF NOT EXISTS boards (
+        id UUID PRIMARY KEY NOT NULL,
+        owner_id UUID NOT NULL REFERENCES users (id),
+        name TEXT NOT NULL,
+        description TEXT,
+        is_default BOOLEAN NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL,
+        updated_at TIMESTAMPTZ NOT NULL,
+        UNIQUE (owner_id, name)
+    );
+
+CREATE INDEX idx_boards_owner_id ON boards (owner_id);
+
+-- REPORTS
+CREATE TABLE
+    IF NOT EXISTS reports (
+        id UUID PRIMARY KEY NOT NULL,
+        user_id UUID NOT NULL REFERENCES users (id),
+        board_id UUID NOT NULL REFERENCES boards (id),
+        report_type TEXT NOT NULL,
+        status TEXT NOT NULL DEFAULT 'Open',
+        title TEXT NOT NULL,
+        description TEXT,
+        file_path TEXT NOT NULL,
+        thumbnail_file_path TEXT,
+        url TEXT,
+        browser_name TEXT,
+        browser_version TEXT,
+        os_name TEXT,
+        contact_email TEXT,
+        external_issue_key TEXT,
+        external_issue_url TEXT,
+        created_at TIMESTAMPTZ NOT NULL,
+        updated_at TIMESTAMPTZ NOT NULL
+    );
+
+CREATE INDEX idx_reports_user_id ON reports (user_id);
+
+CREATE INDEX idx_reports_board_id ON reports (board_id);
+
+CREATE INDEX idx_reports_external_issue_key ON reports (external_issue_key);
+
+-- RECOVERY CODES
+CREATE TABLE
+    IF NOT EXISTS user_recovery_codes (
+        id UUID PRIMARY KEY NOT NULL,
+        user_id UUID NOT NULL REFERENCES users (id),
+        code_hash TEXT NOT NULL,
+        used_at TIMESTAMPTZ,
+        created_at TIMESTAMPTZ NOT NULL
+    );
+
+CREATE INDEX idx_user_recovery_codes_user_id ON user_recovery_codes (user_id);
+
+-- SETTINGS
+CREATE TABLE
+    IF NOT EXISTS settings (
+        key TEXT PRIMARY KEY NOT NULL,
+        value TEXT NOT NULL,
+        updated_at TIMESTAMPTZ NOT NULL
+    );
+
+-- USER INVITATIONS
+CREATE TABLE
+    IF NOT EXISTS user_invitations (
+        id UUID PRIMARY KEY NOT NULL,
+        email TEXT NOT NULL,
+        role TEXT NOT NULL,
+        token_hash TEXT UNIQUE NOT NULL,
+        invited_by UUID NOT NULL REFERENCES users (id),
+        expires_at TIMESTAMPTZ NOT NULL,
+        accepted_at TIMESTAMPTZ,
+        revoked_at TIMESTAMPTZ,
+        created_at TIMESTAMPTZ NOT NULL
+    );
+
+CREATE INDEX idx_user_invitations_email ON user_invitations (email);
+
+-- WEBHOOKS
+CREATE TABLE
+    IF NOT EXISTS webhooks (
+        id UUID PRIMARY KEY NOT NULL,
+        board_id UUID NOT NULL REFERENCES boards (id),
+        url TEXT NOT NULL,
+        secret TEXT NOT NULL,
+        event_types TEXT NOT NULL,
+        is_active BOOLEAN NOT NULL DEFAULT TRUE,
+        created_by UUID NOT NULL REFERENCES users (id),
+        created_at TIMESTAMPTZ NOT NULL,
+        updated_at TIMESTAMPTZ NOT NULL
+    );
+
+CREATE INDEX idx_webhooks_board_id ON webhooks (board_id);
+
+-- WEBHOOK DELIVERIES
+CREATE TABLE
+    IF NOT EXISTS webhook_deliveries (
+        id UUID PRIMARY KEY NOT NULL,
+        webhook_id UUID NOT NULL REFERENCES webhooks (id),
+        event_id UUID NOT NULL,
+        event_type TEXT NOT NULL,
+        payload TEXT NOT NULL,
+        status TEXT NOT NULL,
+        attempts INTEGER NOT NULL DEFAULT 0,
+        response_status INTEGER,
+        last_error TEXT,
+        created_at TIMESTAMPTZ NOT NULL,
+        updated_at TIMESTAMPTZ NOT NULL
+    );
+
+CREATE INDEX idx_webhook_deliveries_webhook_id ON webhook_deliveries (webhook_id);
+
+-- CHAT INTEGRATIONS
+CREATE TABLE
+    IF NOT EXISTS chat_integrations (
+        id UUID PRIMARY KEY NOT NULL,
+        board_id UUID NOT NULL REFERENCES boards (id),
+        webhook_url TEXT NOT NULL,
+        message_template TEXT,
+        is_active BOOLEAN NOT NULL DEFAULT TRUE,
+        created_by UUID NOT NULL REFERENCES users (id),
+        created_at TIMESTAMPTZ NOT NULL,
+        updated_at TIMESTAMPTZ NOT NULL
+    );
+
+CREATE INDEX idx_chat_integrations_board_id ON chat_integrations (board_id);
+
+-- ISSUE TRACKER INTEGRATIONS
+CREATE TABLE
+    IF NOT EXISTS issue_tracker_integrations (
+        id UUID PRIMARY KEY NOT NULL,
+        board_id UUID NOT NULL UNIQUE REFERENCES boards (id),
+        provider TEXT NOT NULL,
+        api_base_url TEXT NOT NULL,
+        repository TEXT NOT NULL,
+        access_token TEXT NOT NULL,
+        webhook_secret TEXT NOT NULL,
+        created_by UUID NOT NULL REFERENCES users (id),
+        created_at TIMESTAMPTZ NOT NULL,
+        updated_at TIMESTAMPTZ NOT NULL
+    );
+
+-- REPORT HISTORY
+CREATE TABLE
+    IF NOT EXISTS report_history (
+        id UUID PRIMARY KEY NOT NULL,
+        report_id UUID NOT NULL REFERENCES reports (id),
+        field TEXT NOT NULL,
+        old_value TEXT,
+        new_value TEXT,
+        source TEXT NOT NULL,
+        actor_id UUID REFERENCES users (id),
+        details TEXT,
+        created_at TIMESTAMPTZ NOT NULL
+    );
+
+CREATE INDEX idx_report_history_report_id ON report_history (report_id);
+
+-- NOTIFICATION PREFERENCES
+CREATE TABLE
+    IF NOT EXISTS notification_preferences (
+        user_id UUID PRIMARY KEY NOT NULL REFERENCES users (id),
+        email_on_new_report BOOLEAN NOT NULL DEFAULT TRUE,
+        email_on_status_change BOOLEAN NOT NULL DEFAULT TRUE,
+        updated_at TIMESTAMPTZ NOT NULL
+    );
+
+-- INTAKE KEYS
+CREATE TABLE
+    IF NOT EXISTS intake_keys (
+        id UUID PRIMARY KEY NOT NULL,
+        board_id UUID NOT NULL REFERENCES boards (id),
+        name TEXT NOT NULL,
+        public_key TEXT UNIQUE NOT NULL,
+        allowed_origins TEXT NOT NULL,
+        is_active BOOLEAN NOT NULL DEFAULT TRUE,
+        created_by UUID NOT NULL REFERENCES users (id),
+        created_at TIMESTAMPTZ NOT NULL,
+        updated_at TIMESTAMPTZ NOT NULL
+    );
+
+CREATE INDEX idx_intake_keys_board_id ON intake_keys (board_id);
+
+-- INBOUND MAILBOXES
+CREATE TABLE
+    IF NOT EXISTS inbound_mailboxes (
+        id UUID PRIMARY KEY NOT NULL,
+        board_id UUID UNIQUE NOT NULL REFERENCES boards (id),
+        token TEXT UNIQUE NOT NULL,
+        created_by UUID NOT NULL REFERENCES users (id),
+        created_at TIMESTAMPTZ NOT NULL,
+        updated_at TIMESTAMPTZ NOT NULL
+    );
+
+-- ANONYMOUS REPORTER
+-- Synthetic user the intake submissions are attributed to, see the SQLite `intake_keys` migration.
+INSERT INTO
+    users (
+        id,
+        email,
+        password_hash,
+        first_name,
+        last_name,
+        role,
+        is_active,
+        deleted_at,
+        created_at,
+        updated_at
+    )
+VALUES
+    (
+        '00000000-0000-0000-0000-000000000000',
+        'anonymous@rebug.invalid',
+        '',
+        'Anonymous',
+        NULL,
+        'User',
+        FALSE,
+        now (),
+        now (),
+        now ()
+    );
@@ -0,0 +1,30 @@
+-- AUDIT EVENTS
+-- Append-only log of security-relevant and data-changing actions. Actors and targets are not foreign
+-- keys: events outlive the users, boards and reports they mention. `changes` holds a JSON object.
+CREATE TABLE
+    IF NOT EXISTS audit_events (
+        id UUID PRIMARY KEY NOT NULL,
+        actor_id UUID,
+        action TEXT NOT NULL,
+        target_type TEXT NOT NULL,
+        target_id UUID,
+        ip_address TEXT,
+        user_agent TEXT,
+        changes TEXT NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL
+    );
+
+CREATE INDEX idx_audit_events_created_at ON audit_events (created_at);
+
+CREATE INDEX idx_audit_events_actor_id ON audit_events (actor_id);
+
+CREATE INDEX idx_audit_events_target ON audit_events (target_type, target_id);
+
+CREATE FUNCTION reject_audit_event_change () RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit events are append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_events_append_only BEFORE UPDATE OR DELETE ON audit_events
+FOR EACH ROW EXECUTE FUNCTION reject_audit_event_change ();
@@ -0,0 +1,10 @@
+-- WEBHOOK RETRIES
+-- Pending deliveries are attempted once `next_attempt_at` is reached, by a worker that also picks
+-- up the deliveries interrupted by a restart.
+ALTER TABLE webhook_deliveries ADD COLUMN next_attempt_at TIMESTAMPTZ;
+
+UPDATE webhook_deliveries
+SET next_attempt_at = updated_at
+WHERE status = 'Pending';
+
+CREATE INDEX idx_webhook_deliveries_pending ON webhook_deliveries (status, next_attempt_at);
@@ -0,0 +1,4 @@
+-- TOTP REPLAY PROTECTION
+-- Time step of the last TOTP code accepted for the user: codes of this step or an earlier one are
+-- refused, so that each code can only be used once.
+ALTER TABLE users ADD COLUMN totp_last_time_step BIGINT;
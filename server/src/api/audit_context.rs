@@ -0,0 +1,35 @@
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+};
+
+use crate::domain::models::audit::AuditContext;
+
+use super::{client_ip::ClientIp, error::ApiError};
+
+/// Longer user agents are truncated in the audit log.
+const MAX_USER_AGENT_LENGTH: usize = 512;
+
+/// Origin of the request, without an actor: handlers add the authenticated user with
+/// `AuditContext::with_actor`.
+impl<S: Send + Sync> FromRequestParts<S> for AuditContext {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let ip_address = ClientIp::from_request_parts(parts, state)
+            .await
+            .ok()
+            .map(|ClientIp(ip)| ip);
+        let user_agent = parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.chars().take(MAX_USER_AGENT_LENGTH).collect());
+
+        Ok(Self {
+            actor_id: None,
+            ip_address,
+            user_agent,
+        })
+    }
+}
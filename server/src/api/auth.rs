@@ -1,6 +1,6 @@
 use axum::{
     RequestPartsExt,
-    extract::FromRequestParts,
+    extract::{FromRequestParts, Query},
     http::{StatusCode, request::Parts},
     response::{IntoResponse, Json, Response},
 };
@@ -9,8 +9,10 @@ use axum_extra::{
     headers::{Authorization, authorization::Bearer},
 };
 use jsonwebtoken::{Validation, decode};
+use serde::Deserialize;
 use serde_json::json;
 use tracing::instrument;
+use ts_rs::TS;
 
 use crate::{
     api::state::AppState,
@@ -28,6 +30,8 @@ use super::error::ApiError;
 pub enum AuthError {
     MissingToken,
     InvalidToken(String),
+    AccountDeactivated,
+    TwoFactorSetupRequired,
     InternalError(String),
 }
 
@@ -42,6 +46,14 @@ impl IntoResponse for AuthError {
                 StatusCode::UNAUTHORIZED,
                 format!("Invalid token: {}", reason),
             ),
+            AuthError::AccountDeactivated => (
+                StatusCode::UNAUTHORIZED,
+                "Account is deactivated".to_string(),
+            ),
+            AuthError::TwoFactorSetupRequired => (
+                StatusCode::FORBIDDEN,
+                "Two-factor authentication must be enabled for admin accounts".to_string(),
+            ),
             AuthError::InternalError(reason) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 format!("Internal server error: {}", reason),
@@ -57,6 +69,7 @@ pub struct AuthenticatedUser {
     pub id: uuid::Uuid,
     pub email: String,
     pub role: String,
+    pub two_factor_enabled: bool,
 }
 
 impl From<User> for AuthenticatedUser {
@@ -65,36 +78,24 @@ impl From<User> for AuthenticatedUser {
             id: user.id,
             email: user.email,
             role: user.role.to_string(),
+            two_factor_enabled: user.two_factor_enabled,
         }
     }
 }
 
-impl FromRequestParts<AppState> for AuthenticatedUser {
-    type Rejection = AuthError;
-
-    #[instrument(name = "authenticate_user", skip_all, level = "debug")]
-    async fn from_request_parts(
-        parts: &mut Parts,
-        state: &AppState,
-    ) -> Result<Self, Self::Rejection> {
-        tracing::debug!("Attempting to extract and validate JWT.");
-        let TypedHeader(Authorization(bearer)) = parts
-            .extract::<TypedHeader<Authorization<Bearer>>>()
-            .await
+impl AuthenticatedUser {
+    /// Validates the JWT and loads the active user it was issued for.
+    async fn from_token(token: &str, state: &AppState) -> Result<Self, AuthError> {
+        let token_data = decode::<TokenClaims>(token, &JWT_KEYS.decoding, &Validation::default())
             .map_err(|e| {
-                tracing::warn!("Failed to extract Authorization header: {:?}", e);
-                AuthError::MissingToken
-            })?;
+            tracing::warn!("JWT decoding/validation failed: {}", e);
+            AuthError::InvalidToken(e.to_string())
+        })?;
 
-        let token_data =
-            decode::<TokenClaims>(bearer.token(), &JWT_KEYS.decoding, &Validation::default())
-                .map_err(|e| {
-                    tracing::warn!("JWT decoding/validation failed: {}", e);
-                    AuthError::InvalidToken(e.to_string())
-                })?;
-
-        let user_id = token_data.claims.sub;
+        Self::from_user_id(token_data.claims.sub, state).await
+    }
 
+    async fn from_user_id(user_id: uuid::Uuid, state: &AppState) -> Result<Self, AuthError> {
         let user = state
             .user_service()
             .get_user_by_id(user_id)
@@ -113,11 +114,83 @@ impl FromRequestParts<AppState> for AuthenticatedUser {
                 }
             })?;
 
+        if !user.is_active {
+            tracing::warn!(user_id = %user.id, "Authentication failed: account is deactivated.");
+            return Err(AuthError::AccountDeactivated);
+        }
+
         tracing::debug!(user_id = %user.id, "User authenticated successfully.");
         Ok(user.into())
     }
 }
 
+impl FromRequestParts<AppState> for AuthenticatedUser {
+    type Rejection = AuthError;
+
+    #[instrument(name = "authenticate_user", skip_all, level = "debug")]
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        tracing::debug!("Attempting to extract and validate JWT.");
+        let TypedHeader(Authorization(bearer)) = parts
+            .extract::<TypedHeader<Authorization<Bearer>>>()
+            .await
+            .map_err(|e| {
+                tracing::warn!("Failed to extract Authorization header: {:?}", e);
+                AuthError::MissingToken
+            })?;
+
+        Self::from_token(bearer.token(), state).await
+    }
+}
+
+#[derive(Deserialize, TS)]
+pub struct StreamTicketQuery {
+    /// Stream ticket from `POST /events/ticket`, when there is no `Authorization` header.
+    #[ts(optional)]
+    ticket: Option<String>,
+}
+
+/// Authenticated user of a long-lived stream. Browsers cannot set headers on `EventSource`
+/// requests, so a short-lived stream ticket is also accepted in a `ticket` query parameter,
+/// keeping access tokens out of URLs and request logs.
+#[derive(Debug, Clone)]
+pub struct AuthenticatedStreamUser(pub AuthenticatedUser);
+
+impl FromRequestParts<AppState> for AuthenticatedStreamUser {
+    type Rejection = AuthError;
+
+    #[instrument(name = "authenticate_stream_user", skip_all, level = "debug")]
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        if let Ok(TypedHeader(Authorization(bearer))) =
+            parts.extract::<TypedHeader<Authorization<Bearer>>>().await
+        {
+            return AuthenticatedUser::from_token(bearer.token(), state)
+                .await
+                .map(Self);
+        }
+
+        let Query(StreamTicketQuery { ticket }) = parts
+            .extract::<Query<StreamTicketQuery>>()
+            .await
+            .map_err(|_| AuthError::MissingToken)?;
+        let ticket = ticket.ok_or(AuthError::MissingToken)?;
+
+        let user_id = state
+            .auth_service()
+            .verify_stream_ticket(&ticket)
+            .map_err(|e| AuthError::InvalidToken(e.to_string()))?;
+
+        AuthenticatedUser::from_user_id(user_id, state)
+            .await
+            .map(Self)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AuthenticatedAdmin {
     pub id: uuid::Uuid,
@@ -145,17 +218,37 @@ impl FromRequestParts<AppState> for AuthenticatedAdmin {
     ) -> Result<Self, Self::Rejection> {
         tracing::debug!("Attempting to extract and validate admin user.");
 
-        let AuthenticatedUser { id, email, role } =
-            AuthenticatedUser::from_request_parts(parts, state).await?;
+        let AuthenticatedUser {
+            id,
+            email,
+            role,
+            two_factor_enabled,
+        } = AuthenticatedUser::from_request_parts(parts, state).await?;
 
-        if role == UserRole::Admin.to_string() {
-            Ok(AuthenticatedAdmin { id, email, role })
-        } else {
+        if role != UserRole::Admin.to_string() {
             tracing::warn!(user_id = %id, "User is not an admin. Role found: {}. Access denied.", role);
-            Err(AuthError::InvalidToken(
+            return Err(AuthError::InvalidToken(
                 "User does not have admin privileges".to_string(),
-            ))
+            ));
+        }
+
+        if !two_factor_enabled {
+            let settings = state
+                .two_factor_service()
+                .get_security_settings()
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to load security settings: {}", e);
+                    AuthError::InternalError("Authentication service error".to_string())
+                })?;
+
+            if settings.require_admin_two_factor {
+                tracing::warn!(user_id = %id, "Admin has not enabled two-factor authentication. Access denied.");
+                return Err(AuthError::TwoFactorSetupRequired);
+            }
         }
+
+        Ok(AuthenticatedAdmin { id, email, role })
     }
 }
 
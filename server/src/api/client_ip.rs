@@ -0,0 +1,46 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::request::Parts,
+};
+
+use crate::config::app_config::APP_CONFIG;
+
+use super::error::ApiError;
+
+/// IP address of the client. Read from `client_ip_header` when running behind a reverse proxy,
+/// from the connection otherwise.
+#[derive(Debug, Clone, Copy)]
+pub struct ClientIp(pub IpAddr);
+
+impl<S: Send + Sync> FromRequestParts<S> for ClientIp {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        if !APP_CONFIG.client_ip_header.is_empty() {
+            // Proxies append the address they received the request from, so the last one is
+            // the only one that can be trusted.
+            let forwarded_ip = parts
+                .headers
+                .get(APP_CONFIG.client_ip_header.as_str())
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.rsplit(',').next())
+                .and_then(|value| value.trim().parse().ok());
+
+            match forwarded_ip {
+                Some(ip) => return Ok(Self(ip)),
+                None => tracing::warn!(
+                    header = %APP_CONFIG.client_ip_header,
+                    "Client IP header missing or invalid, using the connection address."
+                ),
+            }
+        }
+
+        parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(address)| Self(address.ip()))
+            .ok_or_else(|| ApiError::internal_error("Client address unavailable"))
+    }
+}
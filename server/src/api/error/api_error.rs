@@ -24,6 +24,12 @@ pub enum ApiError {
     #[error("Conflict: {message}")]
     Conflict { message: String },
 
+    #[error("Too many requests")]
+    TooManyRequests,
+
+    #[error("Bad gateway: {message}")]
+    BadGateway { message: String },
+
     #[error("Internal server error")]
     InternalServerError {
         #[source]
@@ -55,6 +61,12 @@ impl ApiError {
         }
     }
 
+    pub fn bad_gateway(message: impl Into<String>) -> Self {
+        Self::BadGateway {
+            message: message.into(),
+        }
+    }
+
     pub fn internal_error(context: impl Into<String>) -> Self {
         Self::InternalServerError {
             source: None,
@@ -79,6 +91,8 @@ impl ApiError {
             Self::Unauthorized => StatusCode::UNAUTHORIZED,
             Self::Forbidden => StatusCode::FORBIDDEN,
             Self::Conflict { .. } => StatusCode::CONFLICT,
+            Self::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+            Self::BadGateway { .. } => StatusCode::BAD_GATEWAY,
             Self::InternalServerError { .. } => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -90,6 +104,8 @@ impl ApiError {
             Self::Unauthorized => ("Unauthorized access".to_string(), None),
             Self::Forbidden => ("Action forbidden".to_string(), None),
             Self::Conflict { message } => (message.clone(), None),
+            Self::TooManyRequests => ("Too many requests, try again later".to_string(), None),
+            Self::BadGateway { message } => (message.clone(), None),
             Self::InternalServerError { .. } => ("An unexpected error occurred".to_string(), None),
         };
 
@@ -1,7 +1,23 @@
-use crate::application::services::{
-    auth_service::AuthServiceError, board_service::BoardServiceError,
-    dashboard_service::DashboardServiceError, report_service::ReportServiceError,
-    user_onboarding_service::UserOnboardingServiceError, user_service::UserServiceError,
+use crate::{
+    application::services::{
+        audit_service::AuditServiceError, auth_service::AuthServiceError,
+        backup_service::BackupServiceError, board_export_service::BoardExportServiceError,
+        board_import_service::BoardImportServiceError, board_service::BoardServiceError,
+        chat_notification_service::ChatNotificationServiceError,
+        dashboard_service::DashboardServiceError,
+        email_notification_service::EmailNotificationServiceError,
+        inbound_email_service::InboundEmailServiceError, intake_service::IntakeServiceError,
+        invitation_service::InvitationServiceError,
+        issue_tracker_service::IssueTrackerServiceError, live_feed_service::LiveFeedServiceError,
+        metrics_service::MetricsServiceError, report_service::ReportServiceError,
+        two_factor_service::TwoFactorServiceError,
+        user_onboarding_service::UserOnboardingServiceError, user_service::UserServiceError,
+        webhook_service::WebhookServiceError,
+    },
+    domain::ports::{
+        database_backup_port::DatabaseBackupError, issue_tracker_port::IssueTrackerError,
+        storage_port::StorageError,
+    },
 };
 
 use super::api_error::ApiError;
@@ -14,6 +30,9 @@ impl IntoApiError for AuthServiceError {
     fn into_api_error(self) -> ApiError {
         match self {
             Self::InvalidCredentials => ApiError::Unauthorized,
+            Self::InvalidTwoFactorChallenge | Self::InvalidTwoFactorCode => ApiError::Unauthorized,
+            Self::TooManyTwoFactorAttempts => ApiError::TooManyRequests,
+            Self::InvalidStreamTicket => ApiError::Unauthorized,
             Self::PasswordHashingError(msg)
             | Self::TokenCreationError(msg)
             | Self::InternalError(msg) => {
@@ -75,6 +94,13 @@ impl IntoApiError for UserServiceError {
             Self::ValidationError(msg) => ApiError::validation(msg),
             Self::UserAlreadyExists => ApiError::conflict("User already exists"),
             Self::UserNotFound => ApiError::not_found("User"),
+            Self::OperationNotAllowed(msg) => ApiError::conflict(msg),
+            Self::InvalidCurrentPassword => ApiError::validation("Current password is incorrect"),
+            Self::StorageError(StorageError::ValidationError(msg)) => ApiError::validation(msg),
+            Self::StorageError(err) => {
+                tracing::error!("Storage error: {}", err);
+                ApiError::internal_error("File storage unavailable")
+            }
             Self::PasswordHashingError(msg) | Self::InternalError(msg) => {
                 tracing::error!("User service error: {}", msg);
                 ApiError::internal_error("User service unavailable")
@@ -111,12 +137,233 @@ impl IntoApiError for DashboardServiceError {
     }
 }
 
+impl IntoApiError for TwoFactorServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::UserNotFound => ApiError::not_found("User"),
+            Self::AlreadyEnabled | Self::NotEnabled | Self::EnrollmentNotStarted => {
+                ApiError::conflict(self.to_string())
+            }
+            Self::InvalidCode => ApiError::validation("Invalid two-factor code"),
+            Self::ValidationError(msg) => ApiError::validation(msg),
+            Self::InternalError(msg) => {
+                tracing::error!("Two-factor service error: {}", msg);
+                ApiError::internal_error("Two-factor service unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for InvitationServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::InvitationNotFound | Self::InvalidInvitation => ApiError::not_found("Invitation"),
+            Self::UserAlreadyExists => ApiError::conflict("User already exists"),
+            Self::ValidationError(msg) => ApiError::validation(msg),
+            Self::OnboardingError(err) => err.into_api_error(),
+            Self::InternalError(msg) => {
+                tracing::error!("Invitation service error: {}", msg);
+                ApiError::internal_error("Invitation service unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for WebhookServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::WebhookNotFound => ApiError::not_found("Webhook"),
+            Self::DeliveryNotFound => ApiError::not_found("Webhook delivery"),
+            Self::ValidationError(msg) => ApiError::validation(msg),
+            Self::InternalError(msg) => {
+                tracing::error!("Webhook service error: {}", msg);
+                ApiError::internal_error("Webhook service unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for ChatNotificationServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::IntegrationNotFound => ApiError::not_found("Chat integration"),
+            Self::ValidationError(msg) => ApiError::validation(msg),
+            Self::NotificationFailed(err) => ApiError::bad_gateway(err.to_string()),
+            Self::InternalError(msg) => {
+                tracing::error!("Chat notification service error: {}", msg);
+                ApiError::internal_error("Chat notification service unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for EmailNotificationServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::UserNotFound => ApiError::not_found("User"),
+            Self::DeliveryFailed(err) => ApiError::bad_gateway(err.to_string()),
+            Self::InternalError(msg) => {
+                tracing::error!("Email notification service error: {}", msg);
+                ApiError::internal_error("Email notification service unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for IssueTrackerServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::IntegrationNotFound => ApiError::not_found("Issue tracker integration"),
+            Self::ReportNotFound => ApiError::not_found("Report"),
+            Self::AlreadyExported(issue_key) => {
+                ApiError::conflict(format!("Report was already exported to {}", issue_key))
+            }
+            Self::ValidationError(msg) => ApiError::validation(msg),
+            Self::InvalidSignature => ApiError::unauthorized(),
+            Self::TrackerError(IssueTrackerError::ConfigurationError(msg)) => {
+                ApiError::validation(msg)
+            }
+            Self::TrackerError(err) => ApiError::bad_gateway(err.to_string()),
+            Self::InternalError(msg) => {
+                tracing::error!("Issue tracker service error: {}", msg);
+                ApiError::internal_error("Issue tracker service unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for LiveFeedServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::InternalError(msg) => {
+                tracing::error!("Live feed service error: {}", msg);
+                ApiError::internal_error("Live feed unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for IntakeServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::IntakeKeyNotFound => ApiError::not_found("Intake key"),
+            Self::OriginNotAllowed => ApiError::Forbidden,
+            Self::RateLimited => ApiError::TooManyRequests,
+            Self::ValidationError(msg) => ApiError::validation(msg),
+            Self::ReportError(err) => err.into_api_error(),
+            Self::InternalError(msg) => {
+                tracing::error!("Intake service error: {}", msg);
+                ApiError::internal_error("Intake service unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for InboundEmailServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::MailboxNotFound => ApiError::not_found("Inbound mailbox"),
+            Self::ValidationError(msg) => ApiError::validation(msg),
+            Self::StorageError(StorageError::ValidationError(msg)) => ApiError::validation(msg),
+            Self::StorageError(err) => {
+                tracing::error!("Storage error: {}", err);
+                ApiError::internal_error("File storage unavailable")
+            }
+            Self::ReportError(err) => err.into_api_error(),
+            Self::InternalError(msg) => {
+                tracing::error!("Inbound email service error: {}", msg);
+                ApiError::internal_error("Inbound email service unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for BoardExportServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::BoardNotFound => ApiError::not_found("Board"),
+            Self::ReportError(err) => err.into_api_error(),
+            Self::InternalError(msg) => {
+                tracing::error!("Board export service error: {}", msg);
+                ApiError::internal_error("Board export service unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for BoardImportServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::InvalidArchive(msg) => ApiError::validation(format!("Invalid archive: {}", msg)),
+            Self::IdConflict(what) => ApiError::conflict(format!("{} already exists", what)),
+            Self::StorageError(StorageError::ValidationError(msg)) => ApiError::validation(msg),
+            Self::StorageError(err) => {
+                tracing::error!("Storage error: {}", err);
+                ApiError::internal_error("File storage unavailable")
+            }
+            Self::InternalError(msg) => {
+                tracing::error!("Board import service error: {}", msg);
+                ApiError::internal_error("Board import service unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for MetricsServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::Disabled => ApiError::not_found("Metrics"),
+            Self::InvalidToken => ApiError::Unauthorized,
+            Self::InternalError(msg) => {
+                tracing::error!("Metrics service error: {}", msg);
+                ApiError::internal_error("Metrics unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for BackupServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::ValidationError(msg) => ApiError::validation(msg),
+            Self::BackupInProgress => ApiError::conflict("A backup is already in progress"),
+            Self::BackupFailed(DatabaseBackupError::Unsupported(msg)) => ApiError::validation(msg),
+            Self::BackupFailed(err) => {
+                tracing::error!("Backup failed: {}", err);
+                ApiError::internal_error("Backup failed")
+            }
+            Self::InternalError(msg) => {
+                tracing::error!("Backup service error: {}", msg);
+                ApiError::internal_error("Backup service unavailable")
+            }
+        }
+    }
+}
+
+impl IntoApiError for AuditServiceError {
+    fn into_api_error(self) -> ApiError {
+        match self {
+            Self::ValidationError(msg) => ApiError::validation(msg),
+            Self::InternalError(msg) => {
+                tracing::error!("Audit service error: {}", msg);
+                ApiError::internal_error("Audit log unavailable")
+            }
+        }
+    }
+}
+
 impl From<AuthServiceError> for ApiError {
     fn from(err: AuthServiceError) -> Self {
         err.into_api_error()
     }
 }
 
+impl From<LiveFeedServiceError> for ApiError {
+    fn from(err: LiveFeedServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
 impl From<ReportServiceError> for ApiError {
     fn from(err: ReportServiceError) -> Self {
         err.into_api_error()
@@ -146,3 +393,81 @@ impl From<DashboardServiceError> for ApiError {
         err.into_api_error()
     }
 }
+
+impl From<TwoFactorServiceError> for ApiError {
+    fn from(err: TwoFactorServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<InvitationServiceError> for ApiError {
+    fn from(err: InvitationServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<WebhookServiceError> for ApiError {
+    fn from(err: WebhookServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<ChatNotificationServiceError> for ApiError {
+    fn from(err: ChatNotificationServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<EmailNotificationServiceError> for ApiError {
+    fn from(err: EmailNotificationServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<IssueTrackerServiceError> for ApiError {
+    fn from(err: IssueTrackerServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<IntakeServiceError> for ApiError {
+    fn from(err: IntakeServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<InboundEmailServiceError> for ApiError {
+    fn from(err: InboundEmailServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<BoardExportServiceError> for ApiError {
+    fn from(err: BoardExportServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<BoardImportServiceError> for ApiError {
+    fn from(err: BoardImportServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<MetricsServiceError> for ApiError {
+    fn from(err: MetricsServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<BackupServiceError> for ApiError {
+    fn from(err: BackupServiceError) -> Self {
+        err.into_api_error()
+    }
+}
+
+impl From<AuditServiceError> for ApiError {
+    fn from(err: AuditServiceError) -> Self {
+        err.into_api_error()
+    }
+}
@@ -0,0 +1,37 @@
+use std::time::Instant;
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::Response,
+};
+
+use super::state::AppState;
+
+/// Label of the requests no route matched, served by the frontend fallback.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Middleware counting and timing requests per route template and status.
+pub async fn track_http_metrics(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let started = Instant::now();
+    let method = request.method().clone();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_owned());
+
+    let response = next.run(request).await;
+
+    state.metrics_service().record_http_request(
+        method.as_str(),
+        route.as_deref().unwrap_or(UNMATCHED_ROUTE),
+        response.status().as_u16(),
+        started.elapsed(),
+    );
+
+    response
+}
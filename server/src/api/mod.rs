@@ -1,5 +1,9 @@
+pub mod audit_context;
 pub mod auth;
+pub mod client_ip;
 pub mod error;
+pub mod metrics;
 pub mod models;
+pub mod openapi;
 pub mod routers;
 pub mod state;
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::domain::models::audit::{AuditAction, AuditEventFilter, AuditTargetType};
+
+#[derive(Deserialize, Debug, TS)]
+pub struct AuditEventSearchParams {
+    #[ts(optional)]
+    pub actor_id: Option<Uuid>,
+    #[ts(optional)]
+    pub action: Option<AuditAction>,
+    #[ts(optional)]
+    pub target_type: Option<AuditTargetType>,
+    #[ts(optional)]
+    pub target_id: Option<Uuid>,
+    /// Events at or after this time.
+    #[ts(optional)]
+    pub from: Option<DateTime<Utc>>,
+    /// Events before this time.
+    #[ts(optional)]
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl From<AuditEventSearchParams> for AuditEventFilter {
+    fn from(params: AuditEventSearchParams) -> Self {
+        Self {
+            actor_id: params.actor_id,
+            action: params.action,
+            target_type: params.target_type,
+            target_id: params.target_id,
+            from: params.from,
+            to: params.to,
+        }
+    }
+}
@@ -9,3 +9,12 @@ pub struct LoginRequest {
     pub email: String,
     pub password: String,
 }
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct TwoFactorLoginRequest {
+    #[validate(length(min = 1))]
+    pub challenge_token: String,
+    #[validate(length(min = 6, max = 16))]
+    pub code: String,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use ts_rs::TS;
+
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq, TS)]
+#[serde(rename_all = "lowercase")]
+pub enum BoardExportFormat {
+    /// JSON manifest and media files.
+    #[default]
+    Zip,
+    /// Reports only, for spreadsheets.
+    Csv,
+}
+
+#[derive(Deserialize, Debug, TS)]
+pub struct BoardExportParams {
+    #[serde(default)]
+    #[ts(as = "Option<BoardExportFormat>", optional)]
+    pub format: BoardExportFormat,
+}
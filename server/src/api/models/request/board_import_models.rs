@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use ts_rs::TS;
+
+#[derive(Deserialize, Debug, TS)]
+pub struct BoardImportParams {
+    /// Validates the archive and reports what would be imported, without writing anything.
+    #[serde(default)]
+    #[ts(as = "Option<bool>", optional)]
+    pub dry_run: bool,
+    /// Keeps the board and report IDs of the archive instead of generating new ones.
+    #[serde(default)]
+    #[ts(as = "Option<bool>", optional)]
+    pub preserve_ids: bool,
+}
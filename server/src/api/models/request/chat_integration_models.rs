@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+use crate::domain::models::chat_integration::UpdateChatIntegrationParams;
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct CreateChatIntegrationRequest {
+    #[validate(url)]
+    pub webhook_url: String,
+    /// Uses the default template when omitted.
+    #[validate(length(max = 2000))]
+    pub message_template: Option<String>,
+}
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct UpdateChatIntegrationRequest {
+    #[validate(url)]
+    pub webhook_url: Option<String>,
+    /// An empty template goes back to the default one.
+    #[validate(length(max = 2000))]
+    pub message_template: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+impl From<UpdateChatIntegrationRequest> for UpdateChatIntegrationParams {
+    fn from(request: UpdateChatIntegrationRequest) -> Self {
+        Self {
+            webhook_url: request.webhook_url,
+            message_template: request.message_template.map(Some),
+            is_active: request.is_active,
+        }
+    }
+}
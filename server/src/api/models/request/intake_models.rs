@@ -0,0 +1,66 @@
+use axum::body::Bytes;
+use axum_typed_multipart::{FieldData, TryFromMultipart};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+use crate::domain::models::intake_key::UpdateIntakeKeyParams;
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct CreateIntakeKeyRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    /// Origins of the sites embedding the widget, e.g. `https://staging.example.com`.
+    #[validate(length(min = 1, max = 20))]
+    pub allowed_origins: Vec<String>,
+}
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct UpdateIntakeKeyRequest {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    #[validate(length(min = 1, max = 20))]
+    pub allowed_origins: Option<Vec<String>>,
+    pub is_active: Option<bool>,
+}
+
+impl From<UpdateIntakeKeyRequest> for UpdateIntakeKeyParams {
+    fn from(request: UpdateIntakeKeyRequest) -> Self {
+        Self {
+            name: request.name,
+            allowed_origins: request.allowed_origins,
+            is_active: request.is_active,
+        }
+    }
+}
+
+/// Report sent from a feedback widget. `website` is a honeypot: the widget hides it, so only
+/// bots fill it in.
+#[derive(TryFromMultipart, Validate, TS)]
+pub struct IntakeReportRequestMultipart {
+    #[validate(length(min = 1, max = 255))]
+    pub title: String,
+    #[validate(length(max = 5000))]
+    #[ts(optional)]
+    pub description: Option<String>,
+    #[validate(email)]
+    #[ts(optional)]
+    pub contact_email: Option<String>,
+    #[validate(url)]
+    #[ts(optional)]
+    pub url: Option<String>,
+    #[ts(optional)]
+    pub browser_name: Option<String>,
+    #[ts(optional)]
+    pub browser_version: Option<String>,
+    #[ts(optional)]
+    pub os_name: Option<String>,
+    #[ts(optional)]
+    pub website: Option<String>,
+    #[ts(type = "Blob")]
+    pub file: FieldData<Bytes>,
+    #[ts(optional, type = "Blob")]
+    pub thumbnail: Option<FieldData<Bytes>>,
+}
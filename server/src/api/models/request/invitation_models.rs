@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+use crate::domain::models::user::UserRole;
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct CreateInvitationRequest {
+    #[validate(email)]
+    pub email: String,
+    pub role: UserRole,
+}
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct AcceptInvitationRequest {
+    #[validate(length(min = 1))]
+    pub token: String,
+    #[validate(length(min = 8, message = "Password must be at least 8 characters long"))]
+    pub password: String,
+    #[validate(length(min = 1))]
+    pub first_name: Option<String>,
+    #[validate(length(min = 1))]
+    pub last_name: Option<String>,
+}
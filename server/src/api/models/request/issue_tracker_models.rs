@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+use crate::domain::models::issue_tracker::{ConfigureIssueTrackerParams, IssueTrackerProvider};
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct ConfigureIssueTrackerRequest {
+    pub provider: IssueTrackerProvider,
+    /// Only needed for self-hosted GitHub Enterprise or GitLab instances.
+    #[validate(url)]
+    pub api_base_url: Option<String>,
+    #[validate(length(min = 1, max = 255))]
+    pub repository: String,
+    #[validate(length(min = 1))]
+    pub access_token: String,
+}
+
+impl From<ConfigureIssueTrackerRequest> for ConfigureIssueTrackerParams {
+    fn from(request: ConfigureIssueTrackerRequest) -> Self {
+        Self {
+            provider: request.provider,
+            api_base_url: request.api_base_url,
+            repository: request.repository,
+            access_token: request.access_token,
+        }
+    }
+}
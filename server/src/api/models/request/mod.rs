@@ -1,4 +1,14 @@
+pub mod audit_models;
 pub mod auth_models;
+pub mod board_export_models;
+pub mod board_import_models;
+pub mod chat_integration_models;
+pub mod intake_models;
+pub mod invitation_models;
+pub mod issue_tracker_models;
+pub mod notification_preference_models;
 pub mod pagination_models;
 pub mod report_models;
+pub mod two_factor_models;
 pub mod user_models;
+pub mod webhook_models;
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::domain::models::notification_preference::UpdateNotificationPreferencesParams;
+
+#[derive(Deserialize, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct UpdateNotificationPreferencesRequest {
+    pub email_on_new_report: bool,
+    pub email_on_status_change: bool,
+}
+
+impl From<UpdateNotificationPreferencesRequest> for UpdateNotificationPreferencesParams {
+    fn from(request: UpdateNotificationPreferencesRequest) -> Self {
+        Self {
+            email_on_new_report: request.email_on_new_report,
+            email_on_status_change: request.email_on_status_change,
+        }
+    }
+}
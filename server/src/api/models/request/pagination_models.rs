@@ -1,16 +1,19 @@
 use serde::Deserialize;
+use ts_rs::TS;
 use validator::Validate;
 
 const DEFAULT_PAGE: i32 = 1;
 const DEFAULT_PER_PAGE: i32 = 20;
 const MAX_PER_PAGE: i32 = 100;
 
-#[derive(Deserialize, Validate, Debug)]
+#[derive(Deserialize, Validate, Debug, TS)]
 pub struct PaginationParams {
     #[serde(default = "default_page")]
+    #[ts(as = "Option<i32>", optional)]
     #[validate(range(min = 1))]
     pub page: i32,
     #[serde(default = "default_per_page")]
+    #[ts(as = "Option<i32>", optional)]
     #[validate(range(min = 1, max = "MAX_PER_PAGE"))]
     pub per_page: i32,
 }
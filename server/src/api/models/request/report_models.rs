@@ -1,19 +1,27 @@
 use axum::body::Bytes;
 use axum_typed_multipart::{FieldData, TryFromMultipart};
+use ts_rs::TS;
 use uuid::Uuid;
 use validator::Validate;
 
-#[derive(TryFromMultipart, Validate)]
+#[derive(TryFromMultipart, Validate, TS)]
 pub struct CreateReportRequestMultipart {
     pub board_id: Uuid,
     #[validate(length(min = 1, max = 255))]
     pub title: String,
+    #[ts(optional)]
     pub description: Option<String>,
     #[validate(url)]
+    #[ts(optional)]
     pub url: Option<String>,
+    #[ts(optional)]
     pub browser_name: Option<String>,
+    #[ts(optional)]
     pub browser_version: Option<String>,
+    #[ts(optional)]
     pub os_name: Option<String>,
+    #[ts(type = "Blob")]
     pub file: FieldData<Bytes>,
+    #[ts(optional, type = "Blob")]
     pub thumbnail: Option<FieldData<Bytes>>,
 }
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct TwoFactorCodeRequest {
+    #[validate(length(min = 6, max = 16))]
+    pub code: String,
+}
+
+#[derive(Deserialize, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct UpdateSecuritySettingsRequest {
+    pub require_admin_two_factor: bool,
+}
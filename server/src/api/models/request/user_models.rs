@@ -1,5 +1,8 @@
+use axum::body::Bytes;
+use axum_typed_multipart::{FieldData, TryFromMultipart};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
+use uuid::Uuid;
 use validator::Validate;
 
 use crate::domain::models::user::UserRole;
@@ -17,3 +20,51 @@ pub struct CreateUserRequest {
     pub last_name: Option<String>,
     pub role: UserRole,
 }
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct UpdateProfileRequest {
+    #[validate(email)]
+    pub email: Option<String>,
+    #[validate(length(min = 1))]
+    pub first_name: Option<String>,
+    #[validate(length(min = 1))]
+    pub last_name: Option<String>,
+    /// Required when `email` changes.
+    pub current_password: Option<String>,
+}
+
+#[derive(TryFromMultipart, TS)]
+pub struct UpdateAvatarRequestMultipart {
+    #[ts(type = "Blob")]
+    pub file: FieldData<Bytes>,
+}
+
+#[derive(Deserialize, Validate, Debug, TS)]
+pub struct UserSearchParams {
+    #[validate(length(max = 100))]
+    #[ts(optional)]
+    pub search: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct UpdateUserRoleRequest {
+    pub role: UserRole,
+}
+
+#[derive(Deserialize, Debug, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum UserDeletionMode {
+    Reassign,
+    Anonymize,
+}
+
+#[derive(Deserialize, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct DeleteUserParams {
+    pub mode: UserDeletionMode,
+    /// Required when `mode` is `reassign`.
+    pub reassign_to: Option<Uuid>,
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use validator::Validate;
+
+use crate::domain::models::{event::EventType, webhook::UpdateWebhookParams};
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct CreateWebhookRequest {
+    #[validate(url)]
+    pub url: String,
+    /// Generated when omitted.
+    #[validate(length(min = 16, message = "Secret must be at least 16 characters long"))]
+    pub secret: Option<String>,
+    #[validate(length(min = 1, message = "At least one event type is required"))]
+    pub event_types: Vec<EventType>,
+}
+
+#[derive(Deserialize, Validate, Debug, Serialize, TS)]
+#[ts(export)]
+pub struct UpdateWebhookRequest {
+    #[validate(url)]
+    pub url: Option<String>,
+    #[validate(length(min = 16, message = "Secret must be at least 16 characters long"))]
+    pub secret: Option<String>,
+    #[validate(length(min = 1, message = "At least one event type is required"))]
+    pub event_types: Option<Vec<EventType>>,
+    pub is_active: Option<bool>,
+}
+
+impl From<UpdateWebhookRequest> for UpdateWebhookParams {
+    fn from(request: UpdateWebhookRequest) -> Self {
+        Self {
+            url: request.url,
+            secret: request.secret,
+            event_types: request.event_types,
+            is_active: request.is_active,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::domain::models::audit::{AuditAction, AuditEvent, AuditTargetType};
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct AuditEventResponse {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub action: AuditAction,
+    pub target_type: AuditTargetType,
+    pub target_id: Option<Uuid>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub changes: serde_json::Value,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<AuditEvent> for AuditEventResponse {
+    fn from(event: AuditEvent) -> Self {
+        Self {
+            id: event.id,
+            actor_id: event.actor_id,
+            action: event.action,
+            target_type: event.target_type,
+            target_id: event.target_id,
+            ip_address: event.ip_address,
+            user_agent: event.user_agent,
+            changes: event.changes,
+            created_at: event.created_at,
+        }
+    }
+}
@@ -9,4 +9,12 @@ pub struct LoginResponse {
     pub access_token: String,
     pub token_type: String,
     pub user: UserResponse,
+    pub two_factor_setup_required: bool,
+}
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct TwoFactorChallengeResponse {
+    pub challenge_token: String,
+    pub expires_in: i64,
 }
@@ -0,0 +1,26 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::domain::models::backup::Backup;
+
+/// Snapshot in the backup directory of the server. Archives also hold the uploaded files.
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct BackupResponse {
+    pub file_name: String,
+    #[ts(type = "number")]
+    pub size_bytes: u64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub includes_uploads: bool,
+}
+
+impl From<Backup> for BackupResponse {
+    fn from(backup: Backup) -> Self {
+        Self {
+            file_name: backup.file_name,
+            size_bytes: backup.size_bytes,
+            created_at: backup.created_at,
+            includes_uploads: backup.includes_uploads,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::domain::models::board_export::BoardImportSummary;
+
+/// Reporters and history actors are matched to existing users by email. Unmatched reporters are
+/// imported as anonymous reports, with their email kept as contact.
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct BoardImportResponse {
+    pub dry_run: bool,
+    pub board_id: Uuid,
+    pub board_name: String,
+    pub report_count: usize,
+    pub media_file_count: usize,
+    pub history_entry_count: usize,
+    pub matched_user_emails: Vec<String>,
+    pub unmatched_user_emails: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl From<BoardImportSummary> for BoardImportResponse {
+    fn from(summary: BoardImportSummary) -> Self {
+        Self {
+            dry_run: summary.dry_run,
+            board_id: summary.board_id,
+            board_name: summary.board_name,
+            report_count: summary.report_count,
+            media_file_count: summary.media_file_count,
+            history_entry_count: summary.history_entry_count,
+            matched_user_emails: summary.matched_user_emails,
+            unmatched_user_emails: summary.unmatched_user_emails,
+            warnings: summary.warnings,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::domain::models::chat_integration::ChatIntegration;
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct ChatIntegrationResponse {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub webhook_url: String,
+    pub message_template: Option<String>,
+    pub is_active: bool,
+    pub created_by: Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ChatIntegration> for ChatIntegrationResponse {
+    fn from(integration: ChatIntegration) -> Self {
+        Self {
+            id: integration.id,
+            board_id: integration.board_id,
+            webhook_url: integration.webhook_url,
+            message_template: integration.message_template,
+            is_active: integration.is_active,
+            created_by: integration.created_by,
+            created_at: integration.created_at,
+            updated_at: integration.updated_at,
+        }
+    }
+}
@@ -1,9 +1,43 @@
 use serde::Serialize;
 use ts_rs::TS;
 
+use crate::domain::models::health::{ComponentHealth, HealthCheck, HealthStatus};
+
 #[derive(Serialize, TS)]
 #[ts(export)]
 pub struct HealthResponse {
-    pub status: String,
+    pub status: HealthStatus,
     pub message: String,
+    /// Checked dependencies, empty for the liveness check.
+    pub components: Vec<ComponentHealthResponse>,
+}
+
+#[derive(Serialize, TS)]
+#[ts(export)]
+pub struct ComponentHealthResponse {
+    pub name: String,
+    pub status: HealthStatus,
+    pub latency_ms: f64,
+    pub message: Option<String>,
+}
+
+impl From<HealthCheck> for HealthResponse {
+    fn from(check: HealthCheck) -> Self {
+        Self {
+            status: check.status,
+            message: check.message,
+            components: check.components.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<ComponentHealth> for ComponentHealthResponse {
+    fn from(component: ComponentHealth) -> Self {
+        Self {
+            name: component.name.to_string(),
+            status: component.status,
+            latency_ms: component.latency.as_secs_f64() * 1000.0,
+            message: component.message,
+        }
+    }
 }
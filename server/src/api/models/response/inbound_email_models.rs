@@ -0,0 +1,36 @@
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{config::app_config::APP_CONFIG, domain::models::email::InboundMailbox};
+
+/// Reports can be emailed to `address`, or posted as raw `.eml` files to `upload_url`.
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct InboundMailboxResponse {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub address: String,
+    pub upload_url: String,
+    pub created_by: Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<InboundMailbox> for InboundMailboxResponse {
+    fn from(mailbox: InboundMailbox) -> Self {
+        Self {
+            id: mailbox.id,
+            board_id: mailbox.board_id,
+            address: format!("{}@{}", mailbox.token, APP_CONFIG.inbound_email_domain),
+            upload_url: format!(
+                "{}/api/inbound-email/{}",
+                APP_CONFIG.public_base_url.trim_end_matches('/'),
+                mailbox.token
+            ),
+            created_by: mailbox.created_by,
+            created_at: mailbox.created_at,
+            updated_at: mailbox.updated_at,
+        }
+    }
+}
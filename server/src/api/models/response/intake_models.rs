@@ -0,0 +1,42 @@
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{config::app_config::APP_CONFIG, domain::models::intake_key::IntakeKey};
+
+/// The public key is meant to be embedded in the widget, along with the submission URL.
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct IntakeKeyResponse {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub name: String,
+    pub public_key: String,
+    pub allowed_origins: Vec<String>,
+    pub submission_url: String,
+    pub is_active: bool,
+    pub created_by: Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<IntakeKey> for IntakeKeyResponse {
+    fn from(intake_key: IntakeKey) -> Self {
+        Self {
+            id: intake_key.id,
+            board_id: intake_key.board_id,
+            name: intake_key.name,
+            submission_url: format!(
+                "{}/api/intake/{}/reports",
+                APP_CONFIG.public_base_url.trim_end_matches('/'),
+                intake_key.public_key
+            ),
+            public_key: intake_key.public_key,
+            allowed_origins: intake_key.allowed_origins,
+            is_active: intake_key.is_active,
+            created_by: intake_key.created_by,
+            created_at: intake_key.created_at,
+            updated_at: intake_key.updated_at,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::domain::models::{
+    invitation::{Invitation, IssuedInvitation},
+    user::UserRole,
+};
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct InvitationResponse {
+    pub id: Uuid,
+    pub email: String,
+    pub role: UserRole,
+    pub invited_by: Uuid,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Invitation> for InvitationResponse {
+    fn from(invitation: Invitation) -> Self {
+        Self {
+            id: invitation.id,
+            email: invitation.email,
+            role: invitation.role,
+            invited_by: invitation.invited_by,
+            expires_at: invitation.expires_at,
+            created_at: invitation.created_at,
+        }
+    }
+}
+
+/// Returned once on creation: the token cannot be retrieved afterwards.
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct IssuedInvitationResponse {
+    pub invitation: InvitationResponse,
+    pub token: String,
+}
+
+impl From<IssuedInvitation> for IssuedInvitationResponse {
+    fn from(issued: IssuedInvitation) -> Self {
+        Self {
+            invitation: issued.invitation.into(),
+            token: issued.token,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::models::issue_tracker::{IssueTrackerIntegration, IssueTrackerProvider},
+};
+
+/// The access token is write-only and never returned. The webhook URL and secret are to be
+/// set on the tracker side to sync issue state back to the reports.
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct IssueTrackerIntegrationResponse {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub provider: IssueTrackerProvider,
+    pub api_base_url: String,
+    pub repository: String,
+    pub webhook_url: String,
+    pub webhook_secret: String,
+    pub created_by: Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<IssueTrackerIntegration> for IssueTrackerIntegrationResponse {
+    fn from(integration: IssueTrackerIntegration) -> Self {
+        Self {
+            id: integration.id,
+            board_id: integration.board_id,
+            provider: integration.provider,
+            api_base_url: integration.api_base_url,
+            repository: integration.repository,
+            webhook_url: format!(
+                "{}/api/issue-tracker/webhooks/{}",
+                APP_CONFIG.public_base_url.trim_end_matches('/'),
+                integration.id
+            ),
+            webhook_secret: integration.webhook_secret,
+            created_by: integration.created_by,
+            created_at: integration.created_at,
+            updated_at: integration.updated_at,
+        }
+    }
+}
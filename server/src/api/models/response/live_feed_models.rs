@@ -0,0 +1,32 @@
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::domain::models::event::EventType;
+
+use super::report_models::ReportResponse;
+
+/// Data of a `report.*` event on the live feed.
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct LiveFeedEventResponse {
+    pub id: Uuid,
+    pub event: EventType,
+    pub board_id: Uuid,
+    pub occurred_at: chrono::DateTime<chrono::Utc>,
+    pub report: ReportResponse,
+}
+
+/// Data of a `lagged` event: events were dropped and the client should refetch.
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct LiveFeedLaggedResponse {
+    pub skipped: u64,
+}
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct StreamTicketResponse {
+    pub ticket: String,
+    pub expires_in: i64,
+}
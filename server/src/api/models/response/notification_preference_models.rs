@@ -0,0 +1,23 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::domain::models::notification_preference::NotificationPreferences;
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct NotificationPreferencesResponse {
+    pub email_on_new_report: bool,
+    pub email_on_status_change: bool,
+    /// Unset until the user changes the defaults.
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl From<NotificationPreferences> for NotificationPreferencesResponse {
+    fn from(preferences: NotificationPreferences) -> Self {
+        Self {
+            email_on_new_report: preferences.email_on_new_report,
+            email_on_status_change: preferences.email_on_status_change,
+            updated_at: preferences.updated_at,
+        }
+    }
+}
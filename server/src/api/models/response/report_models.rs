@@ -2,7 +2,9 @@ use serde::Serialize;
 use ts_rs::TS;
 use uuid::Uuid;
 
-use crate::domain::models::report::{Report, ReportType};
+use crate::domain::models::report::{
+    Report, ReportChangeSource, ReportHistoryEntry, ReportStatus, ReportType,
+};
 
 #[derive(Serialize, Debug, TS)]
 #[ts(export)]
@@ -11,6 +13,7 @@ pub struct ReportResponse {
     pub board_id: Uuid,
     pub title: String,
     pub report_type: ReportType,
+    pub status: ReportStatus,
     pub description: Option<String>,
     pub file_path: String,
     pub thumbnail_file_path: Option<String>,
@@ -18,6 +21,9 @@ pub struct ReportResponse {
     pub browser_name: Option<String>,
     pub browser_version: Option<String>,
     pub os_name: Option<String>,
+    pub contact_email: Option<String>,
+    pub external_issue_key: Option<String>,
+    pub external_issue_url: Option<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -29,6 +35,7 @@ impl From<Report> for ReportResponse {
             board_id: report.board_id,
             title: report.title,
             report_type: report.report_type,
+            status: report.status,
             description: report.description,
             file_path: report.file_path,
             thumbnail_file_path: report.thumbnail_file_path,
@@ -36,8 +43,39 @@ impl From<Report> for ReportResponse {
             browser_name: report.browser_name,
             browser_version: report.browser_version,
             os_name: report.os_name,
+            contact_email: report.contact_email,
+            external_issue_key: report.external_issue_key,
+            external_issue_url: report.external_issue_url,
             created_at: report.created_at,
             updated_at: report.updated_at,
         }
     }
 }
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct ReportHistoryEntryResponse {
+    pub id: Uuid,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub source: ReportChangeSource,
+    pub actor_id: Option<Uuid>,
+    pub details: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ReportHistoryEntry> for ReportHistoryEntryResponse {
+    fn from(entry: ReportHistoryEntry) -> Self {
+        Self {
+            id: entry.id,
+            field: entry.field,
+            old_value: entry.old_value,
+            new_value: entry.new_value,
+            source: entry.source,
+            actor_id: entry.actor_id,
+            details: entry.details,
+            created_at: entry.created_at,
+        }
+    }
+}
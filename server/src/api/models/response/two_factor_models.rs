@@ -0,0 +1,56 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::domain::models::two_factor::{SecuritySettings, TwoFactorEnrollment, TwoFactorStatus};
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct TwoFactorStatusResponse {
+    pub enabled: bool,
+    pub recovery_codes_remaining: usize,
+}
+
+impl From<TwoFactorStatus> for TwoFactorStatusResponse {
+    fn from(status: TwoFactorStatus) -> Self {
+        Self {
+            enabled: status.enabled,
+            recovery_codes_remaining: status.recovery_codes_remaining,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct TwoFactorEnrollmentResponse {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+impl From<TwoFactorEnrollment> for TwoFactorEnrollmentResponse {
+    fn from(enrollment: TwoFactorEnrollment) -> Self {
+        Self {
+            secret: enrollment.secret,
+            provisioning_uri: enrollment.provisioning_uri,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct RecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct SecuritySettingsResponse {
+    pub require_admin_two_factor: bool,
+}
+
+impl From<SecuritySettings> for SecuritySettingsResponse {
+    fn from(settings: SecuritySettings) -> Self {
+        Self {
+            require_admin_two_factor: settings.require_admin_two_factor,
+        }
+    }
+}
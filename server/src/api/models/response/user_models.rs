@@ -11,7 +11,11 @@ pub struct UserResponse {
     pub email: String,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
+    pub avatar_url: Option<String>,
     pub role: UserRole,
+    pub two_factor_enabled: bool,
+    pub is_active: bool,
+    pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
 impl From<User> for UserResponse {
@@ -21,7 +25,11 @@ impl From<User> for UserResponse {
             email: user.email,
             first_name: user.first_name,
             last_name: user.last_name,
+            avatar_url: user.avatar_url,
             role: user.role,
+            two_factor_enabled: user.two_factor_enabled,
+            is_active: user.is_active,
+            created_at: user.created_at,
         }
     }
 }
@@ -0,0 +1,91 @@
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::domain::models::{
+    event::EventType,
+    webhook::{Webhook, WebhookDelivery, WebhookDeliveryStatus},
+};
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct WebhookResponse {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub url: String,
+    pub event_types: Vec<EventType>,
+    pub is_active: bool,
+    pub created_by: Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<Webhook> for WebhookResponse {
+    fn from(webhook: Webhook) -> Self {
+        Self {
+            id: webhook.id,
+            board_id: webhook.board_id,
+            url: webhook.url,
+            event_types: webhook.event_types,
+            is_active: webhook.is_active,
+            created_by: webhook.created_by,
+            created_at: webhook.created_at,
+            updated_at: webhook.updated_at,
+        }
+    }
+}
+
+/// Returned once on creation: the secret is not exposed afterwards.
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct CreatedWebhookResponse {
+    pub webhook: WebhookResponse,
+    pub secret: String,
+}
+
+impl From<Webhook> for CreatedWebhookResponse {
+    fn from(webhook: Webhook) -> Self {
+        let secret = webhook.secret.clone();
+        Self {
+            webhook: webhook.into(),
+            secret,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, TS)]
+#[ts(export)]
+pub struct WebhookDeliveryResponse {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: EventType,
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    /// When the next attempt is due, while the delivery is pending.
+    pub next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryResponse {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            webhook_id: delivery.webhook_id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type,
+            payload: delivery.payload,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error,
+            next_attempt_at: delivery.next_attempt_at,
+            created_at: delivery.created_at,
+            updated_at: delivery.updated_at,
+        }
+    }
+}
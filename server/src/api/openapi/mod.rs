@@ -0,0 +1,229 @@
+use axum::http::StatusCode;
+use serde_json::{Map, Value, json};
+
+use super::error::error_response::ApiErrorResponse;
+
+pub mod operation;
+pub mod ts_schema;
+
+use operation::{ApiAccess, ApiOperation};
+use ts_schema::SchemaRegistry;
+
+pub const API_BASE_PATH: &str = "/api";
+const BEARER_AUTH_SCHEME: &str = "bearerAuth";
+
+/// Standalone page rendering the OpenAPI document, served at `/api/docs`.
+pub const DOCS_PAGE: &str = include_str!("docs.html");
+
+/// Builds the OpenAPI 3.1 document describing the given operations.
+pub fn build_openapi_document(operations: Vec<ApiOperation>) -> Value {
+    let mut registry = SchemaRegistry::new();
+    let error_schema = registry.schema_of::<ApiErrorResponse>();
+
+    let mut paths = Map::new();
+    let mut tags: Vec<&str> = Vec::new();
+    for operation in operations {
+        if !tags.contains(&operation.tag) {
+            tags.push(operation.tag);
+        }
+
+        let method = operation.method;
+        let path_item = paths.entry(operation.path).or_insert_with(|| json!({}));
+        path_item[method] = describe_operation(&mut registry, &error_schema, operation);
+    }
+    tags.sort_unstable();
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "rebug API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Bug reports with screenshots and screen recordings, organized in boards. \
+                Authenticate with `POST /auth/login` and send the access token as a bearer token.",
+        },
+        "servers": [{ "url": API_BASE_PATH }],
+        "tags": tags.iter().map(|tag| json!({ "name": tag })).collect::<Vec<_>>(),
+        "paths": paths,
+        "components": {
+            "schemas": registry.into_schemas(),
+            "securitySchemes": {
+                BEARER_AUTH_SCHEME: {
+                    "type": "http",
+                    "scheme": "bearer",
+                    "bearerFormat": "JWT",
+                },
+            },
+        },
+    })
+}
+
+fn describe_operation(
+    registry: &mut SchemaRegistry,
+    error_schema: &Value,
+    operation: ApiOperation,
+) -> Value {
+    let path_parameters: Vec<&str> = operation
+        .path
+        .split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+        .collect();
+
+    let mut parameters: Vec<Value> = path_parameters
+        .iter()
+        .map(|name| {
+            let schema = if name.ends_with("_id") {
+                json!({ "type": "string", "format": "uuid" })
+            } else {
+                json!({ "type": "string" })
+            };
+            json!({ "name": name, "in": "path", "required": true, "schema": schema })
+        })
+        .collect();
+
+    for query in &operation.queries {
+        let query = query(registry);
+        let required = query["required"].as_array().cloned().unwrap_or_default();
+        if let Some(properties) = query["properties"].as_object() {
+            for (name, schema) in properties {
+                parameters.push(json!({
+                    "name": name,
+                    "in": "query",
+                    "required": required.contains(&json!(name)),
+                    "schema": schema,
+                }));
+            }
+        }
+    }
+
+    let mut responses = Map::new();
+    for response in operation.responses {
+        // Responses sharing a status, in different formats, are merged.
+        let described = responses
+            .entry(response.status.as_u16().to_string())
+            .or_insert_with(|| json!({ "description": response.description }));
+        if let Some(content) = response.content {
+            described["content"][content.content_type] =
+                json!({ "schema": (content.schema)(registry) });
+        }
+    }
+
+    let mut errors = operation.errors;
+    if !operation.queries.is_empty() || operation.request_body.is_some() || !parameters.is_empty() {
+        errors.push(StatusCode::BAD_REQUEST);
+    }
+    if operation.access != ApiAccess::Public {
+        errors.push(StatusCode::UNAUTHORIZED);
+    }
+    if operation.access == ApiAccess::Admin {
+        errors.push(StatusCode::FORBIDDEN);
+    }
+    if !path_parameters.is_empty() {
+        errors.push(StatusCode::NOT_FOUND);
+    }
+    errors.push(StatusCode::INTERNAL_SERVER_ERROR);
+    for status in errors {
+        responses.insert(
+            status.as_u16().to_string(),
+            json!({
+                "description": status.canonical_reason().unwrap_or("Error"),
+                "content": { "application/json": { "schema": error_schema } },
+            }),
+        );
+    }
+
+    let mut described = json!({
+        "operationId": operation_id(operation.method, operation.path),
+        "summary": operation.summary,
+        "tags": [operation.tag],
+        "parameters": parameters,
+        "responses": responses,
+    });
+    if let Some(description) = operation.description {
+        described["description"] = json!(description);
+    }
+    if let Some(body) = operation.request_body {
+        described["requestBody"] = json!({
+            "required": true,
+            "content": { body.content_type: { "schema": (body.schema)(registry) } },
+        });
+    }
+    if operation.access != ApiAccess::Public {
+        described["security"] = json!([{ BEARER_AUTH_SCHEME: [] }]);
+    }
+
+    described
+}
+
+/// `get /boards/{board_id}/reports` becomes `get_boards_by_board_id_reports`.
+fn operation_id(method: &str, path: &str) -> String {
+    let mut id = method.to_string();
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        id.push('_');
+        match segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            Some(parameter) => {
+                id.push_str("by_");
+                id.push_str(parameter);
+            }
+            None => id.push_str(&segment.replace('-', "_")),
+        }
+    }
+    id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::routers::get_api_route_docs;
+
+    fn collect_references<'a>(value: &'a Value, references: &mut Vec<&'a str>) {
+        match value {
+            Value::Object(object) => {
+                if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+                    references.push(reference);
+                }
+                object
+                    .values()
+                    .for_each(|value| collect_references(value, references));
+            }
+            Value::Array(values) => values
+                .iter()
+                .for_each(|value| collect_references(value, references)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn every_reference_resolves() {
+        let document = build_openapi_document(get_api_route_docs());
+        let schemas = document["components"]["schemas"].as_object().unwrap();
+
+        let mut references = Vec::new();
+        collect_references(&document, &mut references);
+
+        assert!(!references.is_empty());
+        for reference in references {
+            let name = reference.strip_prefix("#/components/schemas/").unwrap();
+            assert!(schemas.contains_key(name), "unresolved {}", reference);
+        }
+    }
+
+    #[test]
+    fn every_declared_type_is_described() {
+        let document = build_openapi_document(get_api_route_docs());
+
+        for (name, schema) in document["components"]["schemas"].as_object().unwrap() {
+            let described = schema
+                .as_object()
+                .is_some_and(|schema| schema.keys().any(|key| key != "description"));
+            assert!(described, "{} could not be parsed", name);
+        }
+    }
+
+    #[test]
+    fn derives_operation_ids_from_paths() {
+        assert_eq!(
+            operation_id("get", "/boards/{board_id}/intake-keys"),
+            "get_boards_by_board_id_intake_keys"
+        );
+    }
+}
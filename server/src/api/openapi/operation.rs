@@ -0,0 +1,195 @@
+use axum::http::StatusCode;
+use serde_json::Value;
+use ts_rs::TS;
+
+use super::ts_schema::SchemaRegistry;
+
+type SchemaFn = fn(&mut SchemaRegistry) -> Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiAccess {
+    Public,
+    /// Bearer token of any active user.
+    User,
+    /// Bearer token of an admin.
+    Admin,
+}
+
+pub(super) struct ApiContent {
+    pub(super) content_type: &'static str,
+    pub(super) schema: SchemaFn,
+}
+
+pub(super) struct ApiResponse {
+    pub(super) status: StatusCode,
+    pub(super) description: &'static str,
+    pub(super) content: Option<ApiContent>,
+}
+
+/// Description of an API route for the OpenAPI document. Each router declares the operations of
+/// its routes next to them; request and response schemas come from their ts-rs declarations.
+pub struct ApiOperation {
+    pub(super) method: &'static str,
+    pub(super) path: &'static str,
+    pub(super) summary: &'static str,
+    pub(super) description: Option<&'static str>,
+    pub(super) tag: &'static str,
+    pub(super) access: ApiAccess,
+    pub(super) queries: Vec<SchemaFn>,
+    pub(super) request_body: Option<ApiContent>,
+    pub(super) responses: Vec<ApiResponse>,
+    pub(super) errors: Vec<StatusCode>,
+}
+
+impl ApiOperation {
+    fn new(method: &'static str, path: &'static str, summary: &'static str) -> Self {
+        Self {
+            method,
+            path,
+            summary,
+            description: None,
+            tag: "",
+            access: ApiAccess::User,
+            queries: Vec::new(),
+            request_body: None,
+            responses: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// `path` is relative to `/api`, with axum's `{param}` placeholders.
+    pub fn get(path: &'static str, summary: &'static str) -> Self {
+        Self::new("get", path, summary)
+    }
+
+    pub fn post(path: &'static str, summary: &'static str) -> Self {
+        Self::new("post", path, summary)
+    }
+
+    pub fn put(path: &'static str, summary: &'static str) -> Self {
+        Self::new("put", path, summary)
+    }
+
+    pub fn patch(path: &'static str, summary: &'static str) -> Self {
+        Self::new("patch", path, summary)
+    }
+
+    pub fn delete(path: &'static str, summary: &'static str) -> Self {
+        Self::new("delete", path, summary)
+    }
+
+    pub fn method(&self) -> &'static str {
+        self.method
+    }
+
+    pub fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// Sets the tag of all the operations of a router.
+    pub fn group(tag: &'static str, operations: Vec<ApiOperation>) -> Vec<ApiOperation> {
+        operations
+            .into_iter()
+            .map(|operation| ApiOperation { tag, ..operation })
+            .collect()
+    }
+
+    pub fn description(mut self, description: &'static str) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn public(mut self) -> Self {
+        self.access = ApiAccess::Public;
+        self
+    }
+
+    pub fn admin(mut self) -> Self {
+        self.access = ApiAccess::Admin;
+        self
+    }
+
+    /// Query string parameters, one per field of `T`.
+    pub fn query<T: TS + 'static>(mut self) -> Self {
+        self.queries
+            .push(|registry| registry.inline_schema_of::<T>());
+        self
+    }
+
+    pub fn json_body<T: TS + 'static>(mut self) -> Self {
+        self.request_body = Some(ApiContent {
+            content_type: "application/json",
+            schema: |registry| registry.schema_of::<T>(),
+        });
+        self
+    }
+
+    /// `multipart/form-data` body, one part per field of `T`.
+    pub fn multipart_body<T: TS + 'static>(mut self) -> Self {
+        self.request_body = Some(ApiContent {
+            content_type: "multipart/form-data",
+            schema: |registry| registry.inline_schema_of::<T>(),
+        });
+        self
+    }
+
+    pub fn binary_body(mut self, content_type: &'static str) -> Self {
+        self.request_body = Some(ApiContent {
+            content_type,
+            schema: binary_schema,
+        });
+        self
+    }
+
+    pub fn json_response<T: TS + 'static>(
+        mut self,
+        status: StatusCode,
+        description: &'static str,
+    ) -> Self {
+        self.responses.push(ApiResponse {
+            status,
+            description,
+            content: Some(ApiContent {
+                content_type: "application/json",
+                schema: |registry| registry.schema_of::<T>(),
+            }),
+        });
+        self
+    }
+
+    pub fn binary_response(
+        mut self,
+        status: StatusCode,
+        content_type: &'static str,
+        description: &'static str,
+    ) -> Self {
+        self.responses.push(ApiResponse {
+            status,
+            description,
+            content: Some(ApiContent {
+                content_type,
+                schema: binary_schema,
+            }),
+        });
+        self
+    }
+
+    pub fn empty_response(mut self, status: StatusCode, description: &'static str) -> Self {
+        self.responses.push(ApiResponse {
+            status,
+            description,
+            content: None,
+        });
+        self
+    }
+
+    /// Error status beyond the ones implied by the access, parameters and body of the route.
+    pub fn error(mut self, status: StatusCode) -> Self {
+        self.errors.push(status);
+        self
+    }
+}
+
+fn binary_schema(_: &mut SchemaRegistry) -> Value {
+    serde_json::json!({ "type": "string", "contentMediaType": "application/octet-stream" })
+}
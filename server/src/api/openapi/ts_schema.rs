@@ -0,0 +1,601 @@
+use std::{
+    any::TypeId,
+    collections::{BTreeMap, HashSet},
+};
+
+use serde_json::{Map, Value, json};
+use ts_rs::{TS, TypeVisitor};
+
+/// TypeScript type overridden with `#[ts(type = "Blob")]`, for uploaded files.
+const BLOB_TYPE: &str = "Blob";
+
+/// JSON Schemas of the API models, read from the TypeScript declarations ts-rs generates for
+/// them, so that the OpenAPI document and the frontend types cannot drift apart.
+#[derive(Default)]
+pub struct SchemaRegistry {
+    schemas: BTreeMap<String, Value>,
+    /// Dependencies are visited through wrappers too, so recursive types need this to end.
+    visited: HashSet<TypeId>,
+}
+
+impl SchemaRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Schema of `T`: a reference to `#/components/schemas` for declared types, registered along
+    /// with their dependencies, or an inline schema for the others.
+    pub fn schema_of<T: TS + 'static + ?Sized>(&mut self) -> Value {
+        self.register::<T>();
+        parse_or_any(&T::name())
+    }
+
+    /// Object schema of `T`, inlined, for query strings and multipart forms.
+    pub fn inline_schema_of<T: TS + 'static + ?Sized>(&mut self) -> Value {
+        self.register_dependencies::<T>();
+        parse_or_any(&T::inline())
+    }
+
+    pub fn into_schemas(self) -> Map<String, Value> {
+        self.schemas.into_iter().collect()
+    }
+
+    fn register<T: TS + 'static + ?Sized>(&mut self) {
+        if !self.visited.insert(TypeId::of::<T>()) {
+            return;
+        }
+
+        if T::output_path().is_some() {
+            let mut schema = parse_or_any(&T::inline());
+            if let (Some(docs), Some(schema)) = (T::DOCS, schema.as_object_mut()) {
+                schema.insert("description".to_string(), json!(clean_doc_comment(docs)));
+            }
+            self.schemas.insert(component_id(&T::name()), schema);
+        }
+
+        self.register_dependencies::<T>();
+    }
+
+    fn register_dependencies<T: TS + 'static + ?Sized>(&mut self) {
+        struct Visitor<'a>(&'a mut SchemaRegistry);
+
+        impl TypeVisitor for Visitor<'_> {
+            fn visit<T: TS + 'static + ?Sized>(&mut self) {
+                self.0.register::<T>();
+            }
+        }
+
+        T::visit_dependencies(&mut Visitor(self));
+        T::visit_generics(&mut Visitor(self));
+    }
+}
+
+/// `PaginatedResponse<ReportResponse>` becomes `PaginatedResponse_ReportResponse`.
+fn component_id(type_name: &str) -> String {
+    let mut id = String::new();
+    for c in type_name.chars().filter(|c| !c.is_whitespace()) {
+        match c {
+            c if c.is_ascii_alphanumeric() || c == '_' => id.push(c),
+            '>' => {}
+            _ => id.push('_'),
+        }
+    }
+    id
+}
+
+fn parse_or_any(type_expression: &str) -> Value {
+    TypeParser::new(type_expression)
+        .parse()
+        .unwrap_or_else(|message| {
+            tracing::warn!(
+                "Cannot describe TypeScript type '{}': {}",
+                type_expression,
+                message
+            );
+            json!({})
+        })
+}
+
+/// Removes the `/** */` markers and the leading `*` of each line.
+fn clean_doc_comment(comment: &str) -> String {
+    comment
+        .trim()
+        .trim_start_matches("/**")
+        .trim_end_matches("*/")
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Reads the subset of TypeScript ts-rs writes: primitives, literals, object literals, unions,
+/// intersections, tuples, `Array<T>`, `{ [key in K]?: V }` and references to declared types.
+struct TypeParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+type ParseResult<T> = Result<T, String>;
+
+impl<'a> TypeParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn parse(mut self) -> ParseResult<Value> {
+        let schema = self.parse_type()?;
+        self.skip_whitespace();
+        if self.position < self.input.len() {
+            return Err(format!("unexpected input at {}", self.position));
+        }
+        Ok(schema)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Skips whitespace and comments, returning the last doc comment met.
+    fn skip_whitespace(&mut self) -> Option<String> {
+        let mut doc_comment = None;
+        loop {
+            let trimmed = self.rest().trim_start();
+            self.position = self.input.len() - trimmed.len();
+
+            if trimmed.starts_with("/*") {
+                let end = trimmed.find("*/").map_or(trimmed.len(), |end| end + 2);
+                doc_comment = Some(clean_doc_comment(&trimmed[..end]));
+                self.position += end;
+            } else if trimmed.starts_with("//") {
+                self.position += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else {
+                return doc_comment;
+            }
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> ParseResult<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(format!("expected '{}' at {}", token, self.position))
+        }
+    }
+
+    fn parse_type(&mut self) -> ParseResult<Value> {
+        self.eat("|");
+        let mut members = vec![self.parse_intersection()?];
+        while self.eat("|") {
+            members.push(self.parse_intersection()?);
+        }
+        Ok(union_schema(members))
+    }
+
+    fn parse_intersection(&mut self) -> ParseResult<Value> {
+        let mut members = vec![self.parse_postfix()?];
+        while self.eat("&") {
+            members.push(self.parse_postfix()?);
+        }
+        Ok(match members.len() {
+            1 => members.remove(0),
+            _ => json!({ "allOf": members }),
+        })
+    }
+
+    fn parse_postfix(&mut self) -> ParseResult<Value> {
+        let mut schema = self.parse_primary()?;
+        while self.eat("[]") {
+            schema = json!({ "type": "array", "items": schema });
+        }
+        Ok(schema)
+    }
+
+    fn parse_primary(&mut self) -> ParseResult<Value> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_tuple(),
+            Some('(') => {
+                self.expect("(")?;
+                let schema = self.parse_type()?;
+                self.expect(")")?;
+                Ok(schema)
+            }
+            Some('"') | Some('\'') => Ok(json!({ "const": self.parse_string()? })),
+            Some(c) if c.is_ascii_digit() || c == '-' => {
+                let literal = self.take_while(|c| c.is_ascii_digit() || "-.eE+".contains(c));
+                let number: serde_json::Number = literal
+                    .parse()
+                    .map_err(|_| format!("invalid number '{}'", literal))?;
+                Ok(json!({ "const": number }))
+            }
+            Some(c) if is_identifier_char(c) => self.parse_named(),
+            _ => Err(format!("unexpected input at {}", self.position)),
+        }
+    }
+
+    fn parse_named(&mut self) -> ParseResult<Value> {
+        let start = self.position;
+        let name = self.take_while(is_identifier_char);
+        let arguments = if self.eat("<") {
+            let mut arguments = vec![self.parse_type()?];
+            while self.eat(",") {
+                arguments.push(self.parse_type()?);
+            }
+            self.expect(">")?;
+            arguments
+        } else {
+            Vec::new()
+        };
+
+        Ok(match (name, arguments.as_slice()) {
+            ("string", []) => json!({ "type": "string" }),
+            ("number", []) => json!({ "type": "number" }),
+            ("bigint", []) => json!({ "type": "integer" }),
+            ("boolean", []) => json!({ "type": "boolean" }),
+            ("null", []) => json!({ "type": "null" }),
+            ("unknown" | "any", []) => json!({}),
+            ("true" | "false", []) => json!({ "const": name == "true" }),
+            (BLOB_TYPE, []) => {
+                json!({ "type": "string", "contentMediaType": "application/octet-stream" })
+            }
+            ("Array", [items]) => json!({ "type": "array", "items": items }),
+            ("Record", [_, values]) => {
+                json!({ "type": "object", "additionalProperties": values })
+            }
+            _ => {
+                let reference = &self.input[start..self.position];
+                json!({ "$ref": format!("#/components/schemas/{}", component_id(reference)) })
+            }
+        })
+    }
+
+    fn parse_object(&mut self) -> ParseResult<Value> {
+        self.expect("{")?;
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+
+        loop {
+            let description = self.skip_whitespace();
+            if self.eat("}") {
+                break;
+            }
+
+            if self.eat("[") {
+                // `{ [key in K]?: V }`, as written for maps.
+                self.take_while(|c| c != ']');
+                self.expect("]")?;
+                self.eat("?");
+                self.expect(":")?;
+                let values = self.parse_type()?;
+                self.eat(",");
+                self.eat(";");
+                self.expect("}")?;
+                return Ok(json!({ "type": "object", "additionalProperties": values }));
+            }
+
+            let key = match self.peek() {
+                Some('"') | Some('\'') => self.parse_string()?,
+                _ => {
+                    let key = self.take_while(is_identifier_char);
+                    if key.is_empty() {
+                        return Err(format!("expected a property at {}", self.position));
+                    }
+                    key.to_string()
+                }
+            };
+            let optional = self.eat("?");
+            self.expect(":")?;
+            let mut schema = self.parse_type()?;
+            if let (Some(description), Some(schema)) = (description, schema.as_object_mut()) {
+                schema.insert("description".to_string(), json!(description));
+            }
+            if !optional {
+                required.push(json!(key));
+            }
+            properties.insert(key, schema);
+
+            if !self.eat(",") && !self.eat(";") {
+                self.expect("}")?;
+                break;
+            }
+        }
+
+        let mut schema = json!({ "type": "object", "properties": properties });
+        if !required.is_empty() {
+            schema["required"] = json!(required);
+        }
+        Ok(schema)
+    }
+
+    fn parse_tuple(&mut self) -> ParseResult<Value> {
+        self.expect("[")?;
+        let mut items = Vec::new();
+        if !self.eat("]") {
+            loop {
+                items.push(self.parse_type()?);
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            self.expect("]")?;
+        }
+
+        let length = items.len();
+        Ok(json!({
+            "type": "array",
+            "prefixItems": items,
+            "minItems": length,
+            "maxItems": length,
+        }))
+    }
+
+    fn parse_string(&mut self) -> ParseResult<String> {
+        self.skip_whitespace();
+        let quote = self.peek().ok_or("unexpected end of input")?;
+        self.position += quote.len_utf8();
+
+        let mut value = String::new();
+        let mut chars = self.rest().char_indices();
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    if let Some((_, escaped)) = chars.next() {
+                        value.push(escaped);
+                    }
+                }
+                c if c == quote => {
+                    self.position += index + c.len_utf8();
+                    return Ok(value);
+                }
+                c => value.push(c),
+            }
+        }
+        Err("unterminated string literal".to_string())
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// String literal unions become enums, and `T | null` a nullable `T`.
+fn union_schema(members: Vec<Value>) -> Value {
+    let is_null = |schema: &Value| schema == &json!({ "type": "null" });
+    let nullable = members.iter().any(is_null);
+    let mut members: Vec<Value> = members.into_iter().filter(|m| !is_null(m)).collect();
+
+    let constants: Option<Vec<Value>> = members
+        .iter()
+        .map(|member| {
+            let object = member.as_object()?;
+            (object.len() == 1).then(|| object.get("const").cloned())?
+        })
+        .collect();
+
+    let schema = match (members.len(), constants) {
+        (0, _) => return json!({ "type": "null" }),
+        (1, _) => members.remove(0),
+        (_, Some(constants)) if constants.iter().all(Value::is_string) => {
+            json!({ "type": "string", "enum": constants })
+        }
+        _ => json!({ "anyOf": members }),
+    };
+    if !nullable {
+        return schema;
+    }
+
+    match schema.get("type").and_then(Value::as_str) {
+        Some(single_type) if schema.get("$ref").is_none() => {
+            let mut schema = schema.clone();
+            schema["type"] = json!([single_type, "null"]);
+            if let Some(values) = schema.get_mut("enum").and_then(Value::as_array_mut) {
+                values.push(Value::Null);
+            }
+            schema
+        }
+        _ => json!({ "anyOf": [schema, { "type": "null" }] }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(type_expression: &str) -> Value {
+        TypeParser::new(type_expression).parse().unwrap()
+    }
+
+    #[test]
+    fn parses_primitives_and_literals() {
+        assert_eq!(parse("string"), json!({ "type": "string" }));
+        assert_eq!(parse("bigint"), json!({ "type": "integer" }));
+        assert_eq!(parse("unknown"), json!({}));
+        assert_eq!(parse("true"), json!({ "const": true }));
+        assert_eq!(parse("-1.5e3"), json!({ "const": -1500.0 }));
+        assert_eq!(parse("'it\\'s'"), json!({ "const": "it's" }));
+        assert_eq!(
+            parse("Blob"),
+            json!({ "type": "string", "contentMediaType": "application/octet-stream" })
+        );
+    }
+
+    #[test]
+    fn parses_unions() {
+        assert_eq!(
+            parse("\"open\" | \"closed\""),
+            json!({ "type": "string", "enum": ["open", "closed"] })
+        );
+        assert_eq!(
+            parse("| \"open\" | \"closed\" | null"),
+            json!({ "type": ["string", "null"], "enum": ["open", "closed", null] })
+        );
+        assert_eq!(
+            parse("number | null"),
+            json!({ "type": ["number", "null"] })
+        );
+        assert_eq!(
+            parse("Board | null"),
+            json!({ "anyOf": [{ "$ref": "#/components/schemas/Board" }, { "type": "null" }] })
+        );
+        assert_eq!(
+            parse("string | number"),
+            json!({ "anyOf": [{ "type": "string" }, { "type": "number" }] })
+        );
+    }
+
+    #[test]
+    fn parses_objects() {
+        let schema = parse(
+            "{ id: string, /**\n * Shown on the board.\n */\n\"display-name\"?: string | null; \
+             count: number, }",
+        );
+
+        assert_eq!(
+            schema,
+            json!({
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string" },
+                    "display-name": {
+                        "type": ["string", "null"],
+                        "description": "Shown on the board.",
+                    },
+                    "count": { "type": "number" },
+                },
+                "required": ["id", "count"],
+            })
+        );
+        assert_eq!(parse("{ }"), json!({ "type": "object", "properties": {} }));
+    }
+
+    #[test]
+    fn parses_collections() {
+        let strings = json!({ "type": "array", "items": { "type": "string" } });
+        assert_eq!(parse("Array<string>"), strings);
+        assert_eq!(parse("string[]"), strings);
+        assert_eq!(
+            parse("(string | null)[][]"),
+            json!({
+                "type": "array",
+                "items": { "type": "array", "items": { "type": ["string", "null"] } },
+            })
+        );
+        assert_eq!(
+            parse("{ [key in string]?: number }"),
+            json!({ "type": "object", "additionalProperties": { "type": "number" } })
+        );
+        assert_eq!(
+            parse("Record<string, boolean>"),
+            json!({ "type": "object", "additionalProperties": { "type": "boolean" } })
+        );
+        assert_eq!(
+            parse("[string, number]"),
+            json!({
+                "type": "array",
+                "prefixItems": [{ "type": "string" }, { "type": "number" }],
+                "minItems": 2,
+                "maxItems": 2,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_references_and_intersections() {
+        assert_eq!(
+            parse("PaginatedResponse<ReportResponse>"),
+            json!({ "$ref": "#/components/schemas/PaginatedResponse_ReportResponse" })
+        );
+        assert_eq!(
+            parse("Base & { extra: boolean }"),
+            json!({
+                "allOf": [
+                    { "$ref": "#/components/schemas/Base" },
+                    {
+                        "type": "object",
+                        "properties": { "extra": { "type": "boolean" } },
+                        "required": ["extra"],
+                    },
+                ],
+            })
+        );
+        assert_eq!(
+            component_id("Map<string, Array<Board>>"),
+            "Map_string_Array_Board"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_types() {
+        let cases = [
+            "",
+            "{ id: string",
+            "{ id string }",
+            "{ : string }",
+            "Array<string",
+            "[string,",
+            "\"unterminated",
+            "string string",
+            "(string",
+            "| ",
+            "12abc",
+            "{ [key in string]: number, other: string }",
+        ];
+
+        for case in cases {
+            assert!(TypeParser::new(case).parse().is_err(), "{:?}", case);
+        }
+        assert_eq!(parse_or_any("{ id: "), json!({}));
+    }
+
+    #[test]
+    fn cleans_doc_comments() {
+        assert_eq!(
+            clean_doc_comment("/**\n * First line.\n *\n * Second line.\n */"),
+            "First line. Second line."
+        );
+    }
+
+    #[test]
+    fn registers_declared_types_and_their_dependencies() {
+        use crate::api::models::response::{
+            pagination::PaginatedResponse, report_models::ReportResponse,
+        };
+
+        let mut registry = SchemaRegistry::new();
+        let schema = registry.schema_of::<PaginatedResponse<ReportResponse>>();
+        let schemas = registry.into_schemas();
+
+        assert_eq!(
+            schema,
+            json!({ "$ref": "#/components/schemas/PaginatedResponse_ReportResponse" })
+        );
+        assert!(schemas.contains_key("ReportResponse"));
+        assert_eq!(
+            schemas["PaginatedResponse_ReportResponse"]["properties"]["items"]["items"],
+            json!({ "$ref": "#/components/schemas/ReportResponse" })
+        );
+    }
+}
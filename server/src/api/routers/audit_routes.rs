@@ -0,0 +1,114 @@
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use tracing::instrument;
+use validator::Validate;
+
+use crate::api::{
+    auth::AuthenticatedAdmin,
+    error::ApiError,
+    models::{
+        request::{audit_models::AuditEventSearchParams, pagination_models::PaginationParams},
+        response::{audit_models::AuditEventResponse, pagination::PaginatedResponse},
+    },
+    openapi::operation::ApiOperation,
+    state::AppState,
+};
+
+pub fn audit_routes() -> Router<AppState> {
+    let audit_routes = Router::new()
+        .route("/", get(list_audit_events_handler))
+        .route("/export", get(export_audit_events_handler));
+
+    Router::new().nest("/audit-events", audit_routes)
+}
+
+pub fn audit_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Audit log",
+        vec![
+            ApiOperation::get("/audit-events", "List the audit events")
+                .description("Most recent events first.")
+                .admin()
+                .query::<PaginationParams>()
+                .query::<AuditEventSearchParams>()
+                .json_response::<PaginatedResponse<AuditEventResponse>>(
+                    StatusCode::OK,
+                    "Audit events",
+                ),
+            ApiOperation::get("/audit-events/export", "Export the audit events")
+                .description("Every matching event, oldest first, as one JSON object per line.")
+                .admin()
+                .query::<AuditEventSearchParams>()
+                .binary_response(StatusCode::OK, "application/x-ndjson", "Audit events"),
+        ],
+    )
+}
+
+#[instrument(skip(state, authenticated_admin, pagination, search_params), fields(admin_id = %authenticated_admin.id), level = "debug")]
+async fn list_audit_events_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+    Query(pagination): Query<PaginationParams>,
+    Query(search_params): Query<AuditEventSearchParams>,
+) -> Result<Json<PaginatedResponse<AuditEventResponse>>, ApiError> {
+    pagination.validate().map_err(|e| {
+        tracing::warn!("Pagination validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    let (events, total_items) = state
+        .audit_service()
+        .list_events(search_params.into(), pagination.page, pagination.per_page)
+        .await?;
+
+    Ok(Json(PaginatedResponse::new(
+        events.into_iter().map(AuditEventResponse::from).collect(),
+        pagination.page,
+        pagination.per_page,
+        total_items,
+    )))
+}
+
+/// Downloads the matching events as JSON lines, for archiving or for a log pipeline.
+#[instrument(skip(state, authenticated_admin, search_params), fields(admin_id = %authenticated_admin.id), level = "debug")]
+async fn export_audit_events_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+    Query(search_params): Query<AuditEventSearchParams>,
+) -> Result<Response, ApiError> {
+    let events = state
+        .audit_service()
+        .export_events(search_params.into())
+        .await?;
+
+    let mut body = String::new();
+    for event in events {
+        let line = serde_json::to_string(&AuditEventResponse::from(event)).map_err(|e| {
+            tracing::error!("Failed to serialize an audit event: {}", e);
+            ApiError::internal_error("Failed to export the audit log")
+        })?;
+        body.push_str(&line);
+        body.push('\n');
+    }
+
+    let file_name = format!(
+        "audit-events-{}.jsonl",
+        chrono::Utc::now().format("%Y%m%d%H%M%S")
+    );
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/x-ndjson".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", file_name),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -1,38 +1,139 @@
-use axum::{Json, Router, extract::State, routing::post};
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+};
 use tracing::instrument;
 use validator::Validate;
 
-use crate::api::{
-    error::ApiError, models::request::auth_models::LoginRequest,
-    models::response::auth_models::LoginResponse, state::AppState,
+use crate::{
+    api::{
+        error::ApiError,
+        models::{
+            request::auth_models::{LoginRequest, TwoFactorLoginRequest},
+            response::auth_models::{LoginResponse, TwoFactorChallengeResponse},
+        },
+        openapi::operation::ApiOperation,
+        state::AppState,
+    },
+    config::app_config::APP_CONFIG,
+    domain::models::{audit::AuditContext, auth::LoginOutcome},
 };
 
 pub fn auth_routes() -> Router<AppState> {
-    Router::new().route("/auth/login", post(login_handler))
+    Router::new()
+        .route("/auth/login", post(login_handler))
+        .route("/auth/login/two-factor", post(two_factor_login_handler))
+}
+
+pub fn auth_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Authentication",
+        vec![
+            ApiOperation::post("/auth/login", "Log in with an email and a password")
+                .public()
+                .description(
+                    "Responds with a token, or with a challenge when a second factor is needed.",
+                )
+                .json_body::<LoginRequest>()
+                .json_response::<LoginResponse>(StatusCode::OK, "Logged in")
+                .json_response::<TwoFactorChallengeResponse>(
+                    StatusCode::ACCEPTED,
+                    "Second factor required",
+                )
+                .error(StatusCode::UNAUTHORIZED),
+            ApiOperation::post(
+                "/auth/login/two-factor",
+                "Complete a login with a second factor",
+            )
+            .public()
+            .description(
+                "A challenge only allows a few attempts, after which the login must be started \
+                 again. Too many attempts on an account are refused for a while.",
+            )
+            .json_body::<TwoFactorLoginRequest>()
+            .json_response::<LoginResponse>(StatusCode::OK, "Logged in")
+            .error(StatusCode::UNAUTHORIZED)
+            .error(StatusCode::TOO_MANY_REQUESTS),
+        ],
+    )
 }
 
-#[instrument(skip(state, payload), fields(email = %payload.email), level = "debug")]
+/// Responds with `200 OK` and a token, or `202 Accepted` and a challenge when a second factor is needed.
+#[instrument(skip(state, audit_context, payload), fields(email = %payload.email), level = "debug")]
 async fn login_handler(
     State(state): State<AppState>,
+    audit_context: AuditContext,
     Json(payload): Json<LoginRequest>,
-) -> Result<Json<LoginResponse>, ApiError> {
+) -> Result<Response, ApiError> {
     tracing::debug!("Attempting login.");
     payload.validate().map_err(|e| {
         tracing::warn!("Login validation failed: {}", e);
         ApiError::validation(e.to_string())
     })?;
 
+    let outcome = state
+        .auth_service()
+        .login_user(&payload.email, &payload.password, &audit_context)
+        .await?;
+
+    match outcome {
+        LoginOutcome::Authenticated {
+            user,
+            access_token,
+            two_factor_setup_required,
+        } => {
+            let response = LoginResponse {
+                access_token,
+                token_type: "Bearer".to_string(),
+                user: user.clone().into(),
+                two_factor_setup_required,
+            };
+
+            tracing::info!(user_id = %user.id, "User logged in successfully.");
+            Ok((StatusCode::OK, Json(response)).into_response())
+        }
+        LoginOutcome::TwoFactorRequired {
+            user,
+            challenge_token,
+        } => {
+            let response = TwoFactorChallengeResponse {
+                challenge_token,
+                expires_in: APP_CONFIG.two_factor_challenge_expiration_seconds,
+            };
+
+            tracing::info!(user_id = %user.id, "Password accepted, awaiting two-factor code.");
+            Ok((StatusCode::ACCEPTED, Json(response)).into_response())
+        }
+    }
+}
+
+#[instrument(skip(state, audit_context, payload), level = "debug")]
+async fn two_factor_login_handler(
+    State(state): State<AppState>,
+    audit_context: AuditContext,
+    Json(payload): Json<TwoFactorLoginRequest>,
+) -> Result<Json<LoginResponse>, ApiError> {
+    tracing::debug!("Attempting two-factor login.");
+    payload.validate().map_err(|e| {
+        tracing::warn!("Two-factor login validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
     let (user, token) = state
         .auth_service()
-        .login_user(&payload.email, &payload.password)
+        .complete_two_factor_login(&payload.challenge_token, &payload.code, &audit_context)
         .await?;
 
     let response = LoginResponse {
         access_token: token,
         token_type: "Bearer".to_string(),
         user: user.clone().into(),
+        two_factor_setup_required: false,
     };
 
-    tracing::info!(user_id = %user.id, "User logged in successfully.");
+    tracing::info!(user_id = %user.id, "User logged in successfully with two-factor.");
     Ok(Json(response))
 }
@@ -0,0 +1,55 @@
+use axum::{Json, Router, extract::State, http::StatusCode, routing::get};
+use tracing::instrument;
+
+use crate::api::{
+    auth::AuthenticatedAdmin, error::ApiError, models::response::backup_models::BackupResponse,
+    openapi::operation::ApiOperation, state::AppState,
+};
+
+pub fn backup_routes() -> Router<AppState> {
+    let backup_routes =
+        Router::new().route("/", get(list_backups_handler).post(create_backup_handler));
+
+    Router::new().nest("/backups", backup_routes)
+}
+
+pub fn backup_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Backups",
+        vec![
+            ApiOperation::get("/backups", "List the backups")
+                .description("Snapshots in the backup directory of the server, newest first.")
+                .admin()
+                .json_response::<Vec<BackupResponse>>(StatusCode::OK, "Backups"),
+            ApiOperation::post("/backups", "Take a backup")
+                .description(
+                    "Snapshots the live database, with the uploaded files when the server is \
+                     configured to, then deletes the snapshots beyond the retention.",
+                )
+                .admin()
+                .json_response::<BackupResponse>(StatusCode::CREATED, "Backup")
+                .error(StatusCode::CONFLICT),
+        ],
+    )
+}
+
+#[instrument(skip(state, authenticated_admin), fields(admin_id = %authenticated_admin.id), level = "debug")]
+async fn list_backups_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+) -> Result<Json<Vec<BackupResponse>>, ApiError> {
+    let backups = state.backup_service().list_snapshots().await?;
+
+    Ok(Json(backups.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(skip(state, authenticated_admin), fields(admin_id = %authenticated_admin.id), level = "debug")]
+async fn create_backup_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+) -> Result<(StatusCode, Json<BackupResponse>), ApiError> {
+    let backup = state.backup_service().create_snapshot().await?;
+
+    tracing::info!(admin_id = %authenticated_admin.id, file_name = %backup.file_name, "Backup taken by an admin.");
+    Ok((StatusCode::CREATED, Json(backup.into())))
+}
@@ -0,0 +1,86 @@
+use axum::{
+    Router,
+    body::Body,
+    extract::{Path, Query, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::api::{
+    auth::AuthenticatedUser,
+    error::ApiError,
+    models::request::board_export_models::{BoardExportFormat, BoardExportParams},
+    openapi::operation::ApiOperation,
+    state::AppState,
+};
+
+pub fn board_export_routes() -> Router<AppState> {
+    Router::new().route("/boards/{board_id}/export", get(export_board_handler))
+}
+
+pub fn board_export_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Boards",
+        vec![
+        ApiOperation::get("/boards/{board_id}/export", "Export a board")
+            .description(
+                "ZIP archive with a `manifest.json` and the media files, streamed as it is built, \
+                or CSV file of the reports.",
+            )
+            .query::<BoardExportParams>()
+            .binary_response(StatusCode::OK, "application/zip", "Board archive")
+            .binary_response(StatusCode::OK, "text/csv", "Board reports")
+            .error(StatusCode::FORBIDDEN),
+        ],
+    )
+}
+
+/// Downloads the board as a ZIP archive (`?format=zip`, the default) or a CSV file
+/// (`?format=csv`). The archive is streamed as it is built.
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn export_board_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+    Query(params): Query<BoardExportParams>,
+) -> Result<Response, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let board_export_service = state.board_export_service();
+    let (content_type, file_name, body) = match params.format {
+        BoardExportFormat::Zip => {
+            let export = board_export_service.export_archive(board_id).await?;
+            (
+                "application/zip",
+                export.file_name,
+                Body::from_stream(export.content),
+            )
+        }
+        BoardExportFormat::Csv => {
+            let export = board_export_service.export_csv(board_id).await?;
+            (
+                "text/csv; charset=utf-8",
+                export.file_name,
+                Body::from(export.content),
+            )
+        }
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", file_name),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -0,0 +1,82 @@
+use axum::{
+    Json, Router,
+    body::Bytes,
+    extract::{DefaultBodyLimit, Query, State},
+    http::StatusCode,
+    routing::post,
+};
+use tracing::instrument;
+
+use crate::{
+    api::{
+        auth::AuthenticatedAdmin,
+        error::ApiError,
+        models::{
+            request::board_import_models::BoardImportParams,
+            response::board_import_models::BoardImportResponse,
+        },
+        openapi::operation::ApiOperation,
+        state::AppState,
+    },
+    config::app_config::APP_CONFIG,
+    domain::models::{audit::AuditContext, board_export::BoardImportOptions},
+};
+
+pub fn board_import_routes() -> Router<AppState> {
+    Router::new().route(
+        "/boards/import",
+        post(import_board_handler).layer(DefaultBodyLimit::max(
+            (APP_CONFIG.import_max_archive_size_mb * 1024 * 1024) as usize,
+        )),
+    )
+}
+
+pub fn board_import_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Boards",
+        vec![
+        ApiOperation::post("/boards/import", "Import a board export archive")
+            .admin()
+            .description(
+                "Imports the archive as a new board owned by the admin. Reporters are matched to \
+                existing users by email. With `dry_run` the archive is only validated.",
+            )
+            .query::<BoardImportParams>()
+            .binary_body("application/zip")
+            .json_response::<BoardImportResponse>(StatusCode::OK, "Dry run")
+            .json_response::<BoardImportResponse>(StatusCode::CREATED, "Board imported")
+            .error(StatusCode::CONFLICT),
+        ],
+    )
+}
+
+/// Imports a board export archive, sent as the raw request body, as a new board owned by the
+/// admin. With `?dry_run=true` nothing is written.
+#[instrument(skip(state, authenticated_admin, audit_context, archive), fields(admin_id = %authenticated_admin.id, dry_run = params.dry_run), level = "debug")]
+async fn import_board_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+    audit_context: AuditContext,
+    Query(params): Query<BoardImportParams>,
+    archive: Bytes,
+) -> Result<(StatusCode, Json<BoardImportResponse>), ApiError> {
+    let summary = state
+        .board_import_service()
+        .import_archive(
+            archive,
+            BoardImportOptions {
+                owner_id: authenticated_admin.id,
+                preserve_ids: params.preserve_ids,
+                dry_run: params.dry_run,
+            },
+            &audit_context.with_actor(authenticated_admin.id),
+        )
+        .await?;
+
+    let status = if summary.dry_run {
+        StatusCode::OK
+    } else {
+        StatusCode::CREATED
+    };
+    Ok((status, Json(summary.into())))
+}
@@ -1,6 +1,7 @@
 use axum::{
     Json, Router,
     extract::{Path, Query, State},
+    http::StatusCode,
     routing::get,
 };
 use tracing::instrument;
@@ -17,6 +18,7 @@ use crate::api::{
             report_models::ReportResponse,
         },
     },
+    openapi::operation::ApiOperation,
     state::AppState,
 };
 
@@ -29,6 +31,23 @@ pub fn board_routes() -> Router<AppState> {
     Router::new().nest("/boards", board_routes)
 }
 
+pub fn board_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Boards",
+        vec![
+            ApiOperation::get("/boards", "List the boards of the user")
+                .json_response::<Vec<BoardResponse>>(StatusCode::OK, "Boards"),
+            ApiOperation::get("/boards/{board_id}", "Get a board")
+                .json_response::<BoardResponse>(StatusCode::OK, "Board")
+                .error(StatusCode::FORBIDDEN),
+            ApiOperation::get("/boards/{board_id}/reports", "List the reports of a board")
+                .query::<PaginationParams>()
+                .json_response::<PaginatedResponse<ReportResponse>>(StatusCode::OK, "Reports")
+                .error(StatusCode::FORBIDDEN),
+        ],
+    )
+}
+
 #[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id), level = "debug")]
 async fn get_all_boards_handler(
     State(state): State<AppState>,
@@ -0,0 +1,228 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, post},
+};
+use tracing::instrument;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::api::{
+    auth::AuthenticatedUser,
+    error::ApiError,
+    models::{
+        request::chat_integration_models::{
+            CreateChatIntegrationRequest, UpdateChatIntegrationRequest,
+        },
+        response::chat_integration_models::ChatIntegrationResponse,
+    },
+    openapi::operation::ApiOperation,
+    state::AppState,
+};
+
+pub fn chat_integration_routes() -> Router<AppState> {
+    let chat_integration_routes = Router::new()
+        .route(
+            "/",
+            get(list_chat_integrations_handler).post(create_chat_integration_handler),
+        )
+        .route(
+            "/{integration_id}",
+            get(get_chat_integration_handler)
+                .patch(update_chat_integration_handler)
+                .delete(delete_chat_integration_handler),
+        )
+        .route(
+            "/{integration_id}/test",
+            post(test_chat_integration_handler),
+        );
+
+    Router::new().nest(
+        "/boards/{board_id}/chat-integrations",
+        chat_integration_routes,
+    )
+}
+
+pub fn chat_integration_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Chat integrations",
+        vec![
+            ApiOperation::get(
+                "/boards/{board_id}/chat-integrations",
+                "List the chat integrations of a board",
+            )
+            .json_response::<Vec<ChatIntegrationResponse>>(StatusCode::OK, "Chat integrations")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::post(
+                "/boards/{board_id}/chat-integrations",
+                "Add a chat integration",
+            )
+            .json_body::<CreateChatIntegrationRequest>()
+            .json_response::<ChatIntegrationResponse>(StatusCode::CREATED, "Chat integration")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::get(
+                "/boards/{board_id}/chat-integrations/{integration_id}",
+                "Get a chat integration",
+            )
+            .json_response::<ChatIntegrationResponse>(StatusCode::OK, "Chat integration")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::patch(
+                "/boards/{board_id}/chat-integrations/{integration_id}",
+                "Update a chat integration",
+            )
+            .json_body::<UpdateChatIntegrationRequest>()
+            .json_response::<ChatIntegrationResponse>(StatusCode::OK, "Chat integration")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::delete(
+                "/boards/{board_id}/chat-integrations/{integration_id}",
+                "Delete a chat integration",
+            )
+            .empty_response(StatusCode::NO_CONTENT, "Chat integration deleted")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::post(
+                "/boards/{board_id}/chat-integrations/{integration_id}/test",
+                "Send a test message",
+            )
+            .empty_response(StatusCode::NO_CONTENT, "Message sent")
+            .error(StatusCode::FORBIDDEN)
+            .error(StatusCode::BAD_GATEWAY),
+        ],
+    )
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn create_chat_integration_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+    Json(payload): Json<CreateChatIntegrationRequest>,
+) -> Result<(StatusCode, Json<ChatIntegrationResponse>), ApiError> {
+    payload.validate().map_err(|e| {
+        tracing::warn!("Chat integration validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let integration = state
+        .chat_notification_service()
+        .create_integration(
+            board_id,
+            authenticated_user.id,
+            &payload.webhook_url,
+            payload.message_template,
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(integration.into())))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn list_chat_integrations_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+) -> Result<Json<Vec<ChatIntegrationResponse>>, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let integrations = state
+        .chat_notification_service()
+        .list_integrations(board_id)
+        .await?;
+
+    Ok(Json(
+        integrations
+            .into_iter()
+            .map(ChatIntegrationResponse::from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id, integration_id = %integration_id), level = "debug")]
+async fn get_chat_integration_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path((board_id, integration_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ChatIntegrationResponse>, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let integration = state
+        .chat_notification_service()
+        .get_integration(board_id, integration_id)
+        .await?;
+
+    Ok(Json(integration.into()))
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id, board_id = %board_id, integration_id = %integration_id), level = "debug")]
+async fn update_chat_integration_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path((board_id, integration_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpdateChatIntegrationRequest>,
+) -> Result<Json<ChatIntegrationResponse>, ApiError> {
+    payload.validate().map_err(|e| {
+        tracing::warn!("Chat integration update validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let integration = state
+        .chat_notification_service()
+        .update_integration(board_id, integration_id, payload.into())
+        .await?;
+
+    Ok(Json(integration.into()))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id, integration_id = %integration_id), level = "debug")]
+async fn delete_chat_integration_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path((board_id, integration_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    state
+        .chat_notification_service()
+        .delete_integration(board_id, integration_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id, integration_id = %integration_id), level = "debug")]
+async fn test_chat_integration_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path((board_id, integration_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    state
+        .chat_notification_service()
+        .send_test_message(board_id, integration_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
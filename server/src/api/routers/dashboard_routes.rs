@@ -1,10 +1,11 @@
-use axum::{Json, Router, extract::State, routing::get};
+use axum::{Json, Router, extract::State, http::StatusCode, routing::get};
 use tracing::instrument;
 
 use crate::api::{
     auth::AuthenticatedUser,
     error::ApiError,
     models::response::dashboard_models::{BoardWithRecentReports, DashboardResponse},
+    openapi::operation::ApiOperation,
     state::AppState,
 };
 
@@ -12,6 +13,16 @@ pub fn dashboard_routes() -> Router<AppState> {
     Router::new().route("/dashboard", get(get_dashboard_handler))
 }
 
+pub fn dashboard_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Dashboard",
+        vec![
+            ApiOperation::get("/dashboard", "Get the dashboard of the user")
+                .json_response::<DashboardResponse>(StatusCode::OK, "Dashboard"),
+        ],
+    )
+}
+
 #[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id), level = "debug")]
 async fn get_dashboard_handler(
     State(state): State<AppState>,
@@ -1,31 +1,55 @@
 use axum::{Json, Router, extract::State, http::StatusCode, routing::get};
 use tracing::instrument;
 
-use crate::api::{
-    error::ApiError, models::response::health_models::HealthResponse, state::AppState,
+use crate::{
+    api::{
+        models::response::health_models::HealthResponse, openapi::operation::ApiOperation,
+        state::AppState,
+    },
+    domain::models::health::HealthStatus,
 };
 
 pub fn health_routes() -> Router<AppState> {
-    Router::new().route("/health", get(health_check_handler))
+    Router::new()
+        .route("/health/live", get(liveness_handler))
+        .route("/health/ready", get(readiness_handler))
+}
+
+pub fn health_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Health",
+        vec![
+            ApiOperation::get("/health/live", "Check the service is running")
+                .public()
+                .json_response::<HealthResponse>(StatusCode::OK, "Service running"),
+            ApiOperation::get("/health/ready", "Check the service can handle requests")
+                .description(
+                    "Checks the database connection, the migrations, the storage writability \
+                     and the free disk space.",
+                )
+                .public()
+                .json_response::<HealthResponse>(StatusCode::OK, "Service ready")
+                .json_response::<HealthResponse>(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    "A dependency is unavailable",
+                ),
+        ],
+    )
+}
+
+#[instrument(skip(state), level = "debug")]
+async fn liveness_handler(State(state): State<AppState>) -> Json<HealthResponse> {
+    Json(state.health_service().liveness().into())
 }
 
 #[instrument(skip(state), level = "debug")]
-async fn health_check_handler(
-    State(state): State<AppState>,
-) -> Result<(StatusCode, Json<HealthResponse>), ApiError> {
-    let health_check = state.health_service().health_check();
+async fn readiness_handler(State(state): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
+    let check = state.health_service().readiness().await;
+
+    let status_code = match check.status {
+        HealthStatus::Up => StatusCode::OK,
+        HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+    };
 
-    match health_check {
-        Ok(check) => Ok((
-            StatusCode::OK,
-            Json(HealthResponse {
-                status: check.status,
-                message: check.message,
-            }),
-        )),
-        Err(e) => {
-            tracing::error!("Health check failed: {}", e);
-            Err(ApiError::internal_error(e.to_string()))
-        }
-    }
+    (status_code, Json(check.into()))
 }
@@ -0,0 +1,140 @@
+use axum::{
+    Json, Router,
+    body::Bytes,
+    extract::{DefaultBodyLimit, Path, State},
+    http::StatusCode,
+    routing::{get, post},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        auth::AuthenticatedUser, error::ApiError,
+        models::response::inbound_email_models::InboundMailboxResponse,
+        openapi::operation::ApiOperation, state::AppState,
+    },
+    config::app_config::APP_CONFIG,
+};
+
+pub fn inbound_email_routes() -> Router<AppState> {
+    let inbound_mailbox_routes = Router::new().route(
+        "/",
+        get(get_inbound_mailbox_handler)
+            .put(configure_inbound_mailbox_handler)
+            .delete(delete_inbound_mailbox_handler),
+    );
+
+    Router::new()
+        .nest("/boards/{board_id}/inbound-email", inbound_mailbox_routes)
+        .route(
+            "/inbound-email/{token}",
+            post(upload_inbound_email_handler).layer(DefaultBodyLimit::max(
+                (APP_CONFIG.max_body_size_mb * 1024 * 1024) as usize,
+            )),
+        )
+}
+
+pub fn inbound_email_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Inbound email",
+        vec![
+            ApiOperation::get(
+                "/boards/{board_id}/inbound-email",
+                "Get the email address of a board",
+            )
+            .json_response::<InboundMailboxResponse>(StatusCode::OK, "Board address")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::put(
+                "/boards/{board_id}/inbound-email",
+                "Create or replace the email address of a board",
+            )
+            .json_response::<InboundMailboxResponse>(StatusCode::OK, "Board address")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::delete(
+                "/boards/{board_id}/inbound-email",
+                "Remove the email address of a board",
+            )
+            .empty_response(StatusCode::NO_CONTENT, "Board address removed")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::post("/inbound-email/{token}", "Upload an email as a report")
+                .public()
+                .description("Raw `.eml` upload. The token in the path is the only credential.")
+                .binary_body("message/rfc822")
+                .empty_response(StatusCode::ACCEPTED, "Email accepted"),
+        ],
+    )
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn get_inbound_mailbox_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+) -> Result<Json<InboundMailboxResponse>, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let mailbox = state.inbound_email_service().get_mailbox(board_id).await?;
+
+    Ok(Json(mailbox.into()))
+}
+
+/// Creates the board address, or replaces it with a new one.
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn configure_inbound_mailbox_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+) -> Result<Json<InboundMailboxResponse>, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let mailbox = state
+        .inbound_email_service()
+        .configure_mailbox(board_id, authenticated_user.id)
+        .await?;
+
+    Ok(Json(mailbox.into()))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn delete_inbound_mailbox_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    state
+        .inbound_email_service()
+        .delete_mailbox(board_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Raw `.eml` upload, for mail servers that pipe messages to an HTTP endpoint. The token in the
+/// path is the only credential.
+#[instrument(skip(state, token, body), fields(message_size = body.len()), level = "debug")]
+async fn upload_inbound_email_handler(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    body: Bytes,
+) -> Result<StatusCode, ApiError> {
+    let inbound_email_service = state.inbound_email_service();
+    let mailbox = inbound_email_service.find_mailbox_by_token(&token).await?;
+
+    inbound_email_service
+        .ingest_message(&mailbox, &body)
+        .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
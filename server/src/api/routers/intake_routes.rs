@@ -0,0 +1,334 @@
+use std::net::IpAddr;
+
+use axum::{
+    Json, Router,
+    extract::{DefaultBodyLimit, FromRequestParts, Path, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header, request::Parts},
+    response::{IntoResponse, Response},
+    routing::{get, patch, post},
+};
+use axum_typed_multipart::TypedMultipart;
+use tracing::instrument;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api::{
+        auth::AuthenticatedUser,
+        client_ip::ClientIp,
+        error::ApiError,
+        models::{
+            request::intake_models::{
+                CreateIntakeKeyRequest, IntakeReportRequestMultipart, UpdateIntakeKeyRequest,
+            },
+            response::intake_models::IntakeKeyResponse,
+        },
+        openapi::operation::ApiOperation,
+        state::AppState,
+    },
+    config::app_config::APP_CONFIG,
+    domain::models::intake_key::{IntakeKey, IntakeSubmission},
+};
+
+const PREFLIGHT_MAX_AGE_SECONDS: &str = "600";
+
+pub fn intake_routes() -> Router<AppState> {
+    let intake_key_routes = Router::new()
+        .route(
+            "/",
+            get(list_intake_keys_handler).post(create_intake_key_handler),
+        )
+        .route(
+            "/{key_id}",
+            patch(update_intake_key_handler).delete(delete_intake_key_handler),
+        );
+
+    Router::new()
+        .nest("/boards/{board_id}/intake-keys", intake_key_routes)
+        .route(
+            "/intake/{public_key}/reports",
+            post(submit_intake_report_handler)
+                .options(intake_preflight_handler)
+                .layer(DefaultBodyLimit::max(
+                    (APP_CONFIG.max_body_size_mb * 1024 * 1024) as usize,
+                )),
+        )
+}
+
+pub fn intake_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Intake",
+        vec![
+            ApiOperation::get(
+                "/boards/{board_id}/intake-keys",
+                "List the intake keys of a board",
+            )
+            .json_response::<Vec<IntakeKeyResponse>>(StatusCode::OK, "Intake keys")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::post("/boards/{board_id}/intake-keys", "Create an intake key")
+                .json_body::<CreateIntakeKeyRequest>()
+                .json_response::<IntakeKeyResponse>(StatusCode::CREATED, "Intake key")
+                .error(StatusCode::FORBIDDEN),
+            ApiOperation::patch(
+                "/boards/{board_id}/intake-keys/{key_id}",
+                "Update an intake key",
+            )
+            .json_body::<UpdateIntakeKeyRequest>()
+            .json_response::<IntakeKeyResponse>(StatusCode::OK, "Intake key")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::delete(
+                "/boards/{board_id}/intake-keys/{key_id}",
+                "Delete an intake key",
+            )
+            .empty_response(StatusCode::NO_CONTENT, "Intake key deleted")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::post(
+                "/intake/{public_key}/reports",
+                "Submit a report from a widget",
+            )
+            .public()
+            .description(
+                "Only accepted from the allowed origins of the intake key, and rate limited per \
+                client IP address (per /64 network for IPv6). Accepts PNG, JPEG, GIF and WebP \
+                images and MP4, QuickTime and WebM videos, recognized from their content.",
+            )
+            .multipart_body::<IntakeReportRequestMultipart>()
+            .empty_response(StatusCode::ACCEPTED, "Report accepted")
+            .error(StatusCode::FORBIDDEN)
+            .error(StatusCode::TOO_MANY_REQUESTS),
+        ],
+    )
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn create_intake_key_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+    Json(payload): Json<CreateIntakeKeyRequest>,
+) -> Result<(StatusCode, Json<IntakeKeyResponse>), ApiError> {
+    payload.validate().map_err(|e| {
+        tracing::warn!("Intake key validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let intake_key = state
+        .intake_service()
+        .create_key(
+            board_id,
+            authenticated_user.id,
+            &payload.name,
+            payload.allowed_origins,
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(intake_key.into())))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn list_intake_keys_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+) -> Result<Json<Vec<IntakeKeyResponse>>, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let intake_keys = state.intake_service().list_keys(board_id).await?;
+
+    Ok(Json(
+        intake_keys
+            .into_iter()
+            .map(IntakeKeyResponse::from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id, board_id = %board_id, intake_key_id = %key_id), level = "debug")]
+async fn update_intake_key_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path((board_id, key_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpdateIntakeKeyRequest>,
+) -> Result<Json<IntakeKeyResponse>, ApiError> {
+    payload.validate().map_err(|e| {
+        tracing::warn!("Intake key update validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let intake_key = state
+        .intake_service()
+        .update_key(board_id, key_id, payload.into())
+        .await?;
+
+    Ok(Json(intake_key.into()))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id, intake_key_id = %key_id), level = "debug")]
+async fn delete_intake_key_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path((board_id, key_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    state.intake_service().delete_key(board_id, key_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Intake key of a widget submission with its origin, resolved from the request head: unknown
+/// origins and rate limited clients are turned away before the upload is read.
+struct IntakeSubmissionRequest {
+    intake_key: IntakeKey,
+    origin: HeaderValue,
+    client_ip: IpAddr,
+}
+
+impl FromRequestParts<AppState> for IntakeSubmissionRequest {
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let Path(public_key) = Path::<String>::from_request_parts(parts, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        let ClientIp(client_ip) = ClientIp::from_request_parts(parts, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        let (intake_key, origin) = find_key_for_request(state, &public_key, &parts.headers)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        // From here on the origin is trusted, so the widget gets to read the error responses too.
+        if let Err(e) = state.intake_service().check_rate_limit(client_ip) {
+            return Err(with_cors_headers(ApiError::from(e).into_response(), origin));
+        }
+
+        Ok(Self {
+            intake_key,
+            origin,
+            client_ip,
+        })
+    }
+}
+
+/// Public endpoint of the feedback widgets. Accepted submissions get a bare `202 Accepted`,
+/// honeypot catches included.
+#[instrument(skip(state, request, payload), fields(client_ip = %request.client_ip), level = "debug")]
+async fn submit_intake_report_handler(
+    State(state): State<AppState>,
+    request: IntakeSubmissionRequest,
+    TypedMultipart(payload): TypedMultipart<IntakeReportRequestMultipart>,
+) -> Response {
+    let result = submit_report(&state, &request.intake_key, request.client_ip, payload).await;
+
+    with_cors_headers(result.into_response(), request.origin)
+}
+
+#[instrument(skip(state, headers), level = "debug")]
+async fn intake_preflight_handler(
+    State(state): State<AppState>,
+    Path(public_key): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let (_, origin) = find_key_for_request(&state, &public_key, &headers).await?;
+
+    let mut response = with_cors_headers(StatusCode::NO_CONTENT.into_response(), origin);
+    let response_headers = response.headers_mut();
+    response_headers.insert(
+        header::ACCESS_CONTROL_ALLOW_METHODS,
+        HeaderValue::from_static("POST"),
+    );
+    response_headers.insert(
+        header::ACCESS_CONTROL_ALLOW_HEADERS,
+        HeaderValue::from_static("content-type"),
+    );
+    response_headers.insert(
+        header::ACCESS_CONTROL_MAX_AGE,
+        HeaderValue::from_static(PREFLIGHT_MAX_AGE_SECONDS),
+    );
+
+    Ok(response)
+}
+
+/// The intake key, when the request `Origin` is one of its allowed origins.
+async fn find_key_for_request(
+    state: &AppState,
+    public_key: &str,
+    headers: &HeaderMap,
+) -> Result<(IntakeKey, HeaderValue), ApiError> {
+    let origin = headers.get(header::ORIGIN).cloned();
+
+    let intake_key = state
+        .intake_service()
+        .find_key_for_origin(public_key, origin.as_ref().and_then(|o| o.to_str().ok()))
+        .await?;
+
+    // Only reachable with an allowed, hence present, origin.
+    let origin = origin.ok_or(ApiError::Forbidden)?;
+    Ok((intake_key, origin))
+}
+
+async fn submit_report(
+    state: &AppState,
+    intake_key: &IntakeKey,
+    client_ip: IpAddr,
+    payload: IntakeReportRequestMultipart,
+) -> Result<StatusCode, ApiError> {
+    payload.validate().map_err(|e| {
+        tracing::warn!("Intake submission validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    let original_file_name = payload.file.metadata.file_name.ok_or_else(|| {
+        ApiError::validation("File name is required in the multipart data.".to_string())
+    })?;
+
+    let submission = IntakeSubmission {
+        client_ip,
+        honeypot: payload.website,
+        title: payload.title,
+        description: payload.description,
+        contact_email: payload.contact_email,
+        url: payload.url,
+        browser_name: payload.browser_name,
+        browser_version: payload.browser_version,
+        os_name: payload.os_name,
+        original_file_name,
+        file_data: payload.file.contents,
+        thumbnail_data: payload.thumbnail.map(|t| t.contents),
+    };
+
+    state
+        .intake_service()
+        .submit_report(intake_key, submission)
+        .await?;
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+fn with_cors_headers(mut response: Response, origin: HeaderValue) -> Response {
+    let headers = response.headers_mut();
+    headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, origin);
+    headers.insert(header::VARY, HeaderValue::from_static("origin"));
+    response
+}
@@ -0,0 +1,162 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get, post},
+};
+use tracing::instrument;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api::{
+        auth::AuthenticatedAdmin,
+        error::ApiError,
+        models::{
+            request::invitation_models::{AcceptInvitationRequest, CreateInvitationRequest},
+            response::{
+                invitation_models::{InvitationResponse, IssuedInvitationResponse},
+                user_models::UserResponse,
+            },
+        },
+        openapi::operation::ApiOperation,
+        state::AppState,
+    },
+    domain::models::{audit::AuditContext, invitation::AcceptInvitationParams},
+};
+
+pub fn invitation_routes() -> Router<AppState> {
+    let invitation_routes = Router::new()
+        .route(
+            "/",
+            get(list_pending_invitations_handler).post(create_invitation_handler),
+        )
+        .route("/{invitation_id}", delete(revoke_invitation_handler))
+        .route("/token/{token}", get(get_invitation_by_token_handler))
+        .route("/accept", post(accept_invitation_handler));
+
+    Router::new().nest("/invitations", invitation_routes)
+}
+
+pub fn invitation_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Invitations",
+        vec![
+            ApiOperation::get("/invitations", "List the pending invitations")
+                .admin()
+                .json_response::<Vec<InvitationResponse>>(StatusCode::OK, "Pending invitations"),
+            ApiOperation::post("/invitations", "Invite a user")
+                .admin()
+                .json_body::<CreateInvitationRequest>()
+                .json_response::<IssuedInvitationResponse>(StatusCode::CREATED, "Invitation")
+                .error(StatusCode::CONFLICT),
+            ApiOperation::delete("/invitations/{invitation_id}", "Revoke an invitation")
+                .admin()
+                .empty_response(StatusCode::NO_CONTENT, "Invitation revoked"),
+            ApiOperation::get(
+                "/invitations/token/{token}",
+                "Get an invitation by its token",
+            )
+            .public()
+            .json_response::<InvitationResponse>(StatusCode::OK, "Invitation"),
+            ApiOperation::post("/invitations/accept", "Accept an invitation")
+                .public()
+                .json_body::<AcceptInvitationRequest>()
+                .json_response::<UserResponse>(StatusCode::CREATED, "Account created")
+                .error(StatusCode::NOT_FOUND)
+                .error(StatusCode::CONFLICT),
+        ],
+    )
+}
+
+#[instrument(skip(state, authenticated_admin, payload), fields(admin_id = %authenticated_admin.id), level = "debug")]
+async fn create_invitation_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+    Json(payload): Json<CreateInvitationRequest>,
+) -> Result<(StatusCode, Json<IssuedInvitationResponse>), ApiError> {
+    payload.validate().map_err(|e| {
+        tracing::warn!("Invitation validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    let issued = state
+        .invitation_service()
+        .create_invitation(authenticated_admin.id, &payload.email, payload.role)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(issued.into())))
+}
+
+#[instrument(skip(state, authenticated_admin), fields(admin_id = %authenticated_admin.id), level = "debug")]
+async fn list_pending_invitations_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+) -> Result<Json<Vec<InvitationResponse>>, ApiError> {
+    let invitations = state
+        .invitation_service()
+        .list_pending_invitations()
+        .await?;
+
+    Ok(Json(
+        invitations
+            .into_iter()
+            .map(InvitationResponse::from)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state, authenticated_admin), fields(admin_id = %authenticated_admin.id, invitation_id = %invitation_id), level = "debug")]
+async fn revoke_invitation_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+    Path(invitation_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .invitation_service()
+        .revoke_invitation(invitation_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip(state, token), level = "debug")]
+async fn get_invitation_by_token_handler(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<InvitationResponse>, ApiError> {
+    let invitation = state
+        .invitation_service()
+        .get_pending_invitation(&token)
+        .await?;
+
+    Ok(Json(invitation.into()))
+}
+
+#[instrument(skip(state, audit_context, payload), level = "debug")]
+async fn accept_invitation_handler(
+    State(state): State<AppState>,
+    audit_context: AuditContext,
+    Json(payload): Json<AcceptInvitationRequest>,
+) -> Result<(StatusCode, Json<UserResponse>), ApiError> {
+    payload.validate().map_err(|e| {
+        tracing::warn!("Invitation acceptance validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    let user = state
+        .invitation_service()
+        .accept_invitation(
+            AcceptInvitationParams {
+                token: payload.token,
+                password: payload.password,
+                first_name: payload.first_name,
+                last_name: payload.last_name,
+            },
+            &audit_context,
+        )
+        .await?;
+
+    tracing::info!(user_id = %user.id, "Invitation accepted.");
+    Ok((StatusCode::CREATED, Json(user.into())))
+}
@@ -0,0 +1,171 @@
+use axum::{
+    Json, Router,
+    body::Bytes,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    routing::{get, post},
+};
+use tracing::instrument;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    api::{
+        auth::AuthenticatedUser,
+        error::ApiError,
+        models::{
+            request::issue_tracker_models::ConfigureIssueTrackerRequest,
+            response::issue_tracker_models::IssueTrackerIntegrationResponse,
+        },
+        openapi::operation::ApiOperation,
+        state::AppState,
+    },
+    domain::models::{audit::AuditContext, issue_tracker::InboundWebhook},
+};
+
+pub fn issue_tracker_routes() -> Router<AppState> {
+    let issue_tracker_routes = Router::new().route(
+        "/",
+        get(get_issue_tracker_handler)
+            .put(configure_issue_tracker_handler)
+            .delete(remove_issue_tracker_handler),
+    );
+
+    Router::new()
+        .nest("/boards/{board_id}/issue-tracker", issue_tracker_routes)
+        .route(
+            "/issue-tracker/webhooks/{integration_id}",
+            post(issue_tracker_webhook_handler),
+        )
+}
+
+pub fn issue_tracker_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Issue tracker",
+        vec![
+            ApiOperation::get(
+                "/boards/{board_id}/issue-tracker",
+                "Get the issue tracker of a board",
+            )
+            .json_response::<IssueTrackerIntegrationResponse>(StatusCode::OK, "Issue tracker")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::put(
+                "/boards/{board_id}/issue-tracker",
+                "Configure the issue tracker of a board",
+            )
+            .json_body::<ConfigureIssueTrackerRequest>()
+            .json_response::<IssueTrackerIntegrationResponse>(StatusCode::OK, "Issue tracker")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::delete(
+                "/boards/{board_id}/issue-tracker",
+                "Remove the issue tracker of a board",
+            )
+            .empty_response(StatusCode::NO_CONTENT, "Issue tracker removed")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::post(
+                "/issue-tracker/webhooks/{integration_id}",
+                "Receive an issue tracker webhook",
+            )
+            .public()
+            .description(
+                "Called by GitHub/GitLab, authenticated by the integration webhook secret.",
+            )
+            .binary_body("application/json")
+            .empty_response(StatusCode::NO_CONTENT, "Webhook handled")
+            .error(StatusCode::UNAUTHORIZED),
+        ],
+    )
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn get_issue_tracker_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+) -> Result<Json<IssueTrackerIntegrationResponse>, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let integration = state
+        .issue_tracker_service()
+        .get_integration(board_id)
+        .await?;
+
+    Ok(Json(integration.into()))
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn configure_issue_tracker_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+    Json(payload): Json<ConfigureIssueTrackerRequest>,
+) -> Result<Json<IssueTrackerIntegrationResponse>, ApiError> {
+    payload.validate().map_err(|e| {
+        tracing::warn!("Issue tracker validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let integration = state
+        .issue_tracker_service()
+        .configure_integration(board_id, authenticated_user.id, payload.into())
+        .await?;
+
+    Ok(Json(integration.into()))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn remove_issue_tracker_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    state
+        .issue_tracker_service()
+        .remove_integration(board_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Called by GitHub/GitLab, authenticated by the integration webhook secret.
+#[instrument(skip(state, audit_context, headers, body), fields(integration_id = %integration_id), level = "debug")]
+async fn issue_tracker_webhook_handler(
+    State(state): State<AppState>,
+    Path(integration_id): Path<Uuid>,
+    audit_context: AuditContext,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<StatusCode, ApiError> {
+    let webhook = InboundWebhook {
+        headers: headers
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.as_str().to_string(), value.to_string()))
+            })
+            .collect(),
+        body: body.to_vec(),
+    };
+
+    state
+        .issue_tracker_service()
+        .handle_webhook(integration_id, webhook, &audit_context)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,123 @@
+use std::convert::Infallible;
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::sse::{Event, KeepAlive, Sse},
+    routing::{get, post},
+};
+use futures_util::{Stream, stream};
+use tracing::instrument;
+
+use crate::{
+    api::{
+        auth::{AuthenticatedStreamUser, AuthenticatedUser, StreamTicketQuery},
+        error::ApiError,
+        models::response::live_feed_models::{
+            LiveFeedEventResponse, LiveFeedLaggedResponse, StreamTicketResponse,
+        },
+        openapi::operation::ApiOperation,
+        state::AppState,
+    },
+    application::services::live_feed_service::{LiveFeed, LiveFeedItem},
+    config::app_config::APP_CONFIG,
+    domain::models::{event::DomainEvent, report::Report},
+};
+
+const LAGGED_EVENT: &str = "lagged";
+
+pub fn live_feed_routes() -> Router<AppState> {
+    let live_feed_routes = Router::new()
+        .route("/ticket", post(create_stream_ticket_handler))
+        .route("/stream", get(stream_events_handler));
+
+    Router::new().nest("/events", live_feed_routes)
+}
+
+pub fn live_feed_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Live feed",
+        vec![
+            ApiOperation::post("/events/ticket", "Create a stream ticket")
+                .json_response::<StreamTicketResponse>(StatusCode::OK, "Stream ticket"),
+            ApiOperation::get(
+                "/events/stream",
+                "Stream the report events of the user boards",
+            )
+            .description(
+                "Server-Sent Events. Authenticate with the `Authorization` header, or with a \
+                ticket in `ticket`, since `EventSource` cannot set headers. Access is checked \
+                again periodically: the stream ends once the user is deactivated or deleted. \
+                Admins receive the events of every board.",
+            )
+            .query::<StreamTicketQuery>()
+            .binary_response(StatusCode::OK, "text/event-stream", "Event stream"),
+        ],
+    )
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn create_stream_ticket_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<StreamTicketResponse>, ApiError> {
+    let ticket = state
+        .auth_service()
+        .create_stream_ticket(authenticated_user.id)?;
+
+    Ok(Json(StreamTicketResponse {
+        ticket,
+        expires_in: APP_CONFIG.stream_ticket_expiration_seconds,
+    }))
+}
+
+/// Server-Sent Events stream of the report events of the user boards. Authenticate with the
+/// `Authorization` header, or with a ticket from `POST /events/ticket` in `?ticket=`.
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn stream_events_handler(
+    State(state): State<AppState>,
+    AuthenticatedStreamUser(authenticated_user): AuthenticatedStreamUser,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let feed = state
+        .live_feed_service()
+        .open_feed(authenticated_user.id)
+        .await?;
+
+    tracing::info!("Live feed stream opened.");
+    let events = stream::unfold(feed, |mut feed: LiveFeed| async move {
+        loop {
+            let event = match feed.next_item().await? {
+                LiveFeedItem::Event(event) => to_sse_event(event),
+                LiveFeedItem::Lagged(skipped) => Event::default()
+                    .event(LAGGED_EVENT)
+                    .json_data(LiveFeedLaggedResponse { skipped })
+                    .ok(),
+            };
+
+            if let Some(event) = event {
+                return Some((Ok(event), feed));
+            }
+        }
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+fn to_sse_event(event: DomainEvent) -> Option<Event> {
+    let report: Report = serde_json::from_value(event.data)
+        .inspect_err(|e| tracing::error!("Failed to read report from event: {}", e))
+        .ok()?;
+
+    Event::default()
+        .id(event.id.to_string())
+        .event(event.event_type.as_str())
+        .json_data(LiveFeedEventResponse {
+            id: event.id,
+            event: event.event_type,
+            board_id: event.board_id,
+            occurred_at: event.occurred_at,
+            report: report.into(),
+        })
+        .ok()
+}
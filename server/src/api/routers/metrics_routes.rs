@@ -0,0 +1,35 @@
+use axum::{
+    Router,
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use axum_extra::{
+    TypedHeader,
+    headers::{Authorization, authorization::Bearer},
+};
+use tracing::instrument;
+
+use crate::api::{error::ApiError, state::AppState};
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Served at `/metrics` rather than under `/api`, where Prometheus looks by default.
+pub fn metrics_routes() -> Router<AppState> {
+    Router::new().route("/metrics", get(metrics_handler))
+}
+
+#[instrument(skip_all, level = "debug")]
+async fn metrics_handler(
+    State(state): State<AppState>,
+    authorization: Option<TypedHeader<Authorization<Bearer>>>,
+) -> Result<Response, ApiError> {
+    let token = authorization
+        .as_ref()
+        .map(|TypedHeader(Authorization(bearer))| bearer.token());
+
+    let metrics = state.metrics_service().render_metrics(token).await?;
+
+    Ok(([(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)], metrics).into_response())
+}
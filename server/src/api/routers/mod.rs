@@ -1,26 +1,228 @@
-use auth_routes::auth_routes;
+use audit_routes::{audit_route_docs, audit_routes};
+use auth_routes::{auth_route_docs, auth_routes};
 use axum::Router;
-use board_routes::board_routes;
-use dashboard_routes::dashboard_routes;
-use health_routes::health_routes;
-use report_routes::report_routes;
-use user_routes::user_routes;
+use backup_routes::{backup_route_docs, backup_routes};
+use board_export_routes::{board_export_route_docs, board_export_routes};
+use board_import_routes::{board_import_route_docs, board_import_routes};
+use board_routes::{board_route_docs, board_routes};
+use chat_integration_routes::{chat_integration_route_docs, chat_integration_routes};
+use dashboard_routes::{dashboard_route_docs, dashboard_routes};
+use health_routes::{health_route_docs, health_routes};
+use inbound_email_routes::{inbound_email_route_docs, inbound_email_routes};
+use intake_routes::{intake_route_docs, intake_routes};
+use invitation_routes::{invitation_route_docs, invitation_routes};
+use issue_tracker_routes::{issue_tracker_route_docs, issue_tracker_routes};
+use live_feed_routes::{live_feed_route_docs, live_feed_routes};
+use metrics_routes::metrics_routes;
+use notification_preference_routes::{
+    notification_preference_route_docs, notification_preference_routes,
+};
+use openapi_routes::{openapi_route_docs, openapi_routes};
+use report_routes::{report_route_docs, report_routes};
+use settings_routes::{settings_route_docs, settings_routes};
+use two_factor_routes::{two_factor_route_docs, two_factor_routes};
+use user_routes::{user_route_docs, user_routes};
+use webhook_routes::{webhook_route_docs, webhook_routes};
 
-use super::state::AppState;
+use super::{openapi::operation::ApiOperation, state::AppState};
 
+mod audit_routes;
 mod auth_routes;
+mod backup_routes;
+mod board_export_routes;
+mod board_import_routes;
 mod board_routes;
+mod chat_integration_routes;
 mod dashboard_routes;
 mod health_routes;
+mod inbound_email_routes;
+mod intake_routes;
+mod invitation_routes;
+mod issue_tracker_routes;
+mod live_feed_routes;
+mod metrics_routes;
+mod notification_preference_routes;
+mod openapi_routes;
 mod report_routes;
+mod settings_routes;
+mod two_factor_routes;
 mod user_routes;
+mod webhook_routes;
 
 pub fn get_api_routes() -> Router<AppState> {
     Router::new()
+        .merge(audit_routes())
         .merge(auth_routes())
+        .merge(backup_routes())
+        .merge(board_export_routes())
+        .merge(board_import_routes())
         .merge(board_routes())
+        .merge(chat_integration_routes())
         .merge(dashboard_routes())
         .merge(health_routes())
+        .merge(inbound_email_routes())
+        .merge(intake_routes())
+        .merge(invitation_routes())
+        .merge(issue_tracker_routes())
+        .merge(live_feed_routes())
+        .merge(notification_preference_routes())
+        .merge(openapi_routes())
         .merge(report_routes())
+        .merge(settings_routes())
+        .merge(two_factor_routes())
         .merge(user_routes())
+        .merge(webhook_routes())
+}
+
+/// Routes served outside of `/api`.
+pub fn get_root_routes() -> Router<AppState> {
+    Router::new().merge(metrics_routes())
+}
+
+/// Operations of all the API routes, for the OpenAPI document.
+pub fn get_api_route_docs() -> Vec<ApiOperation> {
+    [
+        audit_route_docs(),
+        auth_route_docs(),
+        backup_route_docs(),
+        board_export_route_docs(),
+        board_import_route_docs(),
+        board_route_docs(),
+        chat_integration_route_docs(),
+        dashboard_route_docs(),
+        health_route_docs(),
+        inbound_email_route_docs(),
+        intake_route_docs(),
+        invitation_route_docs(),
+        issue_tracker_route_docs(),
+        live_feed_route_docs(),
+        notification_preference_route_docs(),
+        openapi_route_docs(),
+        report_route_docs(),
+        settings_route_docs(),
+        two_factor_route_docs(),
+        user_route_docs(),
+        webhook_route_docs(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Method, Request, StatusCode},
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::infrastructure::{
+        container::service_container::ServiceContainer, database::connection::DatabaseConnection,
+    };
+
+    const METHODS: [Method; 5] = [
+        Method::GET,
+        Method::POST,
+        Method::PUT,
+        Method::PATCH,
+        Method::DELETE,
+    ];
+
+    /// Paths of the router. Axum has no API listing them, they are read from its debug output.
+    fn routed_paths(router: &Router<AppState>) -> Vec<String> {
+        let debug = format!("{:?}", router);
+        let (_, paths) = debug
+            .split_once("paths: {")
+            .expect("unexpected router debug output");
+
+        paths
+            .split("RouteId(")
+            .skip(1)
+            .filter_map(|entry| entry.split_once("): \"")?.1.split_once('"'))
+            .map(|(path, _)| path.to_string())
+            .collect()
+    }
+
+    /// Whether a request reaches a handler: unmatched requests get an empty 404 or a 405 from the
+    /// router, before any extractor runs.
+    async fn is_routed(router: &Router, method: &Method, path: &str) -> bool {
+        let uri = path
+            .split('/')
+            .map(|segment| {
+                if segment.starts_with('{') {
+                    "00000000-0000-0000-0000-000000000000"
+                } else {
+                    segment
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        status != StatusCode::METHOD_NOT_ALLOWED
+            && !(status == StatusCode::NOT_FOUND && body.is_empty())
+    }
+
+    #[tokio::test]
+    async fn every_route_is_documented() {
+        let database = DatabaseConnection::new("sqlite::memory:".to_string())
+            .await
+            .unwrap();
+        let state = AppState::new(ServiceContainer::new(&database).await.unwrap());
+        let api_routes = get_api_routes();
+        let paths = routed_paths(&api_routes);
+        let router = api_routes.with_state(state);
+
+        let mut routed = BTreeSet::new();
+        for path in &paths {
+            for method in &METHODS {
+                if is_routed(&router, method, path).await {
+                    routed.insert((method.as_str().to_lowercase(), path.clone()));
+                }
+            }
+        }
+        let documented: BTreeSet<(String, String)> = get_api_route_docs()
+            .iter()
+            .map(|operation| (operation.method().to_string(), operation.path().to_string()))
+            .collect();
+
+        assert!(paths.len() > 40, "only {} paths found", paths.len());
+        let undocumented: Vec<_> = routed.difference(&documented).collect();
+        assert!(
+            undocumented.is_empty(),
+            "undocumented routes: {:?}",
+            undocumented
+        );
+        let unrouted: Vec<_> = documented.difference(&routed).collect();
+        assert!(
+            unrouted.is_empty(),
+            "documented but not routed: {:?}",
+            unrouted
+        );
+    }
+
+    #[test]
+    fn operations_are_documented_once() {
+        let operations = get_api_route_docs();
+        let mut seen = BTreeSet::new();
+        for operation in &operations {
+            assert!(
+                seen.insert((operation.method(), operation.path())),
+                "{} {} is documented twice",
+                operation.method(),
+                operation.path()
+            );
+        }
+    }
 }
@@ -0,0 +1,66 @@
+use axum::{Json, Router, extract::State, http::StatusCode, routing::get};
+use tracing::instrument;
+
+use crate::api::{
+    auth::AuthenticatedUser,
+    error::ApiError,
+    models::{
+        request::notification_preference_models::UpdateNotificationPreferencesRequest,
+        response::notification_preference_models::NotificationPreferencesResponse,
+    },
+    openapi::operation::ApiOperation,
+    state::AppState,
+};
+
+pub fn notification_preference_routes() -> Router<AppState> {
+    Router::new().route(
+        "/users/me/notification-preferences",
+        get(get_notification_preferences_handler).put(update_notification_preferences_handler),
+    )
+}
+
+pub fn notification_preference_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Notifications",
+        vec![
+            ApiOperation::get(
+                "/users/me/notification-preferences",
+                "Get the email notification preferences of the user",
+            )
+            .json_response::<NotificationPreferencesResponse>(StatusCode::OK, "Preferences"),
+            ApiOperation::put(
+                "/users/me/notification-preferences",
+                "Update the email notification preferences of the user",
+            )
+            .json_body::<UpdateNotificationPreferencesRequest>()
+            .json_response::<NotificationPreferencesResponse>(StatusCode::OK, "Preferences"),
+        ],
+    )
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn get_notification_preferences_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<NotificationPreferencesResponse>, ApiError> {
+    let preferences = state
+        .email_notification_service()
+        .get_preferences(authenticated_user.id)
+        .await?;
+
+    Ok(Json(preferences.into()))
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn update_notification_preferences_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Json(payload): Json<UpdateNotificationPreferencesRequest>,
+) -> Result<Json<NotificationPreferencesResponse>, ApiError> {
+    let preferences = state
+        .email_notification_service()
+        .update_preferences(authenticated_user.id, payload.into())
+        .await?;
+
+    Ok(Json(preferences.into()))
+}
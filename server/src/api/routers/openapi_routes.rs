@@ -0,0 +1,45 @@
+use axum::{
+    Json, Router,
+    http::StatusCode,
+    response::{Html, IntoResponse},
+    routing::get,
+};
+use once_cell::sync::Lazy;
+use serde_json::Value;
+
+use crate::api::{
+    openapi::{DOCS_PAGE, build_openapi_document, operation::ApiOperation},
+    state::AppState,
+};
+
+use super::get_api_route_docs;
+
+static OPENAPI_DOCUMENT: Lazy<Value> = Lazy::new(|| build_openapi_document(get_api_route_docs()));
+
+pub fn openapi_routes() -> Router<AppState> {
+    Router::new()
+        .route("/openapi.json", get(openapi_document_handler))
+        .route("/docs", get(docs_handler))
+}
+
+pub fn openapi_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Documentation",
+        vec![
+            ApiOperation::get("/openapi.json", "OpenAPI document of this API")
+                .public()
+                .json_response::<Value>(StatusCode::OK, "OpenAPI 3.1 document"),
+            ApiOperation::get("/docs", "Interactive documentation of this API")
+                .public()
+                .binary_response(StatusCode::OK, "text/html", "Documentation page"),
+        ],
+    )
+}
+
+async fn openapi_document_handler() -> impl IntoResponse {
+    Json(&*OPENAPI_DOCUMENT)
+}
+
+async fn docs_handler() -> Html<&'static str> {
+    Html(DOCS_PAGE)
+}
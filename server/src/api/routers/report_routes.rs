@@ -14,18 +14,21 @@ use crate::{
         error::ApiError,
         models::{
             request::report_models::CreateReportRequestMultipart,
-            response::report_models::ReportResponse,
+            response::report_models::{ReportHistoryEntryResponse, ReportResponse},
         },
+        openapi::operation::ApiOperation,
         state::AppState,
     },
     config::app_config::APP_CONFIG,
-    domain::models::{report::CreateReportServiceParams, user::UserRole},
+    domain::models::{audit::AuditContext, report::CreateReportServiceParams, user::UserRole},
 };
 
 pub fn report_routes() -> Router<AppState> {
     let report_routes = Router::new()
         .route("/", post(create_report_handler))
         .route("/{report_id}", get(get_report_handler))
+        .route("/{report_id}/export", post(export_report_handler))
+        .route("/{report_id}/history", get(get_report_history_handler))
         .layer(DefaultBodyLimit::max(
             (APP_CONFIG.max_body_size_mb * 1024 * 1024) as usize,
         ));
@@ -33,6 +36,35 @@ pub fn report_routes() -> Router<AppState> {
     Router::new().nest("/reports", report_routes)
 }
 
+pub fn report_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Reports",
+        vec![
+            ApiOperation::post("/reports", "Create a report")
+                .multipart_body::<CreateReportRequestMultipart>()
+                .json_response::<ReportResponse>(StatusCode::CREATED, "Report")
+                .error(StatusCode::FORBIDDEN),
+            ApiOperation::get("/reports/{report_id}", "Get a report")
+                .json_response::<ReportResponse>(StatusCode::OK, "Report")
+                .error(StatusCode::FORBIDDEN),
+            ApiOperation::post(
+                "/reports/{report_id}/export",
+                "Export a report to the issue tracker",
+            )
+            .json_response::<ReportResponse>(StatusCode::OK, "Report, with its issue")
+            .error(StatusCode::FORBIDDEN)
+            .error(StatusCode::CONFLICT)
+            .error(StatusCode::BAD_GATEWAY),
+            ApiOperation::get(
+                "/reports/{report_id}/history",
+                "Get the history of a report",
+            )
+            .json_response::<Vec<ReportHistoryEntryResponse>>(StatusCode::OK, "History")
+            .error(StatusCode::FORBIDDEN),
+        ],
+    )
+}
+
 #[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, report_id = %report_id), level = "debug")]
 async fn get_report_handler(
     State(state): State<AppState>,
@@ -56,10 +88,53 @@ async fn get_report_handler(
     Ok((StatusCode::OK, Json(response)))
 }
 
-#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id), level = "debug")]
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, report_id = %report_id), level = "debug")]
+async fn get_report_history_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(report_id): Path<Uuid>,
+) -> Result<Json<Vec<ReportHistoryEntryResponse>>, ApiError> {
+    let report = state.report_service().get_report(report_id).await?;
+
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, report.board_id)
+        .await?;
+
+    let history = state.report_service().get_report_history(report_id).await?;
+
+    Ok(Json(history.into_iter().map(Into::into).collect()))
+}
+
+#[instrument(skip(state, authenticated_user, audit_context), fields(user_id = %authenticated_user.id, report_id = %report_id), level = "debug")]
+async fn export_report_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(report_id): Path<Uuid>,
+    audit_context: AuditContext,
+) -> Result<Json<ReportResponse>, ApiError> {
+    let report = state.report_service().get_report(report_id).await?;
+
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, report.board_id)
+        .await?;
+
+    let report = state
+        .issue_tracker_service()
+        .export_report(report_id, &audit_context.with_actor(authenticated_user.id))
+        .await?;
+
+    tracing::info!(external_issue_key = ?report.external_issue_key, "Report exported to the issue tracker.");
+
+    Ok(Json(report.into()))
+}
+
+#[instrument(skip(state, authenticated_user, audit_context, payload), fields(user_id = %authenticated_user.id), level = "debug")]
 async fn create_report_handler(
     State(state): State<AppState>,
     authenticated_user: AuthenticatedUser,
+    audit_context: AuditContext,
     TypedMultipart(payload): TypedMultipart<CreateReportRequestMultipart>,
 ) -> Result<(StatusCode, Json<ReportResponse>), ApiError> {
     tracing::debug!("Creating screenshot report.");
@@ -82,12 +157,13 @@ async fn create_report_handler(
         browser_name: payload.browser_name,
         browser_version: payload.browser_version,
         os_name: payload.os_name,
+        contact_email: None,
         thumbnail_data: payload.thumbnail.map(|t| t.contents),
     };
 
     let report = state
         .report_service()
-        .create_report(params)
+        .create_report(params, &audit_context.with_actor(authenticated_user.id))
         .await
         .map_err(|e| {
             tracing::error!("Report service error: {:?}", e);
@@ -0,0 +1,75 @@
+use axum::{Json, Router, extract::State, http::StatusCode, routing::get};
+use tracing::instrument;
+
+use crate::{
+    api::{
+        auth::AuthenticatedAdmin,
+        error::ApiError,
+        models::{
+            request::two_factor_models::UpdateSecuritySettingsRequest,
+            response::two_factor_models::SecuritySettingsResponse,
+        },
+        openapi::operation::ApiOperation,
+        state::AppState,
+    },
+    domain::models::two_factor::SecuritySettings,
+};
+
+pub fn settings_routes() -> Router<AppState> {
+    let settings_routes = Router::new().route(
+        "/security",
+        get(get_security_settings_handler).put(update_security_settings_handler),
+    );
+
+    Router::new().nest("/settings", settings_routes)
+}
+
+pub fn settings_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Settings",
+        vec![
+            ApiOperation::get("/settings/security", "Get the security settings")
+                .admin()
+                .json_response::<SecuritySettingsResponse>(StatusCode::OK, "Security settings"),
+            ApiOperation::put("/settings/security", "Update the security settings")
+                .admin()
+                .json_body::<UpdateSecuritySettingsRequest>()
+                .json_response::<SecuritySettingsResponse>(StatusCode::OK, "Security settings"),
+        ],
+    )
+}
+
+#[instrument(skip(state, authenticated_admin), fields(admin_id = %authenticated_admin.id), level = "debug")]
+async fn get_security_settings_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+) -> Result<Json<SecuritySettingsResponse>, ApiError> {
+    let settings = state.two_factor_service().get_security_settings().await?;
+
+    Ok(Json(settings.into()))
+}
+
+#[instrument(skip(state, authenticated_admin, payload), fields(admin_id = %authenticated_admin.id), level = "debug")]
+async fn update_security_settings_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+    Json(payload): Json<UpdateSecuritySettingsRequest>,
+) -> Result<Json<SecuritySettingsResponse>, ApiError> {
+    let admin = state
+        .user_service()
+        .get_user_by_id(authenticated_admin.id)
+        .await?;
+
+    let settings = state
+        .two_factor_service()
+        .update_security_settings(
+            &admin,
+            SecuritySettings {
+                require_admin_two_factor: payload.require_admin_two_factor,
+            },
+        )
+        .await?;
+
+    tracing::info!(admin_id = %admin.id, "Security settings updated.");
+    Ok(Json(settings.into()))
+}
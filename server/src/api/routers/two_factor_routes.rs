@@ -0,0 +1,154 @@
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    routing::{get, post},
+};
+use tracing::instrument;
+use validator::Validate;
+
+use crate::api::{
+    auth::AuthenticatedUser,
+    error::ApiError,
+    models::{
+        request::two_factor_models::TwoFactorCodeRequest,
+        response::two_factor_models::{
+            RecoveryCodesResponse, TwoFactorEnrollmentResponse, TwoFactorStatusResponse,
+        },
+    },
+    openapi::operation::ApiOperation,
+    state::AppState,
+};
+
+pub fn two_factor_routes() -> Router<AppState> {
+    let two_factor_routes = Router::new()
+        .route("/", get(get_two_factor_status_handler))
+        .route("/enrollment", post(begin_enrollment_handler))
+        .route("/enrollment/confirm", post(confirm_enrollment_handler))
+        .route("/disable", post(disable_two_factor_handler))
+        .route("/recovery-codes", post(regenerate_recovery_codes_handler));
+
+    Router::new().nest("/users/me/two-factor", two_factor_routes)
+}
+
+pub fn two_factor_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Two-factor authentication",
+        vec![
+            ApiOperation::get(
+                "/users/me/two-factor",
+                "Get the two-factor status of the user",
+            )
+            .json_response::<TwoFactorStatusResponse>(StatusCode::OK, "Two-factor status"),
+            ApiOperation::post(
+                "/users/me/two-factor/enrollment",
+                "Start a two-factor enrollment",
+            )
+            .json_response::<TwoFactorEnrollmentResponse>(StatusCode::CREATED, "Enrollment")
+            .error(StatusCode::CONFLICT),
+            ApiOperation::post(
+                "/users/me/two-factor/enrollment/confirm",
+                "Confirm a two-factor enrollment",
+            )
+            .json_body::<TwoFactorCodeRequest>()
+            .json_response::<RecoveryCodesResponse>(StatusCode::OK, "Recovery codes"),
+            ApiOperation::post(
+                "/users/me/two-factor/disable",
+                "Disable two-factor authentication",
+            )
+            .json_body::<TwoFactorCodeRequest>()
+            .empty_response(StatusCode::NO_CONTENT, "Two-factor authentication disabled"),
+            ApiOperation::post(
+                "/users/me/two-factor/recovery-codes",
+                "Regenerate the recovery codes",
+            )
+            .json_body::<TwoFactorCodeRequest>()
+            .json_response::<RecoveryCodesResponse>(StatusCode::OK, "Recovery codes"),
+        ],
+    )
+}
+
+fn validate_code_request(payload: &TwoFactorCodeRequest) -> Result<(), ApiError> {
+    payload.validate().map_err(|e| {
+        tracing::warn!("Two-factor code validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn get_two_factor_status_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<TwoFactorStatusResponse>, ApiError> {
+    let status = state
+        .two_factor_service()
+        .get_status(authenticated_user.id)
+        .await?;
+
+    Ok(Json(status.into()))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn begin_enrollment_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<(StatusCode, Json<TwoFactorEnrollmentResponse>), ApiError> {
+    tracing::debug!("Starting two-factor enrollment.");
+
+    let enrollment = state
+        .two_factor_service()
+        .begin_enrollment(authenticated_user.id)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(enrollment.into())))
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn confirm_enrollment_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Json(payload): Json<TwoFactorCodeRequest>,
+) -> Result<Json<RecoveryCodesResponse>, ApiError> {
+    validate_code_request(&payload)?;
+
+    let recovery_codes = state
+        .two_factor_service()
+        .confirm_enrollment(authenticated_user.id, &payload.code)
+        .await?;
+
+    tracing::info!(user_id = %authenticated_user.id, "Two-factor authentication enabled.");
+    Ok(Json(RecoveryCodesResponse { recovery_codes }))
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn disable_two_factor_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Json(payload): Json<TwoFactorCodeRequest>,
+) -> Result<StatusCode, ApiError> {
+    validate_code_request(&payload)?;
+
+    state
+        .two_factor_service()
+        .disable(authenticated_user.id, &payload.code)
+        .await?;
+
+    tracing::info!(user_id = %authenticated_user.id, "Two-factor authentication disabled.");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn regenerate_recovery_codes_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Json(payload): Json<TwoFactorCodeRequest>,
+) -> Result<Json<RecoveryCodesResponse>, ApiError> {
+    validate_code_request(&payload)?;
+
+    let recovery_codes = state
+        .two_factor_service()
+        .regenerate_recovery_codes(authenticated_user.id, &payload.code)
+        .await?;
+
+    Ok(Json(RecoveryCodesResponse { recovery_codes }))
+}
@@ -1,31 +1,110 @@
 use axum::{
     Json, Router,
-    extract::State,
+    extract::{DefaultBodyLimit, Path, Query, State},
     http::StatusCode,
-    routing::{get, post},
+    routing::{delete, get, post, put},
 };
+use axum_typed_multipart::TypedMultipart;
 use tracing::instrument;
+use uuid::Uuid;
 use validator::Validate;
 
-use crate::api::{
-    auth::{AuthenticatedAdmin, AuthenticatedUser},
-    error::ApiError,
-    models::{request::user_models::CreateUserRequest, response::user_models::UserResponse},
-    state::AppState,
+use crate::{
+    api::{
+        auth::{AuthenticatedAdmin, AuthenticatedUser},
+        error::ApiError,
+        models::{
+            request::{
+                pagination_models::PaginationParams,
+                user_models::{
+                    CreateUserRequest, DeleteUserParams, UpdateAvatarRequestMultipart,
+                    UpdateProfileRequest, UpdateUserRoleRequest, UserDeletionMode,
+                    UserSearchParams,
+                },
+            },
+            response::{pagination::PaginatedResponse, user_models::UserResponse},
+        },
+        openapi::operation::ApiOperation,
+        state::AppState,
+    },
+    config::app_config::APP_CONFIG,
+    domain::models::{
+        audit::AuditContext,
+        user::{UpdateProfileParams, UserDeletionStrategy},
+    },
 };
 
 pub fn user_routes() -> Router<AppState> {
     let user_routes = Router::new()
-        .route("/", post(create_user_handler))
-        .route("/me", get(get_current_user_handler));
+        .route("/", get(list_users_handler).post(create_user_handler))
+        .route(
+            "/me",
+            get(get_current_user_handler).patch(update_current_user_handler),
+        )
+        .route(
+            "/me/avatar",
+            put(update_avatar_handler)
+                .delete(remove_avatar_handler)
+                .layer(DefaultBodyLimit::max(
+                    (APP_CONFIG.max_body_size_mb * 1024 * 1024) as usize,
+                )),
+        )
+        .route("/{user_id}", delete(delete_user_handler))
+        .route("/{user_id}/role", put(update_user_role_handler))
+        .route("/{user_id}/deactivate", post(deactivate_user_handler))
+        .route("/{user_id}/reactivate", post(reactivate_user_handler));
 
     Router::new().nest("/users", user_routes)
 }
 
-#[instrument(skip(state, payload, authenticated_admin), fields(admin_id = %authenticated_admin.id), level = "debug")]
+pub fn user_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Users",
+        vec![
+            ApiOperation::get("/users", "List the users")
+                .admin()
+                .query::<PaginationParams>()
+                .query::<UserSearchParams>()
+                .json_response::<PaginatedResponse<UserResponse>>(StatusCode::OK, "Users"),
+            ApiOperation::post("/users", "Create a user")
+                .admin()
+                .json_body::<CreateUserRequest>()
+                .json_response::<UserResponse>(StatusCode::CREATED, "User")
+                .error(StatusCode::CONFLICT),
+            ApiOperation::get("/users/me", "Get the current user")
+                .json_response::<UserResponse>(StatusCode::OK, "User"),
+            ApiOperation::patch("/users/me", "Update the profile of the current user")
+                .json_body::<UpdateProfileRequest>()
+                .json_response::<UserResponse>(StatusCode::OK, "User")
+                .error(StatusCode::CONFLICT),
+            ApiOperation::put("/users/me/avatar", "Upload the avatar of the current user")
+                .multipart_body::<UpdateAvatarRequestMultipart>()
+                .json_response::<UserResponse>(StatusCode::OK, "User"),
+            ApiOperation::delete("/users/me/avatar", "Remove the avatar of the current user")
+                .json_response::<UserResponse>(StatusCode::OK, "User"),
+            ApiOperation::delete("/users/{user_id}", "Delete a user")
+                .admin()
+                .query::<DeleteUserParams>()
+                .empty_response(StatusCode::NO_CONTENT, "User deleted"),
+            ApiOperation::put("/users/{user_id}/role", "Change the role of a user")
+                .admin()
+                .json_body::<UpdateUserRoleRequest>()
+                .json_response::<UserResponse>(StatusCode::OK, "User"),
+            ApiOperation::post("/users/{user_id}/deactivate", "Deactivate a user")
+                .admin()
+                .json_response::<UserResponse>(StatusCode::OK, "User"),
+            ApiOperation::post("/users/{user_id}/reactivate", "Reactivate a user")
+                .admin()
+                .json_response::<UserResponse>(StatusCode::OK, "User"),
+        ],
+    )
+}
+
+#[instrument(skip(state, payload, authenticated_admin, audit_context), fields(admin_id = %authenticated_admin.id), level = "debug")]
 async fn create_user_handler(
     State(state): State<AppState>,
     authenticated_admin: AuthenticatedAdmin,
+    audit_context: AuditContext,
     Json(payload): Json<CreateUserRequest>,
 ) -> Result<(StatusCode, Json<UserResponse>), ApiError> {
     tracing::debug!("Creating user.");
@@ -43,6 +122,7 @@ async fn create_user_handler(
             payload.first_name.as_deref(),
             payload.last_name.as_deref(),
             payload.role,
+            &audit_context.with_actor(authenticated_admin.id),
         )
         .await?;
 
@@ -64,3 +144,194 @@ async fn get_current_user_handler(
 
     Ok(Json(user.into()))
 }
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn update_current_user_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Json(payload): Json<UpdateProfileRequest>,
+) -> Result<Json<UserResponse>, ApiError> {
+    tracing::debug!("Updating current user profile.");
+
+    payload.validate().map_err(|e| {
+        tracing::warn!("Profile validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    let params = UpdateProfileParams {
+        email: payload.email,
+        first_name: payload.first_name,
+        last_name: payload.last_name,
+        current_password: payload.current_password,
+    };
+
+    let user = state
+        .user_service()
+        .update_profile(authenticated_user.id, params)
+        .await?;
+
+    Ok(Json(user.into()))
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn update_avatar_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    TypedMultipart(payload): TypedMultipart<UpdateAvatarRequestMultipart>,
+) -> Result<Json<UserResponse>, ApiError> {
+    tracing::debug!("Updating current user avatar.");
+
+    let file_name = payload.file.metadata.file_name.ok_or_else(|| {
+        ApiError::validation("File name is required in the multipart data.".to_string())
+    })?;
+
+    let user = state
+        .user_service()
+        .update_avatar(authenticated_user.id, &file_name, payload.file.contents)
+        .await?;
+
+    Ok(Json(user.into()))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id), level = "debug")]
+async fn remove_avatar_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+) -> Result<Json<UserResponse>, ApiError> {
+    let user = state
+        .user_service()
+        .remove_avatar(authenticated_user.id)
+        .await?;
+
+    Ok(Json(user.into()))
+}
+
+#[instrument(skip(state, authenticated_admin, pagination, search_params), fields(admin_id = %authenticated_admin.id), level = "debug")]
+async fn list_users_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+    Query(pagination): Query<PaginationParams>,
+    Query(search_params): Query<UserSearchParams>,
+) -> Result<Json<PaginatedResponse<UserResponse>>, ApiError> {
+    pagination.validate().map_err(|e| {
+        tracing::warn!("Pagination validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+    search_params.validate().map_err(|e| {
+        tracing::warn!("User search validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    tracing::debug!(
+        "Listing users, search: {:?}, page: {}, per_page: {}",
+        search_params.search,
+        pagination.page,
+        pagination.per_page
+    );
+
+    let (users, total_items) = state
+        .user_service()
+        .list_users(
+            search_params.search.as_deref(),
+            pagination.page,
+            pagination.per_page,
+        )
+        .await?;
+
+    let user_responses = users.into_iter().map(UserResponse::from).collect();
+
+    Ok(Json(PaginatedResponse::new(
+        user_responses,
+        pagination.page,
+        pagination.per_page,
+        total_items,
+    )))
+}
+
+#[instrument(skip(state, authenticated_admin, audit_context, payload), fields(admin_id = %authenticated_admin.id, user_id = %user_id), level = "debug")]
+async fn update_user_role_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+    audit_context: AuditContext,
+    Path(user_id): Path<Uuid>,
+    Json(payload): Json<UpdateUserRoleRequest>,
+) -> Result<Json<UserResponse>, ApiError> {
+    let user = state
+        .user_service()
+        .update_user_role(
+            &audit_context.with_actor(authenticated_admin.id),
+            user_id,
+            payload.role,
+        )
+        .await?;
+
+    Ok(Json(user.into()))
+}
+
+#[instrument(skip(state, authenticated_admin, audit_context), fields(admin_id = %authenticated_admin.id, user_id = %user_id), level = "debug")]
+async fn deactivate_user_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+    audit_context: AuditContext,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<UserResponse>, ApiError> {
+    let user = state
+        .user_service()
+        .set_user_active(
+            &audit_context.with_actor(authenticated_admin.id),
+            user_id,
+            false,
+        )
+        .await?;
+
+    Ok(Json(user.into()))
+}
+
+#[instrument(skip(state, authenticated_admin, audit_context), fields(admin_id = %authenticated_admin.id, user_id = %user_id), level = "debug")]
+async fn reactivate_user_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+    audit_context: AuditContext,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<UserResponse>, ApiError> {
+    let user = state
+        .user_service()
+        .set_user_active(
+            &audit_context.with_actor(authenticated_admin.id),
+            user_id,
+            true,
+        )
+        .await?;
+
+    Ok(Json(user.into()))
+}
+
+#[instrument(skip(state, authenticated_admin, audit_context, params), fields(admin_id = %authenticated_admin.id, user_id = %user_id), level = "debug")]
+async fn delete_user_handler(
+    State(state): State<AppState>,
+    authenticated_admin: AuthenticatedAdmin,
+    audit_context: AuditContext,
+    Path(user_id): Path<Uuid>,
+    Query(params): Query<DeleteUserParams>,
+) -> Result<StatusCode, ApiError> {
+    let strategy = match params.mode {
+        UserDeletionMode::Reassign => UserDeletionStrategy::Reassign {
+            new_owner_id: params.reassign_to.ok_or_else(|| {
+                ApiError::validation("reassign_to is required when reassigning content")
+            })?,
+        },
+        UserDeletionMode::Anonymize => UserDeletionStrategy::Anonymize,
+    };
+
+    state
+        .user_service()
+        .delete_user(
+            &audit_context.with_actor(authenticated_admin.id),
+            user_id,
+            strategy,
+        )
+        .await?;
+
+    tracing::info!("User deleted.");
+    Ok(StatusCode::NO_CONTENT)
+}
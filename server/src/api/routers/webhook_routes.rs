@@ -0,0 +1,267 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post},
+};
+use tracing::instrument;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::api::{
+    auth::AuthenticatedUser,
+    error::ApiError,
+    models::{
+        request::{
+            pagination_models::PaginationParams,
+            webhook_models::{CreateWebhookRequest, UpdateWebhookRequest},
+        },
+        response::{
+            pagination::PaginatedResponse,
+            webhook_models::{CreatedWebhookResponse, WebhookDeliveryResponse, WebhookResponse},
+        },
+    },
+    openapi::operation::ApiOperation,
+    state::AppState,
+};
+
+pub fn webhook_routes() -> Router<AppState> {
+    let webhook_routes = Router::new()
+        .route("/", get(list_webhooks_handler).post(create_webhook_handler))
+        .route(
+            "/{webhook_id}",
+            get(get_webhook_handler)
+                .patch(update_webhook_handler)
+                .delete(delete_webhook_handler),
+        )
+        .route(
+            "/{webhook_id}/deliveries",
+            get(get_webhook_deliveries_handler),
+        )
+        .route(
+            "/{webhook_id}/deliveries/{delivery_id}/redeliver",
+            post(redeliver_webhook_handler),
+        );
+
+    Router::new().nest("/boards/{board_id}/webhooks", webhook_routes)
+}
+
+pub fn webhook_route_docs() -> Vec<ApiOperation> {
+    ApiOperation::group(
+        "Webhooks",
+        vec![
+            ApiOperation::get(
+                "/boards/{board_id}/webhooks",
+                "List the webhooks of a board",
+            )
+            .json_response::<Vec<WebhookResponse>>(StatusCode::OK, "Webhooks")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::post("/boards/{board_id}/webhooks", "Create a webhook")
+                .description(
+                    "The signing secret is only returned here. Each request carries its Unix time \
+                     in `X-Rebug-Timestamp` and the HMAC-SHA256 of `<timestamp>.<body>` in \
+                     `X-Rebug-Signature-256`: reject requests whose timestamp is more than a few \
+                     minutes old. `board.created` is not available to webhooks.",
+                )
+                .json_body::<CreateWebhookRequest>()
+                .json_response::<CreatedWebhookResponse>(StatusCode::CREATED, "Webhook")
+                .error(StatusCode::FORBIDDEN),
+            ApiOperation::get("/boards/{board_id}/webhooks/{webhook_id}", "Get a webhook")
+                .json_response::<WebhookResponse>(StatusCode::OK, "Webhook")
+                .error(StatusCode::FORBIDDEN),
+            ApiOperation::patch(
+                "/boards/{board_id}/webhooks/{webhook_id}",
+                "Update a webhook",
+            )
+            .json_body::<UpdateWebhookRequest>()
+            .json_response::<WebhookResponse>(StatusCode::OK, "Webhook")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::delete(
+                "/boards/{board_id}/webhooks/{webhook_id}",
+                "Delete a webhook",
+            )
+            .empty_response(StatusCode::NO_CONTENT, "Webhook deleted")
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::get(
+                "/boards/{board_id}/webhooks/{webhook_id}/deliveries",
+                "List the deliveries of a webhook",
+            )
+            .query::<PaginationParams>()
+            .json_response::<PaginatedResponse<WebhookDeliveryResponse>>(
+                StatusCode::OK,
+                "Deliveries",
+            )
+            .error(StatusCode::FORBIDDEN),
+            ApiOperation::post(
+                "/boards/{board_id}/webhooks/{webhook_id}/deliveries/{delivery_id}/redeliver",
+                "Deliver a webhook event again",
+            )
+            .json_response::<WebhookDeliveryResponse>(StatusCode::ACCEPTED, "New delivery")
+            .error(StatusCode::FORBIDDEN),
+        ],
+    )
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn create_webhook_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+    Json(payload): Json<CreateWebhookRequest>,
+) -> Result<(StatusCode, Json<CreatedWebhookResponse>), ApiError> {
+    payload.validate().map_err(|e| {
+        tracing::warn!("Webhook validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let webhook = state
+        .webhook_service()
+        .create_webhook(
+            board_id,
+            authenticated_user.id,
+            &payload.url,
+            payload.secret,
+            payload.event_types,
+        )
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(webhook.into())))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id), level = "debug")]
+async fn list_webhooks_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path(board_id): Path<Uuid>,
+) -> Result<Json<Vec<WebhookResponse>>, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let webhooks = state.webhook_service().list_webhooks(board_id).await?;
+
+    Ok(Json(
+        webhooks.into_iter().map(WebhookResponse::from).collect(),
+    ))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id, webhook_id = %webhook_id), level = "debug")]
+async fn get_webhook_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path((board_id, webhook_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<WebhookResponse>, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let webhook = state
+        .webhook_service()
+        .get_webhook(board_id, webhook_id)
+        .await?;
+
+    Ok(Json(webhook.into()))
+}
+
+#[instrument(skip(state, authenticated_user, payload), fields(user_id = %authenticated_user.id, board_id = %board_id, webhook_id = %webhook_id), level = "debug")]
+async fn update_webhook_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path((board_id, webhook_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpdateWebhookRequest>,
+) -> Result<Json<WebhookResponse>, ApiError> {
+    payload.validate().map_err(|e| {
+        tracing::warn!("Webhook update validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let webhook = state
+        .webhook_service()
+        .update_webhook(board_id, webhook_id, payload.into())
+        .await?;
+
+    Ok(Json(webhook.into()))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id, webhook_id = %webhook_id), level = "debug")]
+async fn delete_webhook_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path((board_id, webhook_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    state
+        .webhook_service()
+        .delete_webhook(board_id, webhook_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[instrument(skip(state, authenticated_user, pagination), fields(user_id = %authenticated_user.id, board_id = %board_id, webhook_id = %webhook_id), level = "debug")]
+async fn get_webhook_deliveries_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path((board_id, webhook_id)): Path<(Uuid, Uuid)>,
+    Query(pagination): Query<PaginationParams>,
+) -> Result<Json<PaginatedResponse<WebhookDeliveryResponse>>, ApiError> {
+    pagination.validate().map_err(|e| {
+        tracing::warn!("Pagination validation failed: {}", e);
+        ApiError::validation(e.to_string())
+    })?;
+
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let (deliveries, total_items) = state
+        .webhook_service()
+        .get_deliveries_paginated(board_id, webhook_id, pagination.page, pagination.per_page)
+        .await?;
+
+    Ok(Json(PaginatedResponse::new(
+        deliveries
+            .into_iter()
+            .map(WebhookDeliveryResponse::from)
+            .collect(),
+        pagination.page,
+        pagination.per_page,
+        total_items,
+    )))
+}
+
+#[instrument(skip(state, authenticated_user), fields(user_id = %authenticated_user.id, board_id = %board_id, webhook_id = %webhook_id, delivery_id = %delivery_id), level = "debug")]
+async fn redeliver_webhook_handler(
+    State(state): State<AppState>,
+    authenticated_user: AuthenticatedUser,
+    Path((board_id, webhook_id, delivery_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<(StatusCode, Json<WebhookDeliveryResponse>), ApiError> {
+    state
+        .board_service()
+        .ensure_user_can_access_board(authenticated_user.id, board_id)
+        .await?;
+
+    let delivery = state
+        .webhook_service()
+        .redeliver(board_id, webhook_id, delivery_id)
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(delivery.into())))
+}
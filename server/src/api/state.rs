@@ -2,11 +2,20 @@ use std::sync::Arc;
 
 use crate::{
     application::services::{
-        auth_service::AuthServiceInterface, board_service::BoardServiceInterface,
-        dashboard_service::DashboardServiceInterface, health_service::HealthServiceInterface,
-        report_service::ReportServiceInterface,
+        audit_service::AuditServiceInterface, auth_service::AuthServiceInterface,
+        backup_service::BackupServiceInterface, board_export_service::BoardExportServiceInterface,
+        board_import_service::BoardImportServiceInterface, board_service::BoardServiceInterface,
+        chat_notification_service::ChatNotificationServiceInterface,
+        dashboard_service::DashboardServiceInterface,
+        email_notification_service::EmailNotificationServiceInterface,
+        health_service::HealthServiceInterface,
+        inbound_email_service::InboundEmailServiceInterface,
+        intake_service::IntakeServiceInterface, invitation_service::InvitationServiceInterface,
+        issue_tracker_service::IssueTrackerServiceInterface,
+        live_feed_service::LiveFeedServiceInterface, metrics_service::MetricsServiceInterface,
+        report_service::ReportServiceInterface, two_factor_service::TwoFactorServiceInterface,
         user_onboarding_service::UserOnboardingServiceInterface,
-        user_service::UserServiceInterface,
+        user_service::UserServiceInterface, webhook_service::WebhookServiceInterface,
     },
     infrastructure::container::service_container::ServiceContainer,
 };
@@ -27,22 +36,62 @@ impl AppState {
         &self.container.health_service
     }
 
+    pub fn audit_service(&self) -> &Arc<dyn AuditServiceInterface> {
+        &self.container.audit_service
+    }
+
     pub fn auth_service(&self) -> &Arc<dyn AuthServiceInterface> {
         &self.container.auth_service
     }
 
+    pub fn backup_service(&self) -> &Arc<dyn BackupServiceInterface> {
+        &self.container.backup_service
+    }
+
     pub fn user_service(&self) -> &Arc<dyn UserServiceInterface> {
         &self.container.user_service
     }
 
+    pub fn board_export_service(&self) -> &Arc<dyn BoardExportServiceInterface> {
+        &self.container.board_export_service
+    }
+
+    pub fn board_import_service(&self) -> &Arc<dyn BoardImportServiceInterface> {
+        &self.container.board_import_service
+    }
+
     pub fn board_service(&self) -> &Arc<dyn BoardServiceInterface> {
         &self.container.board_service
     }
 
+    pub fn chat_notification_service(&self) -> &Arc<dyn ChatNotificationServiceInterface> {
+        &self.container.chat_notification_service
+    }
+
     pub fn dashboard_service(&self) -> &Arc<dyn DashboardServiceInterface> {
         &self.container.dashboard_service
     }
 
+    pub fn email_notification_service(&self) -> &Arc<dyn EmailNotificationServiceInterface> {
+        &self.container.email_notification_service
+    }
+
+    pub fn inbound_email_service(&self) -> &Arc<dyn InboundEmailServiceInterface> {
+        &self.container.inbound_email_service
+    }
+
+    pub fn intake_service(&self) -> &Arc<dyn IntakeServiceInterface> {
+        &self.container.intake_service
+    }
+
+    pub fn live_feed_service(&self) -> &Arc<dyn LiveFeedServiceInterface> {
+        &self.container.live_feed_service
+    }
+
+    pub fn metrics_service(&self) -> &Arc<dyn MetricsServiceInterface> {
+        &self.container.metrics_service
+    }
+
     pub fn report_service(&self) -> &Arc<dyn ReportServiceInterface> {
         &self.container.report_service
     }
@@ -50,4 +99,20 @@ impl AppState {
     pub fn user_onboarding_service(&self) -> &Arc<dyn UserOnboardingServiceInterface> {
         &self.container.user_onboarding_service
     }
+
+    pub fn invitation_service(&self) -> &Arc<dyn InvitationServiceInterface> {
+        &self.container.invitation_service
+    }
+
+    pub fn issue_tracker_service(&self) -> &Arc<dyn IssueTrackerServiceInterface> {
+        &self.container.issue_tracker_service
+    }
+
+    pub fn two_factor_service(&self) -> &Arc<dyn TwoFactorServiceInterface> {
+        &self.container.two_factor_service
+    }
+
+    pub fn webhook_service(&self) -> &Arc<dyn WebhookServiceInterface> {
+        &self.container.webhook_service
+    }
 }
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::{
+    models::audit::{
+        AuditAction, AuditContext, AuditEvent, AuditEventFilter, AuditTargetType,
+        CreateAuditEventParams,
+    },
+    repositories::{RepositoryError, audit_event_repository::AuditEventRepository},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuditServiceError {
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<RepositoryError> for AuditServiceError {
+    fn from(err: RepositoryError) -> Self {
+        AuditServiceError::InternalError(err.to_string())
+    }
+}
+
+pub type AuditServiceResult<T> = Result<T, AuditServiceError>;
+
+#[async_trait]
+pub trait AuditServiceInterface: Send + Sync {
+    /// Appends an event to the audit log. A failure is logged rather than returned: the action
+    /// being audited has already happened.
+    async fn record(
+        &self,
+        context: &AuditContext,
+        action: AuditAction,
+        target_type: AuditTargetType,
+        target_id: Option<Uuid>,
+        changes: serde_json::Value,
+    );
+
+    /// Most recent events first.
+    async fn list_events(
+        &self,
+        filter: AuditEventFilter,
+        page: i32,
+        per_page: i32,
+    ) -> AuditServiceResult<(Vec<AuditEvent>, i32)>;
+
+    /// Every matching event, oldest first.
+    async fn export_events(&self, filter: AuditEventFilter) -> AuditServiceResult<Vec<AuditEvent>>;
+}
+
+pub struct AuditService {
+    audit_event_repository: Arc<dyn AuditEventRepository>,
+}
+
+impl AuditService {
+    pub fn new(audit_event_repository: Arc<dyn AuditEventRepository>) -> Self {
+        Self {
+            audit_event_repository,
+        }
+    }
+
+    fn validate_filter(filter: &AuditEventFilter) -> AuditServiceResult<()> {
+        if let (Some(from), Some(to)) = (filter.from, filter.to)
+            && from >= to
+        {
+            return Err(AuditServiceError::ValidationError(
+                "`from` must be before `to`".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AuditServiceInterface for AuditService {
+    #[instrument(skip(self, context, changes), fields(actor_id = ?context.actor_id, action = %action, target_id = ?target_id), level = "debug")]
+    async fn record(
+        &self,
+        context: &AuditContext,
+        action: AuditAction,
+        target_type: AuditTargetType,
+        target_id: Option<Uuid>,
+        changes: serde_json::Value,
+    ) {
+        let params = CreateAuditEventParams {
+            actor_id: context.actor_id,
+            action,
+            target_type,
+            target_id,
+            ip_address: context.ip_address.map(|ip| ip.to_string()),
+            user_agent: context.user_agent.clone(),
+            changes,
+        };
+
+        if let Err(e) = self.audit_event_repository.create_event(params).await {
+            tracing::error!("Failed to record audit event: {}", e);
+        }
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn list_events(
+        &self,
+        filter: AuditEventFilter,
+        page: i32,
+        per_page: i32,
+    ) -> AuditServiceResult<(Vec<AuditEvent>, i32)> {
+        Self::validate_filter(&filter)?;
+
+        let events = self
+            .audit_event_repository
+            .find_paginated(&filter, page, per_page)
+            .await?;
+        let total_items = self.audit_event_repository.count(&filter).await?;
+
+        Ok((events, total_items))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn export_events(&self, filter: AuditEventFilter) -> AuditServiceResult<Vec<AuditEvent>> {
+        Self::validate_filter(&filter)?;
+
+        let events = self.audit_event_repository.find_all(&filter).await?;
+
+        tracing::info!(count = events.len(), "Audit events exported.");
+        Ok(events)
+    }
+}
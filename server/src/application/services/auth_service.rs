@@ -2,20 +2,32 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use chrono::{Duration, Utc};
-use jsonwebtoken::{Header, encode};
+use jsonwebtoken::{Header, Validation, decode, encode};
+use serde_json::json;
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::{
     config::app_config::{APP_CONFIG, JWT_KEYS},
     domain::models::{
-        auth::TokenClaims,
+        audit::{AuditAction, AuditContext, AuditTargetType},
+        auth::{LoginOutcome, StreamTicketClaims, TokenClaims, TwoFactorChallengeClaims},
         user::{User, UserRole},
     },
-    infrastructure::security::password_hasher::{PasswordError, verify_password},
+    infrastructure::security::{
+        password_hasher::{PasswordError, needs_rehash, verify_password},
+        rate_limiter::RateLimiter,
+    },
+};
+
+use super::{
+    audit_service::AuditServiceInterface,
+    two_factor_service::{TwoFactorServiceError, TwoFactorServiceInterface},
+    user_service::UserServiceInterface,
 };
 
-use super::user_service::UserServiceInterface;
+const TWO_FACTOR_CHALLENGE_AUDIENCE: &str = "rebug:two-factor-challenge";
+const STREAM_TICKET_AUDIENCE: &str = "rebug:event-stream";
 
 #[derive(Debug, thiserror::Error)]
 pub enum AuthServiceError {
@@ -23,6 +35,14 @@ pub enum AuthServiceError {
     TokenCreationError(String),
     #[error("Invalid credentials")]
     InvalidCredentials,
+    #[error("Invalid or expired two-factor challenge")]
+    InvalidTwoFactorChallenge,
+    #[error("Invalid two-factor code")]
+    InvalidTwoFactorCode,
+    #[error("Too many two-factor attempts")]
+    TooManyTwoFactorAttempts,
+    #[error("Invalid or expired stream ticket")]
+    InvalidStreamTicket,
     #[error("Password hashing failed: {0}")]
     PasswordHashingError(String),
     #[error("Internal server error: {0}")]
@@ -40,21 +60,107 @@ impl From<PasswordError> for AuthServiceError {
     }
 }
 
+impl From<TwoFactorServiceError> for AuthServiceError {
+    fn from(err: TwoFactorServiceError) -> Self {
+        match err {
+            TwoFactorServiceError::InvalidCode => AuthServiceError::InvalidTwoFactorCode,
+            TwoFactorServiceError::UserNotFound | TwoFactorServiceError::NotEnabled => {
+                AuthServiceError::InvalidTwoFactorChallenge
+            }
+            _ => AuthServiceError::InternalError(err.to_string()),
+        }
+    }
+}
+
 pub type AuthServiceResult<T> = Result<T, AuthServiceError>;
 
 #[async_trait]
 pub trait AuthServiceInterface: Send + Sync {
-    async fn login_user(&self, email: &str, password: &str) -> AuthServiceResult<(User, String)>;
+    /// Checks the credentials. Users with two-factor enabled get a challenge instead of a token.
+    async fn login_user(
+        &self,
+        email: &str,
+        password: &str,
+        context: &AuditContext,
+    ) -> AuthServiceResult<LoginOutcome>;
+
+    async fn complete_two_factor_login(
+        &self,
+        challenge_token: &str,
+        code: &str,
+        context: &AuditContext,
+    ) -> AuthServiceResult<(User, String)>;
+
+    /// Issues a short-lived ticket to open the live event stream with.
+    fn create_stream_ticket(&self, user_id: Uuid) -> AuthServiceResult<String>;
+
+    /// Returns the user the stream ticket was issued to.
+    fn verify_stream_ticket(&self, ticket: &str) -> AuthServiceResult<Uuid>;
 }
 
-#[derive(Clone)]
 pub struct AuthService {
     user_service: Arc<dyn UserServiceInterface>,
+    two_factor_service: Arc<dyn TwoFactorServiceInterface>,
+    audit_service: Arc<dyn AuditServiceInterface>,
+    /// Codes tried with each challenge, over the lifetime of the challenge.
+    challenge_attempts: RateLimiter<Uuid>,
+    /// Codes tried by each user, whatever the challenge, so that logging in again does not give
+    /// more tries.
+    user_two_factor_attempts: RateLimiter<Uuid>,
 }
 
 impl AuthService {
-    pub fn new(user_service: Arc<dyn UserServiceInterface>) -> Self {
-        Self { user_service }
+    pub fn new(
+        user_service: Arc<dyn UserServiceInterface>,
+        two_factor_service: Arc<dyn TwoFactorServiceInterface>,
+        audit_service: Arc<dyn AuditServiceInterface>,
+    ) -> Self {
+        Self {
+            user_service,
+            two_factor_service,
+            audit_service,
+            challenge_attempts: RateLimiter::new(
+                APP_CONFIG.two_factor_max_attempts_per_challenge,
+                Duration::seconds(APP_CONFIG.two_factor_challenge_expiration_seconds)
+                    .to_std()
+                    .unwrap_or_default(),
+            ),
+            user_two_factor_attempts: RateLimiter::new(
+                APP_CONFIG.two_factor_max_attempts_per_user,
+                std::time::Duration::from_secs(APP_CONFIG.two_factor_attempt_window_seconds),
+            ),
+        }
+    }
+
+    async fn record_login(&self, context: &AuditContext, user: &User) {
+        self.audit_service
+            .record(
+                &context.with_actor(user.id),
+                AuditAction::Login,
+                AuditTargetType::User,
+                Some(user.id),
+                json!({ "two_factor": user.two_factor_enabled }),
+            )
+            .await;
+    }
+
+    /// `user` is the account the attempt was made on, when it exists.
+    async fn record_failed_login(
+        &self,
+        context: &AuditContext,
+        email: &str,
+        user: Option<&User>,
+        reason: &str,
+    ) {
+        self.audit_service
+            .record(
+                context,
+                AuditAction::LoginFailed,
+                AuditTargetType::User,
+                user.map(|user| user.id),
+                json!({ "email": email, "reason": reason }),
+            )
+            .await;
     }
 
     #[instrument(skip(self), fields(user_id = %user_id, role = %role), level = "debug")]
@@ -82,32 +188,191 @@ impl AuthService {
             AuthServiceError::TokenCreationError(format!("Failed to create JWT: {}", e))
         })
     }
+
+    #[instrument(skip(self), fields(user_id = %user_id), level = "debug")]
+    fn create_two_factor_challenge(&self, user_id: Uuid) -> Result<String, AuthServiceError> {
+        let now = Utc::now();
+        let iat = now.timestamp() as usize;
+        let exp = (now + Duration::seconds(APP_CONFIG.two_factor_challenge_expiration_seconds))
+            .timestamp() as usize;
+
+        let claims = TwoFactorChallengeClaims {
+            sub: user_id,
+            aud: TWO_FACTOR_CHALLENGE_AUDIENCE.to_string(),
+            jti: Uuid::new_v4(),
+            exp,
+            iat,
+        };
+
+        encode(&Header::default(), &claims, &JWT_KEYS.encoding).map_err(|e| {
+            AuthServiceError::TokenCreationError(format!(
+                "Failed to create two-factor challenge: {}",
+                e
+            ))
+        })
+    }
+
+    fn decode_two_factor_challenge(
+        &self,
+        challenge_token: &str,
+    ) -> AuthServiceResult<TwoFactorChallengeClaims> {
+        let mut validation = Validation::default();
+        validation.set_audience(&[TWO_FACTOR_CHALLENGE_AUDIENCE]);
+
+        decode::<TwoFactorChallengeClaims>(challenge_token, &JWT_KEYS.decoding, &validation)
+            .map(|token_data| token_data.claims)
+            .map_err(|e| {
+                tracing::warn!("Two-factor challenge validation failed: {}", e);
+                AuthServiceError::InvalidTwoFactorChallenge
+            })
+    }
 }
 
 #[async_trait]
 impl AuthServiceInterface for AuthService {
-    #[instrument(skip(self, password), level = "debug")]
-    async fn login_user(&self, email: &str, password: &str) -> AuthServiceResult<(User, String)> {
+    #[instrument(skip(self, password, context), level = "debug")]
+    async fn login_user(
+        &self,
+        email: &str,
+        password: &str,
+        context: &AuditContext,
+    ) -> AuthServiceResult<LoginOutcome> {
         tracing::debug!("Attempting to authenticate user.");
 
-        let user = self
-            .user_service
-            .get_user_by_email(email)
-            .await
-            .map_err(|_| {
+        let user = match self.user_service.get_user_by_email(email).await {
+            Ok(user) => user,
+            Err(_) => {
                 tracing::warn!(
                     "Authentication failed: user not found or other error during lookup."
                 );
-                AuthServiceError::InvalidCredentials
-            })?;
-
-        if verify_password(password, &user.password_hash)? {
-            tracing::debug!(user_id = %user.id, "Password verification successful.");
-            let token = self.create_jwt(user.id, email, &user.role)?;
-            Ok((user, token))
-        } else {
+                self.record_failed_login(context, email, None, "unknown_user")
+                    .await;
+                return Err(AuthServiceError::InvalidCredentials);
+            }
+        };
+
+        if !user.is_active {
+            tracing::warn!(user_id = %user.id, "Authentication failed: account is deactivated.");
+            self.record_failed_login(context, email, Some(&user), "deactivated")
+                .await;
+            return Err(AuthServiceError::InvalidCredentials);
+        }
+
+        if !verify_password(password, &user.password_hash).await? {
             tracing::warn!(user_id = %user.id, "Password verification failed.");
-            Err(AuthServiceError::InvalidCredentials)
+            self.record_failed_login(context, email, Some(&user), "invalid_password")
+                .await;
+            return Err(AuthServiceError::InvalidCredentials);
+        }
+
+        tracing::debug!(user_id = %user.id, "Password verification successful.");
+
+        if needs_rehash(&user.password_hash)
+            && let Err(e) = self.user_service.rehash_password(user.id, password).await
+        {
+            tracing::warn!(user_id = %user.id, "Failed to upgrade password hash: {}", e);
         }
+
+        if user.two_factor_enabled {
+            tracing::debug!(user_id = %user.id, "Two-factor authentication required.");
+            let challenge_token = self.create_two_factor_challenge(user.id)?;
+            return Ok(LoginOutcome::TwoFactorRequired {
+                user,
+                challenge_token,
+            });
+        }
+
+        let two_factor_setup_required = self
+            .two_factor_service
+            .is_enrollment_required(&user)
+            .await?;
+        let access_token = self.create_jwt(user.id, email, &user.role)?;
+        self.record_login(context, &user).await;
+        Ok(LoginOutcome::Authenticated {
+            user,
+            access_token,
+            two_factor_setup_required,
+        })
+    }
+
+    #[instrument(skip(self, challenge_token, code, context), level = "debug")]
+    async fn complete_two_factor_login(
+        &self,
+        challenge_token: &str,
+        code: &str,
+        context: &AuditContext,
+    ) -> AuthServiceResult<(User, String)> {
+        let challenge = self.decode_two_factor_challenge(challenge_token)?;
+        let user_id = challenge.sub;
+
+        // The window of a challenge outlasts it: once out of attempts, it stays unusable.
+        if !self.challenge_attempts.check(challenge.jti) {
+            tracing::warn!(user_id = %user_id, "Two-factor challenge out of attempts.");
+            return Err(AuthServiceError::InvalidTwoFactorChallenge);
+        }
+        if !self.user_two_factor_attempts.check(user_id) {
+            tracing::warn!(user_id = %user_id, "Too many two-factor attempts for the user.");
+            return Err(AuthServiceError::TooManyTwoFactorAttempts);
+        }
+
+        let user = self
+            .user_service
+            .get_user_by_id(user_id)
+            .await
+            .map_err(|_| AuthServiceError::InvalidTwoFactorChallenge)?;
+
+        if !user.is_active {
+            tracing::warn!(user_id = %user.id, "Two-factor login rejected: account is deactivated.");
+            return Err(AuthServiceError::InvalidTwoFactorChallenge);
+        }
+
+        if let Err(e) = self.two_factor_service.verify_code(&user, code).await {
+            if matches!(e, TwoFactorServiceError::InvalidCode) {
+                self.record_failed_login(
+                    context,
+                    &user.email,
+                    Some(&user),
+                    "invalid_two_factor_code",
+                )
+                .await;
+            }
+            return Err(e.into());
+        }
+
+        tracing::debug!(user_id = %user.id, "Two-factor verification successful.");
+        let token = self.create_jwt(user.id, &user.email, &user.role)?;
+        self.record_login(context, &user).await;
+        Ok((user, token))
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id), level = "debug")]
+    fn create_stream_ticket(&self, user_id: Uuid) -> AuthServiceResult<String> {
+        let now = Utc::now();
+        let iat = now.timestamp() as usize;
+        let exp = (now + Duration::seconds(APP_CONFIG.stream_ticket_expiration_seconds)).timestamp()
+            as usize;
+
+        let claims = StreamTicketClaims {
+            sub: user_id,
+            aud: STREAM_TICKET_AUDIENCE.to_string(),
+            exp,
+            iat,
+        };
+
+        encode(&Header::default(), &claims, &JWT_KEYS.encoding).map_err(|e| {
+            AuthServiceError::TokenCreationError(format!("Failed to create stream ticket: {}", e))
+        })
+    }
+
+    fn verify_stream_ticket(&self, ticket: &str) -> AuthServiceResult<Uuid> {
+        let mut validation = Validation::default();
+        validation.set_audience(&[STREAM_TICKET_AUDIENCE]);
+
+        decode::<StreamTicketClaims>(ticket, &JWT_KEYS.decoding, &validation)
+            .map(|token_data| token_data.claims.sub)
+            .map_err(|e| {
+                tracing::warn!("Stream ticket validation failed: {}", e);
+                AuthServiceError::InvalidStreamTicket
+            })
     }
 }
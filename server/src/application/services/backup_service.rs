@@ -0,0 +1,292 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use tokio::{io::AsyncWriteExt, sync::Mutex};
+use tracing::instrument;
+
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::{
+        models::backup::Backup,
+        ports::database_backup_port::{DatabaseBackupError, DatabaseBackupPort},
+    },
+    infrastructure::export::zip_writer::{ZipCompression, ZipStreamWriter, ZipWriterError},
+};
+
+const SNAPSHOT_PREFIX: &str = "rebug-";
+const SNAPSHOT_TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+const DATABASE_EXTENSION: &str = "db";
+const ARCHIVE_EXTENSION: &str = "zip";
+/// Snapshots are written under a temporary name and renamed once complete, so that an
+/// interrupted backup is never listed nor restored.
+const PARTIAL_EXTENSION: &str = "partial";
+const ARCHIVE_DATABASE_PATH: &str = "rebug.db";
+const ARCHIVE_UPLOADS_DIRECTORY: &str = "uploads";
+
+#[derive(Debug, thiserror::Error)]
+pub enum BackupServiceError {
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("A backup is already in progress")]
+    BackupInProgress,
+    #[error("Backup failed: {0}")]
+    BackupFailed(#[from] DatabaseBackupError),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<std::io::Error> for BackupServiceError {
+    fn from(err: std::io::Error) -> Self {
+        BackupServiceError::InternalError(err.to_string())
+    }
+}
+
+pub type BackupServiceResult<T> = Result<T, BackupServiceError>;
+
+#[async_trait]
+pub trait BackupServiceInterface: Send + Sync {
+    /// Copies the live database to a new file and returns the size of the copy.
+    async fn backup_database(&self, destination: &Path) -> BackupServiceResult<u64>;
+
+    /// Takes a snapshot in the backup directory, bundled with the uploaded files when configured,
+    /// then deletes the snapshots beyond the retention.
+    async fn create_snapshot(&self) -> BackupServiceResult<Backup>;
+
+    /// Snapshots in the backup directory, newest first.
+    async fn list_snapshots(&self) -> BackupServiceResult<Vec<Backup>>;
+}
+
+pub struct BackupService {
+    database_backup: Arc<dyn DatabaseBackupPort>,
+    /// Held while a snapshot is taken, by the schedule or an admin.
+    snapshot_lock: Mutex<()>,
+}
+
+impl BackupService {
+    pub fn new(database_backup: Arc<dyn DatabaseBackupPort>) -> Self {
+        Self {
+            database_backup,
+            snapshot_lock: Mutex::new(()),
+        }
+    }
+
+    fn backup_directory() -> &'static Path {
+        Path::new(&APP_CONFIG.backup_directory)
+    }
+
+    /// Reads a snapshot from its file name, `rebug-<timestamp>.db` or `rebug-<timestamp>.zip`.
+    fn parse_snapshot(file_name: &str, size_bytes: u64) -> Option<Backup> {
+        let (stem, extension) = file_name.strip_prefix(SNAPSHOT_PREFIX)?.rsplit_once('.')?;
+        let includes_uploads = match extension {
+            DATABASE_EXTENSION => false,
+            ARCHIVE_EXTENSION => true,
+            _ => return None,
+        };
+        let created_at = NaiveDateTime::parse_from_str(stem, SNAPSHOT_TIMESTAMP_FORMAT)
+            .ok()?
+            .and_utc();
+
+        Some(Backup {
+            file_name: file_name.to_string(),
+            size_bytes,
+            created_at,
+            includes_uploads,
+        })
+    }
+
+    /// Writes the ZIP archive of the database copy and of every uploaded file. Uploaded files are
+    /// never modified and are stored before the rows referencing them, so the files listed after
+    /// the database copy include all the ones it references.
+    async fn write_archive(database_copy: &Path, destination: &Path) -> BackupServiceResult<()> {
+        let mut archive = tokio::fs::File::create(destination).await?;
+        let mut zip_writer = ZipStreamWriter::new();
+        let zip_error = |e: ZipWriterError| BackupServiceError::InternalError(e.to_string());
+
+        let database = tokio::fs::read(database_copy).await?;
+        let entry = zip_writer
+            .add_file(
+                ARCHIVE_DATABASE_PATH,
+                &database,
+                ZipCompression::Deflated,
+                Utc::now(),
+            )
+            .map_err(zip_error)?;
+        drop(database);
+        archive.write_all(&entry).await?;
+
+        for (path, modified_at) in list_files(Path::new(&APP_CONFIG.upload_directory)).await? {
+            let relative_path = path
+                .strip_prefix(&APP_CONFIG.upload_directory)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let data = tokio::fs::read(&path).await?;
+            let entry = zip_writer
+                .add_file(
+                    &format!("{}/{}", ARCHIVE_UPLOADS_DIRECTORY, relative_path),
+                    &data,
+                    ZipCompression::Stored,
+                    modified_at,
+                )
+                .map_err(zip_error)?;
+            archive.write_all(&entry).await?;
+        }
+
+        archive.write_all(&zip_writer.finish()).await?;
+        archive.sync_all().await?;
+        Ok(())
+    }
+
+    /// Deletes the oldest snapshots beyond the retention.
+    async fn prune_snapshots(&self) -> BackupServiceResult<()> {
+        if APP_CONFIG.backup_retention == 0 {
+            return Ok(());
+        }
+
+        let snapshots = self.list_snapshots().await?;
+        for snapshot in snapshots.iter().skip(APP_CONFIG.backup_retention) {
+            tokio::fs::remove_file(Self::backup_directory().join(&snapshot.file_name)).await?;
+            tracing::info!(file_name = %snapshot.file_name, "Old backup deleted.");
+        }
+        Ok(())
+    }
+}
+
+/// Files under `directory`, recursively, with their modification time.
+async fn list_files(directory: &Path) -> std::io::Result<Vec<(PathBuf, DateTime<Utc>)>> {
+    let mut files = Vec::new();
+    let mut pending = vec![directory.to_path_buf()];
+
+    while let Some(directory) = pending.pop() {
+        let mut entries = match tokio::fs::read_dir(&directory).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let metadata = entry.metadata().await?;
+            if metadata.is_dir() {
+                pending.push(entry.path());
+            } else if metadata.is_file() {
+                files.push((entry.path(), metadata.modified()?.into()));
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+#[async_trait]
+impl BackupServiceInterface for BackupService {
+    #[instrument(skip(self), fields(destination = %destination.display()), level = "info")]
+    async fn backup_database(&self, destination: &Path) -> BackupServiceResult<u64> {
+        if tokio::fs::try_exists(destination).await? {
+            return Err(BackupServiceError::ValidationError(format!(
+                "'{}' already exists",
+                destination.display()
+            )));
+        }
+
+        self.database_backup.backup_to(destination).await?;
+
+        let size = tokio::fs::metadata(destination).await?.len();
+
+        tracing::info!(size, "Database backed up.");
+        Ok(size)
+    }
+
+    #[instrument(skip(self), level = "info")]
+    async fn create_snapshot(&self) -> BackupServiceResult<Backup> {
+        let _guard = self
+            .snapshot_lock
+            .try_lock()
+            .map_err(|_| BackupServiceError::BackupInProgress)?;
+
+        let backup_directory = Self::backup_directory();
+        tokio::fs::create_dir_all(backup_directory).await?;
+
+        let stem = format!(
+            "{}{}",
+            SNAPSHOT_PREFIX,
+            Utc::now().format(SNAPSHOT_TIMESTAMP_FORMAT)
+        );
+        let extension = if APP_CONFIG.backup_include_uploads {
+            ARCHIVE_EXTENSION
+        } else {
+            DATABASE_EXTENSION
+        };
+        let file_name = format!("{}.{}", stem, extension);
+        let destination = backup_directory.join(&file_name);
+        if tokio::fs::try_exists(&destination).await? {
+            return Err(BackupServiceError::ValidationError(format!(
+                "A backup named '{}' already exists",
+                file_name
+            )));
+        }
+
+        let database_copy = backup_directory.join(format!(
+            "{}.{}.{}",
+            stem, DATABASE_EXTENSION, PARTIAL_EXTENSION
+        ));
+        let _ = tokio::fs::remove_file(&database_copy).await;
+        if let Err(e) = self.database_backup.backup_to(&database_copy).await {
+            let _ = tokio::fs::remove_file(&database_copy).await;
+            return Err(e.into());
+        }
+
+        if APP_CONFIG.backup_include_uploads {
+            let partial_archive =
+                backup_directory.join(format!("{}.{}", file_name, PARTIAL_EXTENSION));
+            let written = Self::write_archive(&database_copy, &partial_archive).await;
+            let _ = tokio::fs::remove_file(&database_copy).await;
+            if let Err(e) = written {
+                let _ = tokio::fs::remove_file(&partial_archive).await;
+                return Err(e);
+            }
+            tokio::fs::rename(&partial_archive, &destination).await?;
+        } else {
+            tokio::fs::rename(&database_copy, &destination).await?;
+        }
+
+        let size_bytes = tokio::fs::metadata(&destination).await?.len();
+        let snapshot = Self::parse_snapshot(&file_name, size_bytes).ok_or_else(|| {
+            BackupServiceError::InternalError(format!("Unexpected backup name '{}'", file_name))
+        })?;
+        tracing::info!(file_name = %snapshot.file_name, size_bytes, "Backup created.");
+
+        self.prune_snapshots().await?;
+        Ok(snapshot)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn list_snapshots(&self) -> BackupServiceResult<Vec<Backup>> {
+        let mut entries = match tokio::fs::read_dir(Self::backup_directory()).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut snapshots = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let metadata = entry.metadata().await?;
+            if !metadata.is_file() {
+                continue;
+            }
+            if let Some(snapshot) = entry
+                .file_name()
+                .to_str()
+                .and_then(|file_name| Self::parse_snapshot(file_name, metadata.len()))
+            {
+                snapshots.push(snapshot);
+            }
+        }
+
+        snapshots.sort_by_key(|snapshot| std::cmp::Reverse(snapshot.created_at));
+        Ok(snapshots)
+    }
+}
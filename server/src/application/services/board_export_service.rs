@@ -0,0 +1,415 @@
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::{StreamExt, stream::BoxStream};
+use tokio::sync::mpsc;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::{
+            board::Board,
+            board_export::{
+                BOARD_EXPORT_FORMAT, BOARD_EXPORT_MANIFEST_PATH, BOARD_EXPORT_VERSION,
+                BoardExportManifest, ExportedBoard, ExportedReport, ExportedReportHistoryEntry,
+            },
+            report::Report,
+            user::ANONYMOUS_REPORTER_ID,
+        },
+        ports::storage_port::StoragePort,
+    },
+    infrastructure::export::{
+        csv_writer::write_csv_record,
+        zip_writer::{ZipCompression, ZipStreamWriter},
+    },
+};
+
+use super::{
+    board_service::{BoardServiceError, BoardServiceInterface},
+    report_service::{ReportServiceError, ReportServiceInterface},
+    user_service::{UserServiceError, UserServiceInterface},
+};
+
+/// Chunks waiting to be sent to the client. Each one holds a whole media file, so this bounds
+/// the memory used by a download.
+const ARCHIVE_CHANNEL_CAPACITY: usize = 2;
+const CSV_HEADER: [&str; 17] = [
+    "id",
+    "title",
+    "description",
+    "status",
+    "report_type",
+    "reporter_email",
+    "contact_email",
+    "url",
+    "browser_name",
+    "browser_version",
+    "os_name",
+    "file_url",
+    "thumbnail_url",
+    "external_issue_key",
+    "external_issue_url",
+    "created_at",
+    "updated_at",
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum BoardExportServiceError {
+    #[error("Board not found")]
+    BoardNotFound,
+    #[error("Report error: {0}")]
+    ReportError(#[from] ReportServiceError),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<BoardServiceError> for BoardExportServiceError {
+    fn from(err: BoardServiceError) -> Self {
+        match err {
+            BoardServiceError::BoardNotFound => BoardExportServiceError::BoardNotFound,
+            _ => BoardExportServiceError::InternalError(err.to_string()),
+        }
+    }
+}
+
+pub type BoardExportServiceResult<T> = Result<T, BoardExportServiceError>;
+
+pub type BoardArchiveStream = BoxStream<'static, std::io::Result<Bytes>>;
+
+/// An export ready to be downloaded.
+pub struct BoardExportDownload<T> {
+    pub file_name: String,
+    pub content: T,
+}
+
+#[async_trait]
+pub trait BoardExportServiceInterface: Send + Sync {
+    /// ZIP archive of the board: a versioned `manifest.json` describing the board and its
+    /// reports, and the report media files. Media that cannot be read are left out.
+    async fn export_archive(
+        &self,
+        board_id: Uuid,
+    ) -> BoardExportServiceResult<BoardExportDownload<BoardArchiveStream>>;
+
+    /// One CSV line per report, media linked by URL.
+    async fn export_csv(
+        &self,
+        board_id: Uuid,
+    ) -> BoardExportServiceResult<BoardExportDownload<String>>;
+}
+
+pub struct BoardExportService {
+    board_service: Arc<dyn BoardServiceInterface>,
+    report_service: Arc<dyn ReportServiceInterface>,
+    user_service: Arc<dyn UserServiceInterface>,
+    storage_port: Arc<dyn StoragePort>,
+}
+
+/// Storage identifiers of the media of a manifest report.
+struct ReportMedia {
+    file_path: String,
+    thumbnail_file_path: Option<String>,
+}
+
+impl BoardExportService {
+    pub fn new(
+        board_service: Arc<dyn BoardServiceInterface>,
+        report_service: Arc<dyn ReportServiceInterface>,
+        user_service: Arc<dyn UserServiceInterface>,
+        storage_port: Arc<dyn StoragePort>,
+    ) -> Self {
+        Self {
+            board_service,
+            report_service,
+            user_service,
+            storage_port,
+        }
+    }
+
+    /// Email of the user, cached since the same few users show up on most reports.
+    async fn user_email(
+        &self,
+        emails: &mut HashMap<Uuid, Option<String>>,
+        user_id: Uuid,
+    ) -> BoardExportServiceResult<Option<String>> {
+        if user_id == ANONYMOUS_REPORTER_ID {
+            return Ok(None);
+        }
+        if let Some(email) = emails.get(&user_id) {
+            return Ok(email.clone());
+        }
+
+        let email = match self.user_service.get_user_by_id(user_id).await {
+            Ok(user) => Some(user.email),
+            Err(UserServiceError::UserNotFound) => None,
+            Err(err) => return Err(BoardExportServiceError::InternalError(err.to_string())),
+        };
+        emails.insert(user_id, email.clone());
+
+        Ok(email)
+    }
+
+    async fn build_manifest(
+        &self,
+        board: &Board,
+        reports: Vec<Report>,
+    ) -> BoardExportServiceResult<(BoardExportManifest, Vec<ReportMedia>)> {
+        let mut emails = HashMap::new();
+        let mut exported_reports = Vec::with_capacity(reports.len());
+        let mut media = Vec::with_capacity(reports.len());
+
+        for report in reports {
+            let mut history = Vec::new();
+            for entry in self.report_service.get_report_history(report.id).await? {
+                history.push(ExportedReportHistoryEntry {
+                    field: entry.field,
+                    old_value: entry.old_value,
+                    new_value: entry.new_value,
+                    source: entry.source,
+                    actor_email: match entry.actor_id {
+                        Some(actor_id) => self.user_email(&mut emails, actor_id).await?,
+                        None => None,
+                    },
+                    details: entry.details,
+                    created_at: entry.created_at,
+                });
+            }
+
+            exported_reports.push(ExportedReport {
+                id: report.id,
+                report_type: report.report_type,
+                status: report.status,
+                title: report.title,
+                description: report.description,
+                reporter_email: self.user_email(&mut emails, report.user_id).await?,
+                contact_email: report.contact_email,
+                url: report.url,
+                browser_name: report.browser_name,
+                browser_version: report.browser_version,
+                os_name: report.os_name,
+                external_issue_key: report.external_issue_key,
+                external_issue_url: report.external_issue_url,
+                file: Some(media_archive_path(report.id, "", &report.file_path)),
+                thumbnail_file: report
+                    .thumbnail_file_path
+                    .as_deref()
+                    .map(|path| media_archive_path(report.id, "-thumbnail", path)),
+                history,
+                created_at: report.created_at,
+                updated_at: report.updated_at,
+            });
+            media.push(ReportMedia {
+                file_path: report.file_path,
+                thumbnail_file_path: report.thumbnail_file_path,
+            });
+        }
+
+        let manifest = BoardExportManifest {
+            format: BOARD_EXPORT_FORMAT.to_string(),
+            version: BOARD_EXPORT_VERSION,
+            exported_at: chrono::Utc::now(),
+            board: ExportedBoard {
+                id: board.id,
+                name: board.name.clone(),
+                description: board.description.clone(),
+                owner_email: self.user_email(&mut emails, board.owner_id).await?,
+                created_at: board.created_at,
+                updated_at: board.updated_at,
+            },
+            reports: exported_reports,
+        };
+
+        Ok((manifest, media))
+    }
+}
+
+#[async_trait]
+impl BoardExportServiceInterface for BoardExportService {
+    #[instrument(skip(self), fields(board_id = %board_id), level = "info")]
+    async fn export_archive(
+        &self,
+        board_id: Uuid,
+    ) -> BoardExportServiceResult<BoardExportDownload<BoardArchiveStream>> {
+        let board = self.board_service.get_board_by_id(board_id).await?;
+        let reports = self
+            .report_service
+            .get_all_reports_by_board(board_id)
+            .await?;
+        let (manifest, media) = self.build_manifest(&board, reports).await?;
+
+        // Everything that can fail cleanly is done: the archive is now written as it is sent.
+        let (sender, receiver) = mpsc::channel(ARCHIVE_CHANNEL_CAPACITY);
+        tokio::spawn(write_archive(
+            self.storage_port.clone(),
+            manifest,
+            media,
+            sender,
+        ));
+
+        let stream = futures_util::stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|chunk| (chunk, receiver))
+        });
+
+        tracing::info!("Board archive export started.");
+        Ok(BoardExportDownload {
+            file_name: format!("{}.zip", export_file_stem(&board)),
+            content: stream.boxed(),
+        })
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id), level = "info")]
+    async fn export_csv(
+        &self,
+        board_id: Uuid,
+    ) -> BoardExportServiceResult<BoardExportDownload<String>> {
+        let board = self.board_service.get_board_by_id(board_id).await?;
+        let reports = self
+            .report_service
+            .get_all_reports_by_board(board_id)
+            .await?;
+
+        // The byte order mark makes spreadsheet applications read the file as UTF-8.
+        let mut csv = String::from("\u{feff}");
+        write_csv_record(&mut csv, CSV_HEADER);
+
+        let mut emails = HashMap::new();
+        for report in reports {
+            let reporter_email = self.user_email(&mut emails, report.user_id).await?;
+            let id = report.id.to_string();
+            let status = report.status.to_string();
+            let report_type = report.report_type.to_string();
+            let created_at = report.created_at.to_rfc3339();
+            let updated_at = report.updated_at.to_rfc3339();
+
+            write_csv_record(
+                &mut csv,
+                [
+                    id.as_str(),
+                    &report.title,
+                    report.description.as_deref().unwrap_or_default(),
+                    &status,
+                    &report_type,
+                    reporter_email.as_deref().unwrap_or_default(),
+                    report.contact_email.as_deref().unwrap_or_default(),
+                    report.url.as_deref().unwrap_or_default(),
+                    report.browser_name.as_deref().unwrap_or_default(),
+                    report.browser_version.as_deref().unwrap_or_default(),
+                    report.os_name.as_deref().unwrap_or_default(),
+                    &report.file_path,
+                    report.thumbnail_file_path.as_deref().unwrap_or_default(),
+                    report.external_issue_key.as_deref().unwrap_or_default(),
+                    report.external_issue_url.as_deref().unwrap_or_default(),
+                    &created_at,
+                    &updated_at,
+                ],
+            );
+        }
+
+        tracing::info!("Board CSV export created.");
+        Ok(BoardExportDownload {
+            file_name: format!("{}.csv", export_file_stem(&board)),
+            content: csv,
+        })
+    }
+}
+
+/// Sends the archive entries one by one, the manifest last since it only lists the media that
+/// could be read. Stops as soon as the client is gone.
+async fn write_archive(
+    storage_port: Arc<dyn StoragePort>,
+    mut manifest: BoardExportManifest,
+    media: Vec<ReportMedia>,
+    sender: mpsc::Sender<std::io::Result<Bytes>>,
+) {
+    let mut zip_writer = ZipStreamWriter::new();
+
+    for (report, report_media) in manifest.reports.iter_mut().zip(media) {
+        let files = [
+            (&mut report.file, Some(report_media.file_path)),
+            (&mut report.thumbnail_file, report_media.thumbnail_file_path),
+        ];
+
+        for (archive_path, storage_path) in files {
+            let (Some(path), Some(storage_path)) = (archive_path.as_deref(), storage_path) else {
+                continue;
+            };
+
+            let entry = match storage_port.retrieve_file(&storage_path).await {
+                Ok(data) => zip_writer
+                    .add_file(path, &data, ZipCompression::Stored, report.created_at)
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+
+            match entry {
+                Ok(entry) => {
+                    if sender.send(Ok(Bytes::from(entry))).await.is_err() {
+                        tracing::info!("Board archive download aborted by the client.");
+                        return;
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(report_id = %report.id, file = %storage_path, "Media left out of the board archive: {}", e);
+                    *archive_path = None;
+                }
+            }
+        }
+    }
+
+    let manifest_entry = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| e.to_string())
+        .and_then(|manifest_json| {
+            zip_writer
+                .add_file(
+                    BOARD_EXPORT_MANIFEST_PATH,
+                    &manifest_json,
+                    ZipCompression::Deflated,
+                    manifest.exported_at,
+                )
+                .map_err(|e| e.to_string())
+        });
+
+    let chunks = match manifest_entry {
+        Ok(manifest_entry) => vec![Ok(manifest_entry), Ok(zip_writer.finish())],
+        Err(e) => {
+            tracing::error!("Failed to write the board archive manifest: {}", e);
+            vec![Err(std::io::Error::other(e))]
+        }
+    };
+    for chunk in chunks {
+        if sender.send(chunk.map(Bytes::from)).await.is_err() {
+            return;
+        }
+    }
+
+    tracing::info!(board_id = %manifest.board.id, "Board archive export completed.");
+}
+
+/// Media are named after their report, e.g. `media/<report id>-thumbnail.jpeg`.
+fn media_archive_path(report_id: Uuid, suffix: &str, storage_path: &str) -> String {
+    match Path::new(storage_path).extension().and_then(|e| e.to_str()) {
+        Some(extension) => format!("media/{}{}.{}", report_id, suffix, extension),
+        None => format!("media/{}{}", report_id, suffix),
+    }
+}
+
+/// `<board name slug>-<date>`, ASCII only so it fits in a `Content-Disposition` header as is.
+fn export_file_stem(board: &Board) -> String {
+    let mut slug = String::new();
+    for character in board.name.chars() {
+        if character.is_ascii_alphanumeric() {
+            slug.push(character.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+
+    format!(
+        "{}-{}",
+        if slug.is_empty() { "board" } else { slug },
+        chrono::Utc::now().format("%Y%m%d")
+    )
+}
@@ -0,0 +1,526 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    path::Path,
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde_json::json;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::{
+            audit::{AuditAction, AuditContext, AuditTargetType},
+            board::Board,
+            board_export::{
+                BOARD_EXPORT_FORMAT, BOARD_EXPORT_MANIFEST_PATH, BOARD_EXPORT_VERSION,
+                BoardExportManifest, BoardImport, BoardImportOptions, BoardImportSummary,
+            },
+            media::MediaType,
+            report::{Report, ReportHistoryEntry},
+            user::ANONYMOUS_REPORTER_ID,
+        },
+        ports::storage_port::{StorageError, StoragePort},
+        repositories::{RepositoryError, board_repository::BoardRepository},
+    },
+    infrastructure::export::zip_reader::{ZipArchiveReader, ZipReaderError},
+};
+
+use super::{
+    audit_service::AuditServiceInterface,
+    board_service::{BoardServiceError, BoardServiceInterface},
+    report_service::{ReportServiceError, ReportServiceInterface},
+    user_service::{UserServiceError, UserServiceInterface},
+};
+
+const MAX_MANIFEST_SIZE: usize = 64 * 1024 * 1024;
+/// Storage applies its own, lower, limits per media type.
+const MAX_MEDIA_FILE_SIZE: usize = 256 * 1024 * 1024;
+const MAX_TITLE_LENGTH: usize = 255;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BoardImportServiceError {
+    #[error("Invalid archive: {0}")]
+    InvalidArchive(String),
+    #[error("Already exists: {0}")]
+    IdConflict(String),
+    #[error("Storage error: {0}")]
+    StorageError(#[from] StorageError),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<RepositoryError> for BoardImportServiceError {
+    fn from(err: RepositoryError) -> Self {
+        match err {
+            RepositoryError::AlreadyExists => {
+                BoardImportServiceError::IdConflict("the board or one of its reports".to_string())
+            }
+            RepositoryError::NotFound | RepositoryError::DatabaseError(_) => {
+                BoardImportServiceError::InternalError(err.to_string())
+            }
+        }
+    }
+}
+
+impl From<ZipReaderError> for BoardImportServiceError {
+    fn from(err: ZipReaderError) -> Self {
+        BoardImportServiceError::InvalidArchive(err.to_string())
+    }
+}
+
+impl From<BoardServiceError> for BoardImportServiceError {
+    fn from(err: BoardServiceError) -> Self {
+        BoardImportServiceError::InternalError(err.to_string())
+    }
+}
+
+pub type BoardImportServiceResult<T> = Result<T, BoardImportServiceError>;
+
+#[async_trait]
+pub trait BoardImportServiceInterface: Send + Sync {
+    /// Imports a board export archive as a new board. The archive is fully validated before
+    /// anything is written, and the database changes are made in a single transaction.
+    async fn import_archive(
+        &self,
+        archive: Bytes,
+        options: BoardImportOptions,
+        context: &AuditContext,
+    ) -> BoardImportServiceResult<BoardImportSummary>;
+}
+
+pub struct BoardImportService {
+    board_repository: Arc<dyn BoardRepository>,
+    board_service: Arc<dyn BoardServiceInterface>,
+    report_service: Arc<dyn ReportServiceInterface>,
+    user_service: Arc<dyn UserServiceInterface>,
+    storage_port: Arc<dyn StoragePort>,
+    audit_service: Arc<dyn AuditServiceInterface>,
+}
+
+/// A media file to copy from the archive, and where its storage identifier goes.
+struct PendingMedia {
+    archive_path: String,
+    report_index: usize,
+    is_thumbnail: bool,
+}
+
+impl BoardImportService {
+    pub fn new(
+        board_repository: Arc<dyn BoardRepository>,
+        board_service: Arc<dyn BoardServiceInterface>,
+        report_service: Arc<dyn ReportServiceInterface>,
+        user_service: Arc<dyn UserServiceInterface>,
+        storage_port: Arc<dyn StoragePort>,
+        audit_service: Arc<dyn AuditServiceInterface>,
+    ) -> Self {
+        Self {
+            board_repository,
+            board_service,
+            report_service,
+            user_service,
+            storage_port,
+            audit_service,
+        }
+    }
+
+    /// Entry of the archive, inflated on a blocking thread since entries go up to
+    /// `MAX_MEDIA_FILE_SIZE`.
+    async fn read_archive_file(
+        archive: &ZipArchiveReader,
+        name: &str,
+        max_size: usize,
+    ) -> BoardImportServiceResult<Option<Vec<u8>>> {
+        let archive = archive.clone();
+        let name = name.to_string();
+        let contents = tokio::task::spawn_blocking(move || archive.read_file(&name, max_size))
+            .await
+            .map_err(|e| BoardImportServiceError::InternalError(e.to_string()))??;
+
+        Ok(contents)
+    }
+
+    async fn read_manifest(
+        archive: &ZipArchiveReader,
+    ) -> BoardImportServiceResult<BoardExportManifest> {
+        let manifest_json =
+            Self::read_archive_file(archive, BOARD_EXPORT_MANIFEST_PATH, MAX_MANIFEST_SIZE)
+                .await?
+                .ok_or_else(|| {
+                    BoardImportServiceError::InvalidArchive(format!(
+                        "{} is missing",
+                        BOARD_EXPORT_MANIFEST_PATH
+                    ))
+                })?;
+
+        let manifest: BoardExportManifest = serde_json::from_slice(&manifest_json)
+            .map_err(|e| BoardImportServiceError::InvalidArchive(e.to_string()))?;
+
+        if manifest.format != BOARD_EXPORT_FORMAT {
+            return Err(BoardImportServiceError::InvalidArchive(format!(
+                "unknown format '{}'",
+                manifest.format
+            )));
+        }
+        if manifest.version == 0 || manifest.version > BOARD_EXPORT_VERSION {
+            return Err(BoardImportServiceError::InvalidArchive(format!(
+                "unsupported version {}, this instance reads up to version {}",
+                manifest.version, BOARD_EXPORT_VERSION
+            )));
+        }
+        if manifest.board.name.trim().is_empty() {
+            return Err(BoardImportServiceError::InvalidArchive(
+                "the board has no name".to_string(),
+            ));
+        }
+
+        let mut report_ids = HashSet::new();
+        for report in &manifest.reports {
+            if !report_ids.insert(report.id) {
+                return Err(BoardImportServiceError::InvalidArchive(format!(
+                    "report {} appears twice",
+                    report.id
+                )));
+            }
+            if report.title.trim().is_empty() || report.title.chars().count() > MAX_TITLE_LENGTH {
+                return Err(BoardImportServiceError::InvalidArchive(format!(
+                    "report {} title must be between 1 and {} characters",
+                    report.id, MAX_TITLE_LENGTH
+                )));
+            }
+        }
+
+        Ok(manifest)
+    }
+
+    /// The user behind an archive email, unless they are gone from this instance. Emails are
+    /// stored as typed at sign-up, so the lowercase form is tried as well.
+    async fn find_user_id(
+        &self,
+        user_ids: &mut HashMap<String, Option<Uuid>>,
+        email: Option<&str>,
+    ) -> BoardImportServiceResult<Option<Uuid>> {
+        let Some(email) = email else {
+            return Ok(None);
+        };
+        if let Some(user_id) = user_ids.get(email) {
+            return Ok(*user_id);
+        }
+
+        let mut user_id = None;
+        for candidate in [email.to_string(), email.to_lowercase()] {
+            match self.user_service.get_user_by_email(&candidate).await {
+                Ok(user) if user.deleted_at.is_none() => {
+                    user_id = Some(user.id);
+                    break;
+                }
+                Ok(_) | Err(UserServiceError::UserNotFound) => {}
+                Err(err) => return Err(BoardImportServiceError::InternalError(err.to_string())),
+            }
+        }
+        user_ids.insert(email.to_string(), user_id);
+
+        Ok(user_id)
+    }
+
+    async fn ensure_ids_are_free(&self, import: &BoardImport) -> BoardImportServiceResult<()> {
+        match self.board_service.get_board_by_id(import.board.id).await {
+            Ok(_) => {
+                return Err(BoardImportServiceError::IdConflict(format!(
+                    "board {}",
+                    import.board.id
+                )));
+            }
+            Err(BoardServiceError::BoardNotFound) => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        for report in &import.reports {
+            match self.report_service.get_report(report.id).await {
+                Ok(_) => {
+                    return Err(BoardImportServiceError::IdConflict(format!(
+                        "report {}",
+                        report.id
+                    )));
+                }
+                Err(ReportServiceError::ReportNotFound { .. }) => {}
+                Err(err) => return Err(BoardImportServiceError::InternalError(err.to_string())),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Board name free among the boards of the owner: "Name", then "Name (imported)",
+    /// "Name (imported 2)" and so on.
+    async fn available_board_name(
+        &self,
+        owner_id: Uuid,
+        name: &str,
+    ) -> BoardImportServiceResult<String> {
+        let owner_boards = self.board_service.get_boards_by_user_id(owner_id).await?;
+        let is_taken = |candidate: &str| owner_boards.iter().any(|board| board.name == candidate);
+
+        let name = name.trim();
+        let mut candidate = name.to_string();
+        let mut attempt = 1;
+        while is_taken(&candidate) {
+            candidate = match attempt {
+                1 => format!("{} (imported)", name),
+                _ => format!("{} (imported {})", name, attempt),
+            };
+            attempt += 1;
+        }
+
+        Ok(candidate)
+    }
+}
+
+#[async_trait]
+impl BoardImportServiceInterface for BoardImportService {
+    #[instrument(skip(self, archive, context), fields(archive_size = archive.len(), owner_id = %options.owner_id, preserve_ids = options.preserve_ids, dry_run = options.dry_run), level = "info")]
+    async fn import_archive(
+        &self,
+        archive: Bytes,
+        options: BoardImportOptions,
+        context: &AuditContext,
+    ) -> BoardImportServiceResult<BoardImportSummary> {
+        let archive = ZipArchiveReader::new(archive)?;
+        let manifest = Self::read_manifest(&archive).await?;
+        let archive_files: HashSet<&str> = archive.file_names().collect();
+
+        let mut warnings = Vec::new();
+        let mut user_ids = HashMap::new();
+        let mut matched_user_emails = BTreeSet::new();
+        let mut unmatched_user_emails = BTreeSet::new();
+
+        let board_id = if options.preserve_ids {
+            manifest.board.id
+        } else {
+            Uuid::new_v4()
+        };
+        let board = Board {
+            id: board_id,
+            name: self
+                .available_board_name(options.owner_id, &manifest.board.name)
+                .await?,
+            description: manifest.board.description,
+            owner_id: options.owner_id,
+            is_default: false,
+            created_at: manifest.board.created_at,
+            updated_at: manifest.board.updated_at,
+        };
+
+        let mut reports = Vec::with_capacity(manifest.reports.len());
+        let mut history = Vec::new();
+        let mut pending_media = Vec::new();
+
+        for exported_report in manifest.reports {
+            let media_path = exported_report
+                .file
+                .as_deref()
+                .filter(|path| archive_files.contains(path));
+            let Some(media_path) = media_path else {
+                warnings.push(format!(
+                    "Report '{}' skipped: its media file is missing from the archive",
+                    exported_report.title
+                ));
+                continue;
+            };
+            let thumbnail_path = match exported_report.thumbnail_file.as_deref() {
+                Some(path) if archive_files.contains(path) => Some(path),
+                Some(_) => {
+                    warnings.push(format!(
+                        "Report '{}' imported without its thumbnail, missing from the archive",
+                        exported_report.title
+                    ));
+                    None
+                }
+                None => None,
+            };
+
+            let reporter_id = self
+                .find_user_id(&mut user_ids, exported_report.reporter_email.as_deref())
+                .await?;
+            if let Some(email) = &exported_report.reporter_email {
+                match reporter_id {
+                    Some(_) => matched_user_emails.insert(email.clone()),
+                    None => unmatched_user_emails.insert(email.clone()),
+                };
+            }
+
+            let report_id = if options.preserve_ids {
+                exported_report.id
+            } else {
+                Uuid::new_v4()
+            };
+
+            for entry in exported_report.history {
+                let actor_id = self
+                    .find_user_id(&mut user_ids, entry.actor_email.as_deref())
+                    .await?;
+                if let Some(email) = &entry.actor_email {
+                    match actor_id {
+                        Some(_) => matched_user_emails.insert(email.clone()),
+                        None => unmatched_user_emails.insert(email.clone()),
+                    };
+                }
+
+                history.push(ReportHistoryEntry {
+                    id: Uuid::new_v4(),
+                    report_id,
+                    field: entry.field,
+                    old_value: entry.old_value,
+                    new_value: entry.new_value,
+                    source: entry.source,
+                    actor_id,
+                    details: entry.details,
+                    created_at: entry.created_at,
+                });
+            }
+
+            pending_media.push(PendingMedia {
+                archive_path: media_path.to_string(),
+                report_index: reports.len(),
+                is_thumbnail: false,
+            });
+            if let Some(thumbnail_path) = thumbnail_path {
+                pending_media.push(PendingMedia {
+                    archive_path: thumbnail_path.to_string(),
+                    report_index: reports.len(),
+                    is_thumbnail: true,
+                });
+            }
+
+            reports.push(Report {
+                id: report_id,
+                // Unknown reporters are kept as contact, so that nobody is lost along the way.
+                user_id: reporter_id.unwrap_or(ANONYMOUS_REPORTER_ID),
+                contact_email: match reporter_id {
+                    Some(_) => exported_report.contact_email,
+                    None => exported_report
+                        .contact_email
+                        .or(exported_report.reporter_email),
+                },
+                board_id,
+                report_type: exported_report.report_type,
+                status: exported_report.status,
+                title: exported_report.title.trim().to_string(),
+                description: exported_report.description,
+                file_path: String::new(),
+                thumbnail_file_path: None,
+                url: exported_report.url,
+                browser_name: exported_report.browser_name,
+                browser_version: exported_report.browser_version,
+                os_name: exported_report.os_name,
+                external_issue_key: exported_report.external_issue_key,
+                external_issue_url: exported_report.external_issue_url,
+                created_at: exported_report.created_at,
+                updated_at: exported_report.updated_at,
+            });
+        }
+
+        let mut import = BoardImport {
+            board,
+            reports,
+            history,
+        };
+        if options.preserve_ids {
+            self.ensure_ids_are_free(&import).await?;
+        }
+
+        // Media are read in both modes, so that a dry run also catches corrupted files.
+        let mut stored_file_count = 0;
+        for media in &pending_media {
+            let data = Self::read_archive_file(&archive, &media.archive_path, MAX_MEDIA_FILE_SIZE)
+                .await?
+                .ok_or_else(|| {
+                    BoardImportServiceError::InvalidArchive(format!(
+                        "{} is missing",
+                        media.archive_path
+                    ))
+                })?;
+            if MediaType::sniff(&data).is_none() {
+                return Err(BoardImportServiceError::InvalidArchive(format!(
+                    "{} is not a supported image nor video",
+                    media.archive_path
+                )));
+            }
+            if options.dry_run {
+                continue;
+            }
+
+            let file_name = Path::new(&media.archive_path)
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or(&media.archive_path);
+            let stored_path = self
+                .storage_port
+                .save_file(file_name, Bytes::from(data))
+                .await
+                .inspect_err(|_| {
+                    if stored_file_count > 0 {
+                        tracing::warn!(
+                            stored_file_count,
+                            "Board import aborted, media files already stored are orphaned."
+                        );
+                    }
+                })?;
+            stored_file_count += 1;
+
+            let report = &mut import.reports[media.report_index];
+            if media.is_thumbnail {
+                report.thumbnail_file_path = Some(stored_path);
+            } else {
+                report.file_path = stored_path;
+            }
+        }
+
+        let summary = BoardImportSummary {
+            dry_run: options.dry_run,
+            board_id: import.board.id,
+            board_name: import.board.name.clone(),
+            report_count: import.reports.len(),
+            media_file_count: pending_media.len(),
+            history_entry_count: import.history.len(),
+            matched_user_emails: matched_user_emails.into_iter().collect(),
+            unmatched_user_emails: unmatched_user_emails.into_iter().collect(),
+            warnings,
+        };
+
+        if options.dry_run {
+            tracing::info!("Board import dry run completed.");
+            return Ok(summary);
+        }
+
+        self.board_repository
+            .import_board(import)
+            .await
+            .inspect_err(|_| {
+                tracing::warn!(
+                    stored_file_count,
+                    "Board import rolled back, its media files are orphaned."
+                );
+            })?;
+
+        self.audit_service
+            .record(
+                context,
+                AuditAction::BoardImported,
+                AuditTargetType::Board,
+                Some(summary.board_id),
+                json!({
+                    "name": summary.board_name,
+                    "owner_id": options.owner_id,
+                    "report_count": summary.report_count,
+                }),
+            )
+            .await;
+
+        tracing::info!(board_id = %summary.board_id, report_count = summary.report_count, "Board imported.");
+        Ok(summary)
+    }
+}
@@ -1,14 +1,22 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use serde_json::json;
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::domain::{
-    models::board::Board,
+    models::{
+        audit::{AuditAction, AuditContext, AuditTargetType},
+        board::Board,
+        event::{DomainEvent, EventType},
+    },
+    ports::event_publisher_port::EventPublisherPort,
     repositories::{RepositoryError, board_repository::BoardRepository},
 };
 
+use super::audit_service::AuditServiceInterface;
+
 #[derive(Debug, thiserror::Error)]
 pub enum BoardServiceError {
     #[error("Validation error: {0}")]
@@ -43,6 +51,7 @@ pub trait BoardServiceInterface: Send + Sync {
         description: Option<&str>,
         owner_id: Uuid,
         is_default: bool,
+        context: &AuditContext,
     ) -> BoardServiceResult<Board>;
 
     async fn get_board_by_id(&self, board_id: Uuid) -> BoardServiceResult<Board>;
@@ -58,23 +67,34 @@ pub trait BoardServiceInterface: Send + Sync {
 #[derive(Clone)]
 pub struct BoardService {
     board_repository: Arc<dyn BoardRepository>,
+    event_publisher: Arc<dyn EventPublisherPort>,
+    audit_service: Arc<dyn AuditServiceInterface>,
 }
 
 impl BoardService {
-    pub fn new(board_repository: Arc<dyn BoardRepository>) -> Self {
-        Self { board_repository }
+    pub fn new(
+        board_repository: Arc<dyn BoardRepository>,
+        event_publisher: Arc<dyn EventPublisherPort>,
+        audit_service: Arc<dyn AuditServiceInterface>,
+    ) -> Self {
+        Self {
+            board_repository,
+            event_publisher,
+            audit_service,
+        }
     }
 }
 
 #[async_trait]
 impl BoardServiceInterface for BoardService {
-    #[instrument(skip(self, description), fields(owner_id, board_name = %name), level = "info")]
+    #[instrument(skip(self, description, context), fields(owner_id, board_name = %name), level = "info")]
     async fn create_board(
         &self,
         name: &str,
         description: Option<&str>,
         owner_id: Uuid,
         is_default: bool,
+        context: &AuditContext,
     ) -> BoardServiceResult<Board> {
         if name.is_empty() {
             return Err(BoardServiceError::ValidationError(
@@ -87,7 +107,20 @@ impl BoardServiceInterface for BoardService {
             .create_board(name, description, owner_id, is_default)
             .await?;
 
+        self.audit_service
+            .record(
+                context,
+                AuditAction::BoardCreated,
+                AuditTargetType::Board,
+                Some(board.id),
+                json!({ "name": board.name, "owner_id": board.owner_id }),
+            )
+            .await;
+
         tracing::info!(board_id = %board.id,"Board created successfully");
+        self.event_publisher
+            .publish(DomainEvent::board(EventType::BoardCreated, &board));
+
         Ok(board)
     }
 
@@ -0,0 +1,434 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::{
+        models::{
+            chat_integration::{
+                ChatAttachment, ChatAttachmentField, ChatIntegration, ChatMessage,
+                UpdateChatIntegrationParams,
+            },
+            event::{DomainEvent, EventType},
+            message_template::render_template,
+            report::{Report, ReportStatus, ReportType},
+        },
+        ports::{
+            chat_notifier_port::{ChatNotifierError, ChatNotifierPort},
+            event_publisher_port::EventSubscriber,
+        },
+        repositories::{RepositoryError, chat_integration_repository::ChatIntegrationRepository},
+    },
+    infrastructure::security::public_addresses::{PublicAddressError, check_public_url},
+};
+
+use super::{board_service::BoardServiceInterface, user_service::UserServiceInterface};
+
+const ATTACHMENT_COLOR: &str = "#e5484d";
+const NOT_AVAILABLE: &str = "n/a";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChatNotificationServiceError {
+    #[error("Chat integration not found")]
+    IntegrationNotFound,
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Notification failed: {0}")]
+    NotificationFailed(#[from] ChatNotifierError),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<RepositoryError> for ChatNotificationServiceError {
+    fn from(err: RepositoryError) -> Self {
+        match err {
+            RepositoryError::NotFound => ChatNotificationServiceError::IntegrationNotFound,
+            RepositoryError::AlreadyExists | RepositoryError::DatabaseError(_) => {
+                ChatNotificationServiceError::InternalError(err.to_string())
+            }
+        }
+    }
+}
+
+pub type ChatNotificationServiceResult<T> = Result<T, ChatNotificationServiceError>;
+
+#[async_trait]
+pub trait ChatNotificationServiceInterface: Send + Sync {
+    async fn create_integration(
+        &self,
+        board_id: Uuid,
+        created_by: Uuid,
+        webhook_url: &str,
+        message_template: Option<String>,
+    ) -> ChatNotificationServiceResult<ChatIntegration>;
+
+    async fn list_integrations(
+        &self,
+        board_id: Uuid,
+    ) -> ChatNotificationServiceResult<Vec<ChatIntegration>>;
+
+    async fn get_integration(
+        &self,
+        board_id: Uuid,
+        integration_id: Uuid,
+    ) -> ChatNotificationServiceResult<ChatIntegration>;
+
+    async fn update_integration(
+        &self,
+        board_id: Uuid,
+        integration_id: Uuid,
+        params: UpdateChatIntegrationParams,
+    ) -> ChatNotificationServiceResult<ChatIntegration>;
+
+    async fn delete_integration(
+        &self,
+        board_id: Uuid,
+        integration_id: Uuid,
+    ) -> ChatNotificationServiceResult<()>;
+
+    /// Posts a sample report notification, to check the URL and the template.
+    async fn send_test_message(
+        &self,
+        board_id: Uuid,
+        integration_id: Uuid,
+    ) -> ChatNotificationServiceResult<()>;
+}
+
+pub struct ChatNotificationService {
+    chat_integration_repository: Arc<dyn ChatIntegrationRepository>,
+    chat_notifier: Arc<dyn ChatNotifierPort>,
+    board_service: Arc<dyn BoardServiceInterface>,
+    user_service: Arc<dyn UserServiceInterface>,
+}
+
+impl ChatNotificationService {
+    pub fn new(
+        chat_integration_repository: Arc<dyn ChatIntegrationRepository>,
+        chat_notifier: Arc<dyn ChatNotifierPort>,
+        board_service: Arc<dyn BoardServiceInterface>,
+        user_service: Arc<dyn UserServiceInterface>,
+    ) -> Self {
+        Self {
+            chat_integration_repository,
+            chat_notifier,
+            board_service,
+            user_service,
+        }
+    }
+
+    /// The host must resolve to public addresses only, unless private networks are allowed. The
+    /// notifier checks the addresses again on each message.
+    async fn validate_webhook_url(webhook_url: &str) -> ChatNotificationServiceResult<()> {
+        check_public_url(webhook_url, APP_CONFIG.webhook_allow_private_networks)
+            .await
+            .map_err(|e| match e {
+                PublicAddressError::InvalidUrl => ChatNotificationServiceError::ValidationError(
+                    "Webhook URL must be an absolute http(s) URL".to_string(),
+                ),
+                e => ChatNotificationServiceError::ValidationError(format!("Webhook URL: {}", e)),
+            })
+    }
+
+    /// Empty templates are treated as unset, so the default one applies.
+    fn normalize_template(message_template: Option<String>) -> Option<String> {
+        message_template.filter(|template| !template.trim().is_empty())
+    }
+
+    fn build_report_message(
+        integration: &ChatIntegration,
+        report: &Report,
+        board_name: &str,
+        reporter: &str,
+    ) -> ChatMessage {
+        let report_url = format!(
+            "{}/reports/{}",
+            APP_CONFIG.public_base_url.trim_end_matches('/'),
+            report.id
+        );
+        let report_type = report.report_type.to_string().to_lowercase();
+        let browser = match (&report.browser_name, &report.browser_version) {
+            (Some(name), Some(version)) => format!("{} {}", name, version),
+            (Some(name), None) => name.clone(),
+            _ => NOT_AVAILABLE.to_string(),
+        };
+        let os = report.os_name.as_deref().unwrap_or(NOT_AVAILABLE);
+        let page_url = report.url.as_deref().unwrap_or(NOT_AVAILABLE);
+        let description = report.description.as_deref().unwrap_or_default();
+
+        let template = integration
+            .message_template
+            .as_deref()
+            .unwrap_or(&APP_CONFIG.chat_message_template);
+        let text = render_template(
+            template,
+            &[
+                ("title", &report.title),
+                ("description", description),
+                ("reporter", reporter),
+                ("board", board_name),
+                ("report_type", &report_type),
+                ("report_url", &report_url),
+                ("page_url", page_url),
+                ("browser", &browser),
+                ("os", os),
+            ],
+        );
+
+        let mut fields = vec![
+            ChatAttachmentField {
+                title: "Reporter".to_string(),
+                value: reporter.to_string(),
+                short: true,
+            },
+            ChatAttachmentField {
+                title: "Page".to_string(),
+                value: page_url.to_string(),
+                short: true,
+            },
+            ChatAttachmentField {
+                title: "Browser".to_string(),
+                value: browser,
+                short: true,
+            },
+            ChatAttachmentField {
+                title: "OS".to_string(),
+                value: os.to_string(),
+                short: true,
+            },
+        ];
+        if let Some(thumbnail_url) = &report.thumbnail_file_path {
+            fields.push(ChatAttachmentField {
+                title: "Thumbnail".to_string(),
+                value: thumbnail_url.clone(),
+                short: false,
+            });
+        }
+
+        ChatMessage {
+            text,
+            attachments: vec![ChatAttachment {
+                fallback: format!("New report: {}", report.title),
+                color: ATTACHMENT_COLOR.to_string(),
+                title: report.title.clone(),
+                title_link: Some(report_url),
+                text: report.description.clone(),
+                fields,
+                thumb_url: report.thumbnail_file_path.clone(),
+                footer: format!("rebug · {}", board_name),
+                ts: report.created_at.timestamp(),
+            }],
+        }
+    }
+
+    async fn find_board_integration(
+        &self,
+        board_id: Uuid,
+        integration_id: Uuid,
+    ) -> ChatNotificationServiceResult<ChatIntegration> {
+        self.chat_integration_repository
+            .find_by_id(integration_id)
+            .await?
+            .filter(|integration| integration.board_id == board_id)
+            .ok_or(ChatNotificationServiceError::IntegrationNotFound)
+    }
+
+    async fn notify_report_created(&self, report: &Report) -> ChatNotificationServiceResult<()> {
+        let integrations: Vec<ChatIntegration> = self
+            .chat_integration_repository
+            .find_by_board_id(report.board_id)
+            .await?
+            .into_iter()
+            .filter(|integration| integration.is_active)
+            .collect();
+
+        if integrations.is_empty() {
+            return Ok(());
+        }
+
+        let board = self
+            .board_service
+            .get_board_by_id(report.board_id)
+            .await
+            .map_err(|e| ChatNotificationServiceError::InternalError(e.to_string()))?;
+        let reporter = self
+            .user_service
+            .get_user_by_id(report.user_id)
+            .await
+            .map(|user| user.display_name())
+            .unwrap_or_else(|_| "Unknown user".to_string());
+
+        for integration in integrations {
+            let message = Self::build_report_message(&integration, report, &board.name, &reporter);
+
+            if let Err(e) = self
+                .chat_notifier
+                .post_message(&integration.webhook_url, &message)
+                .await
+            {
+                tracing::warn!(chat_integration_id = %integration.id, "Failed to post chat notification: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ChatNotificationServiceInterface for ChatNotificationService {
+    #[instrument(skip(self, webhook_url, message_template), fields(board_id = %board_id, created_by = %created_by), level = "info")]
+    async fn create_integration(
+        &self,
+        board_id: Uuid,
+        created_by: Uuid,
+        webhook_url: &str,
+        message_template: Option<String>,
+    ) -> ChatNotificationServiceResult<ChatIntegration> {
+        Self::validate_webhook_url(webhook_url).await?;
+
+        let integration = self
+            .chat_integration_repository
+            .create_integration(
+                board_id,
+                webhook_url,
+                Self::normalize_template(message_template).as_deref(),
+                created_by,
+            )
+            .await?;
+
+        tracing::info!(chat_integration_id = %integration.id, "Chat integration created.");
+        Ok(integration)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id), level = "debug")]
+    async fn list_integrations(
+        &self,
+        board_id: Uuid,
+    ) -> ChatNotificationServiceResult<Vec<ChatIntegration>> {
+        Ok(self
+            .chat_integration_repository
+            .find_by_board_id(board_id)
+            .await?)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id, chat_integration_id = %integration_id), level = "debug")]
+    async fn get_integration(
+        &self,
+        board_id: Uuid,
+        integration_id: Uuid,
+    ) -> ChatNotificationServiceResult<ChatIntegration> {
+        self.find_board_integration(board_id, integration_id).await
+    }
+
+    #[instrument(skip(self, params), fields(board_id = %board_id, chat_integration_id = %integration_id), level = "info")]
+    async fn update_integration(
+        &self,
+        board_id: Uuid,
+        integration_id: Uuid,
+        mut params: UpdateChatIntegrationParams,
+    ) -> ChatNotificationServiceResult<ChatIntegration> {
+        self.find_board_integration(board_id, integration_id)
+            .await?;
+
+        if let Some(webhook_url) = &params.webhook_url {
+            Self::validate_webhook_url(webhook_url).await?;
+        }
+        params.message_template = params.message_template.map(Self::normalize_template);
+
+        let integration = self
+            .chat_integration_repository
+            .update_integration(integration_id, params)
+            .await?;
+
+        tracing::info!("Chat integration updated.");
+        Ok(integration)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id, chat_integration_id = %integration_id), level = "info")]
+    async fn delete_integration(
+        &self,
+        board_id: Uuid,
+        integration_id: Uuid,
+    ) -> ChatNotificationServiceResult<()> {
+        self.find_board_integration(board_id, integration_id)
+            .await?;
+        self.chat_integration_repository
+            .delete_integration(integration_id)
+            .await?;
+
+        tracing::info!("Chat integration deleted.");
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id, chat_integration_id = %integration_id), level = "info")]
+    async fn send_test_message(
+        &self,
+        board_id: Uuid,
+        integration_id: Uuid,
+    ) -> ChatNotificationServiceResult<()> {
+        let integration = self
+            .find_board_integration(board_id, integration_id)
+            .await?;
+        let board = self
+            .board_service
+            .get_board_by_id(board_id)
+            .await
+            .map_err(|e| ChatNotificationServiceError::InternalError(e.to_string()))?;
+
+        let now = Utc::now();
+        let sample_report = Report {
+            id: Uuid::nil(),
+            user_id: integration.created_by,
+            board_id,
+            report_type: ReportType::Screenshot,
+            status: ReportStatus::Open,
+            title: "Test notification from rebug".to_string(),
+            description: Some("New reports on this board will be posted here.".to_string()),
+            file_path: String::new(),
+            thumbnail_file_path: None,
+            url: Some(APP_CONFIG.public_base_url.clone()),
+            browser_name: None,
+            browser_version: None,
+            os_name: None,
+            contact_email: None,
+            external_issue_key: None,
+            external_issue_url: None,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let message =
+            Self::build_report_message(&integration, &sample_report, &board.name, "rebug");
+        self.chat_notifier
+            .post_message(&integration.webhook_url, &message)
+            .await?;
+
+        tracing::info!("Chat integration test message sent.");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for ChatNotificationService {
+    #[instrument(skip(self, event), fields(event_id = %event.id, event_type = %event.event_type, board_id = %event.board_id), level = "debug")]
+    async fn on_event(&self, event: &DomainEvent) {
+        if event.event_type != EventType::ReportCreated {
+            return;
+        }
+
+        let report: Report = match serde_json::from_value(event.data.clone()) {
+            Ok(report) => report,
+            Err(e) => {
+                tracing::error!("Failed to read report from event: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = self.notify_report_created(&report).await {
+            tracing::error!(report_id = %report.id, "Failed to send chat notifications: {}", e);
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::Semaphore;
+use tracing::{Instrument, instrument};
+use uuid::Uuid;
+
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::{
+        models::{
+            email::EmailMessage,
+            event::{DomainEvent, EventType},
+            message_template::{escape_html, render_template},
+            notification_preference::{
+                NotificationPreferences, UpdateNotificationPreferencesParams,
+            },
+            report::Report,
+            user::User,
+        },
+        ports::{
+            event_publisher_port::EventSubscriber,
+            mailer_port::{MailerError, MailerPort},
+        },
+        repositories::{
+            RepositoryError, notification_preference_repository::NotificationPreferenceRepository,
+        },
+    },
+};
+
+use super::{
+    board_service::BoardServiceInterface, report_service::ReportServiceInterface,
+    user_service::UserServiceInterface,
+};
+
+const LAYOUT_HTML: &str = include_str!("../../../templates/email/layout.html");
+const REPORT_CREATED_HTML: &str = include_str!("../../../templates/email/report_created.html");
+const REPORT_CREATED_TEXT: &str = include_str!("../../../templates/email/report_created.txt");
+const REPORT_STATUS_CHANGED_HTML: &str =
+    include_str!("../../../templates/email/report_status_changed.html");
+const REPORT_STATUS_CHANGED_TEXT: &str =
+    include_str!("../../../templates/email/report_status_changed.txt");
+const BOARD_OWNER_FOOTER: &str = "You receive this email because you own the board. \
+    You can turn these emails off in your notification preferences.";
+const REPORTER_FOOTER: &str = "You receive this email because you filed the report. \
+    You can turn these emails off in your notification preferences.";
+const NOT_AVAILABLE: &str = "n/a";
+/// Emails sent at once, so that a burst of events does not open as many SMTP connections.
+const MAX_CONCURRENT_SENDS: usize = 4;
+
+#[derive(Debug, thiserror::Error)]
+pub enum EmailNotificationServiceError {
+    #[error("User not found")]
+    UserNotFound,
+    #[error("Email delivery failed: {0}")]
+    DeliveryFailed(#[from] MailerError),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<RepositoryError> for EmailNotificationServiceError {
+    fn from(err: RepositoryError) -> Self {
+        match err {
+            RepositoryError::NotFound => EmailNotificationServiceError::UserNotFound,
+            RepositoryError::AlreadyExists | RepositoryError::DatabaseError(_) => {
+                EmailNotificationServiceError::InternalError(err.to_string())
+            }
+        }
+    }
+}
+
+pub type EmailNotificationServiceResult<T> = Result<T, EmailNotificationServiceError>;
+
+#[async_trait]
+pub trait EmailNotificationServiceInterface: Send + Sync {
+    async fn get_preferences(
+        &self,
+        user_id: Uuid,
+    ) -> EmailNotificationServiceResult<NotificationPreferences>;
+
+    async fn update_preferences(
+        &self,
+        user_id: Uuid,
+        params: UpdateNotificationPreferencesParams,
+    ) -> EmailNotificationServiceResult<NotificationPreferences>;
+}
+
+#[derive(Clone)]
+pub struct EmailNotificationService {
+    notification_preference_repository: Arc<dyn NotificationPreferenceRepository>,
+    mailer: Arc<dyn MailerPort>,
+    board_service: Arc<dyn BoardServiceInterface>,
+    report_service: Arc<dyn ReportServiceInterface>,
+    user_service: Arc<dyn UserServiceInterface>,
+    send_permits: Arc<Semaphore>,
+}
+
+impl EmailNotificationService {
+    pub fn new(
+        notification_preference_repository: Arc<dyn NotificationPreferenceRepository>,
+        mailer: Arc<dyn MailerPort>,
+        board_service: Arc<dyn BoardServiceInterface>,
+        report_service: Arc<dyn ReportServiceInterface>,
+        user_service: Arc<dyn UserServiceInterface>,
+    ) -> Self {
+        Self {
+            notification_preference_repository,
+            mailer,
+            board_service,
+            report_service,
+            user_service,
+            send_permits: Arc::new(Semaphore::new(MAX_CONCURRENT_SENDS)),
+        }
+    }
+
+    /// Renders the text template, and the HTML one within the layout with escaped values.
+    fn build_email(
+        recipient: &User,
+        subject: String,
+        templates: (&str, &str),
+        variables: &[(&str, &str)],
+        footer: &str,
+    ) -> EmailMessage {
+        let (text_template, html_template) = templates;
+        let recipient_name = recipient.display_name();
+
+        let mut text_variables = vec![("recipient", recipient_name.as_str())];
+        text_variables.extend_from_slice(variables);
+        let text_body = render_template(text_template, &text_variables);
+
+        let escaped_variables = text_variables
+            .iter()
+            .map(|(name, value)| (*name, escape_html(value)))
+            .collect::<Vec<_>>();
+        let content = render_template(
+            html_template,
+            &escaped_variables
+                .iter()
+                .map(|(name, value)| (*name, value.as_str()))
+                .collect::<Vec<_>>(),
+        );
+        let html_body = render_template(
+            LAYOUT_HTML,
+            &[
+                ("subject", &escape_html(&subject)),
+                ("footer", &escape_html(footer)),
+                ("content", &content),
+            ],
+        );
+
+        EmailMessage {
+            to: recipient.email.clone(),
+            subject,
+            text_body,
+            html_body,
+        }
+    }
+
+    async fn send(&self, message: &EmailMessage) -> EmailNotificationServiceResult<()> {
+        let _permit = self
+            .send_permits
+            .acquire()
+            .await
+            .map_err(|e| EmailNotificationServiceError::InternalError(e.to_string()))?;
+
+        Ok(self.mailer.send(message).await?)
+    }
+
+    fn report_url(report: &Report) -> String {
+        format!(
+            "{}/reports/{}",
+            APP_CONFIG.public_base_url.trim_end_matches('/'),
+            report.id
+        )
+    }
+
+    /// The user to notify, unless they are gone or opted out.
+    async fn find_recipient(
+        &self,
+        user_id: Uuid,
+        is_enabled: impl Fn(&NotificationPreferences) -> bool,
+    ) -> EmailNotificationServiceResult<Option<User>> {
+        if !is_enabled(&self.get_preferences(user_id).await?) {
+            return Ok(None);
+        }
+
+        let user = self
+            .user_service
+            .get_user_by_id(user_id)
+            .await
+            .map_err(|e| EmailNotificationServiceError::InternalError(e.to_string()))?;
+
+        Ok((user.is_active && user.deleted_at.is_none()).then_some(user))
+    }
+
+    async fn notify_report_created(&self, report: &Report) -> EmailNotificationServiceResult<()> {
+        let board = self
+            .board_service
+            .get_board_by_id(report.board_id)
+            .await
+            .map_err(|e| EmailNotificationServiceError::InternalError(e.to_string()))?;
+        if board.owner_id == report.user_id {
+            return Ok(());
+        }
+
+        let Some(recipient) = self
+            .find_recipient(board.owner_id, |preferences| {
+                preferences.email_on_new_report
+            })
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let reporter = self
+            .user_service
+            .get_user_by_id(report.user_id)
+            .await
+            .map(|user| user.display_name())
+            .unwrap_or_else(|_| "Someone".to_string());
+        let report_type = report.report_type.to_string().to_lowercase();
+        let browser = match (&report.browser_name, &report.browser_version) {
+            (Some(name), Some(version)) => format!("{} {}", name, version),
+            (Some(name), None) => name.clone(),
+            _ => NOT_AVAILABLE.to_string(),
+        };
+        let report_url = Self::report_url(report);
+
+        let message = Self::build_email(
+            &recipient,
+            format!("[{}] New report: {}", board.name, report.title),
+            (REPORT_CREATED_TEXT, REPORT_CREATED_HTML),
+            &[
+                ("reporter", &reporter),
+                ("report_type", &report_type),
+                ("board", &board.name),
+                ("title", &report.title),
+                (
+                    "description",
+                    report.description.as_deref().unwrap_or_default(),
+                ),
+                ("page_url", report.url.as_deref().unwrap_or(NOT_AVAILABLE)),
+                ("browser", &browser),
+                ("os", report.os_name.as_deref().unwrap_or(NOT_AVAILABLE)),
+                ("report_url", &report_url),
+            ],
+            BOARD_OWNER_FOOTER,
+        );
+        self.send(&message).await?;
+
+        tracing::info!(user_id = %recipient.id, "New report email sent.");
+        Ok(())
+    }
+
+    async fn notify_status_changed(&self, report: &Report) -> EmailNotificationServiceResult<()> {
+        let Some(recipient) = self
+            .find_recipient(report.user_id, |preferences| {
+                preferences.email_on_status_change
+            })
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let board = self
+            .board_service
+            .get_board_by_id(report.board_id)
+            .await
+            .map_err(|e| EmailNotificationServiceError::InternalError(e.to_string()))?;
+        let details = self
+            .report_service
+            .get_report_history(report.id)
+            .await
+            .map_err(|e| EmailNotificationServiceError::InternalError(e.to_string()))?
+            .into_iter()
+            .rev()
+            .find(|entry| entry.field == "status")
+            .and_then(|entry| entry.details)
+            .unwrap_or_default();
+        let status = report.status.to_string();
+        let report_url = Self::report_url(report);
+
+        let message = Self::build_email(
+            &recipient,
+            format!("[{}] Report {}: {}", board.name, status, report.title),
+            (REPORT_STATUS_CHANGED_TEXT, REPORT_STATUS_CHANGED_HTML),
+            &[
+                ("board", &board.name),
+                ("status", &status),
+                ("title", &report.title),
+                ("details", &details),
+                ("report_url", &report_url),
+            ],
+            REPORTER_FOOTER,
+        );
+        self.send(&message).await?;
+
+        tracing::info!(user_id = %recipient.id, "Report status email sent.");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EmailNotificationServiceInterface for EmailNotificationService {
+    #[instrument(skip(self), fields(user_id = %user_id), level = "debug")]
+    async fn get_preferences(
+        &self,
+        user_id: Uuid,
+    ) -> EmailNotificationServiceResult<NotificationPreferences> {
+        Ok(self
+            .notification_preference_repository
+            .find_by_user_id(user_id)
+            .await?
+            .unwrap_or_else(|| NotificationPreferences::default_for(user_id)))
+    }
+
+    #[instrument(skip(self, params), fields(user_id = %user_id), level = "info")]
+    async fn update_preferences(
+        &self,
+        user_id: Uuid,
+        params: UpdateNotificationPreferencesParams,
+    ) -> EmailNotificationServiceResult<NotificationPreferences> {
+        let preferences = self
+            .notification_preference_repository
+            .upsert(user_id, params)
+            .await?;
+
+        tracing::info!("Notification preferences updated.");
+        Ok(preferences)
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for EmailNotificationService {
+    #[instrument(skip(self, event), fields(event_id = %event.id, event_type = %event.event_type, board_id = %event.board_id), level = "debug")]
+    async fn on_event(&self, event: &DomainEvent) {
+        if !matches!(
+            event.event_type,
+            EventType::ReportCreated | EventType::ReportStatusChanged
+        ) {
+            return;
+        }
+
+        let report: Report = match serde_json::from_value(event.data.clone()) {
+            Ok(report) => report,
+            Err(e) => {
+                tracing::error!("Failed to read report from event: {}", e);
+                return;
+            }
+        };
+
+        // Sent in the background, so that a slow mail server does not hold up the next events.
+        let service = self.clone();
+        let event_type = event.event_type;
+        tokio::spawn(
+            async move {
+                let result = if event_type == EventType::ReportCreated {
+                    service.notify_report_created(&report).await
+                } else {
+                    service.notify_status_changed(&report).await
+                };
+                if let Err(e) = result {
+                    tracing::error!(report_id = %report.id, "Failed to send email notification: {}", e);
+                }
+            }
+            .in_current_span(),
+        );
+    }
+}
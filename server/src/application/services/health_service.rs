@@ -1,35 +1,106 @@
-use async_trait::async_trait;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use crate::domain::models::health::HealthCheck;
+use async_trait::async_trait;
+use futures_util::future::join_all;
+use tracing::instrument;
 
-pub type HealthServiceResult<T> = Result<T, Box<dyn std::error::Error>>;
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::{
+        models::health::{ComponentHealth, HealthCheck, HealthStatus},
+        ports::health_probe_port::HealthProbePort,
+    },
+};
 
 #[async_trait]
 pub trait HealthServiceInterface: Send + Sync {
-    fn health_check(&self) -> HealthServiceResult<HealthCheck>;
+    /// Whether the process is running. Checks no dependency, so that a struggling database does
+    /// not get the service restarted.
+    fn liveness(&self) -> HealthCheck;
+
+    /// Whether the service can handle requests, from the checks of all its dependencies.
+    async fn readiness(&self) -> HealthCheck;
 }
 
-#[derive(Clone)]
-pub struct HealthService;
+pub struct HealthService {
+    probes: Vec<Arc<dyn HealthProbePort>>,
+}
 
 impl HealthService {
-    pub fn new() -> Self {
-        HealthService
+    pub fn new(probes: Vec<Arc<dyn HealthProbePort>>) -> Self {
+        HealthService { probes }
     }
-}
 
-impl Default for HealthService {
-    fn default() -> Self {
-        Self::new()
+    async fn run_probe(probe: &dyn HealthProbePort) -> ComponentHealth {
+        let timeout = Duration::from_secs(APP_CONFIG.health_check_timeout_seconds);
+        let started = Instant::now();
+
+        let (status, message) = match tokio::time::timeout(timeout, probe.probe()).await {
+            Ok(Ok(details)) => (HealthStatus::Up, details),
+            Ok(Err(e)) => (HealthStatus::Down, Some(e.to_string())),
+            Err(_) => (
+                HealthStatus::Down,
+                Some(format!("No answer within {} seconds", timeout.as_secs())),
+            ),
+        };
+
+        if status == HealthStatus::Down {
+            tracing::warn!(
+                component = probe.component(),
+                reason = message.as_deref().unwrap_or_default(),
+                "Health check failed"
+            );
+        }
+
+        ComponentHealth {
+            name: probe.component(),
+            status,
+            latency: started.elapsed(),
+            message,
+        }
     }
 }
 
 #[async_trait]
 impl HealthServiceInterface for HealthService {
-    fn health_check(&self) -> HealthServiceResult<HealthCheck> {
-        Ok(HealthCheck {
-            status: "success".to_string(),
+    fn liveness(&self) -> HealthCheck {
+        HealthCheck {
+            status: HealthStatus::Up,
             message: "Everything is OK".to_string(),
-        })
+            components: Vec::new(),
+        }
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn readiness(&self) -> HealthCheck {
+        let components = join_all(
+            self.probes
+                .iter()
+                .map(|probe| Self::run_probe(probe.as_ref())),
+        )
+        .await;
+
+        let failing: Vec<&str> = components
+            .iter()
+            .filter(|component| component.status == HealthStatus::Down)
+            .map(|component| component.name)
+            .collect();
+
+        if failing.is_empty() {
+            HealthCheck {
+                status: HealthStatus::Up,
+                message: "Ready to serve requests".to_string(),
+                components,
+            }
+        } else {
+            HealthCheck {
+                status: HealthStatus::Down,
+                message: format!("Unavailable: {}", failing.join(", ")),
+                components,
+            }
+        }
     }
 }
@@ -0,0 +1,322 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::{
+        models::{
+            audit::AuditContext,
+            email::{InboundEmail, InboundMailbox},
+            media::MediaType,
+            report::{CreateReportServiceParams, Report},
+            user::{ANONYMOUS_REPORTER_ID, User, UserRole},
+        },
+        ports::storage_port::{StorageError, StoragePort},
+        repositories::{RepositoryError, inbound_mailbox_repository::InboundMailboxRepository},
+    },
+    infrastructure::{
+        mail::mime_parser::parse_message,
+        security::inbound_mailbox_tokens::generate_inbound_mailbox_token,
+    },
+};
+
+use super::{
+    report_service::{ReportServiceError, ReportServiceInterface},
+    user_service::{UserServiceError, UserServiceInterface},
+};
+
+const MAX_TITLE_LENGTH: usize = 255;
+const MAX_DESCRIPTION_LENGTH: usize = 5000;
+const DEFAULT_TITLE: &str = "(no subject)";
+
+#[derive(Debug, thiserror::Error)]
+pub enum InboundEmailServiceError {
+    #[error("Inbound mailbox not found")]
+    MailboxNotFound,
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Storage error: {0}")]
+    StorageError(#[from] StorageError),
+    #[error("Report error: {0}")]
+    ReportError(#[from] ReportServiceError),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<RepositoryError> for InboundEmailServiceError {
+    fn from(err: RepositoryError) -> Self {
+        match err {
+            RepositoryError::NotFound => InboundEmailServiceError::MailboxNotFound,
+            RepositoryError::AlreadyExists | RepositoryError::DatabaseError(_) => {
+                InboundEmailServiceError::InternalError(err.to_string())
+            }
+        }
+    }
+}
+
+pub type InboundEmailServiceResult<T> = Result<T, InboundEmailServiceError>;
+
+#[async_trait]
+pub trait InboundEmailServiceInterface: Send + Sync {
+    async fn get_mailbox(&self, board_id: Uuid) -> InboundEmailServiceResult<InboundMailbox>;
+
+    /// Creates the board mailbox. When it already exists its token is rotated, so the previous
+    /// address stops working.
+    async fn configure_mailbox(
+        &self,
+        board_id: Uuid,
+        created_by: Uuid,
+    ) -> InboundEmailServiceResult<InboundMailbox>;
+
+    async fn delete_mailbox(&self, board_id: Uuid) -> InboundEmailServiceResult<()>;
+
+    async fn find_mailbox_by_token(&self, token: &str)
+    -> InboundEmailServiceResult<InboundMailbox>;
+
+    /// The mailbox behind a recipient address such as `token@domain` or `bugs+token@domain`.
+    async fn find_mailbox_by_address(
+        &self,
+        address: &str,
+    ) -> InboundEmailServiceResult<InboundMailbox>;
+
+    /// Files a raw RFC 5322 message as a report on the mailbox board. The first image or video
+    /// attachment is the report file, the other ones are linked from the description.
+    async fn ingest_message(
+        &self,
+        mailbox: &InboundMailbox,
+        raw_message: &[u8],
+    ) -> InboundEmailServiceResult<Report>;
+}
+
+pub struct InboundEmailService {
+    inbound_mailbox_repository: Arc<dyn InboundMailboxRepository>,
+    report_service: Arc<dyn ReportServiceInterface>,
+    user_service: Arc<dyn UserServiceInterface>,
+    storage_port: Arc<dyn StoragePort>,
+}
+
+impl InboundEmailService {
+    pub fn new(
+        inbound_mailbox_repository: Arc<dyn InboundMailboxRepository>,
+        report_service: Arc<dyn ReportServiceInterface>,
+        user_service: Arc<dyn UserServiceInterface>,
+        storage_port: Arc<dyn StoragePort>,
+    ) -> Self {
+        Self {
+            inbound_mailbox_repository,
+            report_service,
+            user_service,
+            storage_port,
+        }
+    }
+
+    /// The active user sending from this address. Addresses are stored as typed at sign-up, so
+    /// the lowercase form is tried as well.
+    async fn find_sender(&self, sender: &str) -> InboundEmailServiceResult<Option<User>> {
+        for email in [sender.to_string(), sender.to_lowercase()] {
+            match self.user_service.get_user_by_email(&email).await {
+                Ok(user) if user.is_active && user.deleted_at.is_none() => return Ok(Some(user)),
+                Ok(_) | Err(UserServiceError::UserNotFound) => {}
+                Err(err) => return Err(InboundEmailServiceError::InternalError(err.to_string())),
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Removes the attachments stored for a report that could not be created.
+    async fn delete_attachments(&self, attachment_links: &[(String, String)]) {
+        for (_, url) in attachment_links {
+            if let Err(e) = self.storage_port.delete_file(url).await {
+                tracing::warn!(url = %url, "Failed to delete an orphaned attachment: {}", e);
+            }
+        }
+    }
+
+    fn build_description(email: &InboundEmail, attachment_links: &[(String, String)]) -> String {
+        let mut description = email.text_body.clone().unwrap_or_default();
+
+        if !attachment_links.is_empty() {
+            if !description.is_empty() {
+                description.push_str("\n\n");
+            }
+            description.push_str("Other attachments:");
+            for (file_name, url) in attachment_links {
+                description.push_str(&format!("\n- {}: {}", file_name, url));
+            }
+        }
+
+        truncate_chars(&description, MAX_DESCRIPTION_LENGTH)
+    }
+}
+
+#[async_trait]
+impl InboundEmailServiceInterface for InboundEmailService {
+    #[instrument(skip(self), fields(board_id = %board_id), level = "debug")]
+    async fn get_mailbox(&self, board_id: Uuid) -> InboundEmailServiceResult<InboundMailbox> {
+        self.inbound_mailbox_repository
+            .find_by_board_id(board_id)
+            .await?
+            .ok_or(InboundEmailServiceError::MailboxNotFound)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id, created_by = %created_by), level = "info")]
+    async fn configure_mailbox(
+        &self,
+        board_id: Uuid,
+        created_by: Uuid,
+    ) -> InboundEmailServiceResult<InboundMailbox> {
+        let mailbox = self
+            .inbound_mailbox_repository
+            .upsert_for_board(board_id, &generate_inbound_mailbox_token(), created_by)
+            .await?;
+
+        tracing::info!(inbound_mailbox_id = %mailbox.id, "Inbound mailbox configured.");
+        Ok(mailbox)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id), level = "info")]
+    async fn delete_mailbox(&self, board_id: Uuid) -> InboundEmailServiceResult<()> {
+        self.get_mailbox(board_id).await?;
+        self.inbound_mailbox_repository
+            .delete_by_board_id(board_id)
+            .await?;
+
+        tracing::info!("Inbound mailbox deleted.");
+        Ok(())
+    }
+
+    #[instrument(skip(self, token), level = "debug")]
+    async fn find_mailbox_by_token(
+        &self,
+        token: &str,
+    ) -> InboundEmailServiceResult<InboundMailbox> {
+        self.inbound_mailbox_repository
+            .find_by_token(&token.trim().to_lowercase())
+            .await?
+            .ok_or(InboundEmailServiceError::MailboxNotFound)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_mailbox_by_address(
+        &self,
+        address: &str,
+    ) -> InboundEmailServiceResult<InboundMailbox> {
+        let (local_part, domain) = address
+            .trim()
+            .rsplit_once('@')
+            .ok_or(InboundEmailServiceError::MailboxNotFound)?;
+        if !domain.eq_ignore_ascii_case(&APP_CONFIG.inbound_email_domain) {
+            return Err(InboundEmailServiceError::MailboxNotFound);
+        }
+
+        // Plus addressing keeps the token after the last '+', e.g. `bugs+token@domain`.
+        let token = local_part.rsplit('+').next().unwrap_or(local_part);
+        self.find_mailbox_by_token(token).await
+    }
+
+    #[instrument(skip(self, mailbox, raw_message), fields(board_id = %mailbox.board_id, message_size = raw_message.len()), level = "info")]
+    async fn ingest_message(
+        &self,
+        mailbox: &InboundMailbox,
+        raw_message: &[u8],
+    ) -> InboundEmailServiceResult<Report> {
+        let email = parse_message(raw_message)
+            .map_err(|e| InboundEmailServiceError::ValidationError(e.to_string()))?;
+
+        // Only the accepted media are kept, recognized from their content: anything else, such
+        // as HTML or SVG files, would be served from the app origin.
+        let (media, skipped): (Vec<_>, Vec<_>) = email
+            .attachments
+            .iter()
+            .partition(|attachment| MediaType::sniff(&attachment.data).is_some());
+        if !skipped.is_empty() {
+            tracing::info!(
+                skipped_attachment_count = skipped.len(),
+                "Attachments other than images and videos ignored."
+            );
+        }
+
+        let mut attachments = media.into_iter();
+        let report_file = attachments.next().ok_or_else(|| {
+            InboundEmailServiceError::ValidationError(
+                "The email has no image or video attachment".to_string(),
+            )
+        })?;
+
+        let sender = match email.sender.as_deref() {
+            Some(sender) => self.find_sender(sender).await?,
+            None => None,
+        };
+        let (user_id, user_role, contact_email) = match sender {
+            Some(user) => (user.id, user.role, None),
+            None => (ANONYMOUS_REPORTER_ID, UserRole::User, email.sender.clone()),
+        };
+
+        let title = email
+            .subject
+            .as_deref()
+            .map(|subject| truncate_chars(subject, MAX_TITLE_LENGTH))
+            .unwrap_or_else(|| DEFAULT_TITLE.to_string());
+
+        // The description links to the other attachments, so they are stored first and removed
+        // again if the report cannot be created.
+        let mut attachment_links = Vec::new();
+        for attachment in attachments {
+            let saved_file = self
+                .storage_port
+                .save_file(&attachment.file_name, attachment.data.clone())
+                .await;
+            match saved_file {
+                Ok(url) => attachment_links.push((attachment.file_name.clone(), url)),
+                Err(e) => {
+                    self.delete_attachments(&attachment_links).await;
+                    return Err(e.into());
+                }
+            }
+        }
+
+        let description = Some(Self::build_description(&email, &attachment_links))
+            .filter(|description| !description.is_empty());
+
+        let created_report = self
+            .report_service
+            .create_trusted_report(
+                CreateReportServiceParams {
+                    user_id,
+                    user_role,
+                    board_id: mailbox.board_id,
+                    title,
+                    description,
+                    url: None,
+                    browser_name: None,
+                    browser_version: None,
+                    os_name: None,
+                    contact_email,
+                    original_file_name: report_file.file_name.clone(),
+                    file_data: report_file.data.clone(),
+                    thumbnail_data: None,
+                },
+                // The sender address is not authenticated, so it does not make the sender the actor.
+                &AuditContext::system(),
+            )
+            .await;
+        let report = match created_report {
+            Ok(report) => report,
+            Err(e) => {
+                self.delete_attachments(&attachment_links).await;
+                return Err(e.into());
+            }
+        };
+
+        tracing::info!(report_id = %report.id, reporter_id = %user_id, "Report created from an inbound email.");
+        Ok(report)
+    }
+}
+
+fn truncate_chars(value: &str, max_length: usize) -> String {
+    value.chars().take(max_length).collect()
+}
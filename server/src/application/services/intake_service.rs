@@ -0,0 +1,307 @@
+use std::{net::IpAddr, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::{
+        models::{
+            audit::AuditContext,
+            intake_key::{IntakeKey, IntakeSubmission, UpdateIntakeKeyParams, normalize_origin},
+            media::MediaType,
+            report::{CreateReportServiceParams, Report},
+            user::{ANONYMOUS_REPORTER_ID, UserRole},
+        },
+        repositories::{RepositoryError, intake_key_repository::IntakeKeyRepository},
+    },
+    infrastructure::security::{
+        intake_keys::generate_intake_key,
+        rate_limiter::{RateLimiter, client_network},
+    },
+};
+
+use super::report_service::{ReportServiceError, ReportServiceInterface};
+
+#[derive(Debug, thiserror::Error)]
+pub enum IntakeServiceError {
+    #[error("Intake key not found")]
+    IntakeKeyNotFound,
+    #[error("Origin not allowed for this intake key")]
+    OriginNotAllowed,
+    #[error("Too many submissions")]
+    RateLimited,
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Report error: {0}")]
+    ReportError(#[from] ReportServiceError),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<RepositoryError> for IntakeServiceError {
+    fn from(err: RepositoryError) -> Self {
+        match err {
+            RepositoryError::NotFound => IntakeServiceError::IntakeKeyNotFound,
+            RepositoryError::AlreadyExists | RepositoryError::DatabaseError(_) => {
+                IntakeServiceError::InternalError(err.to_string())
+            }
+        }
+    }
+}
+
+pub type IntakeServiceResult<T> = Result<T, IntakeServiceError>;
+
+#[async_trait]
+pub trait IntakeServiceInterface: Send + Sync {
+    async fn create_key(
+        &self,
+        board_id: Uuid,
+        created_by: Uuid,
+        name: &str,
+        allowed_origins: Vec<String>,
+    ) -> IntakeServiceResult<IntakeKey>;
+
+    async fn list_keys(&self, board_id: Uuid) -> IntakeServiceResult<Vec<IntakeKey>>;
+
+    async fn update_key(
+        &self,
+        board_id: Uuid,
+        key_id: Uuid,
+        params: UpdateIntakeKeyParams,
+    ) -> IntakeServiceResult<IntakeKey>;
+
+    async fn delete_key(&self, board_id: Uuid, key_id: Uuid) -> IntakeServiceResult<()>;
+
+    /// The active intake key, provided the request comes from one of its allowed origins.
+    async fn find_key_for_origin(
+        &self,
+        public_key: &str,
+        origin: Option<&str>,
+    ) -> IntakeServiceResult<IntakeKey>;
+
+    /// Counts a submission from the client IP address, against the limit shared by its network.
+    /// Checked before the request body is read, so that rejected clients cost no upload.
+    fn check_rate_limit(&self, client_ip: IpAddr) -> IntakeServiceResult<()>;
+
+    /// Files the submission as an anonymous report on the key board, once the rate limit has
+    /// been checked. Submissions caught by the
+    /// honeypot are dropped silently and return `None`, so bots cannot tell.
+    async fn submit_report(
+        &self,
+        intake_key: &IntakeKey,
+        submission: IntakeSubmission,
+    ) -> IntakeServiceResult<Option<Report>>;
+}
+
+pub struct IntakeService {
+    intake_key_repository: Arc<dyn IntakeKeyRepository>,
+    report_service: Arc<dyn ReportServiceInterface>,
+    rate_limiter: RateLimiter<IpAddr>,
+}
+
+impl IntakeService {
+    pub fn new(
+        intake_key_repository: Arc<dyn IntakeKeyRepository>,
+        report_service: Arc<dyn ReportServiceInterface>,
+    ) -> Self {
+        Self {
+            intake_key_repository,
+            report_service,
+            rate_limiter: RateLimiter::new(
+                APP_CONFIG.intake_rate_limit_max_submissions,
+                Duration::from_secs(APP_CONFIG.intake_rate_limit_window_seconds),
+            ),
+        }
+    }
+
+    /// Origins must be bare `http(s)://host[:port]` values, as browsers send them.
+    fn normalize_allowed_origins(origins: Vec<String>) -> IntakeServiceResult<Vec<String>> {
+        let mut normalized_origins: Vec<String> = Vec::with_capacity(origins.len());
+
+        for origin in origins {
+            let normalized_origin = normalize_origin(&origin);
+            let is_valid = normalized_origin
+                .split_once("://")
+                .is_some_and(|(scheme, host)| {
+                    matches!(scheme, "http" | "https")
+                        && !host.is_empty()
+                        && !host.contains(['/', ',', ' ', '?', '#'])
+                });
+            if !is_valid {
+                return Err(IntakeServiceError::ValidationError(format!(
+                    "Invalid origin '{}', expected scheme://host[:port]",
+                    origin
+                )));
+            }
+
+            if !normalized_origins.contains(&normalized_origin) {
+                normalized_origins.push(normalized_origin);
+            }
+        }
+
+        if normalized_origins.is_empty() {
+            return Err(IntakeServiceError::ValidationError(
+                "At least one allowed origin is required".to_string(),
+            ));
+        }
+
+        Ok(normalized_origins)
+    }
+
+    async fn find_board_key(&self, board_id: Uuid, key_id: Uuid) -> IntakeServiceResult<IntakeKey> {
+        self.intake_key_repository
+            .find_by_id(key_id)
+            .await?
+            .filter(|intake_key| intake_key.board_id == board_id)
+            .ok_or(IntakeServiceError::IntakeKeyNotFound)
+    }
+}
+
+#[async_trait]
+impl IntakeServiceInterface for IntakeService {
+    #[instrument(skip(self, name, allowed_origins), fields(board_id = %board_id, created_by = %created_by), level = "info")]
+    async fn create_key(
+        &self,
+        board_id: Uuid,
+        created_by: Uuid,
+        name: &str,
+        allowed_origins: Vec<String>,
+    ) -> IntakeServiceResult<IntakeKey> {
+        let allowed_origins = Self::normalize_allowed_origins(allowed_origins)?;
+
+        let intake_key = self
+            .intake_key_repository
+            .create_key(
+                board_id,
+                name.trim(),
+                &generate_intake_key(),
+                &allowed_origins,
+                created_by,
+            )
+            .await?;
+
+        tracing::info!(intake_key_id = %intake_key.id, "Intake key created.");
+        Ok(intake_key)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id), level = "debug")]
+    async fn list_keys(&self, board_id: Uuid) -> IntakeServiceResult<Vec<IntakeKey>> {
+        Ok(self
+            .intake_key_repository
+            .find_by_board_id(board_id)
+            .await?)
+    }
+
+    #[instrument(skip(self, params), fields(board_id = %board_id, intake_key_id = %key_id), level = "info")]
+    async fn update_key(
+        &self,
+        board_id: Uuid,
+        key_id: Uuid,
+        mut params: UpdateIntakeKeyParams,
+    ) -> IntakeServiceResult<IntakeKey> {
+        self.find_board_key(board_id, key_id).await?;
+
+        params.name = params.name.map(|name| name.trim().to_string());
+        params.allowed_origins = params
+            .allowed_origins
+            .map(Self::normalize_allowed_origins)
+            .transpose()?;
+
+        let intake_key = self
+            .intake_key_repository
+            .update_key(key_id, params)
+            .await?;
+
+        tracing::info!("Intake key updated.");
+        Ok(intake_key)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id, intake_key_id = %key_id), level = "info")]
+    async fn delete_key(&self, board_id: Uuid, key_id: Uuid) -> IntakeServiceResult<()> {
+        self.find_board_key(board_id, key_id).await?;
+        self.intake_key_repository.delete_key(key_id).await?;
+
+        tracing::info!("Intake key deleted.");
+        Ok(())
+    }
+
+    #[instrument(skip(self, public_key), fields(origin = ?origin), level = "debug")]
+    async fn find_key_for_origin(
+        &self,
+        public_key: &str,
+        origin: Option<&str>,
+    ) -> IntakeServiceResult<IntakeKey> {
+        let intake_key = self
+            .intake_key_repository
+            .find_by_public_key(public_key)
+            .await?
+            .filter(|intake_key| intake_key.is_active)
+            .ok_or(IntakeServiceError::IntakeKeyNotFound)?;
+
+        match origin {
+            Some(origin) if intake_key.allows_origin(origin) => Ok(intake_key),
+            _ => Err(IntakeServiceError::OriginNotAllowed),
+        }
+    }
+
+    fn check_rate_limit(&self, client_ip: IpAddr) -> IntakeServiceResult<()> {
+        if !self.rate_limiter.check(client_network(client_ip)) {
+            tracing::warn!(client_ip = %client_ip, "Intake submission rate limit exceeded.");
+            return Err(IntakeServiceError::RateLimited);
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, intake_key, submission), fields(intake_key_id = %intake_key.id, board_id = %intake_key.board_id, client_ip = %submission.client_ip), level = "info")]
+    async fn submit_report(
+        &self,
+        intake_key: &IntakeKey,
+        submission: IntakeSubmission,
+    ) -> IntakeServiceResult<Option<Report>> {
+        if submission
+            .honeypot
+            .as_deref()
+            .is_some_and(|value| !value.trim().is_empty())
+        {
+            tracing::info!("Intake submission caught by the honeypot, dropped.");
+            return Ok(None);
+        }
+
+        if MediaType::sniff(&submission.file_data).is_none() {
+            return Err(IntakeServiceError::ValidationError(
+                "Only image and video files are accepted".to_string(),
+            ));
+        }
+
+        let report = self
+            .report_service
+            .create_trusted_report(
+                CreateReportServiceParams {
+                    user_id: ANONYMOUS_REPORTER_ID,
+                    user_role: UserRole::User,
+                    board_id: intake_key.board_id,
+                    title: submission.title,
+                    description: submission.description,
+                    url: submission.url,
+                    browser_name: submission.browser_name,
+                    browser_version: submission.browser_version,
+                    os_name: submission.os_name,
+                    contact_email: submission.contact_email,
+                    original_file_name: submission.original_file_name,
+                    file_data: submission.file_data,
+                    thumbnail_data: submission.thumbnail_data,
+                },
+                &AuditContext {
+                    ip_address: Some(submission.client_ip),
+                    ..AuditContext::system()
+                },
+            )
+            .await?;
+
+        tracing::info!(report_id = %report.id, "Anonymous report submitted through an intake key.");
+        Ok(Some(report))
+    }
+}
@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::{
+        models::{
+            audit::AuditContext,
+            invitation::{AcceptInvitationParams, Invitation, IssuedInvitation},
+            user::{User, UserRole},
+        },
+        repositories::{RepositoryError, invitation_repository::InvitationRepository},
+    },
+    infrastructure::security::invitation_tokens::{
+        generate_invitation_token, hash_invitation_token,
+    },
+};
+
+use super::{
+    user_onboarding_service::{UserOnboardingServiceError, UserOnboardingServiceInterface},
+    user_service::{UserServiceError, UserServiceInterface},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum InvitationServiceError {
+    #[error("Invitation not found")]
+    InvitationNotFound,
+    #[error("Invitation is invalid or has expired")]
+    InvalidInvitation,
+    #[error("User already exists")]
+    UserAlreadyExists,
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Onboarding error: {0}")]
+    OnboardingError(#[from] UserOnboardingServiceError),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<RepositoryError> for InvitationServiceError {
+    fn from(err: RepositoryError) -> Self {
+        match err {
+            RepositoryError::NotFound => InvitationServiceError::InvitationNotFound,
+            RepositoryError::AlreadyExists | RepositoryError::DatabaseError(_) => {
+                InvitationServiceError::InternalError(err.to_string())
+            }
+        }
+    }
+}
+
+pub type InvitationServiceResult<T> = Result<T, InvitationServiceError>;
+
+#[async_trait]
+pub trait InvitationServiceInterface: Send + Sync {
+    /// Issues a new invitation, revoking any pending one for the same email.
+    async fn create_invitation(
+        &self,
+        invited_by: Uuid,
+        email: &str,
+        role: UserRole,
+    ) -> InvitationServiceResult<IssuedInvitation>;
+
+    async fn list_pending_invitations(&self) -> InvitationServiceResult<Vec<Invitation>>;
+
+    async fn revoke_invitation(&self, id: Uuid) -> InvitationServiceResult<()>;
+
+    async fn get_pending_invitation(&self, token: &str) -> InvitationServiceResult<Invitation>;
+
+    /// Creates the invited user, along with their default board, using the password they chose.
+    async fn accept_invitation(
+        &self,
+        params: AcceptInvitationParams,
+        context: &AuditContext,
+    ) -> InvitationServiceResult<User>;
+}
+
+pub struct InvitationService {
+    invitation_repository: Arc<dyn InvitationRepository>,
+    user_service: Arc<dyn UserServiceInterface>,
+    user_onboarding_service: Arc<dyn UserOnboardingServiceInterface>,
+}
+
+impl InvitationService {
+    pub fn new(
+        invitation_repository: Arc<dyn InvitationRepository>,
+        user_service: Arc<dyn UserServiceInterface>,
+        user_onboarding_service: Arc<dyn UserOnboardingServiceInterface>,
+    ) -> Self {
+        Self {
+            invitation_repository,
+            user_service,
+            user_onboarding_service,
+        }
+    }
+}
+
+#[async_trait]
+impl InvitationServiceInterface for InvitationService {
+    #[instrument(skip(self), fields(invited_by = %invited_by, email = %email, role = %role), level = "info")]
+    async fn create_invitation(
+        &self,
+        invited_by: Uuid,
+        email: &str,
+        role: UserRole,
+    ) -> InvitationServiceResult<IssuedInvitation> {
+        if email.is_empty() || !email.contains('@') {
+            return Err(InvitationServiceError::ValidationError(
+                "Invalid email format".to_string(),
+            ));
+        }
+
+        match self.user_service.get_user_by_email(email).await {
+            Ok(_) => return Err(InvitationServiceError::UserAlreadyExists),
+            Err(UserServiceError::UserNotFound) => {}
+            Err(e) => return Err(InvitationServiceError::InternalError(e.to_string())),
+        }
+
+        self.invitation_repository
+            .revoke_open_by_email(email)
+            .await?;
+
+        let token = generate_invitation_token();
+        let expires_at = Utc::now() + Duration::seconds(APP_CONFIG.invitation_expiration_seconds);
+
+        let invitation = self
+            .invitation_repository
+            .create_invitation(
+                email,
+                role,
+                &hash_invitation_token(&token),
+                invited_by,
+                expires_at,
+            )
+            .await?;
+
+        tracing::info!(invitation_id = %invitation.id, "Invitation created.");
+        Ok(IssuedInvitation { invitation, token })
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn list_pending_invitations(&self) -> InvitationServiceResult<Vec<Invitation>> {
+        let now = Utc::now();
+
+        Ok(self
+            .invitation_repository
+            .find_open()
+            .await?
+            .into_iter()
+            .filter(|invitation| invitation.is_pending(now))
+            .collect())
+    }
+
+    #[instrument(skip(self), fields(invitation_id = %id), level = "info")]
+    async fn revoke_invitation(&self, id: Uuid) -> InvitationServiceResult<()> {
+        self.invitation_repository.revoke(id).await?;
+
+        tracing::info!("Invitation revoked.");
+        Ok(())
+    }
+
+    #[instrument(skip(self, token), level = "debug")]
+    async fn get_pending_invitation(&self, token: &str) -> InvitationServiceResult<Invitation> {
+        self.invitation_repository
+            .find_by_token_hash(&hash_invitation_token(token))
+            .await?
+            .filter(|invitation| invitation.is_pending(Utc::now()))
+            .ok_or(InvitationServiceError::InvalidInvitation)
+    }
+
+    #[instrument(skip(self, params, context), level = "info")]
+    async fn accept_invitation(
+        &self,
+        params: AcceptInvitationParams,
+        context: &AuditContext,
+    ) -> InvitationServiceResult<User> {
+        let invitation = self.get_pending_invitation(&params.token).await?;
+
+        let user = self
+            .user_onboarding_service
+            .onboard_user(
+                &invitation.email,
+                &params.password,
+                params.first_name.as_deref(),
+                params.last_name.as_deref(),
+                invitation.role.clone(),
+                context,
+            )
+            .await?;
+
+        if let Err(e) = self
+            .invitation_repository
+            .mark_accepted(invitation.id)
+            .await
+        {
+            tracing::error!(invitation_id = %invitation.id, "Failed to mark invitation as accepted: {}", e);
+        }
+
+        tracing::info!(invitation_id = %invitation.id, user_id = %user.id, "Invitation accepted.");
+        Ok(user)
+    }
+}
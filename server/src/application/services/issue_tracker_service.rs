@@ -0,0 +1,389 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::{
+        models::{
+            audit::AuditContext,
+            issue_tracker::{
+                ConfigureIssueTrackerParams, InboundWebhook, IssueAction, IssueTrackerIntegration,
+                IssueTrackerProvider, NewIssue,
+            },
+            report::{Report, ReportChangeSource, ReportStatus, ReportStatusChange},
+        },
+        ports::issue_tracker_port::{IssueTrackerError, IssueTrackerPort},
+        repositories::{
+            RepositoryError,
+            issue_tracker_integration_repository::IssueTrackerIntegrationRepository,
+        },
+    },
+    infrastructure::security::{
+        public_addresses::{PublicAddressError, check_public_url},
+        webhook_signature::generate_webhook_secret,
+    },
+};
+
+use super::{
+    report_service::{ReportServiceError, ReportServiceInterface},
+    user_service::UserServiceInterface,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum IssueTrackerServiceError {
+    #[error("Issue tracker integration not found")]
+    IntegrationNotFound,
+    #[error("Report not found")]
+    ReportNotFound,
+    #[error("Report was already exported to {0}")]
+    AlreadyExported(String),
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Invalid webhook signature")]
+    InvalidSignature,
+    #[error("Issue tracker error: {0}")]
+    TrackerError(#[from] IssueTrackerError),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<RepositoryError> for IssueTrackerServiceError {
+    fn from(err: RepositoryError) -> Self {
+        match err {
+            RepositoryError::NotFound => IssueTrackerServiceError::IntegrationNotFound,
+            RepositoryError::AlreadyExists | RepositoryError::DatabaseError(_) => {
+                IssueTrackerServiceError::InternalError(err.to_string())
+            }
+        }
+    }
+}
+
+impl From<ReportServiceError> for IssueTrackerServiceError {
+    fn from(err: ReportServiceError) -> Self {
+        match err {
+            ReportServiceError::ReportNotFound { .. } => IssueTrackerServiceError::ReportNotFound,
+            e => IssueTrackerServiceError::InternalError(e.to_string()),
+        }
+    }
+}
+
+pub type IssueTrackerServiceResult<T> = Result<T, IssueTrackerServiceError>;
+
+#[async_trait]
+pub trait IssueTrackerServiceInterface: Send + Sync {
+    /// Sets the repository reports of the board are exported to, replacing any previous one.
+    async fn configure_integration(
+        &self,
+        board_id: Uuid,
+        configured_by: Uuid,
+        params: ConfigureIssueTrackerParams,
+    ) -> IssueTrackerServiceResult<IssueTrackerIntegration>;
+
+    async fn get_integration(
+        &self,
+        board_id: Uuid,
+    ) -> IssueTrackerServiceResult<IssueTrackerIntegration>;
+
+    async fn remove_integration(&self, board_id: Uuid) -> IssueTrackerServiceResult<()>;
+
+    /// Opens an issue for the report and links it to the report.
+    async fn export_report(
+        &self,
+        report_id: Uuid,
+        context: &AuditContext,
+    ) -> IssueTrackerServiceResult<Report>;
+
+    /// Applies an issue close/reopen webhook to the linked report, returning it when its
+    /// status changed.
+    async fn handle_webhook(
+        &self,
+        integration_id: Uuid,
+        webhook: InboundWebhook,
+        context: &AuditContext,
+    ) -> IssueTrackerServiceResult<Option<Report>>;
+}
+
+pub struct IssueTrackerService {
+    issue_tracker_integration_repository: Arc<dyn IssueTrackerIntegrationRepository>,
+    issue_tracker: Arc<dyn IssueTrackerPort>,
+    report_service: Arc<dyn ReportServiceInterface>,
+    user_service: Arc<dyn UserServiceInterface>,
+}
+
+impl IssueTrackerService {
+    pub fn new(
+        issue_tracker_integration_repository: Arc<dyn IssueTrackerIntegrationRepository>,
+        issue_tracker: Arc<dyn IssueTrackerPort>,
+        report_service: Arc<dyn ReportServiceInterface>,
+        user_service: Arc<dyn UserServiceInterface>,
+    ) -> Self {
+        Self {
+            issue_tracker_integration_repository,
+            issue_tracker,
+            report_service,
+            user_service,
+        }
+    }
+
+    /// A custom API URL must resolve to public addresses only, unless private networks are
+    /// allowed: the access token is sent there. The tracker checks the addresses again on each
+    /// request.
+    async fn validate_params(
+        params: &ConfigureIssueTrackerParams,
+    ) -> IssueTrackerServiceResult<()> {
+        let repository = params.repository.trim();
+        let is_valid_repository = match params.provider {
+            IssueTrackerProvider::GitHub => {
+                repository.split_once('/').is_some_and(|(owner, repo)| {
+                    !owner.is_empty() && !repo.is_empty() && !repo.contains('/')
+                })
+            }
+            IssueTrackerProvider::GitLab => !repository.is_empty(),
+        };
+        if !is_valid_repository {
+            return Err(IssueTrackerServiceError::ValidationError(format!(
+                "Invalid {} repository: {}",
+                params.provider, params.repository
+            )));
+        }
+
+        if params.access_token.trim().is_empty() {
+            return Err(IssueTrackerServiceError::ValidationError(
+                "Access token cannot be empty".to_string(),
+            ));
+        }
+
+        if let Some(api_base_url) = &params.api_base_url {
+            check_public_url(api_base_url, APP_CONFIG.webhook_allow_private_networks)
+                .await
+                .map_err(|e| match e {
+                    PublicAddressError::InvalidUrl => IssueTrackerServiceError::ValidationError(
+                        "API URL must be an absolute http(s) URL".to_string(),
+                    ),
+                    e => IssueTrackerServiceError::ValidationError(format!("API URL: {}", e)),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    fn build_issue(report: &Report, reporter: &str) -> NewIssue {
+        let report_url = format!(
+            "{}/reports/{}",
+            APP_CONFIG.public_base_url.trim_end_matches('/'),
+            report.id
+        );
+        let browser = match (&report.browser_name, &report.browser_version) {
+            (Some(name), Some(version)) => format!("{} {}", name, version),
+            (Some(name), None) => name.clone(),
+            _ => "n/a".to_string(),
+        };
+
+        let mut body = String::new();
+        body.push_str(
+            report
+                .description
+                .as_deref()
+                .filter(|description| !description.trim().is_empty())
+                .unwrap_or("_No description provided._"),
+        );
+
+        body.push_str("\n\n### Environment\n\n");
+        body.push_str(&format!(
+            "- **Page:** {}\n",
+            report.url.as_deref().unwrap_or("n/a")
+        ));
+        body.push_str(&format!("- **Browser:** {}\n", browser));
+        body.push_str(&format!(
+            "- **OS:** {}\n",
+            report.os_name.as_deref().unwrap_or("n/a")
+        ));
+        body.push_str(&format!("- **Reported by:** {}\n", reporter));
+        body.push_str(&format!(
+            "- **Reported at:** {}\n",
+            report.created_at.to_rfc3339()
+        ));
+
+        body.push_str("\n### Media\n\n");
+        body.push_str(&format!(
+            "- [{}]({})\n",
+            report.report_type, report.file_path
+        ));
+        if let Some(thumbnail_url) = &report.thumbnail_file_path {
+            body.push_str(&format!("- [Thumbnail]({})\n", thumbnail_url));
+        }
+
+        body.push_str(&format!("\n---\nExported from [rebug]({})\n", report_url));
+
+        NewIssue {
+            title: report.title.clone(),
+            body,
+        }
+    }
+}
+
+#[async_trait]
+impl IssueTrackerServiceInterface for IssueTrackerService {
+    #[instrument(skip(self, params), fields(board_id = %board_id, configured_by = %configured_by, provider = %params.provider), level = "info")]
+    async fn configure_integration(
+        &self,
+        board_id: Uuid,
+        configured_by: Uuid,
+        mut params: ConfigureIssueTrackerParams,
+    ) -> IssueTrackerServiceResult<IssueTrackerIntegration> {
+        Self::validate_params(&params).await?;
+        params.repository = params.repository.trim().to_string();
+
+        let api_base_url = params
+            .api_base_url
+            .as_deref()
+            .unwrap_or(params.provider.default_api_base_url())
+            .trim_end_matches('/')
+            .to_string();
+
+        let integration = self
+            .issue_tracker_integration_repository
+            .upsert_for_board(
+                board_id,
+                &api_base_url,
+                &generate_webhook_secret(),
+                params,
+                configured_by,
+            )
+            .await?;
+
+        tracing::info!(integration_id = %integration.id, "Issue tracker integration configured.");
+        Ok(integration)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id), level = "debug")]
+    async fn get_integration(
+        &self,
+        board_id: Uuid,
+    ) -> IssueTrackerServiceResult<IssueTrackerIntegration> {
+        self.issue_tracker_integration_repository
+            .find_by_board_id(board_id)
+            .await?
+            .ok_or(IssueTrackerServiceError::IntegrationNotFound)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id), level = "info")]
+    async fn remove_integration(&self, board_id: Uuid) -> IssueTrackerServiceResult<()> {
+        self.issue_tracker_integration_repository
+            .delete_by_board_id(board_id)
+            .await?;
+
+        tracing::info!("Issue tracker integration removed.");
+        Ok(())
+    }
+
+    #[instrument(skip(self, context), fields(report_id = %report_id), level = "info")]
+    async fn export_report(
+        &self,
+        report_id: Uuid,
+        context: &AuditContext,
+    ) -> IssueTrackerServiceResult<Report> {
+        let report = self.report_service.get_report(report_id).await?;
+
+        if let Some(external_issue_key) = report.external_issue_key {
+            return Err(IssueTrackerServiceError::AlreadyExported(
+                external_issue_key,
+            ));
+        }
+
+        let integration = self.get_integration(report.board_id).await?;
+        let reporter = self
+            .user_service
+            .get_user_by_id(report.user_id)
+            .await
+            .map(|user| user.display_name())
+            .unwrap_or_else(|_| "Unknown user".to_string());
+
+        let created_issue = self
+            .issue_tracker
+            .create_issue(&integration, &Self::build_issue(&report, &reporter))
+            .await?;
+
+        let issue_key = integration.issue_key(created_issue.number);
+        tracing::info!(issue_key = %issue_key, "Issue created in the tracker.");
+
+        Ok(self
+            .report_service
+            .link_external_issue(report_id, &issue_key, &created_issue.url, context)
+            .await?)
+    }
+
+    #[instrument(skip(self, webhook, context), fields(integration_id = %integration_id), level = "info")]
+    async fn handle_webhook(
+        &self,
+        integration_id: Uuid,
+        webhook: InboundWebhook,
+        context: &AuditContext,
+    ) -> IssueTrackerServiceResult<Option<Report>> {
+        let integration = self
+            .issue_tracker_integration_repository
+            .find_by_id(integration_id)
+            .await?
+            .ok_or(IssueTrackerServiceError::IntegrationNotFound)?;
+
+        if !self.issue_tracker.verify_webhook(&integration, &webhook) {
+            tracing::warn!("Rejected issue tracker webhook with an invalid signature.");
+            return Err(IssueTrackerServiceError::InvalidSignature);
+        }
+
+        let Some(event) = self
+            .issue_tracker
+            .parse_issue_event(&integration, &webhook)
+            .map_err(|e| IssueTrackerServiceError::ValidationError(e.to_string()))?
+        else {
+            tracing::debug!("Ignoring issue tracker webhook without issue state change.");
+            return Ok(None);
+        };
+
+        if !event
+            .repository
+            .eq_ignore_ascii_case(&integration.repository)
+        {
+            tracing::debug!(repository = %event.repository, "Ignoring webhook for another repository.");
+            return Ok(None);
+        }
+
+        let issue_key = integration.issue_key(event.number);
+        let Some(report) = self
+            .report_service
+            .find_by_external_issue(integration.board_id, &issue_key)
+            .await?
+        else {
+            tracing::debug!(issue_key = %issue_key, "No report linked to the issue.");
+            return Ok(None);
+        };
+
+        let status = match event.action {
+            IssueAction::Closed => ReportStatus::Resolved,
+            IssueAction::Reopened => ReportStatus::Open,
+        };
+        if report.status == status {
+            return Ok(None);
+        }
+
+        let change = ReportStatusChange {
+            status,
+            source: ReportChangeSource::IssueTracker,
+            actor_id: None,
+            details: Some(format!(
+                "{} issue {} {}",
+                integration.provider, issue_key, event.action
+            )),
+        };
+        let report = self
+            .report_service
+            .change_status(report.id, change, context)
+            .await?;
+
+        tracing::info!(report_id = %report.id, issue_key = %issue_key, status = %status, "Report status synced from the issue tracker.");
+        Ok(Some(report))
+    }
+}
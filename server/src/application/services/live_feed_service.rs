@@ -0,0 +1,206 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use tokio::{
+    sync::broadcast::{self, error::RecvError},
+    time::{Instant, Interval, MissedTickBehavior},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::{
+    models::{
+        event::{DomainEvent, EventType},
+        user::UserRole,
+    },
+    ports::event_publisher_port::EventSubscriber,
+};
+
+use super::{
+    board_service::{BoardServiceError, BoardServiceInterface},
+    user_service::{UserServiceError, UserServiceInterface},
+};
+
+/// How often an open feed checks that the user can still sign in and which boards they can
+/// access. A feed whose user was deactivated or deleted is closed at the next check.
+const ACCESS_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, thiserror::Error)]
+pub enum LiveFeedServiceError {
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<UserServiceError> for LiveFeedServiceError {
+    fn from(err: UserServiceError) -> Self {
+        LiveFeedServiceError::InternalError(err.to_string())
+    }
+}
+
+pub type LiveFeedServiceResult<T> = Result<T, LiveFeedServiceError>;
+
+/// What a live feed yields next.
+#[derive(Debug, Clone)]
+pub enum LiveFeedItem {
+    Event(DomainEvent),
+    /// The reader fell behind and this many events were dropped; it should refetch.
+    Lagged(u64),
+}
+
+/// Report events of the boards a user can access, from the moment the feed was opened.
+pub struct LiveFeed {
+    user_id: Uuid,
+    /// Admins can open any report, as with `GET /reports/{id}`.
+    is_admin: bool,
+    /// Whether the user can access each board an event was received for, as decided by
+    /// `ensure_user_can_access_board`. Forgotten at each access check, so that granted and
+    /// revoked access is followed.
+    board_access: HashMap<Uuid, bool>,
+    receiver: broadcast::Receiver<DomainEvent>,
+    access_check: Interval,
+    board_service: Arc<dyn BoardServiceInterface>,
+    user_service: Arc<dyn UserServiceInterface>,
+}
+
+impl LiveFeed {
+    /// Waits for the next event of the user boards. `None` once the server shuts down, or once
+    /// the user is no longer allowed to sign in.
+    pub async fn next_item(&mut self) -> Option<LiveFeedItem> {
+        loop {
+            let received = tokio::select! {
+                received = self.receiver.recv() => received,
+                _ = self.access_check.tick() => {
+                    if !self.refresh_access().await {
+                        return None;
+                    }
+                    continue;
+                }
+            };
+            let event = match received {
+                Ok(event) => event,
+                Err(RecvError::Lagged(skipped)) => return Some(LiveFeedItem::Lagged(skipped)),
+                Err(RecvError::Closed) => return None,
+            };
+
+            if self.can_access_board(event.board_id).await {
+                return Some(LiveFeedItem::Event(event));
+            }
+        }
+    }
+
+    async fn can_access_board(&mut self, board_id: Uuid) -> bool {
+        if self.is_admin {
+            return true;
+        }
+        if let Some(can_access) = self.board_access.get(&board_id) {
+            return *can_access;
+        }
+
+        let can_access = match self
+            .board_service
+            .ensure_user_can_access_board(self.user_id, board_id)
+            .await
+        {
+            Ok(can_access) => can_access,
+            Err(BoardServiceError::AccessDenied | BoardServiceError::BoardNotFound) => false,
+            Err(e) => {
+                // Not remembered, the next event of the board checks again.
+                tracing::warn!(user_id = %self.user_id, board_id = %board_id, "Failed to check board access: {}", e);
+                return false;
+            }
+        };
+        self.board_access.insert(board_id, can_access);
+        can_access
+    }
+
+    /// Reloads the user. Returns whether the feed can stay open: not once the user was
+    /// deactivated or deleted, nor when the user cannot be loaded.
+    async fn refresh_access(&mut self) -> bool {
+        let user = match self.user_service.get_user_by_id(self.user_id).await {
+            Ok(user) => user,
+            Err(e) => {
+                tracing::warn!(user_id = %self.user_id, "Closing live feed, user not found: {}", e);
+                return false;
+            }
+        };
+        if !user.is_active || user.deleted_at.is_some() {
+            tracing::info!(user_id = %self.user_id, "Closing live feed of a deactivated user.");
+            return false;
+        }
+
+        self.is_admin = user.role == UserRole::Admin;
+        self.board_access.clear();
+        true
+    }
+}
+
+#[async_trait]
+pub trait LiveFeedServiceInterface: Send + Sync {
+    async fn open_feed(&self, user_id: Uuid) -> LiveFeedServiceResult<LiveFeed>;
+}
+
+/// Relays report events from the event bus to the open live feeds.
+pub struct LiveFeedService {
+    board_service: Arc<dyn BoardServiceInterface>,
+    user_service: Arc<dyn UserServiceInterface>,
+    sender: broadcast::Sender<DomainEvent>,
+}
+
+impl LiveFeedService {
+    const CHANNEL_CAPACITY: usize = 256;
+
+    pub fn new(
+        board_service: Arc<dyn BoardServiceInterface>,
+        user_service: Arc<dyn UserServiceInterface>,
+    ) -> Self {
+        let (sender, _) = broadcast::channel(Self::CHANNEL_CAPACITY);
+        Self {
+            board_service,
+            user_service,
+            sender,
+        }
+    }
+}
+
+#[async_trait]
+impl LiveFeedServiceInterface for LiveFeedService {
+    #[instrument(skip(self), fields(user_id = %user_id), level = "debug")]
+    async fn open_feed(&self, user_id: Uuid) -> LiveFeedServiceResult<LiveFeed> {
+        let user = self.user_service.get_user_by_id(user_id).await?;
+        let receiver = self.sender.subscribe();
+        let mut access_check = tokio::time::interval_at(
+            Instant::now() + ACCESS_CHECK_INTERVAL,
+            ACCESS_CHECK_INTERVAL,
+        );
+        access_check.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        tracing::debug!(
+            open_feeds = self.sender.receiver_count(),
+            "Live feed opened"
+        );
+        Ok(LiveFeed {
+            user_id,
+            is_admin: user.role == UserRole::Admin,
+            board_access: HashMap::new(),
+            receiver,
+            access_check,
+            board_service: self.board_service.clone(),
+            user_service: self.user_service.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for LiveFeedService {
+    async fn on_event(&self, event: &DomainEvent) {
+        if !matches!(
+            event.event_type,
+            EventType::ReportCreated | EventType::ReportUpdated | EventType::ReportStatusChanged
+        ) {
+            return;
+        }
+
+        // Sending only fails when no feed is open.
+        let _ = self.sender.send(event.clone());
+    }
+}
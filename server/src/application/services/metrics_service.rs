@@ -0,0 +1,97 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use subtle::ConstantTimeEq;
+use tracing::instrument;
+
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::{
+        models::{
+            event::{DomainEvent, EventType},
+            report::Report,
+        },
+        ports::{event_publisher_port::EventSubscriber, metrics_port::MetricsPort},
+        repositories::{RepositoryError, user_repository::UserRepository},
+    },
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum MetricsServiceError {
+    #[error("Metrics are disabled")]
+    Disabled,
+    #[error("Invalid metrics token")]
+    InvalidToken,
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<RepositoryError> for MetricsServiceError {
+    fn from(err: RepositoryError) -> Self {
+        MetricsServiceError::InternalError(err.to_string())
+    }
+}
+
+pub type MetricsServiceResult<T> = Result<T, MetricsServiceError>;
+
+#[async_trait]
+pub trait MetricsServiceInterface: Send + Sync {
+    fn record_http_request(&self, method: &str, route: &str, status: u16, duration: Duration);
+
+    /// Renders the metrics for a scraper presenting the configured token. Metrics are disabled
+    /// while no token is configured.
+    async fn render_metrics(&self, token: Option<&str>) -> MetricsServiceResult<String>;
+}
+
+pub struct MetricsService {
+    metrics: Arc<dyn MetricsPort>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl MetricsService {
+    pub fn new(metrics: Arc<dyn MetricsPort>, user_repository: Arc<dyn UserRepository>) -> Self {
+        Self {
+            metrics,
+            user_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl MetricsServiceInterface for MetricsService {
+    fn record_http_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        self.metrics
+            .record_http_request(method, route, status, duration);
+    }
+
+    #[instrument(skip_all, level = "debug")]
+    async fn render_metrics(&self, token: Option<&str>) -> MetricsServiceResult<String> {
+        let expected_token = APP_CONFIG.metrics_token.as_bytes();
+        if expected_token.is_empty() {
+            return Err(MetricsServiceError::Disabled);
+        }
+        let token = token.ok_or(MetricsServiceError::InvalidToken)?;
+        if !bool::from(token.as_bytes().ct_eq(expected_token)) {
+            return Err(MetricsServiceError::InvalidToken);
+        }
+
+        let active_users = self.user_repository.count_active().await?;
+        self.metrics.set_active_users(active_users);
+
+        Ok(self.metrics.render().await)
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for MetricsService {
+    async fn on_event(&self, event: &DomainEvent) {
+        if event.event_type != EventType::ReportCreated {
+            return;
+        }
+
+        match serde_json::from_value::<Report>(event.data.clone()) {
+            Ok(report) => self.metrics.record_report_created(&report.report_type),
+            Err(e) => tracing::error!("Failed to read report from event: {}", e),
+        }
+    }
+}
@@ -1,8 +1,22 @@
+pub mod audit_service;
 pub mod auth_service;
 pub mod authorization_service;
+pub mod backup_service;
+pub mod board_export_service;
+pub mod board_import_service;
 pub mod board_service;
+pub mod chat_notification_service;
 pub mod dashboard_service;
+pub mod email_notification_service;
 pub mod health_service;
+pub mod inbound_email_service;
+pub mod intake_service;
+pub mod invitation_service;
+pub mod issue_tracker_service;
+pub mod live_feed_service;
+pub mod metrics_service;
 pub mod report_service;
+pub mod two_factor_service;
 pub mod user_onboarding_service;
 pub mod user_service;
+pub mod webhook_service;
@@ -1,16 +1,28 @@
 use async_trait::async_trait;
-use mime_guess::MimeGuess;
+use serde_json::json;
 use std::sync::Arc;
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::domain::{
-    models::report::{CreateReportParams, CreateReportServiceParams, Report, ReportType},
-    ports::storage_port::{StorageError, StoragePort},
+    models::{
+        audit::{AuditAction, AuditContext, AuditTargetType},
+        event::{DomainEvent, EventType},
+        media::MediaType,
+        report::{
+            CreateReportParams, CreateReportServiceParams, Report, ReportHistoryEntry,
+            ReportStatusChange, ReportType,
+        },
+    },
+    ports::{
+        event_publisher_port::EventPublisherPort,
+        storage_port::{StorageError, StoragePort},
+    },
     repositories::{RepositoryError, report_repository::ReportRepository},
 };
 
 use super::{
+    audit_service::AuditServiceInterface,
     authorization_service::{AuthorizationError, AuthorizationServiceInterface},
     board_service::BoardServiceError,
 };
@@ -51,8 +63,19 @@ pub type ReportServiceResult<T> = Result<T, ReportServiceError>;
 
 #[async_trait]
 pub trait ReportServiceInterface: Send + Sync {
-    async fn create_report(&self, params: CreateReportServiceParams)
-    -> ReportServiceResult<Report>;
+    async fn create_report(
+        &self,
+        params: CreateReportServiceParams,
+        context: &AuditContext,
+    ) -> ReportServiceResult<Report>;
+
+    /// Creates a report without board access checks: the caller vouches for the submission,
+    /// e.g. through a valid intake key or inbound email address.
+    async fn create_trusted_report(
+        &self,
+        params: CreateReportServiceParams,
+        context: &AuditContext,
+    ) -> ReportServiceResult<Report>;
 
     async fn get_report(&self, id: Uuid) -> ReportServiceResult<Report>;
 
@@ -69,53 +92,85 @@ pub trait ReportServiceInterface: Send + Sync {
         page: i32,
         per_page: i32,
     ) -> ReportServiceResult<(Vec<Report>, i32)>;
+
+    /// Every report of the board, oldest first.
+    async fn get_all_reports_by_board(&self, board_id: Uuid) -> ReportServiceResult<Vec<Report>>;
+
+    /// Records the tracker issue the report was exported to.
+    async fn link_external_issue(
+        &self,
+        report_id: Uuid,
+        external_issue_key: &str,
+        external_issue_url: &str,
+        context: &AuditContext,
+    ) -> ReportServiceResult<Report>;
+
+    async fn find_by_external_issue(
+        &self,
+        board_id: Uuid,
+        external_issue_key: &str,
+    ) -> ReportServiceResult<Option<Report>>;
+
+    /// Changes the report status and records it in the report history.
+    async fn change_status(
+        &self,
+        report_id: Uuid,
+        change: ReportStatusChange,
+        context: &AuditContext,
+    ) -> ReportServiceResult<Report>;
+
+    async fn get_report_history(
+        &self,
+        report_id: Uuid,
+    ) -> ReportServiceResult<Vec<ReportHistoryEntry>>;
 }
 
 #[derive(Clone)]
 pub struct ReportService {
     report_repository: Arc<dyn ReportRepository>,
     storage_port: Arc<dyn StoragePort>,
+    event_publisher: Arc<dyn EventPublisherPort>,
 
     authorization_service: Arc<dyn AuthorizationServiceInterface>,
+    audit_service: Arc<dyn AuditServiceInterface>,
 }
 
 impl ReportService {
     pub fn new(
         report_repository: Arc<dyn ReportRepository>,
         storage_port: Arc<dyn StoragePort>,
+        event_publisher: Arc<dyn EventPublisherPort>,
         authorization_service: Arc<dyn AuthorizationServiceInterface>,
+        audit_service: Arc<dyn AuditServiceInterface>,
     ) -> Self {
         Self {
             report_repository,
             storage_port,
+            event_publisher,
             authorization_service,
+            audit_service,
         }
     }
-}
 
-#[async_trait]
-impl ReportServiceInterface for ReportService {
-    #[instrument(skip(self, params), fields(user_id = %params.user_id), board_id= %params.board_id, level="info")]
-    async fn create_report(
+    /// Stores the file and the report, then announces it.
+    async fn save_report(
         &self,
         params: CreateReportServiceParams,
+        context: &AuditContext,
     ) -> ReportServiceResult<Report> {
-        self.authorization_service
-            .assert_can_user_create_report(params.user_id, params.board_id, &params.user_role)
-            .await?;
-
-        let guess = MimeGuess::from_path(&params.original_file_name).first_or_octet_stream();
-        let report_type = match guess.type_().as_str() {
-            "image" => ReportType::Screenshot,
-            "video" => ReportType::Video,
-            _ => {
-                return Err(ReportServiceError::InternalError(
-                    "Unsupported file type.".to_string(),
-                ));
-            }
+        let media_type = MediaType::sniff(&params.file_data).ok_or_else(|| {
+            StorageError::ValidationError("Only image and video files are accepted".to_string())
+        })?;
+        let report_type = if media_type.is_video() {
+            ReportType::Video
+        } else {
+            ReportType::Screenshot
         };
 
-        tracing::debug!(?report_type, "Determined report type from file MIME type.");
+        tracing::debug!(
+            ?report_type,
+            "Determined report type from the file content."
+        );
 
         tracing::debug!("Saving file to storage");
         let file_path = self
@@ -146,13 +201,56 @@ impl ReportServiceInterface for ReportService {
             browser_name: params.browser_name,
             browser_version: params.browser_version,
             os_name: params.os_name,
+            contact_email: params.contact_email,
         };
 
         let report = self.report_repository.create_report(create_params).await?;
 
+        self.audit_service
+            .record(
+                context,
+                AuditAction::ReportCreated,
+                AuditTargetType::Report,
+                Some(report.id),
+                json!({
+                    "board_id": report.board_id,
+                    "title": report.title,
+                    "status": report.status,
+                }),
+            )
+            .await;
+
         tracing::info!(report_id = %report.id, "Screenshot report created successfully");
+        self.event_publisher
+            .publish(DomainEvent::report(EventType::ReportCreated, &report));
+
         Ok(report)
     }
+}
+
+#[async_trait]
+impl ReportServiceInterface for ReportService {
+    #[instrument(skip(self, params, context), fields(user_id = %params.user_id), board_id= %params.board_id, level="info")]
+    async fn create_report(
+        &self,
+        params: CreateReportServiceParams,
+        context: &AuditContext,
+    ) -> ReportServiceResult<Report> {
+        self.authorization_service
+            .assert_can_user_create_report(params.user_id, params.board_id, &params.user_role)
+            .await?;
+
+        self.save_report(params, context).await
+    }
+
+    #[instrument(skip(self, params, context), fields(user_id = %params.user_id, board_id = %params.board_id), level = "info")]
+    async fn create_trusted_report(
+        &self,
+        params: CreateReportServiceParams,
+        context: &AuditContext,
+    ) -> ReportServiceResult<Report> {
+        self.save_report(params, context).await
+    }
 
     #[instrument(skip(self), fields(report_id = %id), level="info")]
     async fn get_report(&self, id: Uuid) -> ReportServiceResult<Report> {
@@ -207,4 +305,117 @@ impl ReportServiceInterface for ReportService {
         Ok((reports, total_items))
         // TODO: dedicated item to wrap result with total items
     }
+
+    #[instrument(skip(self), fields(board_id = %board_id), level = "debug")]
+    async fn get_all_reports_by_board(&self, board_id: Uuid) -> ReportServiceResult<Vec<Report>> {
+        Ok(self
+            .report_repository
+            .find_all_by_board_id(board_id)
+            .await?)
+    }
+
+    #[instrument(skip(self, external_issue_url, context), fields(report_id = %report_id, external_issue_key = %external_issue_key), level = "info")]
+    async fn link_external_issue(
+        &self,
+        report_id: Uuid,
+        external_issue_key: &str,
+        external_issue_url: &str,
+        context: &AuditContext,
+    ) -> ReportServiceResult<Report> {
+        let report = self
+            .report_repository
+            .set_external_issue(report_id, external_issue_key, external_issue_url)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ReportServiceError::ReportNotFound {
+                    context: format!("Failed to link issue to report with ID: {}", report_id),
+                },
+                e => ReportServiceError::RepositoryError(e),
+            })?;
+
+        self.audit_service
+            .record(
+                context,
+                AuditAction::ReportIssueLinked,
+                AuditTargetType::Report,
+                Some(report.id),
+                json!({
+                    "external_issue_key": { "to": external_issue_key },
+                    "external_issue_url": { "to": external_issue_url },
+                }),
+            )
+            .await;
+
+        tracing::info!("External issue linked to report");
+        self.event_publisher
+            .publish(DomainEvent::report(EventType::ReportUpdated, &report));
+
+        Ok(report)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id, external_issue_key = %external_issue_key), level = "debug")]
+    async fn find_by_external_issue(
+        &self,
+        board_id: Uuid,
+        external_issue_key: &str,
+    ) -> ReportServiceResult<Option<Report>> {
+        Ok(self
+            .report_repository
+            .find_by_external_issue_key(board_id, external_issue_key)
+            .await?)
+    }
+
+    #[instrument(skip(self, change, context), fields(report_id = %report_id, status = %change.status), level = "info")]
+    async fn change_status(
+        &self,
+        report_id: Uuid,
+        change: ReportStatusChange,
+        context: &AuditContext,
+    ) -> ReportServiceResult<Report> {
+        let source = change.source;
+        // Only for the audit log: the report history records the status actually replaced.
+        let previous_status = self
+            .report_repository
+            .find_by_id(report_id)
+            .await?
+            .map(|report| report.status);
+
+        let report = self
+            .report_repository
+            .update_status(report_id, change)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ReportServiceError::ReportNotFound {
+                    context: format!("Failed to change status of report with ID: {}", report_id),
+                },
+                e => ReportServiceError::RepositoryError(e),
+            })?;
+
+        self.audit_service
+            .record(
+                context,
+                AuditAction::ReportStatusChanged,
+                AuditTargetType::Report,
+                Some(report.id),
+                json!({
+                    "status": { "from": previous_status, "to": report.status },
+                    "source": source,
+                }),
+            )
+            .await;
+
+        tracing::info!("Report status changed");
+        self.event_publisher
+            .publish(DomainEvent::report(EventType::ReportStatusChanged, &report));
+
+        Ok(report)
+    }
+
+    #[instrument(skip(self), fields(report_id = %report_id), level = "debug")]
+    async fn get_report_history(
+        &self,
+        report_id: Uuid,
+    ) -> ReportServiceResult<Vec<ReportHistoryEntry>> {
+        Ok(self.report_repository.find_history(report_id).await?)
+    }
 }
@@ -0,0 +1,386 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::{
+        models::{
+            two_factor::{SecuritySettings, TwoFactorEnrollment, TwoFactorStatus},
+            user::{User, UserRole},
+        },
+        repositories::{
+            RepositoryError, recovery_code_repository::RecoveryCodeRepository,
+            settings_repository::SettingsRepository, user_repository::UserRepository,
+        },
+    },
+    infrastructure::security::{
+        recovery_codes::{generate_recovery_codes, hash_recovery_code},
+        totp::{self, TotpError},
+    },
+};
+
+const REQUIRE_ADMIN_TWO_FACTOR_KEY: &str = "security.require_admin_two_factor";
+
+#[derive(Debug, thiserror::Error)]
+pub enum TwoFactorServiceError {
+    #[error("User not found")]
+    UserNotFound,
+    #[error("Two-factor authentication is already enabled")]
+    AlreadyEnabled,
+    #[error("Two-factor authentication is not enabled")]
+    NotEnabled,
+    #[error("No two-factor enrollment in progress")]
+    EnrollmentNotStarted,
+    #[error("Invalid two-factor code")]
+    InvalidCode,
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<RepositoryError> for TwoFactorServiceError {
+    fn from(err: RepositoryError) -> Self {
+        match err {
+            RepositoryError::NotFound => TwoFactorServiceError::UserNotFound,
+            RepositoryError::AlreadyExists | RepositoryError::DatabaseError(_) => {
+                TwoFactorServiceError::InternalError(err.to_string())
+            }
+        }
+    }
+}
+
+impl From<TotpError> for TwoFactorServiceError {
+    fn from(err: TotpError) -> Self {
+        TwoFactorServiceError::InternalError(err.to_string())
+    }
+}
+
+pub type TwoFactorServiceResult<T> = Result<T, TwoFactorServiceError>;
+
+#[async_trait]
+pub trait TwoFactorServiceInterface: Send + Sync {
+    async fn get_status(&self, user_id: Uuid) -> TwoFactorServiceResult<TwoFactorStatus>;
+
+    /// Generates a new secret for the user. It only takes effect once confirmed with a valid code.
+    async fn begin_enrollment(&self, user_id: Uuid) -> TwoFactorServiceResult<TwoFactorEnrollment>;
+
+    /// Enables two-factor authentication and returns the plaintext recovery codes.
+    async fn confirm_enrollment(
+        &self,
+        user_id: Uuid,
+        code: &str,
+    ) -> TwoFactorServiceResult<Vec<String>>;
+
+    async fn disable(&self, user_id: Uuid, code: &str) -> TwoFactorServiceResult<()>;
+
+    async fn regenerate_recovery_codes(
+        &self,
+        user_id: Uuid,
+        code: &str,
+    ) -> TwoFactorServiceResult<Vec<String>>;
+
+    /// Checks a TOTP code, or consumes a recovery code, for a user with two-factor enabled.
+    async fn verify_code(&self, user: &User, code: &str) -> TwoFactorServiceResult<()>;
+
+    /// Whether the user must enroll before being allowed to use admin features.
+    async fn is_enrollment_required(&self, user: &User) -> TwoFactorServiceResult<bool>;
+
+    async fn get_security_settings(&self) -> TwoFactorServiceResult<SecuritySettings>;
+
+    async fn update_security_settings(
+        &self,
+        admin: &User,
+        settings: SecuritySettings,
+    ) -> TwoFactorServiceResult<SecuritySettings>;
+}
+
+pub struct TwoFactorService {
+    user_repository: Arc<dyn UserRepository>,
+    recovery_code_repository: Arc<dyn RecoveryCodeRepository>,
+    settings_repository: Arc<dyn SettingsRepository>,
+}
+
+impl TwoFactorService {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        recovery_code_repository: Arc<dyn RecoveryCodeRepository>,
+        settings_repository: Arc<dyn SettingsRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            recovery_code_repository,
+            settings_repository,
+        }
+    }
+
+    async fn get_user(&self, user_id: Uuid) -> TwoFactorServiceResult<User> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or(TwoFactorServiceError::UserNotFound)
+    }
+
+    /// Checks the code against the TOTP secret of the user. Each code is accepted once: a code
+    /// of the same or an earlier time step than the last accepted one is refused, so that a code
+    /// seen while typed cannot be replayed.
+    async fn check_totp(&self, user: &User, code: &str) -> TwoFactorServiceResult<bool> {
+        let secret = user
+            .totp_secret
+            .as_deref()
+            .ok_or(TwoFactorServiceError::EnrollmentNotStarted)?;
+
+        let Some(time_step) =
+            totp::verify_code(secret, &APP_CONFIG.totp_issuer, &user.email, code)?
+        else {
+            return Ok(false);
+        };
+
+        if !self
+            .user_repository
+            .record_totp_time_step(user.id, time_step as i64)
+            .await?
+        {
+            tracing::warn!(user_id = %user.id, "TOTP code already used.");
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    async fn ensure_enabled_with_valid_totp(
+        &self,
+        user: &User,
+        code: &str,
+    ) -> TwoFactorServiceResult<()> {
+        if !user.two_factor_enabled {
+            return Err(TwoFactorServiceError::NotEnabled);
+        }
+
+        if self.check_totp(user, code).await? {
+            Ok(())
+        } else {
+            tracing::warn!(user_id = %user.id, "Invalid TOTP code.");
+            Err(TwoFactorServiceError::InvalidCode)
+        }
+    }
+
+    async fn issue_recovery_codes(&self, user_id: Uuid) -> TwoFactorServiceResult<Vec<String>> {
+        let codes = generate_recovery_codes();
+        let code_hashes = codes.iter().map(|code| hash_recovery_code(code)).collect();
+
+        self.recovery_code_repository
+            .replace_codes(user_id, code_hashes)
+            .await?;
+
+        Ok(codes)
+    }
+}
+
+#[async_trait]
+impl TwoFactorServiceInterface for TwoFactorService {
+    #[instrument(skip(self), fields(user_id = %user_id), level = "debug")]
+    async fn get_status(&self, user_id: Uuid) -> TwoFactorServiceResult<TwoFactorStatus> {
+        let user = self.get_user(user_id).await?;
+
+        let recovery_codes_remaining = if user.two_factor_enabled {
+            self.recovery_code_repository
+                .find_unused_by_user_id(user_id)
+                .await?
+                .len()
+        } else {
+            0
+        };
+
+        Ok(TwoFactorStatus {
+            enabled: user.two_factor_enabled,
+            recovery_codes_remaining,
+        })
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id), level = "info")]
+    async fn begin_enrollment(&self, user_id: Uuid) -> TwoFactorServiceResult<TwoFactorEnrollment> {
+        let user = self.get_user(user_id).await?;
+
+        if user.two_factor_enabled {
+            return Err(TwoFactorServiceError::AlreadyEnabled);
+        }
+
+        let secret = totp::generate_secret();
+        let provisioning_uri =
+            totp::provisioning_uri(&secret, &APP_CONFIG.totp_issuer, &user.email)?;
+
+        self.user_repository
+            .update_totp(user_id, Some(&secret), false)
+            .await?;
+
+        tracing::info!("Two-factor enrollment started.");
+        Ok(TwoFactorEnrollment {
+            secret,
+            provisioning_uri,
+        })
+    }
+
+    #[instrument(skip(self, code), fields(user_id = %user_id), level = "info")]
+    async fn confirm_enrollment(
+        &self,
+        user_id: Uuid,
+        code: &str,
+    ) -> TwoFactorServiceResult<Vec<String>> {
+        let user = self.get_user(user_id).await?;
+
+        if user.two_factor_enabled {
+            return Err(TwoFactorServiceError::AlreadyEnabled);
+        }
+
+        if !self.check_totp(&user, code).await? {
+            tracing::warn!("Invalid TOTP code during enrollment confirmation.");
+            return Err(TwoFactorServiceError::InvalidCode);
+        }
+
+        self.user_repository
+            .update_totp(user_id, user.totp_secret.as_deref(), true)
+            .await?;
+
+        let recovery_codes = self.issue_recovery_codes(user_id).await?;
+
+        tracing::info!("Two-factor authentication enabled.");
+        Ok(recovery_codes)
+    }
+
+    #[instrument(skip(self, code), fields(user_id = %user_id), level = "info")]
+    async fn disable(&self, user_id: Uuid, code: &str) -> TwoFactorServiceResult<()> {
+        let user = self.get_user(user_id).await?;
+        self.ensure_enabled_with_valid_totp(&user, code).await?;
+
+        if matches!(user.role, UserRole::Admin)
+            && self.get_security_settings().await?.require_admin_two_factor
+        {
+            return Err(TwoFactorServiceError::ValidationError(
+                "Two-factor authentication is required for admin accounts".to_string(),
+            ));
+        }
+
+        self.user_repository
+            .update_totp(user_id, None, false)
+            .await?;
+        self.recovery_code_repository
+            .delete_by_user_id(user_id)
+            .await?;
+
+        tracing::info!("Two-factor authentication disabled.");
+        Ok(())
+    }
+
+    #[instrument(skip(self, code), fields(user_id = %user_id), level = "info")]
+    async fn regenerate_recovery_codes(
+        &self,
+        user_id: Uuid,
+        code: &str,
+    ) -> TwoFactorServiceResult<Vec<String>> {
+        let user = self.get_user(user_id).await?;
+        self.ensure_enabled_with_valid_totp(&user, code).await?;
+
+        let recovery_codes = self.issue_recovery_codes(user_id).await?;
+
+        tracing::info!("Recovery codes regenerated.");
+        Ok(recovery_codes)
+    }
+
+    #[instrument(skip(self, user, code), fields(user_id = %user.id), level = "debug")]
+    async fn verify_code(&self, user: &User, code: &str) -> TwoFactorServiceResult<()> {
+        if !user.two_factor_enabled {
+            return Err(TwoFactorServiceError::NotEnabled);
+        }
+
+        if self.check_totp(user, code).await? {
+            tracing::debug!("TOTP code accepted.");
+            return Ok(());
+        }
+
+        let code_hash = hash_recovery_code(code);
+        let recovery_code = self
+            .recovery_code_repository
+            .find_unused_by_user_id(user.id)
+            .await?
+            .into_iter()
+            .find(|recovery_code| recovery_code.code_hash == code_hash);
+
+        match recovery_code {
+            Some(recovery_code) => {
+                match self
+                    .recovery_code_repository
+                    .mark_used(recovery_code.id)
+                    .await
+                {
+                    Ok(()) => {
+                        tracing::info!(recovery_code_id = %recovery_code.id, "Recovery code consumed.");
+                        Ok(())
+                    }
+                    Err(RepositoryError::NotFound) => {
+                        tracing::warn!(recovery_code_id = %recovery_code.id, "Recovery code already used.");
+                        Err(TwoFactorServiceError::InvalidCode)
+                    }
+                    Err(e) => Err(e.into()),
+                }
+            }
+            None => {
+                tracing::warn!("Invalid two-factor code.");
+                Err(TwoFactorServiceError::InvalidCode)
+            }
+        }
+    }
+
+    #[instrument(skip(self, user), fields(user_id = %user.id), level = "debug")]
+    async fn is_enrollment_required(&self, user: &User) -> TwoFactorServiceResult<bool> {
+        if user.two_factor_enabled || !matches!(user.role, UserRole::Admin) {
+            return Ok(false);
+        }
+
+        Ok(self.get_security_settings().await?.require_admin_two_factor)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn get_security_settings(&self) -> TwoFactorServiceResult<SecuritySettings> {
+        let require_admin_two_factor = self
+            .settings_repository
+            .get_value(REQUIRE_ADMIN_TWO_FACTOR_KEY)
+            .await?
+            .map(|value| value == "true")
+            .unwrap_or_default();
+
+        Ok(SecuritySettings {
+            require_admin_two_factor,
+        })
+    }
+
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id), level = "info")]
+    async fn update_security_settings(
+        &self,
+        admin: &User,
+        settings: SecuritySettings,
+    ) -> TwoFactorServiceResult<SecuritySettings> {
+        if settings.require_admin_two_factor && !admin.two_factor_enabled {
+            return Err(TwoFactorServiceError::ValidationError(
+                "Enable two-factor authentication on your own account before requiring it for admins"
+                    .to_string(),
+            ));
+        }
+
+        self.settings_repository
+            .set_value(
+                REQUIRE_ADMIN_TWO_FACTOR_KEY,
+                &settings.require_admin_two_factor.to_string(),
+            )
+            .await?;
+
+        tracing::info!(
+            require_admin_two_factor = settings.require_admin_two_factor,
+            "Security settings updated."
+        );
+        Ok(settings)
+    }
+}
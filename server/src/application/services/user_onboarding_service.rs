@@ -2,7 +2,10 @@ use super::{
     board_service::{BoardServiceError, BoardServiceInterface},
     user_service::{UserServiceError, UserServiceInterface},
 };
-use crate::domain::models::user::{User, UserRole};
+use crate::domain::models::{
+    audit::AuditContext,
+    user::{User, UserRole},
+};
 use async_trait::async_trait;
 use std::sync::Arc;
 use tracing::instrument;
@@ -36,6 +39,7 @@ pub trait UserOnboardingServiceInterface: Send + Sync {
         first_name: Option<&str>,
         last_name: Option<&str>,
         user_role: UserRole,
+        context: &AuditContext,
     ) -> UserOnboardingServiceResult<User>;
 }
 
@@ -48,7 +52,12 @@ impl From<UserServiceError> for UserOnboardingServiceError {
             }
             UserServiceError::UserAlreadyExists => UserOnboardingServiceError::UserAlreadyExists,
             UserServiceError::UserNotFound => UserOnboardingServiceError::UserNotFound,
-            UserServiceError::InternalError(msg) => UserOnboardingServiceError::InternalError(msg),
+            UserServiceError::OperationNotAllowed(msg) | UserServiceError::InternalError(msg) => {
+                UserOnboardingServiceError::InternalError(msg)
+            }
+            UserServiceError::InvalidCurrentPassword | UserServiceError::StorageError(_) => {
+                UserOnboardingServiceError::InternalError(err.to_string())
+            }
         }
     }
 }
@@ -89,7 +98,7 @@ impl UserOnboardingService {
 #[async_trait]
 impl UserOnboardingServiceInterface for UserOnboardingService {
     #[instrument(
-        skip(self, email, password),
+        skip(self, email, password, context),
         fields(email = %email, first_name = ?first_name, last_name = ?last_name),
         level = "info"
     )]
@@ -100,10 +109,11 @@ impl UserOnboardingServiceInterface for UserOnboardingService {
         first_name: Option<&str>,
         last_name: Option<&str>,
         user_role: UserRole,
+        context: &AuditContext,
     ) -> UserOnboardingServiceResult<User> {
         let user = self
             .user_service
-            .create_user(email, password, first_name, last_name, user_role)
+            .create_user(email, password, first_name, last_name, user_role, context)
             .await?;
 
         let board_name = format!(
@@ -112,7 +122,7 @@ impl UserOnboardingServiceInterface for UserOnboardingService {
         );
 
         self.board_service
-            .create_board(&board_name, Some("Default board"), user.id, true)
+            .create_board(&board_name, Some("Default board"), user.id, true, context)
             .await?;
 
         Ok(user)
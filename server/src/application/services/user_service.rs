@@ -1,18 +1,25 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use bytes::Bytes;
+use serde_json::json;
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::{
     domain::{
-        models::user::{User, UserRole},
+        models::{
+            audit::{AuditAction, AuditContext, AuditTargetType},
+            media::MediaType,
+            user::{UpdateProfileParams, User, UserDeletionStrategy, UserRole},
+        },
+        ports::storage_port::{StorageError, StoragePort},
         repositories::{RepositoryError, user_repository::UserRepository},
     },
-    infrastructure::security::password_hasher::{PasswordError, hash_password},
+    infrastructure::security::password_hasher::{PasswordError, hash_password, verify_password},
 };
 
-use super::board_service::BoardServiceError;
+use super::{audit_service::AuditServiceInterface, board_service::BoardServiceError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum UserServiceError {
@@ -22,6 +29,12 @@ pub enum UserServiceError {
     UserAlreadyExists,
     #[error("User not found")]
     UserNotFound,
+    #[error("Operation not allowed: {0}")]
+    OperationNotAllowed(String),
+    #[error("Current password is incorrect")]
+    InvalidCurrentPassword,
+    #[error("Storage error: {0}")]
+    StorageError(#[from] StorageError),
     #[error("Password hashing failed: {0}")]
     PasswordHashingError(String),
     #[error("Internal server error: {0}")]
@@ -78,26 +91,116 @@ pub trait UserServiceInterface: Send + Sync {
         first_name: Option<&str>,
         last_name: Option<&str>,
         user_role: UserRole,
+        context: &AuditContext,
     ) -> UserServiceResult<User>;
 
     async fn get_user_by_id(&self, user_id: Uuid) -> UserServiceResult<User>;
     async fn get_user_by_email(&self, email: &str) -> UserServiceResult<User>;
+
+    async fn update_profile(
+        &self,
+        user_id: Uuid,
+        params: UpdateProfileParams,
+    ) -> UserServiceResult<User>;
+
+    /// Stores a fresh hash of an already verified password, e.g. to move off bcrypt.
+    async fn rehash_password(&self, user_id: Uuid, password: &str) -> UserServiceResult<()>;
+
+    /// Replaces the password without knowing the current one, for operators.
+    async fn reset_password(&self, user_id: Uuid, new_password: &str) -> UserServiceResult<()>;
+
+    async fn update_avatar(
+        &self,
+        user_id: Uuid,
+        original_file_name: &str,
+        data: Bytes,
+    ) -> UserServiceResult<User>;
+
+    async fn remove_avatar(&self, user_id: Uuid) -> UserServiceResult<User>;
+
+    async fn list_users(
+        &self,
+        search: Option<&str>,
+        page: i32,
+        per_page: i32,
+    ) -> UserServiceResult<(Vec<User>, i32)>;
+
+    /// The actor of the context is the admin making the change, if any.
+    async fn update_user_role(
+        &self,
+        context: &AuditContext,
+        user_id: Uuid,
+        role: UserRole,
+    ) -> UserServiceResult<User>;
+
+    /// Deactivated users keep their data but are rejected on login and by the auth extractors.
+    async fn set_user_active(
+        &self,
+        context: &AuditContext,
+        user_id: Uuid,
+        is_active: bool,
+    ) -> UserServiceResult<User>;
+
+    async fn delete_user(
+        &self,
+        context: &AuditContext,
+        user_id: Uuid,
+        strategy: UserDeletionStrategy,
+    ) -> UserServiceResult<()>;
 }
 
 #[derive(Clone)]
 pub struct UserService {
     user_repository: Arc<dyn UserRepository>,
+    storage_port: Arc<dyn StoragePort>,
+    audit_service: Arc<dyn AuditServiceInterface>,
 }
 
 impl UserService {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
-        Self { user_repository }
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        storage_port: Arc<dyn StoragePort>,
+        audit_service: Arc<dyn AuditServiceInterface>,
+    ) -> Self {
+        Self {
+            user_repository,
+            storage_port,
+            audit_service,
+        }
+    }
+
+    /// Fetches a user an admin is about to manage, refusing to act on the admin's own account.
+    async fn get_managed_user(
+        &self,
+        context: &AuditContext,
+        user_id: Uuid,
+    ) -> UserServiceResult<User> {
+        if context.actor_id == Some(user_id) {
+            return Err(UserServiceError::OperationNotAllowed(
+                "Admins cannot change their own role, status or account".to_string(),
+            ));
+        }
+
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .filter(|user| user.deleted_at.is_none())
+            .ok_or(UserServiceError::UserNotFound)
+    }
+
+    /// Deletes an avatar no longer referenced by the user. Avatars stored elsewhere, or already
+    /// gone, are left alone.
+    async fn delete_avatar_file(&self, avatar_url: &str) {
+        match self.storage_port.delete_file(avatar_url).await {
+            Ok(()) | Err(StorageError::NotFound) => {}
+            Err(e) => tracing::warn!(avatar_url = %avatar_url, "Failed to delete an avatar: {}", e),
+        }
     }
 }
 
 #[async_trait]
 impl UserServiceInterface for UserService {
-    #[instrument(skip(self, password), fields(email = %email, first_name = ?first_name, last_name = ?last_name), level = "info")]
+    #[instrument(skip(self, password, context), fields(email = %email, first_name = ?first_name, last_name = ?last_name), level = "info")]
     async fn create_user(
         &self,
         email: &str,
@@ -105,6 +208,7 @@ impl UserServiceInterface for UserService {
         first_name: Option<&str>,
         last_name: Option<&str>,
         user_role: UserRole,
+        context: &AuditContext,
     ) -> UserServiceResult<User> {
         tracing::debug!("Validating user input.");
 
@@ -120,7 +224,7 @@ impl UserServiceInterface for UserService {
         }
 
         tracing::debug!("Hashing password.");
-        let password_hash = hash_password(password)?;
+        let password_hash = hash_password(password).await?;
 
         tracing::debug!("Attempting to save user to repository.");
         let user = self
@@ -129,6 +233,16 @@ impl UserServiceInterface for UserService {
             .await
             .map_err(UserServiceError::from)?;
 
+        self.audit_service
+            .record(
+                context,
+                AuditAction::UserCreated,
+                AuditTargetType::User,
+                Some(user.id),
+                json!({ "email": user.email, "role": user.role }),
+            )
+            .await;
+
         tracing::info!(user_id = %user.id, "User created successfully.");
         Ok(user)
     }
@@ -148,4 +262,389 @@ impl UserServiceInterface for UserService {
             .await?
             .ok_or(UserServiceError::UserNotFound)
     }
+
+    #[instrument(skip(self, params), fields(user_id = %user_id), level = "info")]
+    async fn update_profile(
+        &self,
+        user_id: Uuid,
+        params: UpdateProfileParams,
+    ) -> UserServiceResult<User> {
+        let user = self.get_user_by_id(user_id).await?;
+
+        let email = match params.email {
+            Some(email) if email != user.email => {
+                if email.is_empty() || !email.contains('@') {
+                    return Err(UserServiceError::ValidationError(
+                        "Invalid email format".to_string(),
+                    ));
+                }
+
+                let current_password = params.current_password.as_deref().ok_or_else(|| {
+                    UserServiceError::ValidationError(
+                        "Current password is required to change the email".to_string(),
+                    )
+                })?;
+                if !verify_password(current_password, &user.password_hash).await? {
+                    tracing::warn!("Current password verification failed on email change.");
+                    return Err(UserServiceError::InvalidCurrentPassword);
+                }
+
+                tracing::debug!("Email change authorized.");
+                email
+            }
+            _ => user.email,
+        };
+
+        let first_name = params.first_name.or(user.first_name);
+        let last_name = params.last_name.or(user.last_name);
+
+        let user = self
+            .user_repository
+            .update_profile(user_id, &email, first_name.as_deref(), last_name.as_deref())
+            .await?;
+
+        tracing::info!("Profile updated.");
+        Ok(user)
+    }
+
+    #[instrument(skip(self, password), fields(user_id = %user_id), level = "info")]
+    async fn rehash_password(&self, user_id: Uuid, password: &str) -> UserServiceResult<()> {
+        let password_hash = hash_password(password).await?;
+
+        self.user_repository
+            .update_password_hash(user_id, &password_hash)
+            .await?;
+
+        tracing::info!("Password hash upgraded.");
+        Ok(())
+    }
+
+    #[instrument(skip(self, new_password), fields(user_id = %user_id), level = "info")]
+    async fn reset_password(&self, user_id: Uuid, new_password: &str) -> UserServiceResult<()> {
+        if new_password.len() < 8 {
+            return Err(UserServiceError::ValidationError(
+                "Password must be at least 8 characters long".to_string(),
+            ));
+        }
+
+        let password_hash = hash_password(new_password).await?;
+        self.user_repository
+            .update_password_hash(user_id, &password_hash)
+            .await?;
+
+        tracing::info!("Password reset.");
+        Ok(())
+    }
+
+    #[instrument(skip(self, data), fields(user_id = %user_id, file_size = data.len()), level = "info")]
+    async fn update_avatar(
+        &self,
+        user_id: Uuid,
+        original_file_name: &str,
+        data: Bytes,
+    ) -> UserServiceResult<User> {
+        // Sniffed from the content, as the storage does: the file name is not trusted.
+        if MediaType::sniff(&data).is_none_or(|media_type| media_type.is_video()) {
+            return Err(UserServiceError::ValidationError(
+                "Avatar must be a PNG, JPEG, GIF or WebP image".to_string(),
+            ));
+        }
+
+        let previous_avatar_url = self.get_user_by_id(user_id).await?.avatar_url;
+        let avatar_url = self
+            .storage_port
+            .save_file(original_file_name, data)
+            .await?;
+
+        let user = match self
+            .user_repository
+            .update_avatar(user_id, Some(&avatar_url))
+            .await
+        {
+            Ok(user) => user,
+            Err(e) => {
+                self.delete_avatar_file(&avatar_url).await;
+                return Err(e.into());
+            }
+        };
+        if let Some(previous_avatar_url) = previous_avatar_url {
+            self.delete_avatar_file(&previous_avatar_url).await;
+        }
+
+        tracing::info!("Avatar updated.");
+        Ok(user)
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id), level = "info")]
+    async fn remove_avatar(&self, user_id: Uuid) -> UserServiceResult<User> {
+        let previous_avatar_url = self.get_user_by_id(user_id).await?.avatar_url;
+        let user = self.user_repository.update_avatar(user_id, None).await?;
+        if let Some(previous_avatar_url) = previous_avatar_url {
+            self.delete_avatar_file(&previous_avatar_url).await;
+        }
+
+        tracing::info!("Avatar removed.");
+        Ok(user)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn list_users(
+        &self,
+        search: Option<&str>,
+        page: i32,
+        per_page: i32,
+    ) -> UserServiceResult<(Vec<User>, i32)> {
+        let users = self
+            .user_repository
+            .find_paginated(search, page, per_page)
+            .await?;
+        let total_items = self.user_repository.count(search).await?;
+
+        Ok((users, total_items))
+    }
+
+    #[instrument(skip(self, context), fields(actor_id = ?context.actor_id, role = %role), level = "info")]
+    async fn update_user_role(
+        &self,
+        context: &AuditContext,
+        user_id: Uuid,
+        role: UserRole,
+    ) -> UserServiceResult<User> {
+        let previous = self.get_managed_user(context, user_id).await?;
+
+        let user = self.user_repository.update_role(user_id, role).await?;
+
+        self.audit_service
+            .record(
+                context,
+                AuditAction::UserRoleChanged,
+                AuditTargetType::User,
+                Some(user.id),
+                json!({ "role": { "from": previous.role, "to": user.role } }),
+            )
+            .await;
+
+        tracing::info!(user_id = %user.id, "User role updated.");
+        Ok(user)
+    }
+
+    #[instrument(skip(self, context), fields(actor_id = ?context.actor_id), level = "info")]
+    async fn set_user_active(
+        &self,
+        context: &AuditContext,
+        user_id: Uuid,
+        is_active: bool,
+    ) -> UserServiceResult<User> {
+        let previous = self.get_managed_user(context, user_id).await?;
+
+        let user = self
+            .user_repository
+            .update_active(user_id, is_active)
+            .await?;
+
+        let action = if is_active {
+            AuditAction::UserReactivated
+        } else {
+            AuditAction::UserDeactivated
+        };
+        self.audit_service
+            .record(
+                context,
+                action,
+                AuditTargetType::User,
+                Some(user.id),
+                json!({ "is_active": { "from": previous.is_active, "to": user.is_active } }),
+            )
+            .await;
+
+        tracing::info!(user_id = %user.id, "User active status updated.");
+        Ok(user)
+    }
+
+    #[instrument(skip(self, context), fields(actor_id = ?context.actor_id), level = "info")]
+    async fn delete_user(
+        &self,
+        context: &AuditContext,
+        user_id: Uuid,
+        strategy: UserDeletionStrategy,
+    ) -> UserServiceResult<()> {
+        let user = self.get_managed_user(context, user_id).await?;
+
+        match strategy {
+            UserDeletionStrategy::Reassign { new_owner_id } => {
+                if new_owner_id == user_id {
+                    return Err(UserServiceError::ValidationError(
+                        "Content cannot be reassigned to the user being deleted".to_string(),
+                    ));
+                }
+
+                let new_owner = self
+                    .user_repository
+                    .find_by_id(new_owner_id)
+                    .await?
+                    .filter(|user| user.deleted_at.is_none())
+                    .ok_or_else(|| {
+                        UserServiceError::ValidationError(
+                            "The user to reassign content to does not exist".to_string(),
+                        )
+                    })?;
+
+                self.user_repository
+                    .delete_reassigning_content(user_id, new_owner.id)
+                    .await?;
+
+                self.audit_service
+                    .record(
+                        context,
+                        AuditAction::UserDeleted,
+                        AuditTargetType::User,
+                        Some(user_id),
+                        json!({ "email": user.email, "strategy": "reassign", "new_owner_id": new_owner.id }),
+                    )
+                    .await;
+
+                tracing::info!(new_owner_id = %new_owner.id, "User deleted and content reassigned.");
+            }
+            UserDeletionStrategy::Anonymize => {
+                self.user_repository.anonymize(user_id).await?;
+
+                // The email is not kept: anonymization removes the personal data.
+                self.audit_service
+                    .record(
+                        context,
+                        AuditAction::UserDeleted,
+                        AuditTargetType::User,
+                        Some(user_id),
+                        json!({ "strategy": "anonymize" }),
+                    )
+                    .await;
+
+                tracing::info!("User anonymized.");
+            }
+        }
+
+        if let Some(avatar_url) = &user.avatar_url {
+            self.delete_avatar_file(avatar_url).await;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use super::*;
+    use crate::{
+        application::services::audit_service::AuditService,
+        infrastructure::{
+            database::sqlite::MIGRATOR,
+            repositories::{
+                audit_event_repository::SqliteAuditEventRepository,
+                user_repository::SqliteUserRepository,
+            },
+            storage::file_system_storage::FileSystemStorage,
+        },
+    };
+
+    const BASE_URL: &str = "/uploads";
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    /// User service over an in-memory database, storing files in a fresh directory.
+    async fn user_service() -> (UserService, PathBuf) {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        MIGRATOR.run(&pool).await.unwrap();
+
+        let upload_directory = std::env::temp_dir().join(format!("rebug-test-{}", Uuid::new_v4()));
+        let storage = FileSystemStorage::new(
+            upload_directory.to_string_lossy().into_owned(),
+            BASE_URL.to_string(),
+        )
+        .unwrap();
+
+        let service = UserService::new(
+            Arc::new(SqliteUserRepository::new(pool.clone())),
+            Arc::new(storage),
+            Arc::new(AuditService::new(Arc::new(
+                SqliteAuditEventRepository::new(pool),
+            ))),
+        );
+        (service, upload_directory)
+    }
+
+    /// Creates a user with an avatar, returning the path of the avatar file.
+    async fn user_with_avatar(
+        service: &UserService,
+        upload_directory: &Path,
+        email: &str,
+    ) -> (User, PathBuf) {
+        let user = service
+            .create_user(
+                email,
+                "a long enough password",
+                Some("Jane"),
+                None,
+                UserRole::User,
+                &AuditContext::system(),
+            )
+            .await
+            .unwrap();
+        let user = service
+            .update_avatar(user.id, "avatar.png", Bytes::from_static(PNG))
+            .await
+            .unwrap();
+
+        let avatar_url = user.avatar_url.as_deref().unwrap();
+        let avatar_path = upload_directory.join(
+            avatar_url
+                .strip_prefix(BASE_URL)
+                .unwrap()
+                .trim_start_matches('/'),
+        );
+        assert!(avatar_path.exists());
+
+        (user, avatar_path)
+    }
+
+    #[tokio::test]
+    async fn deleting_a_user_deletes_their_avatar() {
+        let (service, upload_directory) = user_service().await;
+        let (anonymized, anonymized_avatar) =
+            user_with_avatar(&service, &upload_directory, "anonymized@example.com").await;
+        let (deleted, deleted_avatar) =
+            user_with_avatar(&service, &upload_directory, "deleted@example.com").await;
+        let (new_owner, new_owner_avatar) =
+            user_with_avatar(&service, &upload_directory, "owner@example.com").await;
+
+        service
+            .delete_user(
+                &AuditContext::system(),
+                anonymized.id,
+                UserDeletionStrategy::Anonymize,
+            )
+            .await
+            .unwrap();
+        service
+            .delete_user(
+                &AuditContext::system(),
+                deleted.id,
+                UserDeletionStrategy::Reassign {
+                    new_owner_id: new_owner.id,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(!anonymized_avatar.exists());
+        assert!(!deleted_avatar.exists());
+        assert!(new_owner_avatar.exists());
+        let _ = std::fs::remove_dir_all(upload_directory);
+    }
 }
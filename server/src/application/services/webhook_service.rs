@@ -0,0 +1,526 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::app_config::APP_CONFIG,
+    domain::{
+        models::{
+            event::{DomainEvent, EventType},
+            webhook::{
+                UpdateWebhookParams, Webhook, WebhookDelivery, WebhookDeliveryAttempt,
+                WebhookDeliveryStatus,
+            },
+        },
+        ports::{
+            event_publisher_port::EventSubscriber,
+            webhook_sender_port::{WebhookRequest, WebhookSenderPort},
+        },
+        repositories::{
+            RepositoryError, webhook_delivery_repository::WebhookDeliveryRepository,
+            webhook_repository::WebhookRepository,
+        },
+    },
+    infrastructure::security::{
+        public_addresses::{PublicAddressError, check_public_url},
+        webhook_signature::{generate_webhook_secret, sign_payload},
+    },
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookServiceError {
+    #[error("Webhook not found")]
+    WebhookNotFound,
+    #[error("Webhook delivery not found")]
+    DeliveryNotFound,
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Internal server error: {0}")]
+    InternalError(String),
+}
+
+impl From<RepositoryError> for WebhookServiceError {
+    fn from(err: RepositoryError) -> Self {
+        match err {
+            RepositoryError::NotFound => WebhookServiceError::WebhookNotFound,
+            RepositoryError::AlreadyExists | RepositoryError::DatabaseError(_) => {
+                WebhookServiceError::InternalError(err.to_string())
+            }
+        }
+    }
+}
+
+pub type WebhookServiceResult<T> = Result<T, WebhookServiceError>;
+
+/// Most deliveries claimed by the retry worker at once.
+const DUE_DELIVERIES_BATCH_SIZE: i32 = 100;
+
+/// Time given to an attempt, on top of the request timeout, before the delivery is considered
+/// abandoned, e.g. by a server that stopped, and attempted again.
+const ATTEMPT_LEASE_MARGIN_SECONDS: i64 = 60;
+
+#[async_trait]
+pub trait WebhookServiceInterface: Send + Sync {
+    /// Registers a webhook on the board. A secret is generated when none is given.
+    async fn create_webhook(
+        &self,
+        board_id: Uuid,
+        created_by: Uuid,
+        url: &str,
+        secret: Option<String>,
+        event_types: Vec<EventType>,
+    ) -> WebhookServiceResult<Webhook>;
+
+    async fn list_webhooks(&self, board_id: Uuid) -> WebhookServiceResult<Vec<Webhook>>;
+    async fn get_webhook(&self, board_id: Uuid, webhook_id: Uuid) -> WebhookServiceResult<Webhook>;
+
+    async fn update_webhook(
+        &self,
+        board_id: Uuid,
+        webhook_id: Uuid,
+        params: UpdateWebhookParams,
+    ) -> WebhookServiceResult<Webhook>;
+
+    async fn delete_webhook(&self, board_id: Uuid, webhook_id: Uuid) -> WebhookServiceResult<()>;
+
+    async fn get_deliveries_paginated(
+        &self,
+        board_id: Uuid,
+        webhook_id: Uuid,
+        page: i32,
+        per_page: i32,
+    ) -> WebhookServiceResult<(Vec<WebhookDelivery>, i32)>;
+
+    /// Sends the payload of a past delivery again, as a new delivery.
+    async fn redeliver(
+        &self,
+        board_id: Uuid,
+        webhook_id: Uuid,
+        delivery_id: Uuid,
+    ) -> WebhookServiceResult<WebhookDelivery>;
+
+    /// Starts the attempts of the pending deliveries that are due, returning how many were
+    /// started. Called periodically, including right after startup, so that retries survive a
+    /// restart.
+    async fn deliver_due_deliveries(&self) -> WebhookServiceResult<usize>;
+}
+
+#[derive(Clone)]
+pub struct WebhookService {
+    webhook_repository: Arc<dyn WebhookRepository>,
+    webhook_delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    webhook_sender: Arc<dyn WebhookSenderPort>,
+}
+
+impl WebhookService {
+    pub fn new(
+        webhook_repository: Arc<dyn WebhookRepository>,
+        webhook_delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+        webhook_sender: Arc<dyn WebhookSenderPort>,
+    ) -> Self {
+        Self {
+            webhook_repository,
+            webhook_delivery_repository,
+            webhook_sender,
+        }
+    }
+
+    /// The host must resolve to public addresses only, unless private networks are allowed. The
+    /// sender checks the addresses again on each request.
+    async fn validate_url(url: &str) -> WebhookServiceResult<()> {
+        check_public_url(url, APP_CONFIG.webhook_allow_private_networks)
+            .await
+            .map_err(|e| match e {
+                PublicAddressError::InvalidUrl => WebhookServiceError::ValidationError(
+                    "Webhook URL must be an absolute http(s) URL".to_string(),
+                ),
+                e => WebhookServiceError::ValidationError(format!("Webhook URL: {}", e)),
+            })
+    }
+
+    fn validate_event_types(event_types: &mut Vec<EventType>) -> WebhookServiceResult<()> {
+        event_types.sort_by_key(EventType::as_str);
+        event_types.dedup();
+
+        if event_types.is_empty() {
+            return Err(WebhookServiceError::ValidationError(
+                "At least one event type is required".to_string(),
+            ));
+        }
+        if let Some(event_type) = event_types
+            .iter()
+            .find(|event_type| !event_type.is_webhook_event())
+        {
+            return Err(WebhookServiceError::ValidationError(format!(
+                "Webhooks cannot subscribe to {}",
+                event_type
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn validate_secret(secret: &str) -> WebhookServiceResult<()> {
+        if secret.trim().is_empty() {
+            return Err(WebhookServiceError::ValidationError(
+                "Webhook secret cannot be empty".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Retry delay after the given (1-based) failed attempt: the base delay, doubled each time.
+    fn retry_delay(attempt: i32) -> Duration {
+        let factor = 2u64.saturating_pow(attempt.saturating_sub(1) as u32);
+        Duration::from_secs(
+            APP_CONFIG
+                .webhook_retry_base_delay_seconds
+                .saturating_mul(factor),
+        )
+    }
+
+    /// Until when an attempt starting now holds its delivery: a delivery still pending past it is
+    /// picked up again by the retry worker.
+    fn attempt_lease_until() -> DateTime<Utc> {
+        Utc::now()
+            + chrono::Duration::seconds(
+                APP_CONFIG.webhook_timeout_seconds as i64 + ATTEMPT_LEASE_MARGIN_SECONDS,
+            )
+    }
+
+    /// Attempts in the background, so the event source is never slowed down by a receiver.
+    fn spawn_attempt(&self, webhook: Webhook, delivery: WebhookDelivery) {
+        let service = self.clone();
+        tokio::spawn(async move { service.attempt(webhook, delivery).await });
+    }
+
+    /// Sends the delivery once. A failed attempt that is not the last one leaves the delivery
+    /// pending, with the time of the next attempt for the retry worker.
+    #[instrument(skip(self, webhook, delivery), fields(webhook_id = %webhook.id, delivery_id = %delivery.id), level = "debug")]
+    async fn attempt(&self, webhook: Webhook, delivery: WebhookDelivery) {
+        let max_attempts = APP_CONFIG.webhook_max_attempts.max(1);
+        let attempt = delivery.attempts + 1;
+
+        let timestamp = Utc::now().timestamp();
+        let request = WebhookRequest {
+            url: webhook.url.clone(),
+            delivery_id: delivery.id,
+            event_type: delivery.event_type,
+            timestamp,
+            signature: sign_payload(&webhook.secret, timestamp, &delivery.payload),
+            body: delivery.payload,
+        };
+
+        let (response_status, last_error) = match self.webhook_sender.send(request).await {
+            Ok(status) if (200..300).contains(&status) => (Some(status as i32), None),
+            Ok(status) => (
+                Some(status as i32),
+                Some(format!("Receiver responded with HTTP {}", status)),
+            ),
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        let (status, next_attempt_at) = if last_error.is_none() {
+            (WebhookDeliveryStatus::Succeeded, None)
+        } else if attempt < max_attempts {
+            let delay = chrono::Duration::from_std(Self::retry_delay(attempt))
+                .unwrap_or(chrono::Duration::MAX);
+            (
+                WebhookDeliveryStatus::Pending,
+                Some(
+                    Utc::now()
+                        .checked_add_signed(delay)
+                        .unwrap_or(DateTime::<Utc>::MAX_UTC),
+                ),
+            )
+        } else {
+            (WebhookDeliveryStatus::Failed, None)
+        };
+
+        if let Some(error) = &last_error {
+            tracing::warn!(attempt, "Webhook delivery attempt failed: {}", error);
+        }
+        if status != WebhookDeliveryStatus::Pending {
+            tracing::debug!(attempt, %status, "Webhook delivery finished");
+        }
+
+        self.record_attempt(
+            delivery.id,
+            WebhookDeliveryAttempt {
+                status,
+                attempts: attempt,
+                response_status,
+                last_error,
+                next_attempt_at,
+            },
+        )
+        .await;
+    }
+
+    async fn record_attempt(&self, delivery_id: Uuid, attempt: WebhookDeliveryAttempt) {
+        if let Err(e) = self
+            .webhook_delivery_repository
+            .record_attempt(delivery_id, attempt)
+            .await
+        {
+            tracing::error!(%delivery_id, "Failed to record webhook delivery attempt: {}", e);
+        }
+    }
+
+    async fn find_board_webhook(
+        &self,
+        board_id: Uuid,
+        webhook_id: Uuid,
+    ) -> WebhookServiceResult<Webhook> {
+        self.webhook_repository
+            .find_by_id(webhook_id)
+            .await?
+            .filter(|webhook| webhook.board_id == board_id)
+            .ok_or(WebhookServiceError::WebhookNotFound)
+    }
+}
+
+#[async_trait]
+impl WebhookServiceInterface for WebhookService {
+    #[instrument(skip(self, secret, event_types), fields(board_id = %board_id, created_by = %created_by), level = "info")]
+    async fn create_webhook(
+        &self,
+        board_id: Uuid,
+        created_by: Uuid,
+        url: &str,
+        secret: Option<String>,
+        mut event_types: Vec<EventType>,
+    ) -> WebhookServiceResult<Webhook> {
+        Self::validate_url(url).await?;
+        Self::validate_event_types(&mut event_types)?;
+
+        let secret = match secret {
+            Some(secret) => {
+                Self::validate_secret(&secret)?;
+                secret
+            }
+            None => generate_webhook_secret(),
+        };
+
+        let webhook = self
+            .webhook_repository
+            .create_webhook(board_id, url, &secret, &event_types, created_by)
+            .await?;
+
+        tracing::info!(webhook_id = %webhook.id, "Webhook created.");
+        Ok(webhook)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id), level = "debug")]
+    async fn list_webhooks(&self, board_id: Uuid) -> WebhookServiceResult<Vec<Webhook>> {
+        Ok(self.webhook_repository.find_by_board_id(board_id).await?)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id, webhook_id = %webhook_id), level = "debug")]
+    async fn get_webhook(&self, board_id: Uuid, webhook_id: Uuid) -> WebhookServiceResult<Webhook> {
+        self.find_board_webhook(board_id, webhook_id).await
+    }
+
+    #[instrument(skip(self, params), fields(board_id = %board_id, webhook_id = %webhook_id), level = "info")]
+    async fn update_webhook(
+        &self,
+        board_id: Uuid,
+        webhook_id: Uuid,
+        mut params: UpdateWebhookParams,
+    ) -> WebhookServiceResult<Webhook> {
+        self.find_board_webhook(board_id, webhook_id).await?;
+
+        if let Some(url) = &params.url {
+            Self::validate_url(url).await?;
+        }
+        if let Some(secret) = &params.secret {
+            Self::validate_secret(secret)?;
+        }
+        if let Some(event_types) = params.event_types.as_mut() {
+            Self::validate_event_types(event_types)?;
+        }
+
+        let webhook = self
+            .webhook_repository
+            .update_webhook(webhook_id, params)
+            .await?;
+
+        tracing::info!("Webhook updated.");
+        Ok(webhook)
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id, webhook_id = %webhook_id), level = "info")]
+    async fn delete_webhook(&self, board_id: Uuid, webhook_id: Uuid) -> WebhookServiceResult<()> {
+        self.find_board_webhook(board_id, webhook_id).await?;
+        self.webhook_repository.delete_webhook(webhook_id).await?;
+
+        tracing::info!("Webhook deleted.");
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id, webhook_id = %webhook_id, page = %page, per_page = %per_page), level = "debug")]
+    async fn get_deliveries_paginated(
+        &self,
+        board_id: Uuid,
+        webhook_id: Uuid,
+        page: i32,
+        per_page: i32,
+    ) -> WebhookServiceResult<(Vec<WebhookDelivery>, i32)> {
+        self.find_board_webhook(board_id, webhook_id).await?;
+
+        let deliveries = self
+            .webhook_delivery_repository
+            .find_by_webhook_id_paginated(webhook_id, page, per_page)
+            .await?;
+        let total_items = self
+            .webhook_delivery_repository
+            .count_by_webhook_id(webhook_id)
+            .await?;
+
+        Ok((deliveries, total_items))
+    }
+
+    #[instrument(skip(self), fields(board_id = %board_id, webhook_id = %webhook_id, delivery_id = %delivery_id), level = "info")]
+    async fn redeliver(
+        &self,
+        board_id: Uuid,
+        webhook_id: Uuid,
+        delivery_id: Uuid,
+    ) -> WebhookServiceResult<WebhookDelivery> {
+        let webhook = self.find_board_webhook(board_id, webhook_id).await?;
+
+        let previous_delivery = self
+            .webhook_delivery_repository
+            .find_by_id(delivery_id)
+            .await?
+            .filter(|delivery| delivery.webhook_id == webhook_id)
+            .ok_or(WebhookServiceError::DeliveryNotFound)?;
+
+        let delivery = self
+            .webhook_delivery_repository
+            .create_delivery(
+                webhook_id,
+                previous_delivery.event_id,
+                previous_delivery.event_type,
+                &previous_delivery.payload,
+                Self::attempt_lease_until(),
+            )
+            .await?;
+
+        tracing::info!(new_delivery_id = %delivery.id, "Webhook redelivery scheduled.");
+        self.spawn_attempt(webhook, delivery.clone());
+        Ok(delivery)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn deliver_due_deliveries(&self) -> WebhookServiceResult<usize> {
+        let deliveries = self
+            .webhook_delivery_repository
+            .claim_due_deliveries(
+                Utc::now(),
+                Self::attempt_lease_until(),
+                DUE_DELIVERIES_BATCH_SIZE,
+            )
+            .await?;
+        let count = deliveries.len();
+
+        for delivery in deliveries {
+            let webhook = match self
+                .webhook_repository
+                .find_by_id(delivery.webhook_id)
+                .await
+            {
+                Ok(webhook) => webhook.filter(|webhook| webhook.is_active),
+                Err(e) => {
+                    tracing::error!(delivery_id = %delivery.id, "Failed to load webhook for delivery: {}", e);
+                    continue;
+                }
+            };
+
+            match webhook {
+                Some(webhook) => self.spawn_attempt(webhook, delivery),
+                // Disabled webhooks get nothing more, not even the retries of earlier events.
+                None => {
+                    self.record_attempt(
+                        delivery.id,
+                        WebhookDeliveryAttempt {
+                            status: WebhookDeliveryStatus::Failed,
+                            attempts: delivery.attempts,
+                            response_status: delivery.response_status,
+                            last_error: Some("The webhook was disabled or deleted".to_string()),
+                            next_attempt_at: None,
+                        },
+                    )
+                    .await
+                }
+            }
+        }
+
+        if count > 0 {
+            tracing::debug!(count, "Started due webhook delivery attempts");
+        }
+        Ok(count)
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for WebhookService {
+    #[instrument(skip(self, event), fields(event_id = %event.id, event_type = %event.event_type, board_id = %event.board_id), level = "debug")]
+    async fn on_event(&self, event: &DomainEvent) {
+        if !event.event_type.is_webhook_event() {
+            return;
+        }
+
+        let webhooks = match self
+            .webhook_repository
+            .find_by_board_id(event.board_id)
+            .await
+        {
+            Ok(webhooks) => webhooks,
+            Err(e) => {
+                tracing::error!("Failed to load webhooks for event: {}", e);
+                return;
+            }
+        };
+
+        let subscribed_webhooks: Vec<Webhook> = webhooks
+            .into_iter()
+            .filter(|webhook| webhook.is_subscribed_to(event.event_type))
+            .collect();
+
+        if subscribed_webhooks.is_empty() {
+            return;
+        }
+
+        let payload = match serde_json::to_string(event) {
+            Ok(payload) => payload,
+            Err(e) => {
+                tracing::error!("Failed to serialize event payload: {}", e);
+                return;
+            }
+        };
+
+        for webhook in subscribed_webhooks {
+            match self
+                .webhook_delivery_repository
+                .create_delivery(
+                    webhook.id,
+                    event.id,
+                    event.event_type,
+                    &payload,
+                    Self::attempt_lease_until(),
+                )
+                .await
+            {
+                Ok(delivery) => self.spawn_attempt(webhook, delivery),
+                Err(e) => {
+                    tracing::error!(webhook_id = %webhook.id, "Failed to create webhook delivery: {}", e)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use crate::domain::models::user::UserRole;
+
+pub mod commands;
+
+pub const USAGE: &str = "\
+Usage: rebug [command]
+
+Commands:
+  serve                       Start the server (default), applying pending migrations
+  migrate                     Apply pending database migrations
+  create-user <email>         Create a user with their default board
+      [--first-name <name>] [--last-name <name>] [--admin] [--password <password>]
+  reset-password <email>      Set a new password for a user
+      [--password <password>]
+  promote <email>             Change the role of a user
+      [--role <admin|user>]   Defaults to admin
+  list-users [--search <text>]
+  backup [destination.db]     Copy the live database to a new file, or take a snapshot in
+                              the backup directory when no destination is given
+  import-board <archive.zip>  Import a board export archive
+      [--dry-run] [--preserve-ids] [--owner <email>]
+  check-config                Print the configuration and check the dependencies
+  help                        Print this message
+
+Passwords are prompted for when not given. The other commands leave the schema as is: after an
+upgrade, start the server or run `migrate` first.";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    #[error("{0}\n\n{USAGE}")]
+    InvalidArguments(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Serve,
+    Migrate,
+    CreateUser {
+        email: String,
+        first_name: Option<String>,
+        last_name: Option<String>,
+        role: UserRole,
+        password: Option<String>,
+    },
+    ResetPassword {
+        email: String,
+        password: Option<String>,
+    },
+    Promote {
+        email: String,
+        role: UserRole,
+    },
+    ListUsers {
+        search: Option<String>,
+    },
+    Backup {
+        destination: Option<PathBuf>,
+    },
+    ImportBoard {
+        archive: PathBuf,
+        dry_run: bool,
+        preserve_ids: bool,
+        owner_email: Option<String>,
+    },
+    CheckConfig,
+    Help,
+}
+
+impl Command {
+    /// Parses the arguments following the binary name.
+    pub fn parse(args: &[String]) -> Result<Self, CliError> {
+        let Some((name, args)) = args.split_first() else {
+            return Ok(Command::Serve);
+        };
+
+        match name.as_str() {
+            "serve" => {
+                Arguments::parse(args, &[], &[])?.positionals::<0>()?;
+                Ok(Command::Serve)
+            }
+            "migrate" => {
+                Arguments::parse(args, &[], &[])?.positionals::<0>()?;
+                Ok(Command::Migrate)
+            }
+            "create-user" => {
+                let mut parsed = Arguments::parse(
+                    args,
+                    &["--admin"],
+                    &["--first-name", "--last-name", "--password"],
+                )?;
+                let [email] = parsed.positionals()?;
+                Ok(Command::CreateUser {
+                    email,
+                    first_name: parsed.option("--first-name"),
+                    last_name: parsed.option("--last-name"),
+                    role: if parsed.flag("--admin") {
+                        UserRole::Admin
+                    } else {
+                        UserRole::User
+                    },
+                    password: parsed.option("--password"),
+                })
+            }
+            "reset-password" => {
+                let mut parsed = Arguments::parse(args, &[], &["--password"])?;
+                let [email] = parsed.positionals()?;
+                Ok(Command::ResetPassword {
+                    email,
+                    password: parsed.option("--password"),
+                })
+            }
+            "promote" => {
+                let mut parsed = Arguments::parse(args, &[], &["--role"])?;
+                let [email] = parsed.positionals()?;
+                let role = match parsed.option("--role") {
+                    Some(role) => role.parse().map_err(CliError::InvalidArguments)?,
+                    None => UserRole::Admin,
+                };
+                Ok(Command::Promote { email, role })
+            }
+            "list-users" => {
+                let mut parsed = Arguments::parse(args, &[], &["--search"])?;
+                parsed.positionals::<0>()?;
+                Ok(Command::ListUsers {
+                    search: parsed.option("--search"),
+                })
+            }
+            "backup" => {
+                let mut parsed = Arguments::parse(args, &[], &[])?;
+                let destination = if parsed.positionals.is_empty() {
+                    None
+                } else {
+                    let [destination] = parsed.positionals()?;
+                    Some(destination.into())
+                };
+                Ok(Command::Backup { destination })
+            }
+            "import-board" => {
+                let mut parsed =
+                    Arguments::parse(args, &["--dry-run", "--preserve-ids"], &["--owner"])?;
+                let [archive] = parsed.positionals()?;
+                Ok(Command::ImportBoard {
+                    archive: archive.into(),
+                    dry_run: parsed.flag("--dry-run"),
+                    preserve_ids: parsed.flag("--preserve-ids"),
+                    owner_email: parsed.option("--owner"),
+                })
+            }
+            "check-config" => {
+                Arguments::parse(args, &[], &[])?.positionals::<0>()?;
+                Ok(Command::CheckConfig)
+            }
+            "help" | "--help" | "-h" => Ok(Command::Help),
+            _ => Err(CliError::InvalidArguments(format!(
+                "Unknown command '{}'",
+                name
+            ))),
+        }
+    }
+
+    /// Only the server and `migrate` change the schema. The other commands, `check-config` in
+    /// particular, can run against a database without touching it.
+    pub fn runs_migrations(&self) -> bool {
+        matches!(self, Command::Serve | Command::Migrate)
+    }
+}
+
+/// Arguments of a command: positionals, `--flag`s and `--option value`s.
+struct Arguments {
+    positionals: Vec<String>,
+    flags: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl Arguments {
+    fn parse(args: &[String], flags: &[&str], options: &[&str]) -> Result<Self, CliError> {
+        let mut parsed = Self {
+            positionals: Vec::new(),
+            flags: Vec::new(),
+            options: HashMap::new(),
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if flags.contains(&arg.as_str()) {
+                parsed.flags.push(arg.clone());
+            } else if options.contains(&arg.as_str()) {
+                let value = args.next().ok_or_else(|| {
+                    CliError::InvalidArguments(format!("Missing value for {}", arg))
+                })?;
+                parsed.options.insert(arg.clone(), value.clone());
+            } else if arg.starts_with("--") {
+                return Err(CliError::InvalidArguments(format!(
+                    "Unknown option {}",
+                    arg
+                )));
+            } else {
+                parsed.positionals.push(arg.clone());
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    fn positionals<const N: usize>(&mut self) -> Result<[String; N], CliError> {
+        std::mem::take(&mut self.positionals)
+            .try_into()
+            .map_err(|positionals: Vec<String>| {
+                CliError::InvalidArguments(format!(
+                    "Expected {} argument(s), got {}",
+                    N,
+                    positionals.len()
+                ))
+            })
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+
+    fn option(&mut self, name: &str) -> Option<String> {
+        self.options.remove(name)
+    }
+}
@@ -1,33 +1,214 @@
-use std::env;
+use std::{
+    env, fs,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+};
 
 use config::{Config, ConfigError, Environment, File, FileFormat};
 use jsonwebtoken::{DecodingKey, EncodingKey};
 use once_cell::sync::Lazy;
+use reqwest::Url;
 use rust_embed::RustEmbed;
 use serde::Deserialize;
 
+const ENV_PREFIX: &str = "REBUG__";
+const DEVELOPMENT_RUN_MODE: &str = "development";
+/// Values shipped in `config/default.toml`, refused outside of development.
+const DEFAULT_JWT_SECRET: &str = "your-super-secure-and-long-secret-key-here";
+const DEFAULT_ADMIN_PASSWORD: &str = "administrator";
+/// HS256 keys shorter than the 256 bits of the hash are easier to brute force.
+const MIN_JWT_SECRET_LENGTH: usize = 32;
+
 #[derive(RustEmbed)]
 #[folder = "config/"]
 struct ConfigAssets;
 
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MailTransport {
+    Smtp,
+    Capture,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// Plain text connection, for local relays only.
+    None,
+    StartTls,
+    /// Implicit TLS, usually on port 465.
+    Tls,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SqliteJournalMode {
+    Delete,
+    Truncate,
+    Persist,
+    Memory,
+    Wal,
+    Off,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SqliteSynchronous {
+    Off,
+    Normal,
+    Full,
+    Extra,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    Sqlite,
+    Postgres,
+}
+
+impl DatabaseBackend {
+    /// Backend of a `sqlite:` or `postgres:` (also `postgresql:`) URL.
+    pub fn from_url(database_url: &str) -> Result<Self, ConfigError> {
+        let scheme = database_url
+            .split_once(':')
+            .map(|(scheme, _)| scheme.to_ascii_lowercase())
+            .unwrap_or_default();
+
+        match scheme.as_str() {
+            "sqlite" => Ok(DatabaseBackend::Sqlite),
+            "postgres" | "postgresql" => Ok(DatabaseBackend::Postgres),
+            _ => Err(ConfigError::Message(format!(
+                "Unsupported database_url scheme '{}': expected sqlite or postgres",
+                scheme
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct AppConfig {
+    pub bind_address: String,
     pub server_port: u16,
+    pub tls_cert_path: String,
+    pub tls_key_path: String,
+    pub tls_reload_interval_seconds: u64,
+    pub http_redirect_port: u16,
     pub max_body_size_mb: u64,
+    pub client_ip_header: String,
     pub database_url: String,
+    pub database_max_connections: u32,
+    pub sqlite_journal_mode: SqliteJournalMode,
+    pub sqlite_synchronous: SqliteSynchronous,
+    pub sqlite_busy_timeout_ms: u64,
+    pub sqlite_foreign_keys: bool,
     pub upload_directory: String,
     pub file_base_url: String,
+    pub public_base_url: String,
     pub jwt_secret: String,
     pub jwt_expiration_seconds: i64,
+    pub argon2_memory_cost_kib: u32,
+    pub argon2_time_cost: u32,
+    pub argon2_parallelism: u32,
+    pub totp_issuer: String,
+    pub two_factor_challenge_expiration_seconds: i64,
+    pub two_factor_max_attempts_per_challenge: u32,
+    pub two_factor_max_attempts_per_user: u32,
+    pub two_factor_attempt_window_seconds: u64,
+    pub stream_ticket_expiration_seconds: i64,
+    pub invitation_expiration_seconds: i64,
+    pub webhook_max_attempts: i32,
+    pub webhook_retry_base_delay_seconds: u64,
+    pub webhook_timeout_seconds: u64,
+    pub webhook_allow_private_networks: bool,
+    pub chat_message_template: String,
+    pub chat_timeout_seconds: u64,
+    pub issue_tracker_timeout_seconds: u64,
+    pub mail_transport: MailTransport,
+    pub mail_from: String,
+    pub mail_capture_directory: String,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_security: SmtpSecurity,
+    pub smtp_username: String,
+    pub smtp_password: String,
+    pub smtp_timeout_seconds: u64,
+    pub intake_rate_limit_max_submissions: u32,
+    pub intake_rate_limit_window_seconds: u64,
+    pub inbound_email_domain: String,
+    pub inbound_smtp_enabled: bool,
+    pub inbound_smtp_bind_address: String,
+    pub import_max_archive_size_mb: u64,
+    pub metrics_token: String,
+    pub health_check_timeout_seconds: u64,
+    pub health_min_free_disk_space_mb: u64,
+    pub backup_directory: String,
+    pub backup_interval_hours: u64,
+    pub backup_retention: usize,
+    pub backup_include_uploads: bool,
     pub default_admin_email: String,
     pub default_admin_password: String,
     pub default_admin_first_name: String,
     pub default_admin_last_name: String,
 }
 
+/// `RUN_MODE` environment variable, selecting the `config/<run mode>.toml` overrides.
+pub fn run_mode() -> String {
+    env::var("RUN_MODE").unwrap_or_else(|_| DEVELOPMENT_RUN_MODE.into())
+}
+
+/// Outside of development, unsafe settings stop the server instead of being tolerated.
+pub fn is_development_run_mode() -> bool {
+    run_mode() == DEVELOPMENT_RUN_MODE
+}
+
+/// Settings read from the file named by a `REBUG__<SETTING>_FILE` environment variable, e.g.
+/// `REBUG__JWT_SECRET_FILE=/run/secrets/jwt_secret`, which keeps secrets out of the environment.
+/// Trailing newlines of the file are ignored.
+fn file_overrides() -> Result<Vec<(String, String)>, ConfigError> {
+    let mut overrides = Vec::new();
+    for (name, path) in env::vars() {
+        let Some(setting) = name
+            .strip_prefix(ENV_PREFIX)
+            .and_then(|name| name.strip_suffix("_FILE"))
+        else {
+            continue;
+        };
+
+        let direct_name = format!("{}{}", ENV_PREFIX, setting);
+        if env::var_os(&direct_name).is_some() {
+            return Err(ConfigError::Message(format!(
+                "Both {} and {} are set, keep only one of them",
+                direct_name, name
+            )));
+        }
+
+        let value = fs::read_to_string(&path).map_err(|e| {
+            ConfigError::Message(format!("Failed to read {} from '{}': {}", name, path, e))
+        })?;
+        overrides.push((
+            setting.to_ascii_lowercase(),
+            value.trim_end_matches(['\r', '\n']).to_string(),
+        ));
+    }
+
+    Ok(overrides)
+}
+
+/// Checks the URL is an absolute http(s) URL with a host, which links built from it need.
+fn check_http_url(url: &str) -> Result<(), String> {
+    let url = Url::parse(url).map_err(|e| e.to_string())?;
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(format!("expected http or https, got {}", url.scheme()));
+    }
+    if url.host().is_none() {
+        return Err("missing host".into());
+    }
+
+    Ok(())
+}
+
 impl AppConfig {
     pub fn new() -> Result<Self, ConfigError> {
-        let run_mode = env::var("RUN_MODE").unwrap_or_else(|_| "development".into());
+        let run_mode = run_mode();
 
         // 1. Load the embedded default configuration file
         let default_config = ConfigAssets::get("default.toml").ok_or_else(|| {
@@ -39,7 +220,7 @@ impl AppConfig {
                 ConfigError::Message(format!("Failed to parse embedded config as UTF-8: {}", e))
             })?;
 
-        let s = Config::builder()
+        let mut builder = Config::builder()
             // Start with the embedded default config as the base
             .add_source(File::from_str(default_config_str, FileFormat::Toml))
             // Layer on optional external files for overrides.
@@ -47,10 +228,127 @@ impl AppConfig {
             .add_source(File::with_name(&format!("config/{}", run_mode)).required(false))
             .add_source(File::with_name("config/local.toml").required(false))
             // Layer on settings from environment variables (e.g., `REBUG_SERVER_PORT=8000`)
-            .add_source(Environment::with_prefix("rebug").separator("__"))
-            .build()?;
+            .add_source(Environment::with_prefix("rebug").separator("__"));
+        for (setting, value) in file_overrides()? {
+            builder = builder.set_override(setting, value)?;
+        }
+
+        let config: AppConfig = builder.build()?.try_deserialize()?;
+        DatabaseBackend::from_url(&config.database_url)?;
+        config.validate_server()?;
 
-        s.try_deserialize()
+        if run_mode != DEVELOPMENT_RUN_MODE {
+            let problems = config.production_problems();
+            if !problems.is_empty() {
+                return Err(ConfigError::Message(format!(
+                    "Unsafe configuration for the '{}' run mode:\n{}",
+                    run_mode,
+                    problems
+                        .iter()
+                        .map(|problem| format!("  - {}", problem))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                )));
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Settings that do for a developer machine but not for a real instance: a default secret or
+    /// malformed public URLs. Outside of the development run mode, any of them stops the server
+    /// at startup. Only the settings are looked at, nothing is written.
+    pub fn production_problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.jwt_secret == DEFAULT_JWT_SECRET {
+            problems.push(
+                "jwt_secret is the placeholder of config/default.toml: set REBUG__JWT_SECRET or \
+                 REBUG__JWT_SECRET_FILE to a random value"
+                    .to_string(),
+            );
+        } else if self.jwt_secret.len() < MIN_JWT_SECRET_LENGTH {
+            problems.push(format!(
+                "jwt_secret is {} bytes long, it needs at least {}",
+                self.jwt_secret.len(),
+                MIN_JWT_SECRET_LENGTH
+            ));
+        }
+        for (setting, url) in [
+            ("public_base_url", &self.public_base_url),
+            ("file_base_url", &self.file_base_url),
+        ] {
+            if let Err(reason) = check_http_url(url) {
+                problems.push(format!(
+                    "{} '{}' is not a valid URL: {}",
+                    setting, url, reason
+                ));
+            }
+        }
+
+        problems
+    }
+
+    /// The default admin password is only a problem while it would be used, when the default
+    /// admin is created on a server without any user yet.
+    pub fn default_admin_password_problem(&self) -> Option<String> {
+        (self.default_admin_password == DEFAULT_ADMIN_PASSWORD).then(|| {
+            "default_admin_password is the default 'administrator' and no user exists yet: set \
+             REBUG__DEFAULT_ADMIN_PASSWORD or REBUG__DEFAULT_ADMIN_PASSWORD_FILE"
+                .to_string()
+        })
+    }
+
+    fn validate_server(&self) -> Result<(), ConfigError> {
+        self.bind_address.parse::<IpAddr>().map_err(|_| {
+            ConfigError::Message(format!(
+                "Invalid bind_address '{}': expected an IP address such as 127.0.0.1 or 0.0.0.0",
+                self.bind_address
+            ))
+        })?;
+        if self.tls_cert_path.is_empty() != self.tls_key_path.is_empty() {
+            return Err(ConfigError::Message(
+                "tls_cert_path and tls_key_path must be set together".into(),
+            ));
+        }
+        if self.http_redirect_port != 0 {
+            if !self.tls_enabled() {
+                return Err(ConfigError::Message(
+                    "http_redirect_port requires tls_cert_path and tls_key_path".into(),
+                ));
+            }
+            if self.http_redirect_port == self.server_port {
+                return Err(ConfigError::Message(
+                    "http_redirect_port must differ from server_port".into(),
+                ));
+            }
+        }
+        if self.tls_reload_interval_seconds == 0 {
+            return Err(ConfigError::Message(
+                "tls_reload_interval_seconds must be at least 1".into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Address of the HTTP(S) server, checked when loading the configuration.
+    pub fn server_address(&self) -> SocketAddr {
+        let ip = self
+            .bind_address
+            .parse()
+            .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        SocketAddr::new(ip, self.server_port)
+    }
+
+    /// Whether the server speaks HTTPS, with the certificate and key files set.
+    pub fn tls_enabled(&self) -> bool {
+        !self.tls_cert_path.is_empty() && !self.tls_key_path.is_empty()
+    }
+
+    /// Backend selected by the scheme of `database_url`, checked when loading the configuration.
+    pub fn database_backend(&self) -> DatabaseBackend {
+        DatabaseBackend::from_url(&self.database_url).unwrap_or(DatabaseBackend::Sqlite)
     }
 }
 
@@ -76,3 +374,77 @@ impl Keys {
 }
 
 pub static JWT_KEYS: Lazy<Keys> = Lazy::new(|| Keys::new(APP_CONFIG.jwt_secret.as_bytes()));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+    /// Configuration of config/default.toml alone, whatever the environment of the tests.
+    fn default_config() -> AppConfig {
+        let default_config = ConfigAssets::get("default.toml").unwrap();
+        Config::builder()
+            .add_source(File::from_str(
+                std::str::from_utf8(&default_config.data).unwrap(),
+                FileFormat::Toml,
+            ))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap()
+    }
+
+    #[test]
+    fn accepts_a_configured_instance() {
+        let config = AppConfig {
+            jwt_secret: SECRET.to_string(),
+            public_base_url: "https://bugs.example.com".to_string(),
+            file_base_url: "https://bugs.example.com/uploads".to_string(),
+            ..default_config()
+        };
+
+        assert_eq!(config.production_problems(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn refuses_placeholder_and_short_jwt_secrets() {
+        let placeholder = default_config().production_problems();
+        assert_eq!(placeholder.len(), 1);
+        assert!(placeholder[0].starts_with("jwt_secret is the placeholder"));
+
+        let short = AppConfig {
+            jwt_secret: "too short".to_string(),
+            ..default_config()
+        }
+        .production_problems();
+        assert_eq!(short, ["jwt_secret is 9 bytes long, it needs at least 32"]);
+    }
+
+    #[test]
+    fn refuses_invalid_public_urls() {
+        let problems = AppConfig {
+            jwt_secret: SECRET.to_string(),
+            public_base_url: "bugs.example.com".to_string(),
+            file_base_url: "ftp://bugs.example.com/uploads".to_string(),
+            ..default_config()
+        }
+        .production_problems();
+
+        assert_eq!(problems.len(), 2);
+        assert!(problems[0].starts_with("public_base_url 'bugs.example.com'"));
+        assert!(problems[1].ends_with("expected http or https, got ftp"));
+        assert!(check_http_url("http://").is_err());
+    }
+
+    #[test]
+    fn refuses_the_default_admin_password() {
+        assert!(default_config().default_admin_password_problem().is_some());
+
+        let config = AppConfig {
+            default_admin_password: "a password of one's own".to_string(),
+            ..default_config()
+        };
+        assert_eq!(config.default_admin_password_problem(), None);
+    }
+}
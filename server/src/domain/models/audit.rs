@@ -0,0 +1,141 @@
+use std::{fmt, net::IpAddr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub enum AuditAction {
+    #[serde(rename = "auth.login")]
+    Login,
+    #[serde(rename = "auth.login_failed")]
+    LoginFailed,
+    #[serde(rename = "user.created")]
+    UserCreated,
+    #[serde(rename = "user.role_changed")]
+    UserRoleChanged,
+    #[serde(rename = "user.deactivated")]
+    UserDeactivated,
+    #[serde(rename = "user.reactivated")]
+    UserReactivated,
+    #[serde(rename = "user.deleted")]
+    UserDeleted,
+    #[serde(rename = "board.created")]
+    BoardCreated,
+    #[serde(rename = "board.imported")]
+    BoardImported,
+    #[serde(rename = "report.created")]
+    ReportCreated,
+    #[serde(rename = "report.status_changed")]
+    ReportStatusChanged,
+    #[serde(rename = "report.issue_linked")]
+    ReportIssueLinked,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Login => "auth.login",
+            AuditAction::LoginFailed => "auth.login_failed",
+            AuditAction::UserCreated => "user.created",
+            AuditAction::UserRoleChanged => "user.role_changed",
+            AuditAction::UserDeactivated => "user.deactivated",
+            AuditAction::UserReactivated => "user.reactivated",
+            AuditAction::UserDeleted => "user.deleted",
+            AuditAction::BoardCreated => "board.created",
+            AuditAction::BoardImported => "board.imported",
+            AuditAction::ReportCreated => "report.created",
+            AuditAction::ReportStatusChanged => "report.status_changed",
+            AuditAction::ReportIssueLinked => "report.issue_linked",
+        }
+    }
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub enum AuditTargetType {
+    User,
+    Board,
+    Report,
+}
+
+impl fmt::Display for AuditTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditTargetType::User => write!(f, "User"),
+            AuditTargetType::Board => write!(f, "Board"),
+            AuditTargetType::Report => write!(f, "Report"),
+        }
+    }
+}
+
+/// Who performed an action and where the request came from. Actions taken by the server itself,
+/// from the command line or on behalf of an anonymous visitor have no actor.
+#[derive(Debug, Clone, Default)]
+pub struct AuditContext {
+    pub actor_id: Option<Uuid>,
+    pub ip_address: Option<IpAddr>,
+    pub user_agent: Option<String>,
+}
+
+impl AuditContext {
+    /// Actions not triggered by a request: command line, startup and background jobs.
+    pub fn system() -> Self {
+        Self::default()
+    }
+
+    /// Same origin, performed by the given user.
+    pub fn with_actor(&self, actor_id: Uuid) -> Self {
+        Self {
+            actor_id: Some(actor_id),
+            ..self.clone()
+        }
+    }
+}
+
+/// Entry of the audit log. Entries are never updated nor deleted.
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub action: AuditAction,
+    pub target_type: AuditTargetType,
+    pub target_id: Option<Uuid>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    /// Changed fields as `{"field": {"from": .., "to": ..}}`, or details of the action.
+    pub changes: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateAuditEventParams {
+    pub actor_id: Option<Uuid>,
+    pub action: AuditAction,
+    pub target_type: AuditTargetType,
+    pub target_id: Option<Uuid>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub changes: serde_json::Value,
+}
+
+/// Criteria audit events must all match. `None` matches any value.
+#[derive(Debug, Clone, Default)]
+pub struct AuditEventFilter {
+    pub actor_id: Option<Uuid>,
+    pub action: Option<AuditAction>,
+    pub target_type: Option<AuditTargetType>,
+    pub target_id: Option<Uuid>,
+    /// Inclusive.
+    pub from: Option<DateTime<Utc>>,
+    /// Exclusive.
+    pub to: Option<DateTime<Utc>>,
+}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use uuid::Uuid;
 
+use super::user::User;
+
 #[derive(Debug, Serialize, Deserialize, Clone, TS)]
 #[ts(export)]
 pub struct TokenClaims {
@@ -11,3 +13,38 @@ pub struct TokenClaims {
     pub exp: usize,   // Expiration timestamp (seconds since Unix epoch)
     pub iat: usize,   // Issued at timestamp
 }
+
+/// Claims of the short-lived token issued when a login still needs a second factor.
+/// The audience keeps it from being accepted as an access token.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TwoFactorChallengeClaims {
+    pub sub: Uuid,
+    pub aud: String,
+    /// Identifies the challenge, to count the attempts made with it.
+    pub jti: Uuid,
+    pub exp: usize,
+    pub iat: usize,
+}
+
+/// Claims of the short-lived ticket that opens the live event stream, for clients that cannot
+/// send an `Authorization` header.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StreamTicketClaims {
+    pub sub: Uuid,
+    pub aud: String,
+    pub exp: usize,
+    pub iat: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum LoginOutcome {
+    Authenticated {
+        user: User,
+        access_token: String,
+        two_factor_setup_required: bool,
+    },
+    TwoFactorRequired {
+        user: User,
+        challenge_token: String,
+    },
+}
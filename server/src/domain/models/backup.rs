@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+
+/// Snapshot of the instance in the backup directory: a copy of the database, or a ZIP archive of
+/// the database and the uploaded files.
+#[derive(Debug, Clone)]
+pub struct Backup {
+    pub file_name: String,
+    pub size_bytes: u64,
+    pub created_at: DateTime<Utc>,
+    pub includes_uploads: bool,
+}
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{
+    board::Board,
+    report::{Report, ReportChangeSource, ReportHistoryEntry, ReportStatus, ReportType},
+};
+
+pub const BOARD_EXPORT_FORMAT: &str = "rebug-board-export";
+/// Bumped on breaking changes to the manifest, so that imports can tell what they read.
+pub const BOARD_EXPORT_VERSION: u32 = 1;
+pub const BOARD_EXPORT_MANIFEST_PATH: &str = "manifest.json";
+
+/// The `manifest.json` of a board export archive. Media files are stored next to it, at the
+/// paths given by each report. Users are referenced by email so that the archive can be
+/// imported on another instance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardExportManifest {
+    pub format: String,
+    pub version: u32,
+    pub exported_at: chrono::DateTime<chrono::Utc>,
+    pub board: ExportedBoard,
+    pub reports: Vec<ExportedReport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedBoard {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub owner_email: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedReport {
+    pub id: Uuid,
+    pub report_type: ReportType,
+    pub status: ReportStatus,
+    pub title: String,
+    pub description: Option<String>,
+    /// `None` for anonymous reports.
+    pub reporter_email: Option<String>,
+    pub contact_email: Option<String>,
+    pub url: Option<String>,
+    pub browser_name: Option<String>,
+    pub browser_version: Option<String>,
+    pub os_name: Option<String>,
+    pub external_issue_key: Option<String>,
+    pub external_issue_url: Option<String>,
+    /// Archive path of the media file, `None` when it could not be read at export time.
+    pub file: Option<String>,
+    pub thumbnail_file: Option<String>,
+    pub history: Vec<ExportedReportHistoryEntry>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedReportHistoryEntry {
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub source: ReportChangeSource,
+    pub actor_email: Option<String>,
+    pub details: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BoardImportOptions {
+    /// Owner of the imported board.
+    pub owner_id: Uuid,
+    /// Keeps the board and report IDs of the archive instead of generating new ones, e.g. to
+    /// restore a deleted board.
+    pub preserve_ids: bool,
+    /// Validates the archive and reports what would be imported, without writing anything.
+    pub dry_run: bool,
+}
+
+/// Everything an import writes to the database, at once.
+#[derive(Debug, Clone)]
+pub struct BoardImport {
+    pub board: Board,
+    pub reports: Vec<Report>,
+    pub history: Vec<ReportHistoryEntry>,
+}
+
+/// Outcome of an import, or what it would be for a dry run.
+#[derive(Debug, Clone)]
+pub struct BoardImportSummary {
+    pub dry_run: bool,
+    pub board_id: Uuid,
+    pub board_name: String,
+    pub report_count: usize,
+    pub media_file_count: usize,
+    pub history_entry_count: usize,
+    /// Emails of the archive matched to users of this instance.
+    pub matched_user_emails: Vec<String>,
+    /// Emails without a matching user: their reports are attributed to the anonymous reporter.
+    pub unmatched_user_emails: Vec<String>,
+    pub warnings: Vec<String>,
+}
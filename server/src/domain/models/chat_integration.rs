@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct ChatIntegration {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub webhook_url: String,
+    /// Falls back to the configured default template when `None`.
+    pub message_template: Option<String>,
+    pub is_active: bool,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Fields of a chat integration that can be changed. `None` leaves the value untouched.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateChatIntegrationParams {
+    pub webhook_url: Option<String>,
+    /// `Some(None)` goes back to the default template.
+    pub message_template: Option<Option<String>>,
+    pub is_active: Option<bool>,
+}
+
+/// Message in the incoming-webhook format shared by Slack and Mattermost.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatMessage {
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<ChatAttachment>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatAttachment {
+    pub fallback: String,
+    pub color: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    pub fields: Vec<ChatAttachmentField>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb_url: Option<String>,
+    pub footer: String,
+    /// Unix timestamp shown next to the footer.
+    pub ts: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatAttachmentField {
+    pub title: String,
+    pub value: String,
+    pub short: bool,
+}
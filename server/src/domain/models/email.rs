@@ -0,0 +1,42 @@
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Email with a plain text and an HTML alternative.
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+}
+
+/// Email received on a board inbound address, to be filed as a report.
+#[derive(Debug, Clone, Default)]
+pub struct InboundEmail {
+    /// Address from the `From` header, which the sender can forge.
+    pub sender: Option<String>,
+    pub subject: Option<String>,
+    /// Plain text body, or the HTML one stripped of its markup.
+    pub text_body: Option<String>,
+    /// Attachments in message order, those of forwarded messages included. Their content is not
+    /// checked yet.
+    pub attachments: Vec<EmailAttachment>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmailAttachment {
+    pub file_name: String,
+    pub data: Bytes,
+}
+
+/// Board email address. The full address is `<token>@<inbound_email_domain>`.
+#[derive(Debug, Clone)]
+pub struct InboundMailbox {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub token: String,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
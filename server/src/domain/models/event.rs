@@ -0,0 +1,89 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::{board::Board, report::Report};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, TS)]
+#[ts(export)]
+pub enum EventType {
+    #[serde(rename = "report.created")]
+    ReportCreated,
+    #[serde(rename = "report.updated")]
+    ReportUpdated,
+    #[serde(rename = "report.status_changed")]
+    ReportStatusChanged,
+    /// Only published inside the server. Webhooks belong to a board that already exists, so they
+    /// cannot subscribe to it.
+    #[serde(rename = "board.created")]
+    BoardCreated,
+}
+
+impl EventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventType::ReportCreated => "report.created",
+            EventType::ReportUpdated => "report.updated",
+            EventType::ReportStatusChanged => "report.status_changed",
+            EventType::BoardCreated => "board.created",
+        }
+    }
+
+    pub fn is_webhook_event(&self) -> bool {
+        !matches!(self, EventType::BoardCreated)
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for EventType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "report.created" => Ok(EventType::ReportCreated),
+            "report.updated" => Ok(EventType::ReportUpdated),
+            "report.status_changed" => Ok(EventType::ReportStatusChanged),
+            "board.created" => Ok(EventType::BoardCreated),
+            _ => Err(format!("Invalid event type: {}", s)),
+        }
+    }
+}
+
+/// Something that happened on a board, as published to in-process subscribers.
+#[derive(Debug, Clone, Serialize)]
+pub struct DomainEvent {
+    pub id: Uuid,
+    #[serde(rename = "event")]
+    pub event_type: EventType,
+    pub board_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    pub data: serde_json::Value,
+}
+
+impl DomainEvent {
+    fn new(event_type: EventType, board_id: Uuid, data: impl Serialize) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            event_type,
+            board_id,
+            occurred_at: Utc::now(),
+            data: serde_json::to_value(data).unwrap_or_default(),
+        }
+    }
+
+    pub fn report(event_type: EventType, report: &Report) -> Self {
+        Self::new(event_type, report.board_id, report)
+    }
+
+    pub fn board(event_type: EventType, board: &Board) -> Self {
+        Self::new(event_type, board.id, board)
+    }
+}
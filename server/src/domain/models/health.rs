@@ -1,5 +1,26 @@
+use serde::Serialize;
+use ts_rs::TS;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+/// Outcome of the check of a dependency, such as the database or the storage.
+#[derive(Debug, Clone)]
+pub struct ComponentHealth {
+    pub name: &'static str,
+    pub status: HealthStatus,
+    pub latency: std::time::Duration,
+    /// Details on success, such as the free disk space, or the reason of the failure.
+    pub message: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct HealthCheck {
-    pub status: String,
+    pub status: HealthStatus,
     pub message: String,
+    pub components: Vec<ComponentHealth>,
 }
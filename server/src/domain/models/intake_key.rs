@@ -0,0 +1,57 @@
+use std::net::IpAddr;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Public key of a feedback widget, accepting anonymous reports on a board from a few origins.
+#[derive(Debug, Clone)]
+pub struct IntakeKey {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub name: String,
+    pub public_key: String,
+    /// Origins such as `https://staging.example.com`, without trailing slash.
+    pub allowed_origins: Vec<String>,
+    pub is_active: bool,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl IntakeKey {
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.allowed_origins.contains(&normalize_origin(origin))
+    }
+}
+
+/// Origins are compared case-insensitively and without trailing slash.
+pub fn normalize_origin(origin: &str) -> String {
+    origin.trim().trim_end_matches('/').to_lowercase()
+}
+
+/// Fields of an intake key that can be changed. `None` leaves the value untouched.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateIntakeKeyParams {
+    pub name: Option<String>,
+    pub allowed_origins: Option<Vec<String>>,
+    pub is_active: Option<bool>,
+}
+
+/// Report sent by an anonymous visitor through a feedback widget.
+#[derive(Debug, Clone)]
+pub struct IntakeSubmission {
+    pub client_ip: IpAddr,
+    /// Hidden form field only bots fill in.
+    pub honeypot: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+    pub contact_email: Option<String>,
+    pub url: Option<String>,
+    pub browser_name: Option<String>,
+    pub browser_version: Option<String>,
+    pub os_name: Option<String>,
+    pub original_file_name: String,
+    pub file_data: Bytes,
+    pub thumbnail_data: Option<Bytes>,
+}
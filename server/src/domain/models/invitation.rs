@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::user::UserRole;
+
+#[derive(Debug, Clone)]
+pub struct Invitation {
+    pub id: Uuid,
+    pub email: String,
+    pub role: UserRole,
+    pub token_hash: String,
+    pub invited_by: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Invitation {
+    /// Whether the invitation can still be accepted.
+    pub fn is_pending(&self, now: DateTime<Utc>) -> bool {
+        self.accepted_at.is_none() && self.revoked_at.is_none() && self.expires_at > now
+    }
+}
+
+/// A freshly created invitation along with its plaintext token, which is not stored.
+#[derive(Debug, Clone)]
+pub struct IssuedInvitation {
+    pub invitation: Invitation,
+    pub token: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct AcceptInvitationParams {
+    pub token: String,
+    pub password: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+}
@@ -0,0 +1,111 @@
+use std::{collections::HashMap, fmt};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub enum IssueTrackerProvider {
+    GitHub,
+    GitLab,
+}
+
+impl IssueTrackerProvider {
+    pub fn default_api_base_url(&self) -> &'static str {
+        match self {
+            IssueTrackerProvider::GitHub => "https://api.github.com",
+            IssueTrackerProvider::GitLab => "https://gitlab.com/api/v4",
+        }
+    }
+}
+
+impl fmt::Display for IssueTrackerProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IssueTrackerProvider::GitHub => write!(f, "GitHub"),
+            IssueTrackerProvider::GitLab => write!(f, "GitLab"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IssueTrackerIntegration {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub provider: IssueTrackerProvider,
+    pub api_base_url: String,
+    /// `owner/repo` on GitHub, the project path (`group/project`) on GitLab.
+    pub repository: String,
+    pub access_token: String,
+    /// Secret the tracker signs (GitHub) or sends back (GitLab) with its webhooks.
+    pub webhook_secret: String,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl IssueTrackerIntegration {
+    /// Key under which an issue of this repository is stored on the report.
+    pub fn issue_key(&self, issue_number: u64) -> String {
+        format!("{}#{}", self.repository, issue_number)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigureIssueTrackerParams {
+    pub provider: IssueTrackerProvider,
+    /// Defaults to the public API of the provider, set it for self-hosted instances.
+    pub api_base_url: Option<String>,
+    pub repository: String,
+    pub access_token: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewIssue {
+    pub title: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreatedIssue {
+    pub number: u64,
+    pub url: String,
+}
+
+/// Webhook request received from an issue tracker, with lowercased header names.
+#[derive(Debug, Clone)]
+pub struct InboundWebhook {
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl InboundWebhook {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueAction {
+    Closed,
+    Reopened,
+}
+
+impl fmt::Display for IssueAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IssueAction::Closed => write!(f, "closed"),
+            IssueAction::Reopened => write!(f, "reopened"),
+        }
+    }
+}
+
+/// Issue state change reported by a tracker webhook.
+#[derive(Debug, Clone)]
+pub struct IssueEvent {
+    pub repository: String,
+    pub number: u64,
+    pub action: IssueAction,
+}
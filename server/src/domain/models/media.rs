@@ -0,0 +1,137 @@
+/// Media files accepted for uploads, recognized from their first bytes rather than from the file
+/// name sent by the client. Only raster images and videos are listed: formats that can carry
+/// scripts, such as SVG or HTML, are never stored since uploads are served from the app origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaType {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+    Mp4,
+    QuickTime,
+    Webm,
+}
+
+/// ISO base media brands of MP4 videos, as found after `ftyp`. Image brands such as `avif` and
+/// `heic` share the container and are left out.
+const MP4_BRANDS: [&[u8; 4]; 10] = [
+    b"isom", b"iso2", b"iso4", b"iso5", b"iso6", b"mp41", b"mp42", b"avc1", b"M4V ", b"dash",
+];
+
+impl MediaType {
+    /// Type of the file, when it is one of the accepted formats.
+    pub fn sniff(data: &[u8]) -> Option<Self> {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            return Some(MediaType::Png);
+        }
+        if data.starts_with(b"\xff\xd8\xff") {
+            return Some(MediaType::Jpeg);
+        }
+        if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            return Some(MediaType::Gif);
+        }
+        if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+            return Some(MediaType::Webp);
+        }
+        if data.starts_with(b"\x1a\x45\xdf\xa3") {
+            return Some(MediaType::Webm);
+        }
+        if data.len() >= 12 && &data[4..8] == b"ftyp" {
+            let brand = &data[8..12];
+            if brand == b"qt  " {
+                return Some(MediaType::QuickTime);
+            }
+            if MP4_BRANDS.iter().any(|mp4_brand| brand == *mp4_brand) {
+                return Some(MediaType::Mp4);
+            }
+        }
+
+        None
+    }
+
+    pub fn is_video(&self) -> bool {
+        matches!(
+            self,
+            MediaType::Mp4 | MediaType::QuickTime | MediaType::Webm
+        )
+    }
+
+    /// Extension of the stored file, which decides the content type it is served with.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            MediaType::Png => "png",
+            MediaType::Jpeg => "jpg",
+            MediaType::Gif => "gif",
+            MediaType::Webp => "webp",
+            MediaType::Mp4 => "mp4",
+            MediaType::QuickTime => "mov",
+            MediaType::Webm => "webm",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            MediaType::Png => "image/png",
+            MediaType::Jpeg => "image/jpeg",
+            MediaType::Gif => "image/gif",
+            MediaType::Webp => "image/webp",
+            MediaType::Mp4 => "video/mp4",
+            MediaType::QuickTime => "video/quicktime",
+            MediaType::Webm => "video/webm",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniffs_accepted_formats() {
+        let cases: [(&[u8], MediaType); 7] = [
+            (b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR", MediaType::Png),
+            (b"\xff\xd8\xff\xe0\0\x10JFIF", MediaType::Jpeg),
+            (b"GIF89a\x01\0\x01\0", MediaType::Gif),
+            (b"RIFF\x24\0\0\0WEBPVP8 ", MediaType::Webp),
+            (b"\0\0\0\x20ftypisom\0\0\x02\0", MediaType::Mp4),
+            (b"\0\0\0\x14ftypqt  \0\0\0\0", MediaType::QuickTime),
+            (b"\x1a\x45\xdf\xa3\x9f\x42\x86\x81", MediaType::Webm),
+        ];
+
+        for (data, expected) in cases {
+            assert_eq!(MediaType::sniff(data), Some(expected));
+        }
+    }
+
+    #[test]
+    fn rejects_scriptable_and_unknown_content() {
+        let cases: [&[u8]; 7] = [
+            b"<svg xmlns=\"http://www.w3.org/2000/svg\"><script>alert(1)</script></svg>",
+            b"<?xml version=\"1.0\"?><svg/>",
+            b"<!DOCTYPE html><html><script></script></html>",
+            b"%PDF-1.7",
+            b"\0\0\0\x1cftypavif\0\0\0\0",
+            b"",
+            b"RIFF\x24\0\0\0WAVE",
+        ];
+
+        for data in cases {
+            assert_eq!(MediaType::sniff(data), None, "{:?}", data);
+        }
+    }
+
+    #[test]
+    fn rejects_truncated_signatures() {
+        assert_eq!(MediaType::sniff(b"\x89PNG"), None);
+        assert_eq!(MediaType::sniff(b"RIFF\0\0\0\0WEB"), None);
+        assert_eq!(MediaType::sniff(b"\0\0\0\x20ftyp"), None);
+    }
+
+    #[test]
+    fn videos_are_told_apart_from_images() {
+        assert!(MediaType::Webm.is_video());
+        assert!(MediaType::Mp4.is_video());
+        assert!(!MediaType::Png.is_video());
+        assert_eq!(MediaType::Jpeg.extension(), "jpg");
+    }
+}
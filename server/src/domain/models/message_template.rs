@@ -0,0 +1,18 @@
+/// Replaces every `{{name}}` placeholder with its value. Unknown placeholders are left as is.
+pub fn render_template(template: &str, variables: &[(&str, &str)]) -> String {
+    variables
+        .iter()
+        .fold(template.to_string(), |rendered, (name, value)| {
+            rendered.replace(&format!("{{{{{}}}}}", name), value)
+        })
+}
+
+/// Escapes a value before it is rendered into an HTML template.
+pub fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
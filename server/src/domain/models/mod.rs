@@ -1,5 +1,19 @@
+pub mod audit;
 pub mod auth;
+pub mod backup;
 pub mod board;
+pub mod board_export;
+pub mod chat_integration;
+pub mod email;
+pub mod event;
 pub mod health;
+pub mod intake_key;
+pub mod invitation;
+pub mod issue_tracker;
+pub mod media;
+pub mod message_template;
+pub mod notification_preference;
 pub mod report;
+pub mod two_factor;
 pub mod user;
+pub mod webhook;
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationPreferences {
+    pub user_id: Uuid,
+    /// New reports on the boards the user owns.
+    pub email_on_new_report: bool,
+    /// Status changes of the reports the user filed.
+    pub email_on_status_change: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl NotificationPreferences {
+    /// Preferences of a user who never changed them: every notification is enabled.
+    pub fn default_for(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+            email_on_new_report: true,
+            email_on_status_change: true,
+            updated_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateNotificationPreferencesParams {
+    pub email_on_new_report: bool,
+    pub email_on_status_change: bool,
+}
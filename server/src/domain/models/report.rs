@@ -23,6 +23,22 @@ impl fmt::Display for ReportType {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub enum ReportStatus {
+    Open,
+    Resolved,
+}
+
+impl fmt::Display for ReportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportStatus::Open => write!(f, "Open"),
+            ReportStatus::Resolved => write!(f, "Resolved"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
 pub struct Report {
@@ -30,6 +46,7 @@ pub struct Report {
     pub user_id: Uuid,
     pub board_id: Uuid,
     pub report_type: ReportType,
+    pub status: ReportStatus,
     pub title: String,
     pub description: Option<String>,
     pub file_path: String,
@@ -38,6 +55,10 @@ pub struct Report {
     pub browser_name: Option<String>,
     pub browser_version: Option<String>,
     pub os_name: Option<String>,
+    /// Left by anonymous submitters who want to be contacted back.
+    pub contact_email: Option<String>,
+    pub external_issue_key: Option<String>,
+    pub external_issue_url: Option<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -55,6 +76,7 @@ pub struct CreateReportParams {
     pub browser_name: Option<String>,
     pub browser_version: Option<String>,
     pub os_name: Option<String>,
+    pub contact_email: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -68,7 +90,38 @@ pub struct CreateReportServiceParams {
     pub browser_name: Option<String>,
     pub browser_version: Option<String>,
     pub os_name: Option<String>,
+    pub contact_email: Option<String>,
     pub original_file_name: String,
     pub file_data: Bytes,
     pub thumbnail_data: Option<Bytes>,
 }
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub enum ReportChangeSource {
+    User,
+    IssueTracker,
+}
+
+/// One change made to a report.
+#[derive(Debug, Clone)]
+pub struct ReportHistoryEntry {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub source: ReportChangeSource,
+    /// The user who made the change, when it was not synced from elsewhere.
+    pub actor_id: Option<Uuid>,
+    pub details: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReportStatusChange {
+    pub status: ReportStatus,
+    pub source: ReportChangeSource,
+    pub actor_id: Option<Uuid>,
+    pub details: Option<String>,
+}
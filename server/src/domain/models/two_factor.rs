@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Secret material handed to the user when they start enrolling an authenticator app.
+#[derive(Debug, Clone)]
+pub struct TwoFactorEnrollment {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct TwoFactorStatus {
+    pub enabled: bool,
+    pub recovery_codes_remaining: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecoveryCode {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub code_hash: String,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SecuritySettings {
+    pub require_admin_two_factor: bool,
+}
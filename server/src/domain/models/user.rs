@@ -31,6 +31,9 @@ impl std::str::FromStr for UserRole {
     }
 }
 
+/// Synthetic user the reports submitted through an intake key are attributed to.
+pub const ANONYMOUS_REPORTER_ID: Uuid = Uuid::nil();
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
 pub struct User {
@@ -40,7 +43,49 @@ pub struct User {
     pub password_hash: String,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
+    pub avatar_url: Option<String>,
     pub role: UserRole,
+    #[serde(skip)]
+    pub totp_secret: Option<String>,
+    pub two_factor_enabled: bool,
+    pub is_active: bool,
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
+
+impl User {
+    /// Full name when known, email otherwise.
+    pub fn display_name(&self) -> String {
+        let full_name = [self.first_name.as_deref(), self.last_name.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if full_name.is_empty() {
+            self.email.clone()
+        } else {
+            full_name
+        }
+    }
+}
+
+/// Fields a user may change on their own profile. `None` leaves the value untouched.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateProfileParams {
+    pub email: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    /// Required when changing the email.
+    pub current_password: Option<String>,
+}
+
+/// What happens to the boards and reports of a deleted user.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserDeletionStrategy {
+    /// Moves everything to another user, then removes the account.
+    Reassign { new_owner_id: Uuid },
+    /// Strips personal data but keeps the account as a deactivated placeholder.
+    Anonymize,
+}
@@ -0,0 +1,83 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::event::EventType;
+
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<EventType>,
+    pub is_active: bool,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Webhook {
+    pub fn is_subscribed_to(&self, event_type: EventType) -> bool {
+        self.is_active && self.event_types.contains(&event_type)
+    }
+}
+
+/// Fields of a webhook that can be changed. `None` leaves the value untouched.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateWebhookParams {
+    pub url: Option<String>,
+    pub secret: Option<String>,
+    pub event_types: Option<Vec<EventType>>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[ts(export)]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+impl fmt::Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookDeliveryStatus::Pending => write!(f, "Pending"),
+            WebhookDeliveryStatus::Succeeded => write!(f, "Succeeded"),
+            WebhookDeliveryStatus::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+/// One event sent (or being sent) to one webhook, with the outcome of its latest attempt.
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: EventType,
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    /// When the delivery is due to be attempted, while it is pending.
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Outcome of a single delivery attempt.
+#[derive(Debug, Clone)]
+pub struct WebhookDeliveryAttempt {
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    /// When to retry, for a failed attempt that is not the last one.
+    pub next_attempt_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::domain::models::chat_integration::ChatMessage;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChatNotifierError {
+    #[error("Request failed: {0}")]
+    RequestFailed(String),
+    #[error("Chat platform responded with HTTP {0}")]
+    Rejected(u16),
+    #[error("Configuration error: {0}")]
+    ConfigurationError(String),
+}
+
+#[async_trait]
+pub trait ChatNotifierPort: Send + Sync {
+    /// Posts the message to a Slack/Mattermost-compatible incoming webhook.
+    async fn post_message(
+        &self,
+        webhook_url: &str,
+        message: &ChatMessage,
+    ) -> Result<(), ChatNotifierError>;
+}
@@ -0,0 +1,18 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DatabaseBackupError {
+    #[error("Backup failed: {0}")]
+    BackupFailed(String),
+    /// The database backend has no online backup; its own tools are to be used instead.
+    #[error("{0}")]
+    Unsupported(String),
+}
+
+#[async_trait]
+pub trait DatabaseBackupPort: Send + Sync {
+    /// Writes a consistent copy of the live database to `destination`, which must not exist.
+    async fn backup_to(&self, destination: &Path) -> Result<(), DatabaseBackupError>;
+}
@@ -0,0 +1,18 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::models::event::DomainEvent;
+
+pub trait EventPublisherPort: Send + Sync {
+    /// Hands the event to every subscriber. Never blocks nor fails the caller.
+    fn publish(&self, event: DomainEvent);
+
+    /// Registers a subscriber, called for every event published from now on.
+    fn subscribe(&self, name: &'static str, subscriber: Arc<dyn EventSubscriber>);
+}
+
+#[async_trait]
+pub trait EventSubscriber: Send + Sync {
+    async fn on_event(&self, event: &DomainEvent);
+}
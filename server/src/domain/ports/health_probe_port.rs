@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+#[derive(Debug, thiserror::Error)]
+pub enum HealthProbeError {
+    #[error("{0}")]
+    Unhealthy(String),
+}
+
+pub type HealthProbeResult = Result<Option<String>, HealthProbeError>;
+
+/// Check of a dependency the service cannot serve requests without.
+#[async_trait]
+pub trait HealthProbePort: Send + Sync {
+    /// Name of the checked component in the readiness report.
+    fn component(&self) -> &'static str;
+
+    /// Returns optional details when the component is usable.
+    async fn probe(&self) -> HealthProbeResult;
+}
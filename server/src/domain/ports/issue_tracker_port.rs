@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use crate::domain::models::issue_tracker::{
+    CreatedIssue, InboundWebhook, IssueEvent, IssueTrackerIntegration, NewIssue,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum IssueTrackerError {
+    #[error("Request failed: {0}")]
+    RequestFailed(String),
+    #[error("Issue tracker responded with HTTP {status}: {message}")]
+    Rejected { status: u16, message: String },
+    #[error("Unexpected response from the issue tracker: {0}")]
+    InvalidResponse(String),
+    #[error("Configuration error: {0}")]
+    ConfigurationError(String),
+}
+
+#[async_trait]
+pub trait IssueTrackerPort: Send + Sync {
+    /// Opens an issue in the repository configured on the integration.
+    async fn create_issue(
+        &self,
+        integration: &IssueTrackerIntegration,
+        issue: &NewIssue,
+    ) -> Result<CreatedIssue, IssueTrackerError>;
+
+    /// Checks the webhook was sent by the tracker, using the integration webhook secret.
+    fn verify_webhook(
+        &self,
+        integration: &IssueTrackerIntegration,
+        webhook: &InboundWebhook,
+    ) -> bool;
+
+    /// Extracts the issue close/reopen event from a webhook, `None` for any other event.
+    fn parse_issue_event(
+        &self,
+        integration: &IssueTrackerIntegration,
+        webhook: &InboundWebhook,
+    ) -> Result<Option<IssueEvent>, IssueTrackerError>;
+}
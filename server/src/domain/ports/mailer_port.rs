@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::models::email::EmailMessage;
+
+#[derive(Debug, thiserror::Error)]
+pub enum MailerError {
+    #[error("Connection failed: {0}")]
+    ConnectionFailed(String),
+    #[error("Mail server rejected the message: {0}")]
+    Rejected(String),
+    #[error("Configuration error: {0}")]
+    ConfigurationError(String),
+}
+
+#[async_trait]
+pub trait MailerPort: Send + Sync {
+    async fn send(&self, message: &EmailMessage) -> Result<(), MailerError>;
+}
@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::domain::models::report::ReportType;
+
+#[async_trait]
+pub trait MetricsPort: Send + Sync {
+    /// `route` is the matched route template, such as `/api/boards/{board_id}`, to keep the number
+    /// of series bounded.
+    fn record_http_request(&self, method: &str, route: &str, status: u16, duration: Duration);
+
+    fn record_report_created(&self, report_type: &ReportType);
+
+    fn record_stored_file(&self, size_bytes: u64);
+
+    fn set_active_users(&self, count: i64);
+
+    /// Encodes every metric in the Prometheus text exposition format.
+    async fn render(&self) -> String;
+}
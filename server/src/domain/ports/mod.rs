@@ -1 +1,9 @@
+pub mod chat_notifier_port;
+pub mod database_backup_port;
+pub mod event_publisher_port;
+pub mod health_probe_port;
+pub mod issue_tracker_port;
+pub mod mailer_port;
+pub mod metrics_port;
 pub mod storage_port;
+pub mod webhook_sender_port;
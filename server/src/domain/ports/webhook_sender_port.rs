@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::models::event::EventType;
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookSendError {
+    #[error("Request failed: {0}")]
+    RequestFailed(String),
+    #[error("Configuration error: {0}")]
+    ConfigurationError(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookRequest {
+    pub url: String,
+    pub delivery_id: Uuid,
+    pub event_type: EventType,
+    /// Unix time of the attempt, in seconds.
+    pub timestamp: i64,
+    /// `sha256=<hex>` HMAC of `<timestamp>.<body>`, computed with the webhook secret.
+    pub signature: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait WebhookSenderPort: Send + Sync {
+    /// Posts the payload and returns the HTTP status code of the response.
+    async fn send(&self, request: WebhookRequest) -> Result<u16, WebhookSendError>;
+}
@@ -0,0 +1,21 @@
+use crate::domain::models::audit::{AuditEvent, AuditEventFilter, CreateAuditEventParams};
+
+use super::RepositoryResult;
+
+/// Append-only: events are never updated nor deleted.
+#[async_trait::async_trait]
+pub trait AuditEventRepository: Send + Sync {
+    async fn create_event(&self, params: CreateAuditEventParams) -> RepositoryResult<AuditEvent>;
+
+    /// Most recent events first.
+    async fn find_paginated(
+        &self,
+        filter: &AuditEventFilter,
+        page: i32,
+        per_page: i32,
+    ) -> RepositoryResult<Vec<AuditEvent>>;
+    async fn count(&self, filter: &AuditEventFilter) -> RepositoryResult<i32>;
+
+    /// Every matching event, oldest first.
+    async fn find_all(&self, filter: &AuditEventFilter) -> RepositoryResult<Vec<AuditEvent>>;
+}
@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-use crate::domain::models::board::Board;
+use crate::domain::models::{board::Board, board_export::BoardImport};
 
 use super::RepositoryResult;
 
@@ -16,4 +16,7 @@ pub trait BoardRepository: Send + Sync {
 
     async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Board>>;
     async fn find_by_user_id(&self, user_id: Uuid) -> RepositoryResult<Vec<Board>>;
+
+    /// Inserts the board with its reports and their history in a single transaction.
+    async fn import_board(&self, import: BoardImport) -> RepositoryResult<Board>;
 }
@@ -0,0 +1,27 @@
+use uuid::Uuid;
+
+use crate::domain::models::chat_integration::{ChatIntegration, UpdateChatIntegrationParams};
+
+use super::RepositoryResult;
+
+#[async_trait::async_trait]
+pub trait ChatIntegrationRepository: Send + Sync {
+    async fn create_integration(
+        &self,
+        board_id: Uuid,
+        webhook_url: &str,
+        message_template: Option<&str>,
+        created_by: Uuid,
+    ) -> RepositoryResult<ChatIntegration>;
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<ChatIntegration>>;
+    async fn find_by_board_id(&self, board_id: Uuid) -> RepositoryResult<Vec<ChatIntegration>>;
+
+    async fn update_integration(
+        &self,
+        id: Uuid,
+        params: UpdateChatIntegrationParams,
+    ) -> RepositoryResult<ChatIntegration>;
+
+    async fn delete_integration(&self, id: Uuid) -> RepositoryResult<()>;
+}
@@ -0,0 +1,20 @@
+use uuid::Uuid;
+
+use crate::domain::models::email::InboundMailbox;
+
+use super::RepositoryResult;
+
+#[async_trait::async_trait]
+pub trait InboundMailboxRepository: Send + Sync {
+    /// Creates the board mailbox, or gives the existing one a new token.
+    async fn upsert_for_board(
+        &self,
+        board_id: Uuid,
+        token: &str,
+        created_by: Uuid,
+    ) -> RepositoryResult<InboundMailbox>;
+
+    async fn find_by_board_id(&self, board_id: Uuid) -> RepositoryResult<Option<InboundMailbox>>;
+    async fn find_by_token(&self, token: &str) -> RepositoryResult<Option<InboundMailbox>>;
+    async fn delete_by_board_id(&self, board_id: Uuid) -> RepositoryResult<()>;
+}
@@ -0,0 +1,29 @@
+use uuid::Uuid;
+
+use crate::domain::models::intake_key::{IntakeKey, UpdateIntakeKeyParams};
+
+use super::RepositoryResult;
+
+#[async_trait::async_trait]
+pub trait IntakeKeyRepository: Send + Sync {
+    async fn create_key(
+        &self,
+        board_id: Uuid,
+        name: &str,
+        public_key: &str,
+        allowed_origins: &[String],
+        created_by: Uuid,
+    ) -> RepositoryResult<IntakeKey>;
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<IntakeKey>>;
+    async fn find_by_public_key(&self, public_key: &str) -> RepositoryResult<Option<IntakeKey>>;
+    async fn find_by_board_id(&self, board_id: Uuid) -> RepositoryResult<Vec<IntakeKey>>;
+
+    async fn update_key(
+        &self,
+        id: Uuid,
+        params: UpdateIntakeKeyParams,
+    ) -> RepositoryResult<IntakeKey>;
+
+    async fn delete_key(&self, id: Uuid) -> RepositoryResult<()>;
+}
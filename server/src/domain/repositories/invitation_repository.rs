@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::models::{invitation::Invitation, user::UserRole};
+
+use super::RepositoryResult;
+
+#[async_trait::async_trait]
+pub trait InvitationRepository: Send + Sync {
+    async fn create_invitation(
+        &self,
+        email: &str,
+        role: UserRole,
+        token_hash: &str,
+        invited_by: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> RepositoryResult<Invitation>;
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Invitation>>;
+    async fn find_by_token_hash(&self, token_hash: &str) -> RepositoryResult<Option<Invitation>>;
+
+    /// Invitations that were neither accepted nor revoked, expired ones included.
+    async fn find_open(&self) -> RepositoryResult<Vec<Invitation>>;
+
+    async fn mark_accepted(&self, id: Uuid) -> RepositoryResult<()>;
+    async fn revoke(&self, id: Uuid) -> RepositoryResult<()>;
+    async fn revoke_open_by_email(&self, email: &str) -> RepositoryResult<()>;
+}
@@ -0,0 +1,28 @@
+use uuid::Uuid;
+
+use crate::domain::models::issue_tracker::{ConfigureIssueTrackerParams, IssueTrackerIntegration};
+
+use super::RepositoryResult;
+
+#[async_trait::async_trait]
+pub trait IssueTrackerIntegrationRepository: Send + Sync {
+    /// Creates the board integration, or replaces the existing one while keeping its
+    /// webhook secret.
+    async fn upsert_for_board(
+        &self,
+        board_id: Uuid,
+        api_base_url: &str,
+        webhook_secret: &str,
+        params: ConfigureIssueTrackerParams,
+        created_by: Uuid,
+    ) -> RepositoryResult<IssueTrackerIntegration>;
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<IssueTrackerIntegration>>;
+
+    async fn find_by_board_id(
+        &self,
+        board_id: Uuid,
+    ) -> RepositoryResult<Option<IssueTrackerIntegration>>;
+
+    async fn delete_by_board_id(&self, board_id: Uuid) -> RepositoryResult<()>;
+}
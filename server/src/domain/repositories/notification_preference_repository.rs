@@ -0,0 +1,21 @@
+use uuid::Uuid;
+
+use crate::domain::models::notification_preference::{
+    NotificationPreferences, UpdateNotificationPreferencesParams,
+};
+
+use super::RepositoryResult;
+
+#[async_trait::async_trait]
+pub trait NotificationPreferenceRepository: Send + Sync {
+    async fn find_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<NotificationPreferences>>;
+
+    async fn upsert(
+        &self,
+        user_id: Uuid,
+        params: UpdateNotificationPreferencesParams,
+    ) -> RepositoryResult<NotificationPreferences>;
+}
@@ -0,0 +1,20 @@
+use uuid::Uuid;
+
+use crate::domain::models::two_factor::RecoveryCode;
+
+use super::RepositoryResult;
+
+#[async_trait::async_trait]
+pub trait RecoveryCodeRepository: Send + Sync {
+    /// Replaces every recovery code of the user with the given hashes.
+    async fn replace_codes(
+        &self,
+        user_id: Uuid,
+        code_hashes: Vec<String>,
+    ) -> RepositoryResult<Vec<RecoveryCode>>;
+
+    async fn find_unused_by_user_id(&self, user_id: Uuid) -> RepositoryResult<Vec<RecoveryCode>>;
+    /// Fails with `NotFound` when the code does not exist or was already used.
+    async fn mark_used(&self, id: Uuid) -> RepositoryResult<()>;
+    async fn delete_by_user_id(&self, user_id: Uuid) -> RepositoryResult<()>;
+}
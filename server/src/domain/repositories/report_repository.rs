@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::domain::models::report::{CreateReportParams, Report};
+use crate::domain::models::report::{
+    CreateReportParams, Report, ReportHistoryEntry, ReportStatusChange,
+};
 
 use super::RepositoryResult;
 
@@ -25,5 +27,28 @@ pub trait ReportRepository: Send + Sync {
         per_page: i32,
     ) -> RepositoryResult<Vec<Report>>;
 
+    /// Every report of the board, oldest first.
+    async fn find_all_by_board_id(&self, board_id: Uuid) -> RepositoryResult<Vec<Report>>;
+
     async fn count_by_board_id(&self, board_id: Uuid) -> RepositoryResult<i32>;
+
+    async fn find_by_external_issue_key(
+        &self,
+        board_id: Uuid,
+        external_issue_key: &str,
+    ) -> RepositoryResult<Option<Report>>;
+
+    /// Links the report to the tracker issue it was exported to.
+    async fn set_external_issue(
+        &self,
+        id: Uuid,
+        external_issue_key: &str,
+        external_issue_url: &str,
+    ) -> RepositoryResult<Report>;
+
+    /// Updates the status and records the change in the report history.
+    async fn update_status(&self, id: Uuid, change: ReportStatusChange)
+    -> RepositoryResult<Report>;
+
+    async fn find_history(&self, report_id: Uuid) -> RepositoryResult<Vec<ReportHistoryEntry>>;
 }
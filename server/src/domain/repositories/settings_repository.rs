@@ -0,0 +1,7 @@
+use super::RepositoryResult;
+
+#[async_trait::async_trait]
+pub trait SettingsRepository: Send + Sync {
+    async fn get_value(&self, key: &str) -> RepositoryResult<Option<String>>;
+    async fn set_value(&self, key: &str, value: &str) -> RepositoryResult<()>;
+}
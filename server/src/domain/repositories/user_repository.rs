@@ -17,4 +17,52 @@ pub trait UserRepository: Send + Sync {
 
     async fn find_by_email(&self, email: &str) -> RepositoryResult<Option<User>>;
     async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<User>>;
+
+    /// Lists users that have not been deleted, optionally filtered on email or name.
+    async fn find_paginated(
+        &self,
+        search: Option<&str>,
+        page: i32,
+        per_page: i32,
+    ) -> RepositoryResult<Vec<User>>;
+    async fn count(&self, search: Option<&str>) -> RepositoryResult<i32>;
+    /// Counts the users allowed to sign in, neither deactivated nor deleted.
+    async fn count_active(&self) -> RepositoryResult<i64>;
+
+    /// Storing another secret forgets the time step of the last accepted code, which belonged to
+    /// the previous secret.
+    async fn update_totp(
+        &self,
+        id: Uuid,
+        totp_secret: Option<&str>,
+        totp_enabled: bool,
+    ) -> RepositoryResult<User>;
+
+    /// Stores the time step of an accepted TOTP code, unless a code of this step or a later one
+    /// was already accepted. Returns whether it was stored.
+    async fn record_totp_time_step(&self, id: Uuid, time_step: i64) -> RepositoryResult<bool>;
+
+    async fn update_password_hash(&self, id: Uuid, password_hash: &str) -> RepositoryResult<()>;
+
+    async fn update_profile(
+        &self,
+        id: Uuid,
+        email: &str,
+        first_name: Option<&str>,
+        last_name: Option<&str>,
+    ) -> RepositoryResult<User>;
+    async fn update_avatar(&self, id: Uuid, avatar_url: Option<&str>) -> RepositoryResult<User>;
+
+    async fn update_role(&self, id: Uuid, role: UserRole) -> RepositoryResult<User>;
+    async fn update_active(&self, id: Uuid, is_active: bool) -> RepositoryResult<User>;
+
+    /// Moves the user's boards, reports and sent invitations to another user, then deletes the user.
+    async fn delete_reassigning_content(
+        &self,
+        id: Uuid,
+        new_owner_id: Uuid,
+    ) -> RepositoryResult<()>;
+
+    /// Replaces the user's personal data with placeholders and deactivates the account.
+    async fn anonymize(&self, id: Uuid) -> RepositoryResult<()>;
 }
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::models::{
+    event::EventType,
+    webhook::{WebhookDelivery, WebhookDeliveryAttempt},
+};
+
+use super::RepositoryResult;
+
+#[async_trait::async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// Creates a pending delivery, due at `next_attempt_at`.
+    async fn create_delivery(
+        &self,
+        webhook_id: Uuid,
+        event_id: Uuid,
+        event_type: EventType,
+        payload: &str,
+        next_attempt_at: DateTime<Utc>,
+    ) -> RepositoryResult<WebhookDelivery>;
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<WebhookDelivery>>;
+
+    /// Most recent deliveries first.
+    async fn find_by_webhook_id_paginated(
+        &self,
+        webhook_id: Uuid,
+        page: i32,
+        per_page: i32,
+    ) -> RepositoryResult<Vec<WebhookDelivery>>;
+    async fn count_by_webhook_id(&self, webhook_id: Uuid) -> RepositoryResult<i32>;
+
+    /// Pending deliveries due at `now`, oldest first. They are postponed to `lease_until` so that
+    /// no other worker attempts them meanwhile, and attempted again after that if the attempt
+    /// was interrupted.
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i32,
+    ) -> RepositoryResult<Vec<WebhookDelivery>>;
+
+    async fn record_attempt(
+        &self,
+        id: Uuid,
+        attempt: WebhookDeliveryAttempt,
+    ) -> RepositoryResult<WebhookDelivery>;
+}
@@ -0,0 +1,31 @@
+use uuid::Uuid;
+
+use crate::domain::models::{
+    event::EventType,
+    webhook::{UpdateWebhookParams, Webhook},
+};
+
+use super::RepositoryResult;
+
+#[async_trait::async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn create_webhook(
+        &self,
+        board_id: Uuid,
+        url: &str,
+        secret: &str,
+        event_types: &[EventType],
+        created_by: Uuid,
+    ) -> RepositoryResult<Webhook>;
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Webhook>>;
+    async fn find_by_board_id(&self, board_id: Uuid) -> RepositoryResult<Vec<Webhook>>;
+    async fn update_webhook(
+        &self,
+        id: Uuid,
+        params: UpdateWebhookParams,
+    ) -> RepositoryResult<Webhook>;
+
+    /// Deletes the webhook along with its delivery log.
+    async fn delete_webhook(&self, id: Uuid) -> RepositoryResult<()>;
+}
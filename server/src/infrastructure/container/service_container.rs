@@ -1,63 +1,263 @@
 use std::sync::Arc;
 
+use sqlx::{PgPool, SqlitePool};
+
 use crate::{
     application::services::{
+        audit_service::{AuditService, AuditServiceInterface},
         auth_service::{AuthService, AuthServiceInterface},
         authorization_service::{AuthorizationService, AuthorizationServiceInterface},
+        backup_service::{BackupService, BackupServiceInterface},
+        board_export_service::{BoardExportService, BoardExportServiceInterface},
+        board_import_service::{BoardImportService, BoardImportServiceInterface},
         board_service::{BoardService, BoardServiceInterface},
+        chat_notification_service::{ChatNotificationService, ChatNotificationServiceInterface},
         dashboard_service::{DashboardService, DashboardServiceInterface},
+        email_notification_service::{EmailNotificationService, EmailNotificationServiceInterface},
         health_service::{HealthService, HealthServiceInterface},
+        inbound_email_service::{InboundEmailService, InboundEmailServiceInterface},
+        intake_service::{IntakeService, IntakeServiceInterface},
+        invitation_service::{InvitationService, InvitationServiceInterface},
+        issue_tracker_service::{IssueTrackerService, IssueTrackerServiceInterface},
+        live_feed_service::{LiveFeedService, LiveFeedServiceInterface},
+        metrics_service::{MetricsService, MetricsServiceInterface},
         report_service::{ReportService, ReportServiceInterface},
+        two_factor_service::{TwoFactorService, TwoFactorServiceInterface},
         user_onboarding_service::{UserOnboardingService, UserOnboardingServiceInterface},
         user_service::{UserService, UserServiceInterface},
+        webhook_service::{WebhookService, WebhookServiceInterface},
+    },
+    config::app_config::{APP_CONFIG, MailTransport},
+    domain::{
+        ports::{
+            chat_notifier_port::ChatNotifierPort, database_backup_port::DatabaseBackupPort,
+            event_publisher_port::EventPublisherPort, health_probe_port::HealthProbePort,
+            issue_tracker_port::IssueTrackerPort, mailer_port::MailerPort,
+            metrics_port::MetricsPort, storage_port::StoragePort,
+            webhook_sender_port::WebhookSenderPort,
+        },
+        repositories::{
+            audit_event_repository::AuditEventRepository, board_repository::BoardRepository,
+            chat_integration_repository::ChatIntegrationRepository,
+            inbound_mailbox_repository::InboundMailboxRepository,
+            intake_key_repository::IntakeKeyRepository,
+            invitation_repository::InvitationRepository,
+            issue_tracker_integration_repository::IssueTrackerIntegrationRepository,
+            notification_preference_repository::NotificationPreferenceRepository,
+            recovery_code_repository::RecoveryCodeRepository, report_repository::ReportRepository,
+            settings_repository::SettingsRepository, user_repository::UserRepository,
+            webhook_delivery_repository::WebhookDeliveryRepository,
+            webhook_repository::WebhookRepository,
+        },
     },
-    config::app_config::APP_CONFIG,
-    domain::ports::storage_port::StoragePort,
     infrastructure::{
-        database::sqlite::Sqlite,
+        database::{
+            connection::DatabaseConnection, postgres_backup::PostgresBackup,
+            sqlite_backup::SqliteBackup,
+        },
+        events::broadcast_event_publisher::BroadcastEventPublisher,
+        health::{
+            database_probes::{MigrationProbe, SqlxPoolProbe},
+            storage_probes::{DiskSpaceProbe, StorageWritableProbe},
+        },
+        issue_trackers::http_issue_tracker::HttpIssueTracker,
+        mail::{capture_mailer::CaptureMailer, smtp_mailer::SmtpMailer},
+        metrics::{
+            prometheus_metrics::{MeteredPool, PrometheusMetrics},
+            query_metrics_layer::QueryMetricsLayer,
+        },
+        notifications::http_chat_notifier::HttpChatNotifier,
         repositories::{
-            board_repository::SqliteBoardRepository, report_repository::SqliteReportRepository,
+            audit_event_repository::SqliteAuditEventRepository,
+            board_repository::SqliteBoardRepository,
+            chat_integration_repository::SqliteChatIntegrationRepository,
+            inbound_mailbox_repository::SqliteInboundMailboxRepository,
+            intake_key_repository::SqliteIntakeKeyRepository,
+            invitation_repository::SqliteInvitationRepository,
+            issue_tracker_integration_repository::SqliteIssueTrackerIntegrationRepository,
+            notification_preference_repository::SqliteNotificationPreferenceRepository,
+            postgres::{
+                audit_event_repository::PostgresAuditEventRepository,
+                board_repository::PostgresBoardRepository,
+                chat_integration_repository::PostgresChatIntegrationRepository,
+                inbound_mailbox_repository::PostgresInboundMailboxRepository,
+                intake_key_repository::PostgresIntakeKeyRepository,
+                invitation_repository::PostgresInvitationRepository,
+                issue_tracker_integration_repository::PostgresIssueTrackerIntegrationRepository,
+                notification_preference_repository::PostgresNotificationPreferenceRepository,
+                recovery_code_repository::PostgresRecoveryCodeRepository,
+                report_repository::PostgresReportRepository,
+                settings_repository::PostgresSettingsRepository,
+                user_repository::PostgresUserRepository,
+                webhook_delivery_repository::PostgresWebhookDeliveryRepository,
+                webhook_repository::PostgresWebhookRepository,
+            },
+            recovery_code_repository::SqliteRecoveryCodeRepository,
+            report_repository::SqliteReportRepository,
+            settings_repository::SqliteSettingsRepository,
             user_repository::SqliteUserRepository,
+            webhook_delivery_repository::SqliteWebhookDeliveryRepository,
+            webhook_repository::SqliteWebhookRepository,
         },
-        storage::file_system_storage::FileSystemStorage,
+        storage::{file_system_storage::FileSystemStorage, metered_storage::MeteredStorage},
+        webhooks::http_webhook_sender::HttpWebhookSender,
     },
 };
 
 pub struct ServiceContainer {
     pub health_service: Arc<dyn HealthServiceInterface>,
+    pub audit_service: Arc<dyn AuditServiceInterface>,
     pub auth_service: Arc<dyn AuthServiceInterface>,
     pub authorization_service: Arc<dyn AuthorizationServiceInterface>,
+    pub backup_service: Arc<dyn BackupServiceInterface>,
     pub user_service: Arc<dyn UserServiceInterface>,
     pub board_service: Arc<dyn BoardServiceInterface>,
+    pub board_export_service: Arc<dyn BoardExportServiceInterface>,
+    pub board_import_service: Arc<dyn BoardImportServiceInterface>,
+    pub chat_notification_service: Arc<dyn ChatNotificationServiceInterface>,
     pub dashboard_service: Arc<dyn DashboardServiceInterface>,
+    pub email_notification_service: Arc<dyn EmailNotificationServiceInterface>,
+    pub inbound_email_service: Arc<dyn InboundEmailServiceInterface>,
+    pub intake_service: Arc<dyn IntakeServiceInterface>,
     pub report_service: Arc<dyn ReportServiceInterface>,
     pub user_onboarding_service: Arc<dyn UserOnboardingServiceInterface>,
+    pub two_factor_service: Arc<dyn TwoFactorServiceInterface>,
+    pub invitation_service: Arc<dyn InvitationServiceInterface>,
+    pub issue_tracker_service: Arc<dyn IssueTrackerServiceInterface>,
+    pub live_feed_service: Arc<dyn LiveFeedServiceInterface>,
+    pub metrics_service: Arc<dyn MetricsServiceInterface>,
+    pub webhook_service: Arc<dyn WebhookServiceInterface>,
 }
 
 impl ServiceContainer {
-    pub async fn new(sqlite_connection: &Sqlite) -> Result<Self, Box<dyn std::error::Error>> {
+    pub async fn new(database: &DatabaseConnection) -> Result<Self, Box<dyn std::error::Error>> {
         // Repository layer
-        let user_repository = Arc::new(SqliteUserRepository::new(sqlite_connection.get_rbatis()));
-        let board_repository = Arc::new(SqliteBoardRepository::new(sqlite_connection.get_rbatis()));
-        let report_repository =
-            Arc::new(SqliteReportRepository::new(sqlite_connection.get_rbatis()));
+        let Repositories {
+            user_repository,
+            board_repository,
+            report_repository,
+            recovery_code_repository,
+            settings_repository,
+            invitation_repository,
+            webhook_repository,
+            webhook_delivery_repository,
+            chat_integration_repository,
+            issue_tracker_integration_repository,
+            notification_preference_repository,
+            intake_key_repository,
+            inbound_mailbox_repository,
+            audit_event_repository,
+        } = match database {
+            DatabaseConnection::Sqlite(sqlite) => Repositories::sqlite(sqlite.get_pool()),
+            DatabaseConnection::Postgres(postgres) => Repositories::postgres(postgres.get_pool()),
+        };
+
+        // Metrics
+        let prometheus_metrics = Arc::new(PrometheusMetrics::new(match database {
+            DatabaseConnection::Sqlite(sqlite) => MeteredPool::Sqlite(sqlite.get_pool()),
+            DatabaseConnection::Postgres(postgres) => MeteredPool::Postgres(postgres.get_pool()),
+        })?);
+        QueryMetricsLayer::record_to(prometheus_metrics.clone());
+        let metrics: Arc<dyn MetricsPort> = prometheus_metrics;
 
         // Storage layer
-        let storage_port: Arc<dyn StoragePort> = Arc::new(FileSystemStorage::new(
-            APP_CONFIG.upload_directory.clone(),
-            APP_CONFIG.file_base_url.clone(),
+        let storage_port: Arc<dyn StoragePort> = Arc::new(MeteredStorage::new(
+            Arc::new(FileSystemStorage::new(
+                APP_CONFIG.upload_directory.clone(),
+                APP_CONFIG.file_base_url.clone(),
+            )?),
+            metrics.clone(),
+        ));
+
+        // Backups
+        let database_backup: Arc<dyn DatabaseBackupPort> = match database {
+            DatabaseConnection::Sqlite(sqlite) => Arc::new(SqliteBackup::new(sqlite.get_pool())),
+            DatabaseConnection::Postgres(_) => Arc::new(PostgresBackup),
+        };
+
+        // Events and outgoing webhooks
+        let event_publisher: Arc<dyn EventPublisherPort> = Arc::new(BroadcastEventPublisher::new());
+        let webhook_sender: Arc<dyn WebhookSenderPort> = Arc::new(HttpWebhookSender::new(
+            APP_CONFIG.webhook_timeout_seconds,
+            APP_CONFIG.webhook_allow_private_networks,
+        )?);
+        let chat_notifier: Arc<dyn ChatNotifierPort> = Arc::new(HttpChatNotifier::new(
+            APP_CONFIG.chat_timeout_seconds,
+            APP_CONFIG.webhook_allow_private_networks,
+        )?);
+        let issue_tracker: Arc<dyn IssueTrackerPort> = Arc::new(HttpIssueTracker::new(
+            APP_CONFIG.issue_tracker_timeout_seconds,
+            APP_CONFIG.webhook_allow_private_networks,
         )?);
+        let mailer: Arc<dyn MailerPort> = match APP_CONFIG.mail_transport {
+            MailTransport::Smtp => Arc::new(SmtpMailer::new(
+                APP_CONFIG.smtp_host.clone(),
+                APP_CONFIG.smtp_port,
+                APP_CONFIG.smtp_security,
+                APP_CONFIG.smtp_username.clone(),
+                APP_CONFIG.smtp_password.clone(),
+                APP_CONFIG.mail_from.clone(),
+                APP_CONFIG.smtp_timeout_seconds,
+            )?),
+            MailTransport::Capture => Arc::new(CaptureMailer::new(
+                APP_CONFIG.mail_capture_directory.clone(),
+                APP_CONFIG.mail_from.clone(),
+            )?),
+        };
 
         // Service layer
-        let health_service = Arc::new(HealthService::new());
-        let user_service = Arc::new(UserService::new(user_repository));
-        let board_service = Arc::new(BoardService::new(board_repository.clone()));
-        let auth_service = Arc::new(AuthService::new(user_service.clone()));
-        let authorization_service = Arc::new(AuthorizationService::new(board_repository));
+        let mut health_probes: Vec<Arc<dyn HealthProbePort>> = match database {
+            DatabaseConnection::Sqlite(sqlite) => vec![
+                Arc::new(SqlxPoolProbe::new(sqlite.get_pool())),
+                Arc::new(MigrationProbe::new(sqlite.get_pool(), database.migrator())),
+            ],
+            DatabaseConnection::Postgres(postgres) => vec![
+                Arc::new(SqlxPoolProbe::new(postgres.get_pool())),
+                Arc::new(MigrationProbe::new(
+                    postgres.get_pool(),
+                    database.migrator(),
+                )),
+            ],
+        };
+        health_probes.extend::<[Arc<dyn HealthProbePort>; 2]>([
+            Arc::new(StorageWritableProbe::new(&APP_CONFIG.upload_directory)),
+            Arc::new(DiskSpaceProbe::new(
+                &APP_CONFIG.upload_directory,
+                APP_CONFIG.health_min_free_disk_space_mb * 1024 * 1024,
+            )),
+        ]);
+        let health_service = Arc::new(HealthService::new(health_probes));
+        let backup_service = Arc::new(BackupService::new(database_backup));
+        let audit_service = Arc::new(AuditService::new(audit_event_repository));
+        let user_service = Arc::new(UserService::new(
+            user_repository.clone(),
+            storage_port.clone(),
+            audit_service.clone(),
+        ));
+        let board_service = Arc::new(BoardService::new(
+            board_repository.clone(),
+            event_publisher.clone(),
+            audit_service.clone(),
+        ));
+        let metrics_service = Arc::new(MetricsService::new(metrics, user_repository.clone()));
+        event_publisher.subscribe("metrics", metrics_service.clone());
+        let two_factor_service = Arc::new(TwoFactorService::new(
+            user_repository,
+            recovery_code_repository,
+            settings_repository,
+        ));
+        let auth_service = Arc::new(AuthService::new(
+            user_service.clone(),
+            two_factor_service.clone(),
+            audit_service.clone(),
+        ));
+        let authorization_service = Arc::new(AuthorizationService::new(board_repository.clone()));
         let report_service = Arc::new(ReportService::new(
             report_repository,
-            storage_port,
+            storage_port.clone(),
+            event_publisher.clone(),
             authorization_service.clone(),
+            audit_service.clone(),
         ));
         let dashboard_service = Arc::new(DashboardService::new(
             board_service.clone(),
@@ -67,16 +267,167 @@ impl ServiceContainer {
             user_service.clone(),
             board_service.clone(),
         ));
+        let invitation_service = Arc::new(InvitationService::new(
+            invitation_repository,
+            user_service.clone(),
+            user_onboarding_service.clone(),
+        ));
+        let webhook_service = Arc::new(WebhookService::new(
+            webhook_repository,
+            webhook_delivery_repository,
+            webhook_sender,
+        ));
+        event_publisher.subscribe("webhooks", webhook_service.clone());
+        let chat_notification_service = Arc::new(ChatNotificationService::new(
+            chat_integration_repository,
+            chat_notifier,
+            board_service.clone(),
+            user_service.clone(),
+        ));
+        event_publisher.subscribe("chat_notifications", chat_notification_service.clone());
+        let email_notification_service = Arc::new(EmailNotificationService::new(
+            notification_preference_repository,
+            mailer,
+            board_service.clone(),
+            report_service.clone(),
+            user_service.clone(),
+        ));
+        event_publisher.subscribe("email_notifications", email_notification_service.clone());
+        let live_feed_service = Arc::new(LiveFeedService::new(
+            board_service.clone(),
+            user_service.clone(),
+        ));
+        event_publisher.subscribe("live_feed", live_feed_service.clone());
+        let intake_service = Arc::new(IntakeService::new(
+            intake_key_repository,
+            report_service.clone(),
+        ));
+        let inbound_email_service = Arc::new(InboundEmailService::new(
+            inbound_mailbox_repository,
+            report_service.clone(),
+            user_service.clone(),
+            storage_port.clone(),
+        ));
+        let board_export_service = Arc::new(BoardExportService::new(
+            board_service.clone(),
+            report_service.clone(),
+            user_service.clone(),
+            storage_port.clone(),
+        ));
+        let board_import_service = Arc::new(BoardImportService::new(
+            board_repository,
+            board_service.clone(),
+            report_service.clone(),
+            user_service.clone(),
+            storage_port,
+            audit_service.clone(),
+        ));
+        let issue_tracker_service = Arc::new(IssueTrackerService::new(
+            issue_tracker_integration_repository,
+            issue_tracker,
+            report_service.clone(),
+            user_service.clone(),
+        ));
 
         Ok(Self {
             health_service,
+            audit_service,
             auth_service,
             authorization_service,
+            backup_service,
             user_service,
             board_service,
+            board_export_service,
+            board_import_service,
+            chat_notification_service,
             dashboard_service,
+            email_notification_service,
+            inbound_email_service,
+            intake_service,
             report_service,
             user_onboarding_service,
+            two_factor_service,
+            invitation_service,
+            issue_tracker_service,
+            live_feed_service,
+            metrics_service,
+            webhook_service,
         })
     }
 }
+
+/// Repositories of the backend selected by `database_url`.
+struct Repositories {
+    user_repository: Arc<dyn UserRepository>,
+    board_repository: Arc<dyn BoardRepository>,
+    report_repository: Arc<dyn ReportRepository>,
+    recovery_code_repository: Arc<dyn RecoveryCodeRepository>,
+    settings_repository: Arc<dyn SettingsRepository>,
+    invitation_repository: Arc<dyn InvitationRepository>,
+    webhook_repository: Arc<dyn WebhookRepository>,
+    webhook_delivery_repository: Arc<dyn WebhookDeliveryRepository>,
+    chat_integration_repository: Arc<dyn ChatIntegrationRepository>,
+    issue_tracker_integration_repository: Arc<dyn IssueTrackerIntegrationRepository>,
+    notification_preference_repository: Arc<dyn NotificationPreferenceRepository>,
+    intake_key_repository: Arc<dyn IntakeKeyRepository>,
+    inbound_mailbox_repository: Arc<dyn InboundMailboxRepository>,
+    audit_event_repository: Arc<dyn AuditEventRepository>,
+}
+
+impl Repositories {
+    fn sqlite(pool: SqlitePool) -> Self {
+        Self {
+            user_repository: Arc::new(SqliteUserRepository::new(pool.clone())),
+            board_repository: Arc::new(SqliteBoardRepository::new(pool.clone())),
+            report_repository: Arc::new(SqliteReportRepository::new(pool.clone())),
+            recovery_code_repository: Arc::new(SqliteRecoveryCodeRepository::new(pool.clone())),
+            settings_repository: Arc::new(SqliteSettingsRepository::new(pool.clone())),
+            invitation_repository: Arc::new(SqliteInvitationRepository::new(pool.clone())),
+            webhook_repository: Arc::new(SqliteWebhookRepository::new(pool.clone())),
+            webhook_delivery_repository: Arc::new(SqliteWebhookDeliveryRepository::new(
+                pool.clone(),
+            )),
+            chat_integration_repository: Arc::new(SqliteChatIntegrationRepository::new(
+                pool.clone(),
+            )),
+            issue_tracker_integration_repository: Arc::new(
+                SqliteIssueTrackerIntegrationRepository::new(pool.clone()),
+            ),
+            notification_preference_repository: Arc::new(
+                SqliteNotificationPreferenceRepository::new(pool.clone()),
+            ),
+            intake_key_repository: Arc::new(SqliteIntakeKeyRepository::new(pool.clone())),
+            inbound_mailbox_repository: Arc::new(SqliteInboundMailboxRepository::new(pool.clone())),
+            audit_event_repository: Arc::new(SqliteAuditEventRepository::new(pool)),
+        }
+    }
+
+    fn postgres(pool: PgPool) -> Self {
+        Self {
+            user_repository: Arc::new(PostgresUserRepository::new(pool.clone())),
+            board_repository: Arc::new(PostgresBoardRepository::new(pool.clone())),
+            report_repository: Arc::new(PostgresReportRepository::new(pool.clone())),
+            recovery_code_repository: Arc::new(PostgresRecoveryCodeRepository::new(pool.clone())),
+            settings_repository: Arc::new(PostgresSettingsRepository::new(pool.clone())),
+            invitation_repository: Arc::new(PostgresInvitationRepository::new(pool.clone())),
+            webhook_repository: Arc::new(PostgresWebhookRepository::new(pool.clone())),
+            webhook_delivery_repository: Arc::new(PostgresWebhookDeliveryRepository::new(
+                pool.clone(),
+            )),
+            chat_integration_repository: Arc::new(PostgresChatIntegrationRepository::new(
+                pool.clone(),
+            )),
+            issue_tracker_integration_repository: Arc::new(
+                PostgresIssueTrackerIntegrationRepository::new(pool.clone()),
+            ),
+            notification_preference_repository: Arc::new(
+                PostgresNotificationPreferenceRepository::new(pool.clone()),
+            ),
+            intake_key_repository: Arc::new(PostgresIntakeKeyRepository::new(pool.clone())),
+            inbound_mailbox_repository: Arc::new(PostgresInboundMailboxRepository::new(
+                pool.clone(),
+            )),
+            audit_event_repository: Arc::new(PostgresAuditEventRepository::new(pool)),
+        }
+    }
+}
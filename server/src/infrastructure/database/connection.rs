@@ -0,0 +1,40 @@
+use sqlx::migrate::{MigrateError, Migrator};
+
+use crate::{
+    config::app_config::DatabaseBackend,
+    infrastructure::database::{
+        postgres::{POSTGRES_MIGRATOR, Postgres},
+        sqlite::{MIGRATOR, Sqlite},
+    },
+};
+
+/// Connection to the backend selected by the scheme of `database_url`.
+#[derive(Clone)]
+pub enum DatabaseConnection {
+    Sqlite(Sqlite),
+    Postgres(Postgres),
+}
+
+impl DatabaseConnection {
+    pub async fn new(database_url: String) -> Result<Self, Box<dyn std::error::Error>> {
+        match DatabaseBackend::from_url(&database_url)? {
+            DatabaseBackend::Sqlite => Ok(Self::Sqlite(Sqlite::new(database_url).await?)),
+            DatabaseBackend::Postgres => Ok(Self::Postgres(Postgres::new(database_url).await?)),
+        }
+    }
+
+    /// Migrations embedded in the binary for this backend.
+    pub fn migrator(&self) -> &'static Migrator {
+        match self {
+            Self::Sqlite(_) => &MIGRATOR,
+            Self::Postgres(_) => &POSTGRES_MIGRATOR,
+        }
+    }
+
+    pub async fn run_migrations(&self) -> Result<(), MigrateError> {
+        match self {
+            Self::Sqlite(sqlite) => MIGRATOR.run(&sqlite.get_pool()).await,
+            Self::Postgres(postgres) => POSTGRES_MIGRATOR.run(&postgres.get_pool()).await,
+        }
+    }
+}
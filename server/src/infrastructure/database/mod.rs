@@ -1 +1,5 @@
+pub mod connection;
+pub mod postgres;
+pub mod postgres_backup;
 pub mod sqlite;
+pub mod sqlite_backup;
@@ -0,0 +1,27 @@
+use sqlx::{PgPool, migrate::Migrator, postgres::PgPoolOptions};
+
+use crate::config::app_config::APP_CONFIG;
+
+/// Migrations embedded in the binary, from the `migrations/postgres` directory.
+pub static POSTGRES_MIGRATOR: Migrator = sqlx::migrate!("migrations/postgres");
+
+#[derive(Clone)]
+pub struct Postgres {
+    pool: PgPool,
+}
+
+impl Postgres {
+    pub async fn new(database_url: String) -> Result<Self, Box<dyn std::error::Error>> {
+        let pool = PgPoolOptions::new()
+            .max_connections(APP_CONFIG.database_max_connections)
+            .connect(&database_url)
+            .await
+            .map_err(|e| format!("Failed to connect to PostgreSQL: {}", e))?;
+
+        Ok(Self { pool })
+    }
+
+    pub fn get_pool(&self) -> PgPool {
+        self.pool.clone()
+    }
+}
@@ -0,0 +1,18 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::domain::ports::database_backup_port::{DatabaseBackupError, DatabaseBackupPort};
+
+/// PostgreSQL databases are backed up with `pg_dump` or the server's own tooling, which also
+/// cover restoring them.
+pub struct PostgresBackup;
+
+#[async_trait]
+impl DatabaseBackupPort for PostgresBackup {
+    async fn backup_to(&self, _destination: &Path) -> Result<(), DatabaseBackupError> {
+        Err(DatabaseBackupError::Unsupported(
+            "Backups are not supported with PostgreSQL; use pg_dump instead".into(),
+        ))
+    }
+}
@@ -1,32 +1,53 @@
-use rbatis::rbatis::RBatis;
-use rbdc_sqlite::driver::SqliteDriver;
-use sqlx::SqlitePool;
+use std::{str::FromStr, time::Duration};
+
+use sqlx::{
+    SqlitePool,
+    migrate::Migrator,
+    sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous},
+};
+
+use crate::config::app_config::{self, APP_CONFIG};
+
+/// Migrations embedded in the binary, from the `migrations` directory.
+pub static MIGRATOR: Migrator = sqlx::migrate!();
 
 #[derive(Clone)]
 pub struct Sqlite {
     pool: SqlitePool,
-    rb: RBatis,
 }
 
 impl Sqlite {
+    /// Opens the pool every query and migration goes through, with the `sqlite_*` settings
+    /// applied to each connection.
     pub async fn new(database_url: String) -> Result<Self, Box<dyn std::error::Error>> {
-        let db_pool = SqlitePool::connect(&database_url)
-            .await
-            .expect("Failed to create SQLite connection pool");
+        let options = SqliteConnectOptions::from_str(&database_url)?
+            .journal_mode(match APP_CONFIG.sqlite_journal_mode {
+                app_config::SqliteJournalMode::Delete => SqliteJournalMode::Delete,
+                app_config::SqliteJournalMode::Truncate => SqliteJournalMode::Truncate,
+                app_config::SqliteJournalMode::Persist => SqliteJournalMode::Persist,
+                app_config::SqliteJournalMode::Memory => SqliteJournalMode::Memory,
+                app_config::SqliteJournalMode::Wal => SqliteJournalMode::Wal,
+                app_config::SqliteJournalMode::Off => SqliteJournalMode::Off,
+            })
+            .synchronous(match APP_CONFIG.sqlite_synchronous {
+                app_config::SqliteSynchronous::Off => SqliteSynchronous::Off,
+                app_config::SqliteSynchronous::Normal => SqliteSynchronous::Normal,
+                app_config::SqliteSynchronous::Full => SqliteSynchronous::Full,
+                app_config::SqliteSynchronous::Extra => SqliteSynchronous::Extra,
+            })
+            .busy_timeout(Duration::from_millis(APP_CONFIG.sqlite_busy_timeout_ms))
+            .foreign_keys(APP_CONFIG.sqlite_foreign_keys);
 
-        let rb = RBatis::new();
-        rb.link(SqliteDriver {}, &database_url)
+        let pool = SqlitePoolOptions::new()
+            .max_connections(APP_CONFIG.database_max_connections)
+            .connect_with(options)
             .await
-            .expect("Failed to link RBatis with SQLite driver");
+            .map_err(|e| format!("Failed to open SQLite database: {}", e))?;
 
-        Ok(Self { pool: db_pool, rb })
+        Ok(Self { pool })
     }
 
     pub fn get_pool(&self) -> SqlitePool {
         self.pool.clone()
     }
-
-    pub fn get_rbatis(&self) -> RBatis {
-        self.rb.clone()
-    }
 }
@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use tracing::instrument;
+
+use crate::domain::ports::database_backup_port::{DatabaseBackupError, DatabaseBackupPort};
+
+/// Online backup through `VACUUM INTO`, which reads the database in a single transaction while
+/// the server keeps writing to it.
+pub struct SqliteBackup {
+    pool: SqlitePool,
+}
+
+impl SqliteBackup {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DatabaseBackupPort for SqliteBackup {
+    #[instrument(skip(self), fields(destination = %destination.display()), level = "debug")]
+    async fn backup_to(&self, destination: &Path) -> Result<(), DatabaseBackupError> {
+        let destination = destination.to_str().ok_or_else(|| {
+            DatabaseBackupError::BackupFailed("Destination path is not valid UTF-8".into())
+        })?;
+
+        sqlx::query("VACUUM INTO ?")
+            .bind(destination)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DatabaseBackupError::BackupFailed(e.to_string()))?;
+
+        Ok(())
+    }
+}
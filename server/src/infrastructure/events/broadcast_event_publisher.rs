@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::domain::{
+    models::event::DomainEvent,
+    ports::event_publisher_port::{EventPublisherPort, EventSubscriber},
+};
+
+/// In-process event bus. Each subscriber gets its own receiver, drained by a dedicated task.
+#[derive(Clone)]
+pub struct BroadcastEventPublisher {
+    sender: broadcast::Sender<DomainEvent>,
+}
+
+impl BroadcastEventPublisher {
+    const CHANNEL_CAPACITY: usize = 1024;
+
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(Self::CHANNEL_CAPACITY);
+        Self { sender }
+    }
+}
+
+impl Default for BroadcastEventPublisher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventPublisherPort for BroadcastEventPublisher {
+    fn publish(&self, event: DomainEvent) {
+        tracing::debug!(event_id = %event.id, event_type = %event.event_type, "Publishing event");
+
+        // Sending only fails when nobody is subscribed, which is fine.
+        let _ = self.sender.send(event);
+    }
+
+    fn subscribe(&self, name: &'static str, subscriber: Arc<dyn EventSubscriber>) {
+        let mut receiver = self.sender.subscribe();
+
+        tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => subscriber.on_event(&event).await,
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!(
+                            subscriber = name,
+                            skipped,
+                            "Event subscriber lagged behind"
+                        );
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+
+            tracing::debug!(subscriber = name, "Event subscriber stopped");
+        });
+    }
+}
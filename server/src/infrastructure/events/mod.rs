@@ -0,0 +1 @@
+pub mod broadcast_event_publisher;
@@ -0,0 +1,85 @@
+/// Appends one RFC 4180 record to `output`.
+///
+/// Values starting like a formula are prefixed with a quote, since exports end up in
+/// spreadsheets and report contents come from anyone holding an intake key.
+pub fn write_csv_record<'a>(output: &mut String, values: impl IntoIterator<Item = &'a str>) {
+    for (index, value) in values.into_iter().enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+
+        let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+            format!("'{}", value)
+        } else {
+            value.to_string()
+        };
+
+        if value.contains([',', '"', '\r', '\n']) {
+            output.push('"');
+            output.push_str(&value.replace('"', "\"\""));
+            output.push('"');
+        } else {
+            output.push_str(&value);
+        }
+    }
+
+    output.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_back(csv: &str) -> Vec<Vec<String>> {
+        csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(csv.as_bytes())
+            .records()
+            .map(|record| record.unwrap().iter().map(str::to_string).collect())
+            .collect()
+    }
+
+    #[test]
+    fn round_trips_special_characters() {
+        let records = [
+            vec!["id", "title", "description"],
+            vec!["1", "Comma, inside", "Line one\r\nline two\nline three"],
+            vec!["2", "\"Quoted\" title", ""],
+            vec!["3", "Écran noir 🖥", "trailing space "],
+        ];
+
+        let mut output = String::new();
+        for record in &records {
+            write_csv_record(&mut output, record.iter().copied());
+        }
+
+        assert!(output.ends_with("\r\n"));
+        assert_eq!(read_back(&output), records);
+    }
+
+    #[test]
+    fn escapes_values_starting_like_a_formula() {
+        let values = [
+            "=HYPERLINK(\"http://evil\")",
+            "+1",
+            "-2+3",
+            "@SUM(A1)",
+            "\tcmd",
+            "\rcmd",
+        ];
+
+        let mut output = String::new();
+        write_csv_record(&mut output, values);
+
+        let expected: Vec<String> = values.iter().map(|value| format!("'{}", value)).collect();
+        assert_eq!(read_back(&output), [expected]);
+    }
+
+    #[test]
+    fn leaves_other_values_alone() {
+        let mut output = String::new();
+        write_csv_record(&mut output, ["a=b", "1-2", "mail@example.com", "'quoted"]);
+
+        assert_eq!(output, "a=b,1-2,mail@example.com,'quoted\r\n");
+    }
+}
@@ -0,0 +1,3 @@
+pub mod csv_writer;
+pub mod zip_reader;
+pub mod zip_writer;
@@ -0,0 +1,446 @@
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_DIRECTORY_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0606_4b50;
+const ZIP64_END_OF_CENTRAL_DIRECTORY_LOCATOR_SIGNATURE: u32 = 0x0706_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+
+const END_OF_CENTRAL_DIRECTORY_SIZE: usize = 22;
+const ZIP64_LOCATOR_SIZE: usize = 20;
+const MAX_COMMENT_LENGTH: usize = u16::MAX as usize;
+const FLAG_ENCRYPTED: u16 = 1;
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATED: u16 = 8;
+const ZIP64_EXTRA_FIELD_ID: u16 = 0x0001;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ZipReaderError {
+    #[error("Not a ZIP archive")]
+    NotAZipArchive,
+    #[error("Corrupted ZIP archive: {0}")]
+    Corrupted(String),
+    #[error("Entry '{0}' uses an unsupported compression or encryption")]
+    UnsupportedEntry(String),
+    #[error("Entry '{0}' is too large")]
+    EntryTooLarge(String),
+}
+
+type ZipReaderResult<T> = Result<T, ZipReaderError>;
+
+struct ZipEntry {
+    name: String,
+    flags: u16,
+    method: u16,
+    crc32: u32,
+    compressed_size: u64,
+    uncompressed_size: u64,
+    local_header_offset: u64,
+}
+
+/// Reads the ZIP archives written by `ZipStreamWriter`, and the usual ones made by other tools:
+/// stored or deflated entries, ZIP64 included.
+///
+/// Every offset and size comes from the archive, so all of them are bounds checked. Clones share
+/// the archive, to inflate entries on a blocking thread.
+#[derive(Clone)]
+pub struct ZipArchiveReader {
+    data: Bytes,
+    entries: Arc<[ZipEntry]>,
+}
+
+impl ZipArchiveReader {
+    pub fn new(data: Bytes) -> ZipReaderResult<Self> {
+        let entries = read_central_directory(&data)?;
+
+        Ok(Self {
+            data,
+            entries: entries.into(),
+        })
+    }
+
+    pub fn file_names(&self) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .filter(|entry| !entry.name.ends_with('/'))
+            .map(|entry| entry.name.as_str())
+    }
+
+    /// Contents of the entry, `None` when the archive has no such file. Inflating is CPU bound:
+    /// run it on a blocking thread for large entries.
+    pub fn read_file(&self, name: &str, max_size: usize) -> ZipReaderResult<Option<Vec<u8>>> {
+        let Some(entry) = self.entries.iter().find(|entry| entry.name == name) else {
+            return Ok(None);
+        };
+        if entry.flags & FLAG_ENCRYPTED != 0 {
+            return Err(ZipReaderError::UnsupportedEntry(entry.name.clone()));
+        }
+        if entry.uncompressed_size > max_size as u64 {
+            return Err(ZipReaderError::EntryTooLarge(entry.name.clone()));
+        }
+
+        let header_offset = to_offset(entry.local_header_offset)?;
+        if read_u32(&self.data, header_offset)? != LOCAL_FILE_HEADER_SIGNATURE {
+            return Err(corrupted(&format!("invalid local header for '{}'", name)));
+        }
+        let name_length = usize::from(read_u16(&self.data, add(header_offset, 26)?)?);
+        let extra_length = usize::from(read_u16(&self.data, add(header_offset, 28)?)?);
+        let data_offset = add(add(add(header_offset, 30)?, name_length)?, extra_length)?;
+        let compressed = read_bytes(&self.data, data_offset, to_offset(entry.compressed_size)?)?;
+
+        let contents = match entry.method {
+            METHOD_STORED => compressed.to_vec(),
+            METHOD_DEFLATED => {
+                miniz_oxide::inflate::decompress_to_vec_with_limit(compressed, max_size)
+                    .map_err(|_| corrupted(&format!("cannot inflate '{}'", name)))?
+            }
+            _ => return Err(ZipReaderError::UnsupportedEntry(entry.name.clone())),
+        };
+
+        if contents.len() as u64 != entry.uncompressed_size
+            || crc32fast::hash(&contents) != entry.crc32
+        {
+            return Err(corrupted(&format!("checksum mismatch for '{}'", name)));
+        }
+
+        Ok(Some(contents))
+    }
+}
+
+fn read_central_directory(data: &[u8]) -> ZipReaderResult<Vec<ZipEntry>> {
+    let end_offset = find_end_of_central_directory(data)?;
+    // The end record is 22 bytes long, offsets within it cannot overflow.
+    let mut entry_count = u64::from(read_u16(data, end_offset + 10)?);
+    let mut central_directory_offset = u64::from(read_u32(data, end_offset + 16)?);
+
+    let locator_offset = end_offset.checked_sub(ZIP64_LOCATOR_SIZE);
+    if let Some(locator_offset) = locator_offset.filter(|offset| {
+        read_u32(data, *offset).ok() == Some(ZIP64_END_OF_CENTRAL_DIRECTORY_LOCATOR_SIGNATURE)
+    }) {
+        let zip64_end_offset = to_offset(read_u64(data, add(locator_offset, 8)?)?)?;
+        if read_u32(data, zip64_end_offset)? != ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE {
+            return Err(corrupted("invalid ZIP64 end of central directory"));
+        }
+        entry_count = read_u64(data, add(zip64_end_offset, 32)?)?;
+        central_directory_offset = read_u64(data, add(zip64_end_offset, 48)?)?;
+    }
+
+    // A forged entry count runs into the end of the archive, each entry being 46 bytes or more.
+    let mut entries = Vec::new();
+    let mut offset = to_offset(central_directory_offset)?;
+    for _ in 0..entry_count {
+        let (entry, entry_length) = read_central_directory_entry(data, offset)?;
+        entries.push(entry);
+        offset = add(offset, entry_length)?;
+    }
+
+    Ok(entries)
+}
+
+/// The end record sits at the very end of the archive, unless it is followed by a comment.
+fn find_end_of_central_directory(data: &[u8]) -> ZipReaderResult<usize> {
+    let last_candidate = data
+        .len()
+        .checked_sub(END_OF_CENTRAL_DIRECTORY_SIZE)
+        .ok_or(ZipReaderError::NotAZipArchive)?;
+    let first_candidate = last_candidate.saturating_sub(MAX_COMMENT_LENGTH);
+
+    (first_candidate..=last_candidate)
+        .rev()
+        .find(|offset| read_u32(data, *offset).ok() == Some(END_OF_CENTRAL_DIRECTORY_SIGNATURE))
+        .ok_or(ZipReaderError::NotAZipArchive)
+}
+
+fn read_central_directory_entry(data: &[u8], offset: usize) -> ZipReaderResult<(ZipEntry, usize)> {
+    if read_u32(data, offset)? != CENTRAL_DIRECTORY_HEADER_SIGNATURE {
+        return Err(corrupted("invalid central directory entry"));
+    }
+
+    // The fixed part of the entry is 46 bytes long: once it is known to be in the archive,
+    // offsets within it cannot overflow.
+    let header = read_bytes(data, offset, 46)?;
+    let name_length = usize::from(read_u16(header, 28)?);
+    let extra_length = usize::from(read_u16(header, 30)?);
+    let comment_length = usize::from(read_u16(header, 32)?);
+    let name_offset = add(offset, 46)?;
+    let name_bytes = read_bytes(data, name_offset, name_length)?;
+    let extra = read_bytes(data, add(name_offset, name_length)?, extra_length)?;
+
+    let mut entry = ZipEntry {
+        name: String::from_utf8_lossy(name_bytes).into_owned(),
+        flags: read_u16(header, 8)?,
+        method: read_u16(header, 10)?,
+        crc32: read_u32(header, 16)?,
+        compressed_size: u64::from(read_u32(header, 20)?),
+        uncompressed_size: u64::from(read_u32(header, 24)?),
+        local_header_offset: u64::from(read_u32(header, 42)?),
+    };
+    apply_zip64_extra_field(&mut entry, extra)?;
+
+    // At most 46 + 3 * u16::MAX.
+    Ok((entry, 46 + name_length + extra_length + comment_length))
+}
+
+/// ZIP64 values replace, in this order, the fields saturated at `u32::MAX`.
+fn apply_zip64_extra_field(entry: &mut ZipEntry, extra: &[u8]) -> ZipReaderResult<()> {
+    let mut offset = 0;
+    // The extra field is at most `u16::MAX` bytes long, offsets within it cannot overflow.
+    while offset + 4 <= extra.len() {
+        let field_id = read_u16(extra, offset)?;
+        let field_length = usize::from(read_u16(extra, offset + 2)?);
+        if field_id == ZIP64_EXTRA_FIELD_ID {
+            let mut value_offset = offset + 4;
+            for value in [
+                &mut entry.uncompressed_size,
+                &mut entry.compressed_size,
+                &mut entry.local_header_offset,
+            ] {
+                if *value == u64::from(u32::MAX) {
+                    *value = read_u64(extra, value_offset)?;
+                    value_offset += 8;
+                }
+            }
+            return Ok(());
+        }
+        offset += 4 + field_length;
+    }
+
+    Ok(())
+}
+
+fn corrupted(message: &str) -> ZipReaderError {
+    ZipReaderError::Corrupted(message.to_string())
+}
+
+fn to_offset(value: u64) -> ZipReaderResult<usize> {
+    usize::try_from(value).map_err(|_| corrupted("offset out of range"))
+}
+
+fn add(offset: usize, length: usize) -> ZipReaderResult<usize> {
+    offset
+        .checked_add(length)
+        .ok_or_else(|| corrupted("offset out of range"))
+}
+
+fn read_bytes(data: &[u8], offset: usize, length: usize) -> ZipReaderResult<&[u8]> {
+    offset
+        .checked_add(length)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| corrupted("unexpected end of archive"))
+}
+
+fn read_u16(data: &[u8], offset: usize) -> ZipReaderResult<u16> {
+    let bytes = read_bytes(data, offset, 2)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> ZipReaderResult<u32> {
+    let bytes = read_bytes(data, offset, 4)?;
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> ZipReaderResult<u64> {
+    let bytes = read_bytes(data, offset, 8)?;
+    let mut value = [0; 8];
+    value.copy_from_slice(bytes);
+    Ok(u64::from_le_bytes(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::infrastructure::export::zip_writer::{ZipCompression, ZipStreamWriter};
+
+    const MAX_SIZE: usize = 1024 * 1024;
+
+    fn archive(files: &[(&str, &[u8], ZipCompression)]) -> Vec<u8> {
+        let mut writer = ZipStreamWriter::new();
+        let mut output = Vec::new();
+        for (name, data, compression) in files {
+            output.extend(
+                writer
+                    .add_file(name, data, *compression, Utc::now())
+                    .unwrap(),
+            );
+        }
+        output.extend(writer.finish());
+        output
+    }
+
+    fn reader(data: &[u8]) -> ZipReaderResult<ZipArchiveReader> {
+        ZipArchiveReader::new(Bytes::copy_from_slice(data))
+    }
+
+    fn find(data: &[u8], signature: u32) -> usize {
+        data.windows(4)
+            .rposition(|window| window == signature.to_le_bytes())
+            .unwrap()
+    }
+
+    fn patch(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    #[test]
+    fn reads_back_written_archives() {
+        let text = "manifest ".repeat(1000);
+        let data = archive(&[
+            ("media/a.png", b"\x89PNG data", ZipCompression::Stored),
+            ("manifest.json", text.as_bytes(), ZipCompression::Deflated),
+        ]);
+
+        let reader = reader(&data).unwrap();
+
+        assert_eq!(
+            reader.file_names().collect::<Vec<_>>(),
+            ["media/a.png", "manifest.json"]
+        );
+        assert_eq!(
+            reader.read_file("media/a.png", MAX_SIZE).unwrap().unwrap(),
+            b"\x89PNG data"
+        );
+        assert_eq!(
+            reader
+                .read_file("manifest.json", MAX_SIZE)
+                .unwrap()
+                .unwrap(),
+            text.as_bytes()
+        );
+        assert!(reader.read_file("missing", MAX_SIZE).unwrap().is_none());
+    }
+
+    #[test]
+    fn rejects_non_archives() {
+        for data in [&b""[..], b"PK", b"not a zip archive at all, really not"] {
+            assert!(matches!(reader(data), Err(ZipReaderError::NotAZipArchive)));
+        }
+    }
+
+    #[test]
+    fn rejects_truncated_archives() {
+        let data = archive(&[
+            (
+                "a.txt",
+                "aaaa".repeat(100).as_bytes(),
+                ZipCompression::Deflated,
+            ),
+            ("b.txt", b"b", ZipCompression::Stored),
+        ]);
+
+        for length in 0..data.len() {
+            let result = reader(&data[..length]).and_then(|reader| {
+                reader.read_file("a.txt", MAX_SIZE)?;
+                reader.read_file("b.txt", MAX_SIZE)
+            });
+            assert!(result.is_err(), "truncated to {} bytes", length);
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_offsets() {
+        let data = archive(&[("a.txt", b"a", ZipCompression::Stored)]);
+        let end_offset = find(&data, END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        let directory_offset = find(&data, CENTRAL_DIRECTORY_HEADER_SIGNATURE);
+
+        // Central directory past the end of the archive.
+        let mut forged = data.clone();
+        patch(&mut forged, end_offset + 16, &u32::MAX.to_le_bytes());
+        assert!(matches!(reader(&forged), Err(ZipReaderError::Corrupted(_))));
+
+        // More entries than the archive holds.
+        let mut forged = data.clone();
+        patch(&mut forged, end_offset + 10, &u16::MAX.to_le_bytes());
+        assert!(matches!(reader(&forged), Err(ZipReaderError::Corrupted(_))));
+
+        // ZIP64 end record near the end of the address space.
+        let mut forged = data[..end_offset].to_vec();
+        forged.extend(ZIP64_END_OF_CENTRAL_DIRECTORY_LOCATOR_SIGNATURE.to_le_bytes());
+        forged.extend(0u32.to_le_bytes());
+        forged.extend((u64::MAX - 8).to_le_bytes());
+        forged.extend(1u32.to_le_bytes());
+        forged.extend(&data[end_offset..]);
+        assert!(matches!(reader(&forged), Err(ZipReaderError::Corrupted(_))));
+
+        // ZIP64 local header offset near the end of the address space.
+        let mut forged = data[..directory_offset].to_vec();
+        let mut entry = data[directory_offset..end_offset].to_vec();
+        patch(&mut entry, 30, &12u16.to_le_bytes());
+        patch(&mut entry, 42, &u32::MAX.to_le_bytes());
+        entry.extend(ZIP64_EXTRA_FIELD_ID.to_le_bytes());
+        entry.extend(8u16.to_le_bytes());
+        entry.extend((u64::MAX - 4).to_le_bytes());
+        forged.extend(&entry);
+        let mut end_record = data[end_offset..].to_vec();
+        patch(&mut end_record, 12, &(entry.len() as u32).to_le_bytes());
+        forged.extend(end_record);
+        let reader = reader(&forged).unwrap();
+        assert!(matches!(
+            reader.read_file("a.txt", MAX_SIZE),
+            Err(ZipReaderError::Corrupted(_))
+        ));
+    }
+
+    #[test]
+    fn limits_the_inflated_size() {
+        let zeros = vec![0; 4 * MAX_SIZE];
+        let data = archive(&[("bomb", &zeros, ZipCompression::Deflated)]);
+
+        let archive = reader(&data).unwrap();
+        assert!(matches!(
+            archive.read_file("bomb", MAX_SIZE),
+            Err(ZipReaderError::EntryTooLarge(_))
+        ));
+
+        // Sizes understated in the central directory do not lift the limit.
+        let mut forged = data.clone();
+        let directory_offset = find(&forged, CENTRAL_DIRECTORY_HEADER_SIGNATURE);
+        patch(&mut forged, directory_offset + 24, &16u32.to_le_bytes());
+        let archive = reader(&forged).unwrap();
+        assert!(matches!(
+            archive.read_file("bomb", MAX_SIZE),
+            Err(ZipReaderError::Corrupted(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_corrupted_and_unsupported_entries() {
+        let data = archive(&[("a.txt", b"hello", ZipCompression::Stored)]);
+        let directory_offset = find(&data, CENTRAL_DIRECTORY_HEADER_SIGNATURE);
+
+        let mut forged = data.clone();
+        patch(&mut forged, 30 + "a.txt".len(), b"j");
+        assert!(matches!(
+            reader(&forged).unwrap().read_file("a.txt", MAX_SIZE),
+            Err(ZipReaderError::Corrupted(_))
+        ));
+
+        let mut forged = data.clone();
+        patch(
+            &mut forged,
+            directory_offset + 8,
+            &FLAG_ENCRYPTED.to_le_bytes(),
+        );
+        assert!(matches!(
+            reader(&forged).unwrap().read_file("a.txt", MAX_SIZE),
+            Err(ZipReaderError::UnsupportedEntry(_))
+        ));
+
+        let mut forged = data.clone();
+        patch(&mut forged, directory_offset + 10, &12u16.to_le_bytes());
+        assert!(matches!(
+            reader(&forged).unwrap().read_file("a.txt", MAX_SIZE),
+            Err(ZipReaderError::UnsupportedEntry(_))
+        ));
+
+        let mut forged = data.clone();
+        patch(&mut forged, directory_offset + 42, &1u32.to_le_bytes());
+        assert!(matches!(
+            reader(&forged).unwrap().read_file("a.txt", MAX_SIZE),
+            Err(ZipReaderError::Corrupted(_))
+        ));
+    }
+}
@@ -0,0 +1,347 @@
+use chrono::{DateTime, Datelike, Timelike, Utc};
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_DIRECTORY_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0606_4b50;
+const ZIP64_END_OF_CENTRAL_DIRECTORY_LOCATOR_SIGNATURE: u32 = 0x0706_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+
+const VERSION_DEFAULT: u16 = 20;
+const VERSION_ZIP64: u16 = 45;
+/// Unix host, so that the external attributes below are read as permissions.
+const VERSION_MADE_BY: u16 = (3 << 8) | VERSION_ZIP64;
+const FLAG_UTF8_NAMES: u16 = 1 << 11;
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATED: u16 = 8;
+const REGULAR_FILE_ATTRIBUTES: u32 = 0o100644 << 16;
+const ZIP64_EXTRA_FIELD_ID: u16 = 0x0001;
+const DEFLATE_LEVEL: u8 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZipCompression {
+    /// For already compressed media.
+    Stored,
+    Deflated,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ZipWriterError {
+    #[error("Entry '{0}' is too large for the archive")]
+    EntryTooLarge(String),
+}
+
+struct CentralDirectoryEntry {
+    name: String,
+    method: u16,
+    dos_time: u16,
+    dos_date: u16,
+    crc32: u32,
+    compressed_size: u32,
+    uncompressed_size: u32,
+    offset: u64,
+}
+
+/// Builds a ZIP archive one entry at a time, so that it can be streamed without holding more
+/// than one file in memory. Archives beyond 4 GiB use the ZIP64 extensions, single entries are
+/// limited to 4 GiB.
+#[derive(Default)]
+pub struct ZipStreamWriter {
+    offset: u64,
+    entries: Vec<CentralDirectoryEntry>,
+}
+
+impl ZipStreamWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bytes of the entry to append to the archive: its local header followed by its data.
+    pub fn add_file(
+        &mut self,
+        name: &str,
+        data: &[u8],
+        compression: ZipCompression,
+        modified_at: DateTime<Utc>,
+    ) -> Result<Vec<u8>, ZipWriterError> {
+        let crc32 = crc32fast::hash(data);
+
+        let deflated = match compression {
+            ZipCompression::Deflated => {
+                Some(miniz_oxide::deflate::compress_to_vec(data, DEFLATE_LEVEL))
+                    .filter(|deflated| deflated.len() < data.len())
+            }
+            ZipCompression::Stored => None,
+        };
+        let (method, contents) = match &deflated {
+            Some(deflated) => (METHOD_DEFLATED, deflated.as_slice()),
+            None => (METHOD_STORED, data),
+        };
+
+        let too_large = || ZipWriterError::EntryTooLarge(name.to_string());
+        let uncompressed_size = u32::try_from(data.len())
+            .ok()
+            .filter(|size| *size != u32::MAX)
+            .ok_or_else(too_large)?;
+        let compressed_size = u32::try_from(contents.len())
+            .ok()
+            .filter(|size| *size != u32::MAX)
+            .ok_or_else(too_large)?;
+        let name_length = u16::try_from(name.len()).map_err(|_| too_large())?;
+        let (dos_time, dos_date) = to_dos_date_time(modified_at);
+
+        let mut output = Vec::with_capacity(30 + name.len() + contents.len());
+        put_u32(&mut output, LOCAL_FILE_HEADER_SIGNATURE);
+        put_u16(&mut output, VERSION_DEFAULT);
+        put_u16(&mut output, FLAG_UTF8_NAMES);
+        put_u16(&mut output, method);
+        put_u16(&mut output, dos_time);
+        put_u16(&mut output, dos_date);
+        put_u32(&mut output, crc32);
+        put_u32(&mut output, compressed_size);
+        put_u32(&mut output, uncompressed_size);
+        put_u16(&mut output, name_length);
+        put_u16(&mut output, 0);
+        output.extend_from_slice(name.as_bytes());
+        output.extend_from_slice(contents);
+
+        self.entries.push(CentralDirectoryEntry {
+            name: name.to_string(),
+            method,
+            dos_time,
+            dos_date,
+            crc32,
+            compressed_size,
+            uncompressed_size,
+            offset: self.offset,
+        });
+        self.offset += output.len() as u64;
+
+        Ok(output)
+    }
+
+    /// Bytes closing the archive: the central directory and its end records.
+    pub fn finish(self) -> Vec<u8> {
+        let mut output = Vec::new();
+        let central_directory_offset = self.offset;
+
+        for entry in &self.entries {
+            let needs_zip64 = entry.offset >= u64::from(u32::MAX);
+
+            put_u32(&mut output, CENTRAL_DIRECTORY_HEADER_SIGNATURE);
+            put_u16(&mut output, VERSION_MADE_BY);
+            put_u16(
+                &mut output,
+                if needs_zip64 {
+                    VERSION_ZIP64
+                } else {
+                    VERSION_DEFAULT
+                },
+            );
+            put_u16(&mut output, FLAG_UTF8_NAMES);
+            put_u16(&mut output, entry.method);
+            put_u16(&mut output, entry.dos_time);
+            put_u16(&mut output, entry.dos_date);
+            put_u32(&mut output, entry.crc32);
+            put_u32(&mut output, entry.compressed_size);
+            put_u32(&mut output, entry.uncompressed_size);
+            put_u16(&mut output, entry.name.len() as u16);
+            put_u16(&mut output, if needs_zip64 { 12 } else { 0 });
+            put_u16(&mut output, 0); // comment length
+            put_u16(&mut output, 0); // disk number
+            put_u16(&mut output, 0); // internal attributes
+            put_u32(&mut output, REGULAR_FILE_ATTRIBUTES);
+            put_u32(
+                &mut output,
+                if needs_zip64 {
+                    u32::MAX
+                } else {
+                    entry.offset as u32
+                },
+            );
+            output.extend_from_slice(entry.name.as_bytes());
+            if needs_zip64 {
+                put_u16(&mut output, ZIP64_EXTRA_FIELD_ID);
+                put_u16(&mut output, 8);
+                put_u64(&mut output, entry.offset);
+            }
+        }
+
+        let central_directory_size = output.len() as u64;
+        let entry_count = self.entries.len() as u64;
+        let needs_zip64 = entry_count >= u64::from(u16::MAX)
+            || central_directory_offset >= u64::from(u32::MAX)
+            || central_directory_size >= u64::from(u32::MAX);
+
+        if needs_zip64 {
+            let zip64_end_offset = central_directory_offset + central_directory_size;
+
+            put_u32(&mut output, ZIP64_END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+            put_u64(&mut output, 44); // size of the remaining record
+            put_u16(&mut output, VERSION_MADE_BY);
+            put_u16(&mut output, VERSION_ZIP64);
+            put_u32(&mut output, 0); // disk number
+            put_u32(&mut output, 0); // disk with the central directory
+            put_u64(&mut output, entry_count);
+            put_u64(&mut output, entry_count);
+            put_u64(&mut output, central_directory_size);
+            put_u64(&mut output, central_directory_offset);
+
+            put_u32(
+                &mut output,
+                ZIP64_END_OF_CENTRAL_DIRECTORY_LOCATOR_SIGNATURE,
+            );
+            put_u32(&mut output, 0); // disk with the ZIP64 end record
+            put_u64(&mut output, zip64_end_offset);
+            put_u32(&mut output, 1); // total number of disks
+        }
+
+        put_u32(&mut output, END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        put_u16(&mut output, 0); // disk number
+        put_u16(&mut output, 0); // disk with the central directory
+        let short_entry_count = u16::try_from(entry_count).unwrap_or(u16::MAX);
+        put_u16(&mut output, short_entry_count);
+        put_u16(&mut output, short_entry_count);
+        put_u32(
+            &mut output,
+            u32::try_from(central_directory_size).unwrap_or(u32::MAX),
+        );
+        put_u32(
+            &mut output,
+            u32::try_from(central_directory_offset).unwrap_or(u32::MAX),
+        );
+        put_u16(&mut output, 0); // comment length
+
+        output
+    }
+}
+
+/// MS-DOS timestamps cannot go below 1980 and have a two seconds resolution.
+fn to_dos_date_time(date_time: DateTime<Utc>) -> (u16, u16) {
+    if date_time.year() < 1980 {
+        return (0, (1 << 5) | 1);
+    }
+
+    let time = (date_time.hour() << 11) | (date_time.minute() << 5) | (date_time.second() / 2);
+    let date = (((date_time.year() as u32 - 1980).min(127)) << 9)
+        | (date_time.month() << 5)
+        | date_time.day();
+    (time as u16, date as u16)
+}
+
+fn put_u16(output: &mut Vec<u8>, value: u16) {
+    output.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(output: &mut Vec<u8>, value: u32) {
+    output.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u64(output: &mut Vec<u8>, value: u64) {
+    output.extend_from_slice(&value.to_le_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn write_archive(
+        files: &[(&str, &[u8], ZipCompression)],
+        modified_at: DateTime<Utc>,
+    ) -> Vec<u8> {
+        let mut writer = ZipStreamWriter::new();
+        let mut output = Vec::new();
+        for (name, data, compression) in files {
+            output.extend(
+                writer
+                    .add_file(name, data, *compression, modified_at)
+                    .unwrap(),
+            );
+        }
+        output.extend(writer.finish());
+        output
+    }
+
+    #[test]
+    fn archives_are_read_by_other_tools() {
+        let modified_at = Utc.with_ymd_and_hms(2024, 5, 17, 13, 45, 31).unwrap();
+        let text = "{\"reports\": []}\n".repeat(100);
+        let archive = write_archive(
+            &[
+                ("media/é.png", b"\x89PNG", ZipCompression::Stored),
+                ("manifest.json", text.as_bytes(), ZipCompression::Deflated),
+                // Kept as is, since deflating does not make it smaller.
+                ("tiny.txt", b"x", ZipCompression::Deflated),
+            ],
+            modified_at,
+        );
+
+        let mut zip = zip::ZipArchive::new(Cursor::new(archive)).unwrap();
+        assert_eq!(zip.len(), 3);
+
+        let expected: [(&str, &[u8], zip::CompressionMethod); 3] = [
+            ("media/é.png", b"\x89PNG", zip::CompressionMethod::Stored),
+            (
+                "manifest.json",
+                text.as_bytes(),
+                zip::CompressionMethod::Deflated,
+            ),
+            ("tiny.txt", b"x", zip::CompressionMethod::Stored),
+        ];
+        for (index, (name, data, method)) in expected.into_iter().enumerate() {
+            let mut file = zip.by_index(index).unwrap();
+            assert_eq!(file.name(), name);
+            assert_eq!(file.compression(), method);
+            assert_eq!(file.unix_mode(), Some(0o100644));
+
+            let modified = file.last_modified().unwrap();
+            assert_eq!(
+                (modified.year(), modified.month(), modified.day()),
+                (2024, 5, 17)
+            );
+            // Two seconds resolution.
+            assert_eq!(
+                (modified.hour(), modified.minute(), modified.second()),
+                (13, 45, 30)
+            );
+
+            let mut contents = Vec::new();
+            file.read_to_end(&mut contents).unwrap();
+            assert_eq!(contents, data);
+        }
+    }
+
+    #[test]
+    fn switches_to_zip64_beyond_u16_entries() {
+        let names: Vec<String> = (0..70_000).map(|index| format!("{}", index)).collect();
+        let files: Vec<(&str, &[u8], ZipCompression)> = names
+            .iter()
+            .map(|name| (name.as_str(), &b""[..], ZipCompression::Stored))
+            .collect();
+        let archive = write_archive(&files, Utc::now());
+
+        let mut zip = zip::ZipArchive::new(Cursor::new(archive)).unwrap();
+        assert_eq!(zip.len(), 70_000);
+        assert_eq!(zip.by_index(69_999).unwrap().name(), "69999");
+    }
+
+    #[test]
+    fn clamps_dates_before_1980() {
+        let date_time = Utc.with_ymd_and_hms(1970, 1, 1, 12, 0, 0).unwrap();
+
+        assert_eq!(to_dos_date_time(date_time), (0, (1 << 5) | 1));
+    }
+
+    #[test]
+    fn rejects_names_longer_than_the_format_allows() {
+        let name = "a".repeat(usize::from(u16::MAX) + 1);
+
+        assert!(matches!(
+            ZipStreamWriter::new().add_file(&name, b"", ZipCompression::Stored, Utc::now()),
+            Err(ZipWriterError::EntryTooLarge(_))
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use sqlx::{
+    Connection, Database, Pool,
+    migrate::{Migrate, Migrator},
+};
+
+use crate::domain::ports::health_probe_port::{
+    HealthProbeError, HealthProbePort, HealthProbeResult,
+};
+
+/// Pings the database through a connection of the pool.
+pub struct SqlxPoolProbe<DB: Database> {
+    pool: Pool<DB>,
+}
+
+impl<DB: Database> SqlxPoolProbe<DB> {
+    pub fn new(pool: Pool<DB>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl<DB: Database> HealthProbePort for SqlxPoolProbe<DB> {
+    fn component(&self) -> &'static str {
+        "database"
+    }
+
+    async fn probe(&self) -> HealthProbeResult {
+        let unhealthy = |e: sqlx::Error| HealthProbeError::Unhealthy(e.to_string());
+
+        let mut connection = self.pool.acquire().await.map_err(unhealthy)?;
+        connection.ping().await.map_err(unhealthy)?;
+        drop(connection);
+
+        Ok(Some(format!(
+            "{} connections, {} idle",
+            self.pool.size(),
+            self.pool.num_idle()
+        )))
+    }
+}
+
+/// Compares the migrations applied to the database with the ones embedded in the binary.
+pub struct MigrationProbe<DB: Database> {
+    pool: Pool<DB>,
+    migrator: &'static Migrator,
+}
+
+impl<DB: Database> MigrationProbe<DB> {
+    pub fn new(pool: Pool<DB>, migrator: &'static Migrator) -> Self {
+        Self { pool, migrator }
+    }
+}
+
+#[async_trait]
+impl<DB: Database> HealthProbePort for MigrationProbe<DB>
+where
+    DB::Connection: Migrate,
+{
+    fn component(&self) -> &'static str {
+        "migrations"
+    }
+
+    async fn probe(&self) -> HealthProbeResult {
+        let unhealthy = |e: sqlx::Error| HealthProbeError::Unhealthy(e.to_string());
+
+        let mut connection = self.pool.acquire().await.map_err(unhealthy)?;
+        if let Some(version) = connection
+            .dirty_version()
+            .await
+            .map_err(|e| unhealthy(e.into()))?
+        {
+            return Err(HealthProbeError::Unhealthy(format!(
+                "Migration {} failed partway",
+                version
+            )));
+        }
+
+        let applied: HashMap<i64, _> = connection
+            .list_applied_migrations()
+            .await
+            .map_err(|e| unhealthy(e.into()))?
+            .into_iter()
+            .map(|migration| (migration.version, migration.checksum))
+            .collect();
+
+        let mut known_count = 0;
+        for migration in self
+            .migrator
+            .iter()
+            .filter(|migration| !migration.migration_type.is_down_migration())
+        {
+            match applied.get(&migration.version) {
+                None => {
+                    return Err(HealthProbeError::Unhealthy(format!(
+                        "Migration {} is pending",
+                        migration.version
+                    )));
+                }
+                Some(checksum) if *checksum != migration.checksum => {
+                    return Err(HealthProbeError::Unhealthy(format!(
+                        "Migration {} was modified after being applied",
+                        migration.version
+                    )));
+                }
+                Some(_) => known_count += 1,
+            }
+        }
+        if applied.len() > known_count {
+            return Err(HealthProbeError::Unhealthy(
+                "The database has migrations this version does not know".into(),
+            ));
+        }
+
+        Ok(Some(format!("{} applied", known_count)))
+    }
+}
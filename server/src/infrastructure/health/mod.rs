@@ -0,0 +1,2 @@
+pub mod database_probes;
+pub mod storage_probes;
@@ -0,0 +1,85 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use tokio::fs;
+use uuid::Uuid;
+
+use crate::domain::ports::health_probe_port::{
+    HealthProbeError, HealthProbePort, HealthProbeResult,
+};
+
+/// Writes and removes a file in the upload directory.
+pub struct StorageWritableProbe {
+    directory: PathBuf,
+}
+
+impl StorageWritableProbe {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl HealthProbePort for StorageWritableProbe {
+    fn component(&self) -> &'static str {
+        "storage"
+    }
+
+    async fn probe(&self) -> HealthProbeResult {
+        let probe_path = self
+            .directory
+            .join(format!(".health-{}", Uuid::new_v4().simple()));
+
+        fs::write(&probe_path, b"ok")
+            .await
+            .map_err(|e| HealthProbeError::Unhealthy(format!("Cannot write files: {}", e)))?;
+        fs::remove_file(&probe_path)
+            .await
+            .map_err(|e| HealthProbeError::Unhealthy(format!("Cannot remove files: {}", e)))?;
+
+        Ok(None)
+    }
+}
+
+/// Checks the free space left on the file system of the upload directory.
+pub struct DiskSpaceProbe {
+    directory: PathBuf,
+    min_free_bytes: u64,
+}
+
+impl DiskSpaceProbe {
+    pub fn new(directory: impl Into<PathBuf>, min_free_bytes: u64) -> Self {
+        Self {
+            directory: directory.into(),
+            min_free_bytes,
+        }
+    }
+}
+
+#[async_trait]
+impl HealthProbePort for DiskSpaceProbe {
+    fn component(&self) -> &'static str {
+        "disk_space"
+    }
+
+    async fn probe(&self) -> HealthProbeResult {
+        let directory = self.directory.clone();
+        let free_bytes = tokio::task::spawn_blocking(move || fs4::available_space(directory))
+            .await
+            .map_err(|e| HealthProbeError::Unhealthy(e.to_string()))?
+            .map_err(|e| HealthProbeError::Unhealthy(e.to_string()))?;
+
+        let free_mb = free_bytes / (1024 * 1024);
+        if free_bytes < self.min_free_bytes {
+            return Err(HealthProbeError::Unhealthy(format!(
+                "Only {} MB free, below the {} MB minimum",
+                free_mb,
+                self.min_free_bytes / (1024 * 1024)
+            )));
+        }
+
+        Ok(Some(format!("{} MB free", free_mb)))
+    }
+}
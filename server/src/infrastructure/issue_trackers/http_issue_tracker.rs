@@ -0,0 +1,320 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder, Response, Url, header};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        models::issue_tracker::{
+            CreatedIssue, InboundWebhook, IssueAction, IssueEvent, IssueTrackerIntegration,
+            IssueTrackerProvider, NewIssue,
+        },
+        ports::issue_tracker_port::{IssueTrackerError, IssueTrackerPort},
+    },
+    infrastructure::security::{
+        public_addresses::{check_ip_host, outbound_client_builder},
+        webhook_signature::{verify_signature, verify_token},
+    },
+};
+
+const GITHUB_ACCEPT: &str = "application/vnd.github+json";
+const GITHUB_API_VERSION_HEADER: &str = "X-GitHub-Api-Version";
+const GITHUB_API_VERSION: &str = "2022-11-28";
+const GITLAB_TOKEN_HEADER: &str = "PRIVATE-TOKEN";
+const MAX_ERROR_MESSAGE_LENGTH: usize = 200;
+
+const GITHUB_EVENT_HEADER: &str = "x-github-event";
+const GITHUB_SIGNATURE_HEADER: &str = "x-hub-signature-256";
+const GITHUB_ISSUES_EVENT: &str = "issues";
+const GITLAB_EVENT_HEADER: &str = "x-gitlab-event";
+const GITLAB_WEBHOOK_TOKEN_HEADER: &str = "x-gitlab-token";
+const GITLAB_ISSUE_EVENT: &str = "Issue Hook";
+
+#[derive(Deserialize)]
+struct GitHubIssue {
+    number: u64,
+    html_url: String,
+}
+
+#[derive(Deserialize)]
+struct GitLabIssue {
+    iid: u64,
+    web_url: String,
+}
+
+#[derive(Deserialize)]
+struct GitHubIssuesEvent {
+    action: String,
+    issue: GitHubEventIssue,
+    repository: GitHubEventRepository,
+}
+
+#[derive(Deserialize)]
+struct GitHubEventIssue {
+    number: u64,
+}
+
+#[derive(Deserialize)]
+struct GitHubEventRepository {
+    full_name: String,
+}
+
+#[derive(Deserialize)]
+struct GitLabIssueEvent {
+    object_attributes: GitLabEventIssue,
+    project: GitLabEventProject,
+}
+
+#[derive(Deserialize)]
+struct GitLabEventIssue {
+    iid: u64,
+    #[serde(default)]
+    action: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GitLabEventProject {
+    path_with_namespace: String,
+}
+
+/// Talks to the GitHub and GitLab REST APIs.
+#[derive(Clone)]
+pub struct HttpIssueTracker {
+    client: Client,
+    allow_private_networks: bool,
+}
+
+impl HttpIssueTracker {
+    /// Unless `allow_private_networks` is set, requests only reach public addresses, since the
+    /// API URL can be chosen by board members. Redirects are never followed.
+    #[instrument(name = "HttpIssueTracker::new", level = "debug")]
+    pub fn new(
+        timeout_seconds: u64,
+        allow_private_networks: bool,
+    ) -> Result<Self, IssueTrackerError> {
+        let client = outbound_client_builder(allow_private_networks)
+            .timeout(Duration::from_secs(timeout_seconds))
+            .user_agent(concat!("rebug/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| IssueTrackerError::ConfigurationError(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            allow_private_networks,
+        })
+    }
+
+    /// Appends path segments to the API base URL, percent-encoding each of them. URLs holding an
+    /// IP address are not resolved, so they are checked here.
+    fn endpoint(&self, api_base_url: &str, segments: &[&str]) -> Result<Url, IssueTrackerError> {
+        let mut url = Url::parse(api_base_url.trim_end_matches('/'))
+            .map_err(|e| IssueTrackerError::ConfigurationError(e.to_string()))?;
+
+        url.path_segments_mut()
+            .map_err(|_| {
+                IssueTrackerError::ConfigurationError("Invalid issue tracker API URL".to_string())
+            })?
+            .pop_if_empty()
+            .extend(segments);
+
+        if !self.allow_private_networks {
+            check_ip_host(&url).map_err(|e| IssueTrackerError::RequestFailed(e.to_string()))?;
+        }
+
+        Ok(url)
+    }
+
+    async fn send(request: RequestBuilder) -> Result<Response, IssueTrackerError> {
+        let response = request
+            .send()
+            .await
+            .map_err(|e| IssueTrackerError::RequestFailed(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let body = response.text().await.unwrap_or_default();
+        Err(IssueTrackerError::Rejected {
+            status: status.as_u16(),
+            message: Self::error_message(&body),
+        })
+    }
+
+    /// Start of the response body, cut on a character boundary.
+    fn error_message(body: &str) -> String {
+        body.chars().take(MAX_ERROR_MESSAGE_LENGTH).collect()
+    }
+
+    async fn create_github_issue(
+        &self,
+        integration: &IssueTrackerIntegration,
+        issue: &NewIssue,
+    ) -> Result<CreatedIssue, IssueTrackerError> {
+        let (owner, repo) = integration.repository.split_once('/').ok_or_else(|| {
+            IssueTrackerError::ConfigurationError(
+                "GitHub repository must read `owner/repo`".to_string(),
+            )
+        })?;
+        let url = self.endpoint(&integration.api_base_url, &["repos", owner, repo, "issues"])?;
+
+        let request = self
+            .client
+            .post(url)
+            .bearer_auth(&integration.access_token)
+            .header(header::ACCEPT, GITHUB_ACCEPT)
+            .header(GITHUB_API_VERSION_HEADER, GITHUB_API_VERSION)
+            .json(&json!({ "title": issue.title, "body": issue.body }));
+
+        let created: GitHubIssue = Self::send(request)
+            .await?
+            .json()
+            .await
+            .map_err(|e| IssueTrackerError::InvalidResponse(e.to_string()))?;
+
+        Ok(CreatedIssue {
+            number: created.number,
+            url: created.html_url,
+        })
+    }
+
+    async fn create_gitlab_issue(
+        &self,
+        integration: &IssueTrackerIntegration,
+        issue: &NewIssue,
+    ) -> Result<CreatedIssue, IssueTrackerError> {
+        let url = self.endpoint(
+            &integration.api_base_url,
+            &["projects", &integration.repository, "issues"],
+        )?;
+
+        let request = self
+            .client
+            .post(url)
+            .header(GITLAB_TOKEN_HEADER, &integration.access_token)
+            .json(&json!({ "title": issue.title, "description": issue.body }));
+
+        let created: GitLabIssue = Self::send(request)
+            .await?
+            .json()
+            .await
+            .map_err(|e| IssueTrackerError::InvalidResponse(e.to_string()))?;
+
+        Ok(CreatedIssue {
+            number: created.iid,
+            url: created.web_url,
+        })
+    }
+
+    fn parse_github_event(
+        webhook: &InboundWebhook,
+    ) -> Result<Option<IssueEvent>, IssueTrackerError> {
+        if webhook.header(GITHUB_EVENT_HEADER) != Some(GITHUB_ISSUES_EVENT) {
+            return Ok(None);
+        }
+
+        let event: GitHubIssuesEvent = serde_json::from_slice(&webhook.body)
+            .map_err(|e| IssueTrackerError::InvalidResponse(e.to_string()))?;
+        let action = match event.action.as_str() {
+            "closed" => IssueAction::Closed,
+            "reopened" => IssueAction::Reopened,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(IssueEvent {
+            repository: event.repository.full_name,
+            number: event.issue.number,
+            action,
+        }))
+    }
+
+    fn parse_gitlab_event(
+        webhook: &InboundWebhook,
+    ) -> Result<Option<IssueEvent>, IssueTrackerError> {
+        if webhook.header(GITLAB_EVENT_HEADER) != Some(GITLAB_ISSUE_EVENT) {
+            return Ok(None);
+        }
+
+        let event: GitLabIssueEvent = serde_json::from_slice(&webhook.body)
+            .map_err(|e| IssueTrackerError::InvalidResponse(e.to_string()))?;
+        let action = match event.object_attributes.action.as_deref() {
+            Some("close") => IssueAction::Closed,
+            Some("reopen") => IssueAction::Reopened,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(IssueEvent {
+            repository: event.project.path_with_namespace,
+            number: event.object_attributes.iid,
+            action,
+        }))
+    }
+}
+
+#[async_trait]
+impl IssueTrackerPort for HttpIssueTracker {
+    #[instrument(skip(self, integration, issue), fields(provider = %integration.provider, repository = %integration.repository), level = "debug")]
+    async fn create_issue(
+        &self,
+        integration: &IssueTrackerIntegration,
+        issue: &NewIssue,
+    ) -> Result<CreatedIssue, IssueTrackerError> {
+        match integration.provider {
+            IssueTrackerProvider::GitHub => self.create_github_issue(integration, issue).await,
+            IssueTrackerProvider::GitLab => self.create_gitlab_issue(integration, issue).await,
+        }
+    }
+
+    fn verify_webhook(
+        &self,
+        integration: &IssueTrackerIntegration,
+        webhook: &InboundWebhook,
+    ) -> bool {
+        match integration.provider {
+            IssueTrackerProvider::GitHub => {
+                webhook
+                    .header(GITHUB_SIGNATURE_HEADER)
+                    .is_some_and(|signature| {
+                        verify_signature(&integration.webhook_secret, &webhook.body, signature)
+                    })
+            }
+            IssueTrackerProvider::GitLab => webhook
+                .header(GITLAB_WEBHOOK_TOKEN_HEADER)
+                .is_some_and(|token| verify_token(&integration.webhook_secret, token)),
+        }
+    }
+
+    fn parse_issue_event(
+        &self,
+        integration: &IssueTrackerIntegration,
+        webhook: &InboundWebhook,
+    ) -> Result<Option<IssueEvent>, IssueTrackerError> {
+        match integration.provider {
+            IssueTrackerProvider::GitHub => Self::parse_github_event(webhook),
+            IssueTrackerProvider::GitLab => Self::parse_gitlab_event(webhook),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cuts_error_messages_on_character_boundaries() {
+        let body = "é".repeat(MAX_ERROR_MESSAGE_LENGTH + 50);
+
+        let message = HttpIssueTracker::error_message(&body);
+
+        assert_eq!(message.chars().count(), MAX_ERROR_MESSAGE_LENGTH);
+        assert!(body.starts_with(&message));
+        assert_eq!(
+            HttpIssueTracker::error_message("Bad credentials"),
+            "Bad credentials"
+        );
+    }
+}
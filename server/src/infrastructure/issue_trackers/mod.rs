@@ -0,0 +1 @@
+pub mod http_issue_tracker;
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::fs;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::{
+    models::email::EmailMessage,
+    ports::mailer_port::{MailerError, MailerPort},
+};
+
+use super::mime_message::format_message;
+
+/// Stand-in for an SMTP server: writes every message as an `.eml` file, for local development.
+#[derive(Clone)]
+pub struct CaptureMailer {
+    directory: PathBuf,
+    from: String,
+}
+
+impl CaptureMailer {
+    #[instrument(name = "CaptureMailer::new", level = "debug")]
+    pub fn new(directory: String, from: String) -> Result<Self, MailerError> {
+        let directory = PathBuf::from(directory);
+        std::fs::create_dir_all(&directory).map_err(|e| {
+            MailerError::ConfigurationError(format!(
+                "Failed to create mail capture directory '{}': {}",
+                directory.display(),
+                e
+            ))
+        })?;
+
+        Ok(Self { directory, from })
+    }
+}
+
+#[async_trait]
+impl MailerPort for CaptureMailer {
+    #[instrument(skip(self, message), fields(subject = %message.subject), level = "debug")]
+    async fn send(&self, message: &EmailMessage) -> Result<(), MailerError> {
+        let file_name = format!(
+            "{}-{}.eml",
+            Utc::now().format("%Y%m%d%H%M%S"),
+            Uuid::new_v4().simple()
+        );
+        let path = self.directory.join(file_name);
+
+        fs::write(&path, format_message(&self.from, message))
+            .await
+            .map_err(|e| MailerError::ConnectionFailed(e.to_string()))?;
+
+        tracing::info!(path = %path.display(), "Email captured.");
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::models::email::EmailMessage;
+
+const LINE_LENGTH: usize = 76;
+
+/// Address part of a `Name <address>` mailbox, as used in the SMTP envelope.
+pub fn envelope_address(mailbox: &str) -> &str {
+    match (mailbox.rfind('<'), mailbox.rfind('>')) {
+        (Some(start), Some(end)) if start < end => mailbox[start + 1..end].trim(),
+        _ => mailbox.trim(),
+    }
+}
+
+/// Encodes a header value as an RFC 2047 encoded-word when it is not plain ASCII. Line breaks
+/// are replaced so user input cannot inject headers.
+fn encode_header(value: &str) -> String {
+    let value = value.replace(['\r', '\n'], " ");
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!("=?UTF-8?B?{}?=", BASE64.encode(value))
+    }
+}
+
+fn encode_body(body: &str) -> String {
+    let encoded = BASE64.encode(body);
+    encoded
+        .as_bytes()
+        .chunks(LINE_LENGTH)
+        .map(|line| std::str::from_utf8(line).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+/// Builds the RFC 5322 `multipart/alternative` message, with CRLF line endings.
+pub fn format_message(from: &str, message: &EmailMessage) -> String {
+    let boundary = format!("rebug-{}", Uuid::new_v4().simple());
+    let domain = envelope_address(from)
+        .rsplit_once('@')
+        .map(|(_, domain)| domain)
+        .unwrap_or("localhost");
+
+    let headers = [
+        format!("From: {}", encode_header(from)),
+        format!("To: {}", encode_header(&message.to)),
+        format!("Subject: {}", encode_header(&message.subject)),
+        format!("Date: {}", Utc::now().to_rfc2822()),
+        format!("Message-ID: <{}@{}>", Uuid::new_v4(), domain),
+        "MIME-Version: 1.0".to_string(),
+        format!(
+            "Content-Type: multipart/alternative; boundary=\"{}\"",
+            boundary
+        ),
+    ];
+
+    let mut formatted = headers.join("\r\n");
+    formatted.push_str("\r\n\r\n");
+    for (content_type, body) in [
+        ("text/plain", &message.text_body),
+        ("text/html", &message.html_body),
+    ] {
+        formatted.push_str(&format!(
+            "--{}\r\nContent-Type: {}; charset=utf-8\r\nContent-Transfer-Encoding: base64\r\n\r\n{}\r\n",
+            boundary,
+            content_type,
+            encode_body(body)
+        ));
+    }
+    formatted.push_str(&format!("--{}--\r\n", boundary));
+
+    formatted
+}
@@ -0,0 +1,249 @@
+use bytes::Bytes;
+use mail_parser::{Message, MessageParser, MimeHeaders};
+
+use crate::domain::models::email::{EmailAttachment, InboundEmail};
+
+/// Forwarded messages nested deeper than this are ignored.
+const MAX_DEPTH: usize = 8;
+
+#[derive(Debug, thiserror::Error)]
+pub enum MimeParseError {
+    #[error("The message is empty")]
+    Empty,
+    #[error("The message has no headers")]
+    MissingHeaders,
+}
+
+/// Parses a raw RFC 5322 message into what is needed to file a report.
+pub fn parse_message(raw: &[u8]) -> Result<InboundEmail, MimeParseError> {
+    if raw.iter().all(u8::is_ascii_whitespace) {
+        return Err(MimeParseError::Empty);
+    }
+
+    let message = MessageParser::default()
+        .parse(raw)
+        .filter(|message| !message.headers().is_empty())
+        .ok_or(MimeParseError::MissingHeaders)?;
+
+    let mut email = InboundEmail {
+        sender: message
+            .from()
+            .and_then(|from| from.first())
+            .and_then(|address| address.address())
+            .filter(|address| address.contains('@'))
+            .map(str::to_string),
+        subject: message
+            .subject()
+            .map(|subject| subject.trim().to_string())
+            .filter(|subject| !subject.is_empty()),
+        // HTML-only messages are rendered as text.
+        text_body: message
+            .body_text(0)
+            .map(|text| text.replace("\r\n", "\n").trim().to_string())
+            .filter(|text| !text.is_empty()),
+        ..Default::default()
+    };
+    collect_attachments(&message, 0, &mut email.attachments);
+
+    Ok(email)
+}
+
+/// Attachments of the message, including those of forwarded messages: they are what the report
+/// is about.
+fn collect_attachments(message: &Message, depth: usize, attachments: &mut Vec<EmailAttachment>) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+
+    for part in message.attachments() {
+        if let Some(forwarded_message) = part.message() {
+            collect_attachments(forwarded_message, depth + 1, attachments);
+            continue;
+        }
+
+        let data = part.contents();
+        if data.is_empty() {
+            continue;
+        }
+        let file_name = part
+            .attachment_name()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("attachment-{}", attachments.len() + 1));
+
+        attachments.push(EmailAttachment {
+            file_name,
+            data: Bytes::copy_from_slice(data),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    fn base64_lines(data: &[u8]) -> String {
+        use base64::{Engine, engine::general_purpose::STANDARD};
+
+        STANDARD
+            .encode(data)
+            .as_bytes()
+            .chunks(76)
+            .map(|line| format!("{}\r\n", String::from_utf8_lossy(line)))
+            .collect()
+    }
+
+    fn multipart_message(parts: &[String]) -> Vec<u8> {
+        let mut message = String::from(
+            "From: \"Jane Doe\" <jane@example.com>\r\n\
+             Subject: =?UTF-8?B?w4ljcmFuIGJsYW5j?=\r\n\
+             MIME-Version: 1.0\r\n\
+             Content-Type: multipart/mixed; boundary=\"outer\"\r\n\r\n",
+        );
+        for part in parts {
+            message.push_str("--outer\r\n");
+            message.push_str(part);
+        }
+        message.push_str("--outer--\r\n");
+        message.into_bytes()
+    }
+
+    fn attachment_part(file_name: &str, content_type: &str, data: &[u8]) -> String {
+        format!(
+            "Content-Type: {}\r\n\
+             Content-Disposition: attachment; filename=\"{}\"\r\n\
+             Content-Transfer-Encoding: base64\r\n\r\n{}",
+            content_type,
+            file_name,
+            base64_lines(data)
+        )
+    }
+
+    #[test]
+    fn parses_a_plain_text_message() {
+        let email = parse_message(
+            b"From: bob@example.com\r\nSubject:  Broken button \r\n\r\nIt does nothing.\r\n",
+        )
+        .unwrap();
+
+        assert_eq!(email.sender.as_deref(), Some("bob@example.com"));
+        assert_eq!(email.subject.as_deref(), Some("Broken button"));
+        assert_eq!(email.text_body.as_deref(), Some("It does nothing."));
+        assert!(email.attachments.is_empty());
+    }
+
+    #[test]
+    fn decodes_headers_and_base64_attachments() {
+        let raw = multipart_message(&[
+            "Content-Type: text/plain; charset=utf-8\r\n\r\nSee the screenshot.\r\n".to_string(),
+            attachment_part("screen.png", "image/png", PNG),
+            attachment_part("notes.svg", "image/svg+xml", b"<svg><script/></svg>"),
+        ]);
+
+        let email = parse_message(&raw).unwrap();
+
+        assert_eq!(email.sender.as_deref(), Some("jane@example.com"));
+        assert_eq!(email.subject.as_deref(), Some("Écran blanc"));
+        assert_eq!(email.text_body.as_deref(), Some("See the screenshot."));
+        assert_eq!(email.attachments.len(), 2);
+        assert_eq!(email.attachments[0].file_name, "screen.png");
+        assert_eq!(email.attachments[0].data.as_ref(), PNG);
+        // Filtering by content is left to the caller.
+        assert_eq!(email.attachments[1].file_name, "notes.svg");
+    }
+
+    #[test]
+    fn renders_html_only_bodies_as_text() {
+        let email = parse_message(
+            b"From: bob@example.com\r\n\
+              Content-Type: text/html; charset=utf-8\r\n\r\n\
+              <html><head><style>p{}</style></head><body><p>Hello &amp; bye</p></body></html>\r\n",
+        )
+        .unwrap();
+
+        let text_body = email.text_body.unwrap();
+        assert!(text_body.contains("Hello & bye"), "{}", text_body);
+        assert!(!text_body.contains('<'));
+    }
+
+    #[test]
+    fn collects_attachments_of_forwarded_messages() {
+        let forwarded = String::from_utf8(multipart_message(&[attachment_part(
+            "original.png",
+            "image/png",
+            PNG,
+        )]))
+        .unwrap();
+        let raw = multipart_message(&[
+            "Content-Type: text/plain\r\n\r\nFYI\r\n".to_string(),
+            format!(
+                "Content-Type: message/rfc822\r\n\r\n{}",
+                forwarded
+                    .replace("--outer", "--inner")
+                    .replace("boundary=\"outer\"", "boundary=\"inner\"")
+            ),
+        ]);
+
+        let email = parse_message(&raw).unwrap();
+
+        assert_eq!(email.attachments.len(), 1);
+        assert_eq!(email.attachments[0].file_name, "original.png");
+        assert_eq!(email.attachments[0].data.as_ref(), PNG);
+    }
+
+    #[test]
+    fn names_attachments_without_a_file_name() {
+        let raw = multipart_message(&[format!(
+            "Content-Type: image/png\r\nContent-Transfer-Encoding: base64\r\n\r\n{}",
+            base64_lines(PNG)
+        )]);
+
+        let email = parse_message(&raw).unwrap();
+
+        assert_eq!(email.attachments.len(), 1);
+        assert_eq!(email.attachments[0].file_name, "attachment-1");
+    }
+
+    #[test]
+    fn rejects_empty_messages() {
+        assert!(matches!(parse_message(b""), Err(MimeParseError::Empty)));
+        assert!(matches!(
+            parse_message(b" \r\n\r\n"),
+            Err(MimeParseError::Empty)
+        ));
+    }
+
+    #[test]
+    fn survives_malformed_messages() {
+        let cases: [&[u8]; 5] = [
+            b"no headers at all, just text",
+            b"Content-Type: multipart/mixed\r\n\r\n--missing-boundary\r\n",
+            b"Content-Type: multipart/mixed; boundary=b\r\n\r\n--b\r\nContent-Type: image/png\r\nContent-Transfer-Encoding: base64\r\n\r\n!!!not base64",
+            b"Subject: =?UTF-8?B?broken\r\n\r\nbody",
+            b"\xff\xfe\x00garbage\r\n\r\n\x00\x01",
+        ];
+
+        for raw in cases {
+            // Either outcome is fine, as long as nothing panics.
+            let _ = parse_message(raw);
+        }
+    }
+
+    #[test]
+    fn stops_at_deeply_nested_forwards() {
+        let mut raw = attachment_part("deep.png", "image/png", PNG);
+        for depth in 0..(MAX_DEPTH + 4) {
+            raw = format!(
+                "Content-Type: multipart/mixed; boundary=\"b{depth}\"\r\n\r\n\
+                 --b{depth}\r\nContent-Type: message/rfc822\r\n\r\n{raw}--b{depth}--\r\n"
+            );
+        }
+
+        let email = parse_message(format!("From: a@b.c\r\n{}", raw).as_bytes()).unwrap();
+
+        assert!(email.attachments.is_empty());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod capture_mailer;
+pub mod mime_message;
+pub mod mime_parser;
+pub mod smtp_listener;
+pub mod smtp_mailer;
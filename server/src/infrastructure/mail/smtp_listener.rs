@@ -0,0 +1,653 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader},
+    net::TcpListener,
+    time::timeout,
+};
+use tracing::Instrument;
+
+use crate::{
+    application::services::inbound_email_service::{
+        InboundEmailServiceError, InboundEmailServiceInterface,
+    },
+    domain::{models::email::InboundMailbox, ports::storage_port::StorageError},
+};
+
+/// RFC 5321 asks servers to wait at least five minutes for each command.
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(300);
+const MAX_LINE_LENGTH: u64 = 1000;
+const MAX_RECIPIENTS: usize = 20;
+
+/// Minimal SMTP server receiving the board reports relayed by the domain mail server.
+///
+/// It only speaks plain SMTP, without TLS nor authentication, and only accepts recipients
+/// matching a board inbound mailbox: it is not an open relay, but is meant to sit behind the
+/// mail server that handles the public side.
+pub struct SmtpListener {
+    listener: TcpListener,
+    hostname: String,
+    max_message_size: usize,
+    inbound_email_service: Arc<dyn InboundEmailServiceInterface>,
+}
+
+impl SmtpListener {
+    pub async fn bind(
+        bind_address: &str,
+        hostname: String,
+        max_message_size: usize,
+        inbound_email_service: Arc<dyn InboundEmailServiceInterface>,
+    ) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(bind_address).await?;
+
+        Ok(Self {
+            listener,
+            hostname,
+            max_message_size,
+            inbound_email_service,
+        })
+    }
+
+    pub async fn run(self) {
+        let session_config = Arc::new(SessionConfig {
+            hostname: self.hostname,
+            max_message_size: self.max_message_size,
+            inbound_email_service: self.inbound_email_service,
+        });
+
+        loop {
+            let (stream, peer_address) = match self.listener.accept().await {
+                Ok(connection) => connection,
+                Err(e) => {
+                    tracing::warn!("Failed to accept SMTP connection: {}", e);
+                    continue;
+                }
+            };
+
+            let session_config = session_config.clone();
+            tokio::spawn(
+                async move {
+                    if let Err(e) = handle_session(stream, &session_config).await {
+                        tracing::debug!("SMTP session ended: {}", e);
+                    }
+                }
+                .instrument(tracing::debug_span!("smtp_session", peer_address = %peer_address)),
+            );
+        }
+    }
+}
+
+struct SessionConfig {
+    hostname: String,
+    max_message_size: usize,
+    inbound_email_service: Arc<dyn InboundEmailServiceInterface>,
+}
+
+#[derive(Default)]
+struct Envelope {
+    sender: Option<String>,
+    mailboxes: Vec<InboundMailbox>,
+}
+
+async fn handle_session<S: AsyncRead + AsyncWrite>(
+    stream: S,
+    config: &SessionConfig,
+) -> std::io::Result<()> {
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut reader = BufReader::new(reader);
+    let mut envelope = Envelope::default();
+
+    reply(
+        &mut writer,
+        &format!("220 {} rebug ESMTP ready", config.hostname),
+    )
+    .await?;
+
+    loop {
+        let Some(line) = read_line(&mut reader).await? else {
+            return Ok(());
+        };
+        let line = String::from_utf8_lossy(&line);
+        let line = line.trim_end();
+        let (verb, argument) = line.split_once(' ').unwrap_or((line, ""));
+        let argument = argument.trim();
+
+        match verb.to_ascii_uppercase().as_str() {
+            "EHLO" => {
+                envelope = Envelope::default();
+                reply(
+                    &mut writer,
+                    &format!(
+                        "250-{}\r\n250-SIZE {}\r\n250-8BITMIME\r\n250 PIPELINING",
+                        config.hostname, config.max_message_size
+                    ),
+                )
+                .await?;
+            }
+            "HELO" => {
+                envelope = Envelope::default();
+                reply(&mut writer, &format!("250 {}", config.hostname)).await?;
+            }
+            "MAIL" => {
+                let response = start_transaction(&mut envelope, argument, config);
+                reply(&mut writer, &response).await?;
+            }
+            "RCPT" => {
+                let response = add_recipient(&mut envelope, argument, config).await;
+                reply(&mut writer, &response).await?;
+            }
+            "DATA" => {
+                if envelope.mailboxes.is_empty() {
+                    reply(&mut writer, "503 5.5.1 No valid recipients").await?;
+                    continue;
+                }
+
+                reply(&mut writer, "354 End data with <CR><LF>.<CR><LF>").await?;
+                let Some(message) = read_message(&mut reader, config.max_message_size).await?
+                else {
+                    reply(&mut writer, "552 5.3.4 Message too big").await?;
+                    envelope = Envelope::default();
+                    continue;
+                };
+
+                let response = deliver(&envelope, &message, config).await;
+                reply(&mut writer, &response).await?;
+                envelope = Envelope::default();
+            }
+            "RSET" => {
+                envelope = Envelope::default();
+                reply(&mut writer, "250 2.0.0 OK").await?;
+            }
+            "NOOP" => reply(&mut writer, "250 2.0.0 OK").await?,
+            "VRFY" => reply(&mut writer, "252 2.5.0 Cannot verify user").await?,
+            "QUIT" => {
+                reply(
+                    &mut writer,
+                    &format!("221 2.0.0 {} closing", config.hostname),
+                )
+                .await?;
+                return Ok(());
+            }
+            _ => reply(&mut writer, "502 5.5.2 Command not implemented").await?,
+        }
+    }
+}
+
+fn start_transaction(envelope: &mut Envelope, argument: &str, config: &SessionConfig) -> String {
+    let Some(path) = strip_prefix_ignore_case(argument, "FROM:") else {
+        return "501 5.5.4 Syntax: MAIL FROM:<address>".to_string();
+    };
+    if envelope.sender.is_some() {
+        return "503 5.5.1 Sender already specified".to_string();
+    }
+
+    let (address, parameters) = split_path(path);
+    let declared_size = parameters
+        .split_whitespace()
+        .find_map(|parameter| strip_prefix_ignore_case(parameter, "SIZE="))
+        .and_then(|size| size.parse::<usize>().ok());
+    if declared_size.is_some_and(|size| size > config.max_message_size) {
+        return "552 5.3.4 Message too big".to_string();
+    }
+
+    envelope.sender = Some(address.to_string());
+    "250 2.1.0 OK".to_string()
+}
+
+async fn add_recipient(envelope: &mut Envelope, argument: &str, config: &SessionConfig) -> String {
+    let Some(path) = strip_prefix_ignore_case(argument, "TO:") else {
+        return "501 5.5.4 Syntax: RCPT TO:<address>".to_string();
+    };
+    if envelope.sender.is_none() {
+        return "503 5.5.1 MAIL first".to_string();
+    }
+    if envelope.mailboxes.len() >= MAX_RECIPIENTS {
+        return "452 4.5.3 Too many recipients".to_string();
+    }
+
+    let (address, _) = split_path(path);
+    match config
+        .inbound_email_service
+        .find_mailbox_by_address(address)
+        .await
+    {
+        Ok(mailbox) => {
+            if !envelope
+                .mailboxes
+                .iter()
+                .any(|known_mailbox| known_mailbox.id == mailbox.id)
+            {
+                envelope.mailboxes.push(mailbox);
+            }
+            "250 2.1.5 OK".to_string()
+        }
+        Err(InboundEmailServiceError::MailboxNotFound) => "550 5.1.1 No such mailbox".to_string(),
+        Err(e) => {
+            tracing::error!("Failed to look up inbound mailbox: {}", e);
+            "451 4.3.0 Temporary failure, try again later".to_string()
+        }
+    }
+}
+
+/// Files the message on every recipient board. The reply reflects the first failure, unless at
+/// least one board got the report.
+async fn deliver(envelope: &Envelope, message: &[u8], config: &SessionConfig) -> String {
+    let mut is_delivered = false;
+    let mut first_error = None;
+
+    for mailbox in &envelope.mailboxes {
+        match config
+            .inbound_email_service
+            .ingest_message(mailbox, message)
+            .await
+        {
+            Ok(_) => is_delivered = true,
+            Err(e) => {
+                tracing::warn!(board_id = %mailbox.board_id, "Inbound email rejected: {}", e);
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+
+    match first_error {
+        None => "250 2.0.0 Report created".to_string(),
+        Some(_) if is_delivered => "250 2.0.0 Report created".to_string(),
+        Some(
+            InboundEmailServiceError::ValidationError(message)
+            | InboundEmailServiceError::StorageError(StorageError::ValidationError(message)),
+        ) => format!("554 5.6.0 {}", message.replace(['\r', '\n'], " ")),
+        Some(_) => "451 4.3.0 Temporary failure, try again later".to_string(),
+    }
+}
+
+/// Message content up to the lone `.` line, dot-unstuffed. `None` when it exceeds the size
+/// limit, in which case the rest is read and dropped to keep the session in sync.
+async fn read_message<R: AsyncRead + Unpin>(
+    reader: &mut BufReader<R>,
+    max_message_size: usize,
+) -> std::io::Result<Option<Vec<u8>>> {
+    let mut message = Vec::new();
+    let mut is_too_big = false;
+
+    loop {
+        let Some(line) = read_line(reader).await? else {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        };
+        if line == b".\r\n" || line == b".\n" {
+            break;
+        }
+
+        let line = line.strip_prefix(b".").unwrap_or(&line);
+        if message.len() + line.len() > max_message_size {
+            is_too_big = true;
+            message.clear();
+        }
+        if !is_too_big {
+            message.extend_from_slice(line);
+        }
+    }
+
+    Ok((!is_too_big).then_some(message))
+}
+
+/// One line, line break included, or `None` once the client is gone. Longer lines than allowed
+/// are split, which is harmless for message content and rejected as commands.
+async fn read_line<R: AsyncRead + Unpin>(
+    reader: &mut BufReader<R>,
+) -> std::io::Result<Option<Vec<u8>>> {
+    let mut line = Vec::new();
+    let read = timeout(
+        COMMAND_TIMEOUT,
+        (&mut *reader)
+            .take(MAX_LINE_LENGTH)
+            .read_until(b'\n', &mut line),
+    )
+    .await
+    .map_err(|_| std::io::Error::from(std::io::ErrorKind::TimedOut))??;
+
+    Ok((read > 0).then_some(line))
+}
+
+async fn reply<W: AsyncWrite + Unpin>(writer: &mut W, response: &str) -> std::io::Result<()> {
+    writer.write_all(response.as_bytes()).await?;
+    writer.write_all(b"\r\n").await?;
+    writer.flush().await
+}
+
+/// Address and parameters of `<address> PARAM=value`.
+fn split_path(path: &str) -> (&str, &str) {
+    let path = path.trim();
+    match path.strip_prefix('<').and_then(|rest| rest.split_once('>')) {
+        Some((address, parameters)) => (address.trim(), parameters.trim()),
+        None => path.split_once(' ').unwrap_or((path, "")),
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    value
+        .get(..prefix.len())
+        .filter(|start| start.eq_ignore_ascii_case(prefix))
+        .map(|_| value[prefix.len()..].trim_start())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use tokio::io::{DuplexStream, ReadHalf, WriteHalf};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        application::services::inbound_email_service::InboundEmailServiceResult,
+        domain::models::report::{Report, ReportStatus, ReportType},
+    };
+
+    const MAILBOX_ADDRESS: &str = "bugs@rebug.test";
+    const MAX_MESSAGE_SIZE: usize = 256;
+
+    /// Knows one mailbox, and keeps the messages it receives. Messages containing "REJECT" are
+    /// refused as invalid.
+    #[derive(Default)]
+    struct FakeInboundEmailService {
+        messages: Mutex<Vec<Vec<u8>>>,
+    }
+
+    fn mailbox() -> InboundMailbox {
+        InboundMailbox {
+            id: Uuid::nil(),
+            board_id: Uuid::nil(),
+            token: "bugs".to_string(),
+            created_by: Uuid::nil(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[async_trait]
+    impl InboundEmailServiceInterface for FakeInboundEmailService {
+        async fn get_mailbox(&self, _board_id: Uuid) -> InboundEmailServiceResult<InboundMailbox> {
+            Ok(mailbox())
+        }
+
+        async fn configure_mailbox(
+            &self,
+            _board_id: Uuid,
+            _created_by: Uuid,
+        ) -> InboundEmailServiceResult<InboundMailbox> {
+            Ok(mailbox())
+        }
+
+        async fn delete_mailbox(&self, _board_id: Uuid) -> InboundEmailServiceResult<()> {
+            Ok(())
+        }
+
+        async fn find_mailbox_by_token(
+            &self,
+            _token: &str,
+        ) -> InboundEmailServiceResult<InboundMailbox> {
+            Ok(mailbox())
+        }
+
+        async fn find_mailbox_by_address(
+            &self,
+            address: &str,
+        ) -> InboundEmailServiceResult<InboundMailbox> {
+            if address.eq_ignore_ascii_case(MAILBOX_ADDRESS) {
+                Ok(mailbox())
+            } else {
+                Err(InboundEmailServiceError::MailboxNotFound)
+            }
+        }
+
+        async fn ingest_message(
+            &self,
+            mailbox: &InboundMailbox,
+            raw_message: &[u8],
+        ) -> InboundEmailServiceResult<Report> {
+            if raw_message.windows(6).any(|window| window == b"REJECT") {
+                return Err(InboundEmailServiceError::ValidationError(
+                    "The email has no image or video attachment".to_string(),
+                ));
+            }
+            self.messages.lock().unwrap().push(raw_message.to_vec());
+
+            Ok(Report {
+                id: Uuid::new_v4(),
+                user_id: Uuid::nil(),
+                board_id: mailbox.board_id,
+                report_type: ReportType::Screenshot,
+                status: ReportStatus::Open,
+                title: String::new(),
+                description: None,
+                file_path: String::new(),
+                thumbnail_file_path: None,
+                url: None,
+                browser_name: None,
+                browser_version: None,
+                os_name: None,
+                contact_email: None,
+                external_issue_key: None,
+                external_issue_url: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            })
+        }
+    }
+
+    struct Client {
+        reader: BufReader<ReadHalf<DuplexStream>>,
+        writer: WriteHalf<DuplexStream>,
+    }
+
+    impl Client {
+        async fn send(&mut self, data: &str) {
+            self.writer.write_all(data.as_bytes()).await.unwrap();
+        }
+
+        /// Next reply, multiline replies joined with `\n`.
+        async fn reply(&mut self) -> String {
+            let mut lines = Vec::new();
+            loop {
+                let mut line = String::new();
+                self.reader.read_line(&mut line).await.unwrap();
+                assert!(!line.is_empty(), "connection closed, got {:?}", lines);
+                let line = line.trim_end().to_string();
+                let is_last = line.as_bytes().get(3) != Some(&b'-');
+                lines.push(line);
+                if is_last {
+                    return lines.join("\n");
+                }
+            }
+        }
+
+        async fn expect(&mut self, code: &str) -> String {
+            let reply = self.reply().await;
+            assert!(
+                reply.starts_with(code),
+                "expected {}, got {:?}",
+                code,
+                reply
+            );
+            reply
+        }
+    }
+
+    async fn connect() -> (Client, Arc<FakeInboundEmailService>) {
+        let service = Arc::new(FakeInboundEmailService::default());
+        let config = SessionConfig {
+            hostname: "rebug.test".to_string(),
+            max_message_size: MAX_MESSAGE_SIZE,
+            inbound_email_service: service.clone(),
+        };
+        let (client_stream, server_stream) = tokio::io::duplex(64 * 1024);
+        tokio::spawn(async move { handle_session(server_stream, &config).await });
+
+        let (reader, writer) = tokio::io::split(client_stream);
+        let mut client = Client {
+            reader: BufReader::new(reader),
+            writer,
+        };
+        client.expect("220 rebug.test").await;
+        (client, service)
+    }
+
+    #[tokio::test]
+    async fn delivers_a_message() {
+        let (mut client, service) = connect().await;
+
+        client.send("EHLO client.test\r\n").await;
+        let ehlo = client.expect("250-rebug.test").await;
+        assert!(ehlo.contains("SIZE 256") && ehlo.contains("PIPELINING"));
+        client.send("MAIL FROM:<jane@example.com>\r\n").await;
+        client.expect("250").await;
+        client
+            .send(&format!("RCPT TO:<{}>\r\n", MAILBOX_ADDRESS))
+            .await;
+        client.expect("250").await;
+        client.send("DATA\r\n").await;
+        client.expect("354").await;
+        client.send("Subject: hi\r\n\r\nbody\r\n.\r\n").await;
+        client.expect("250 2.0.0 Report created").await;
+        client.send("QUIT\r\n").await;
+        client.expect("221").await;
+
+        let messages = service.messages.lock().unwrap();
+        assert_eq!(
+            messages.as_slice(),
+            [b"Subject: hi\r\n\r\nbody\r\n".to_vec()]
+        );
+    }
+
+    #[tokio::test]
+    async fn answers_pipelined_commands_in_order() {
+        let (mut client, service) = connect().await;
+
+        client
+            .send(&format!(
+                "EHLO client.test\r\nMAIL FROM:<jane@example.com>\r\nRCPT TO:<nobody@rebug.test>\r\n\
+                 RCPT TO:<{}>\r\nDATA\r\n",
+                MAILBOX_ADDRESS
+            ))
+            .await;
+        client.expect("250-").await;
+        client.expect("250 2.1.0").await;
+        client.expect("550 5.1.1").await;
+        client.expect("250 2.1.5").await;
+        client.expect("354").await;
+        client.send("first\r\n.\r\nNOOP\r\n").await;
+        client.expect("250 2.0.0 Report created").await;
+        client.expect("250 2.0.0 OK").await;
+
+        assert_eq!(service.messages.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn removes_dot_stuffing() {
+        let (mut client, service) = connect().await;
+
+        client
+            .send(&format!(
+                "HELO client.test\r\nMAIL FROM:<>\r\nRCPT TO:<{}>\r\nDATA\r\n",
+                MAILBOX_ADDRESS
+            ))
+            .await;
+        for code in ["250", "250", "250", "354"] {
+            client.expect(code).await;
+        }
+        client.send("..leading dot\r\n...\r\n.\r\n").await;
+        client.expect("250 2.0.0").await;
+
+        let messages = service.messages.lock().unwrap();
+        assert_eq!(messages[0], b".leading dot\r\n..\r\n");
+    }
+
+    #[tokio::test]
+    async fn rejects_oversized_messages_and_stays_in_sync() {
+        let (mut client, service) = connect().await;
+
+        client
+            .send("MAIL FROM:<jane@example.com> SIZE=100000\r\n")
+            .await;
+        client.expect("552").await;
+
+        client
+            .send(&format!(
+                "MAIL FROM:<jane@example.com>\r\nRCPT TO:<{}>\r\nDATA\r\n",
+                MAILBOX_ADDRESS
+            ))
+            .await;
+        for code in ["250", "250", "354"] {
+            client.expect(code).await;
+        }
+        let line = format!("{}\r\n", "x".repeat(70));
+        for _ in 0..10 {
+            client.send(&line).await;
+        }
+        client.send(".\r\nNOOP\r\n").await;
+        client.expect("552 5.3.4").await;
+        client.expect("250 2.0.0 OK").await;
+
+        assert!(service.messages.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_commands() {
+        let (mut client, _) = connect().await;
+
+        let cases = [
+            ("FOO bar", "502"),
+            ("MAIL jane@example.com", "501"),
+            ("RCPT TO:<bugs@rebug.test>", "503"),
+            ("DATA", "503"),
+            ("MAIL FROM:<jane@example.com>", "250"),
+            ("MAIL FROM:<jane@example.com>", "503"),
+            ("RCPT bugs@rebug.test", "501"),
+            ("DATA", "503"),
+            ("RSET", "250"),
+            ("VRFY bugs", "252"),
+        ];
+        for (command, code) in cases {
+            client.send(&format!("{}\r\n", command)).await;
+            let reply = client.reply().await;
+            assert!(
+                reply.starts_with(code),
+                "{}: expected {}, got {:?}",
+                command,
+                code,
+                reply
+            );
+        }
+
+        // Overlong lines are cut, and the pieces are no valid commands.
+        client.send(&format!("{}\r\n", "A".repeat(2500))).await;
+        for _ in 0..3 {
+            client.expect("502").await;
+        }
+        client.send("NOOP\r\n").await;
+        client.expect("250").await;
+    }
+
+    #[tokio::test]
+    async fn reports_invalid_messages_as_permanent_failures() {
+        let (mut client, service) = connect().await;
+
+        client
+            .send(&format!(
+                "MAIL FROM:<jane@example.com>\r\nRCPT TO:<{}>\r\nDATA\r\n",
+                MAILBOX_ADDRESS
+            ))
+            .await;
+        for code in ["250", "250", "354"] {
+            client.expect(code).await;
+        }
+        client.send("REJECT\r\n.\r\n").await;
+        client
+            .expect("554 5.6.0 The email has no image or video attachment")
+            .await;
+
+        assert!(service.messages.lock().unwrap().is_empty());
+    }
+}
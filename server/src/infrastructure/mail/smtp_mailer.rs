@@ -0,0 +1,268 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufStream},
+    net::TcpStream,
+    time::timeout,
+};
+use tokio_rustls::{
+    TlsConnector,
+    client::TlsStream,
+    rustls::{ClientConfig, RootCertStore, crypto::ring, pki_types::ServerName},
+};
+use tracing::instrument;
+
+use crate::{
+    config::app_config::SmtpSecurity,
+    domain::{
+        models::email::EmailMessage,
+        ports::mailer_port::{MailerError, MailerPort},
+    },
+};
+
+use super::mime_message::{envelope_address, format_message};
+
+/// Minimal SMTP client: one connection per message, optional STARTTLS/implicit TLS and
+/// `AUTH PLAIN`.
+#[derive(Clone)]
+pub struct SmtpMailer {
+    host: String,
+    port: u16,
+    security: SmtpSecurity,
+    credentials: Option<(String, String)>,
+    from: String,
+    timeout: Duration,
+    tls_connector: TlsConnector,
+}
+
+impl SmtpMailer {
+    #[instrument(name = "SmtpMailer::new", skip(password), level = "debug")]
+    pub fn new(
+        host: String,
+        port: u16,
+        security: SmtpSecurity,
+        username: String,
+        password: String,
+        from: String,
+        timeout_seconds: u64,
+    ) -> Result<Self, MailerError> {
+        let root_store = RootCertStore {
+            roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+        };
+        let tls_config = ClientConfig::builder_with_provider(Arc::new(ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .map_err(|e| MailerError::ConfigurationError(e.to_string()))?
+            .with_root_certificates(root_store)
+            .with_no_client_auth();
+
+        let credentials = (!username.is_empty()).then_some((username, password));
+
+        Ok(Self {
+            host,
+            port,
+            security,
+            credentials,
+            from,
+            timeout: Duration::from_secs(timeout_seconds),
+            tls_connector: TlsConnector::from(Arc::new(tls_config)),
+        })
+    }
+
+    async fn connect_tls(&self, stream: TcpStream) -> Result<TlsStream<TcpStream>, MailerError> {
+        let server_name = ServerName::try_from(self.host.clone())
+            .map_err(|e| MailerError::ConfigurationError(e.to_string()))?;
+
+        self.tls_connector
+            .connect(server_name, stream)
+            .await
+            .map_err(|e| MailerError::ConnectionFailed(format!("TLS handshake failed: {}", e)))
+    }
+
+    async fn deliver(&self, message: &EmailMessage) -> Result<(), MailerError> {
+        let stream = TcpStream::connect((self.host.as_str(), self.port))
+            .await
+            .map_err(|e| MailerError::ConnectionFailed(e.to_string()))?;
+
+        match self.security {
+            SmtpSecurity::None => {
+                let mut connection = SmtpConnection::new(stream);
+                connection.expect(220).await?;
+                self.transaction(&mut connection, message).await
+            }
+            SmtpSecurity::StartTls => {
+                let mut connection = SmtpConnection::new(stream);
+                connection.expect(220).await?;
+                self.ehlo(&mut connection).await?;
+                connection.command("STARTTLS", 220).await?;
+
+                let stream = self.connect_tls(connection.into_inner()).await?;
+                self.transaction(&mut SmtpConnection::new(stream), message)
+                    .await
+            }
+            SmtpSecurity::Tls => {
+                let stream = self.connect_tls(stream).await?;
+                let mut connection = SmtpConnection::new(stream);
+                connection.expect(220).await?;
+                self.transaction(&mut connection, message).await
+            }
+        }
+    }
+
+    async fn ehlo<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        connection: &mut SmtpConnection<S>,
+    ) -> Result<(), MailerError> {
+        let client_name = envelope_address(&self.from)
+            .rsplit_once('@')
+            .map(|(_, domain)| domain)
+            .unwrap_or("localhost");
+
+        connection
+            .command(&format!("EHLO {}", client_name), 250)
+            .await
+            .map(|_| ())
+    }
+
+    async fn transaction<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        connection: &mut SmtpConnection<S>,
+        message: &EmailMessage,
+    ) -> Result<(), MailerError> {
+        self.ehlo(connection).await?;
+
+        if let Some((username, password)) = &self.credentials {
+            let token = BASE64.encode(format!("\0{}\0{}", username, password));
+            connection
+                .command(&format!("AUTH PLAIN {}", token), 235)
+                .await?;
+        }
+
+        connection
+            .command(
+                &format!("MAIL FROM:<{}>", envelope_address(&self.from)),
+                250,
+            )
+            .await?;
+        connection
+            .command(&format!("RCPT TO:<{}>", envelope_address(&message.to)), 250)
+            .await?;
+        connection.command("DATA", 354).await?;
+        connection
+            .send_data(&format_message(&self.from, message))
+            .await?;
+
+        // The message is accepted at this point, a failed QUIT does not matter.
+        let _ = connection.command("QUIT", 221).await;
+        Ok(())
+    }
+}
+
+struct SmtpConnection<S> {
+    stream: BufStream<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> SmtpConnection<S> {
+    fn new(stream: S) -> Self {
+        Self {
+            stream: BufStream::new(stream),
+        }
+    }
+
+    fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+
+    /// Reads a possibly multiline reply, returning its code and text.
+    async fn read_reply(&mut self) -> Result<(u16, String), MailerError> {
+        let mut text = String::new();
+
+        loop {
+            let mut line = String::new();
+            let read = self
+                .stream
+                .read_line(&mut line)
+                .await
+                .map_err(|e| MailerError::ConnectionFailed(e.to_string()))?;
+            if read == 0 {
+                return Err(MailerError::ConnectionFailed(
+                    "Connection closed by the mail server".to_string(),
+                ));
+            }
+
+            let line = line.trim_end();
+            let code = line
+                .get(..3)
+                .and_then(|code| code.parse::<u16>().ok())
+                .ok_or_else(|| {
+                    MailerError::ConnectionFailed(format!("Invalid SMTP reply: {}", line))
+                })?;
+            text.push_str(line.get(4..).unwrap_or_default());
+            text.push('\n');
+
+            if line.as_bytes().get(3) != Some(&b'-') {
+                return Ok((code, text.trim_end().to_string()));
+            }
+        }
+    }
+
+    /// Reads a reply, failing unless it is in the same class (2xx, 3xx) as `expected`.
+    async fn expect(&mut self, expected: u16) -> Result<String, MailerError> {
+        let (code, text) = self.read_reply().await?;
+        if code / 100 != expected / 100 {
+            return Err(MailerError::Rejected(format!("{} {}", code, text)));
+        }
+
+        Ok(text)
+    }
+
+    async fn write(&mut self, data: &str) -> Result<(), MailerError> {
+        self.stream
+            .write_all(data.as_bytes())
+            .await
+            .map_err(|e| MailerError::ConnectionFailed(e.to_string()))?;
+        self.stream
+            .flush()
+            .await
+            .map_err(|e| MailerError::ConnectionFailed(e.to_string()))
+    }
+
+    async fn command(&mut self, command: &str, expected: u16) -> Result<String, MailerError> {
+        self.write(&format!("{}\r\n", command)).await?;
+        self.expect(expected).await
+    }
+
+    /// Sends the message content with dot-stuffing, then the end-of-data marker.
+    async fn send_data(&mut self, data: &str) -> Result<(), MailerError> {
+        let stuffed = data
+            .split("\r\n")
+            .map(|line| {
+                if line.starts_with('.') {
+                    format!(".{}", line)
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\r\n");
+        let terminator = if stuffed.ends_with("\r\n") {
+            ".\r\n"
+        } else {
+            "\r\n.\r\n"
+        };
+
+        self.write(&format!("{}{}", stuffed, terminator)).await?;
+        self.expect(250).await.map(|_| ())
+    }
+}
+
+#[async_trait]
+impl MailerPort for SmtpMailer {
+    #[instrument(skip(self, message), fields(host = %self.host, subject = %message.subject), level = "debug")]
+    async fn send(&self, message: &EmailMessage) -> Result<(), MailerError> {
+        timeout(self.timeout, self.deliver(message))
+            .await
+            .map_err(|_| MailerError::ConnectionFailed("Timed out".to_string()))?
+    }
+}
@@ -0,0 +1,2 @@
+pub mod prometheus_metrics;
+pub mod query_metrics_layer;
@@ -0,0 +1,175 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge,
+    IntGaugeVec, Opts, Registry, TextEncoder, exponential_buckets,
+};
+use sqlx::{PgPool, SqlitePool};
+
+use crate::domain::{models::report::ReportType, ports::metrics_port::MetricsPort};
+
+/// Pool the repositories query, described by the pool gauges.
+pub enum MeteredPool {
+    Sqlite(SqlitePool),
+    Postgres(PgPool),
+}
+
+/// Metrics kept in process, for Prometheus to scrape. Gauges describing the database pool are
+/// read when rendering.
+pub struct PrometheusMetrics {
+    registry: Registry,
+    pool: MeteredPool,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    reports_created: IntCounterVec,
+    stored_file_size: Histogram,
+    stored_bytes: IntCounter,
+    active_users: IntGauge,
+    db_query_duration: HistogramVec,
+    db_pool_connections: IntGaugeVec,
+    db_pool_max_connections: IntGauge,
+}
+
+impl PrometheusMetrics {
+    pub fn new(pool: MeteredPool) -> Result<Self, prometheus::Error> {
+        let registry = Registry::new_custom(Some("rebug".into()), None)?;
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests handled."),
+            &["method", "route", "status"],
+        )?;
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Time taken to handle HTTP requests, until the response headers are sent.",
+            ),
+            &["method", "route", "status"],
+        )?;
+        let reports_created = IntCounterVec::new(
+            Opts::new("reports_created_total", "Reports created."),
+            &["report_type"],
+        )?;
+        let stored_file_size = Histogram::with_opts(
+            HistogramOpts::new(
+                "stored_file_size_bytes",
+                "Size of the uploaded files stored.",
+            )
+            // 1 KiB to 1 GiB.
+            .buckets(exponential_buckets(1024.0, 4.0, 11)?),
+        )?;
+        let stored_bytes = IntCounter::new("stored_bytes_total", "Bytes written to the storage.")?;
+        let active_users = IntGauge::new(
+            "active_users",
+            "Users allowed to sign in, neither deactivated nor deleted.",
+        )?;
+        let db_query_duration = HistogramVec::new(
+            HistogramOpts::new("db_query_duration_seconds", "Time taken by SQL statements.")
+                // 0.1 ms to 1.6 s.
+                .buckets(exponential_buckets(0.0001, 4.0, 8)?),
+            &["kind"],
+        )?;
+        let db_pool_connections = IntGaugeVec::new(
+            Opts::new("db_pool_connections", "Open database connections."),
+            &["state"],
+        )?;
+        let db_pool_max_connections = IntGauge::new(
+            "db_pool_max_connections",
+            "Largest number of database connections the pool opens.",
+        )?;
+
+        registry.register(Box::new(http_requests.clone()))?;
+        registry.register(Box::new(http_request_duration.clone()))?;
+        registry.register(Box::new(reports_created.clone()))?;
+        registry.register(Box::new(stored_file_size.clone()))?;
+        registry.register(Box::new(stored_bytes.clone()))?;
+        registry.register(Box::new(active_users.clone()))?;
+        registry.register(Box::new(db_query_duration.clone()))?;
+        registry.register(Box::new(db_pool_connections.clone()))?;
+        registry.register(Box::new(db_pool_max_connections.clone()))?;
+
+        Ok(Self {
+            registry,
+            pool,
+            http_requests,
+            http_request_duration,
+            reports_created,
+            stored_file_size,
+            stored_bytes,
+            active_users,
+            db_query_duration,
+            db_pool_connections,
+            db_pool_max_connections,
+        })
+    }
+
+    /// `kind` is `query` for statements returning rows, `exec` for the others.
+    pub fn record_db_query(&self, kind: &str, duration: Duration) {
+        self.db_query_duration
+            .with_label_values(&[kind])
+            .observe(duration.as_secs_f64());
+    }
+
+    fn update_pool_gauges(&self) {
+        let (size, idle, max_connections) = match &self.pool {
+            MeteredPool::Sqlite(pool) => (
+                pool.size(),
+                pool.num_idle(),
+                pool.options().get_max_connections(),
+            ),
+            MeteredPool::Postgres(pool) => (
+                pool.size(),
+                pool.num_idle(),
+                pool.options().get_max_connections(),
+            ),
+        };
+        let (in_use, idle) = (i64::from(size) - idle as i64, idle as i64);
+
+        self.db_pool_connections
+            .with_label_values(&["in_use"])
+            .set(in_use);
+        self.db_pool_connections
+            .with_label_values(&["idle"])
+            .set(idle);
+        self.db_pool_max_connections.set(i64::from(max_connections));
+    }
+}
+
+#[async_trait]
+impl MetricsPort for PrometheusMetrics {
+    fn record_http_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+
+        self.http_requests.with_label_values(&labels).inc();
+        self.http_request_duration
+            .with_label_values(&labels)
+            .observe(duration.as_secs_f64());
+    }
+
+    fn record_report_created(&self, report_type: &ReportType) {
+        self.reports_created
+            .with_label_values(&[report_type.to_string().to_lowercase()])
+            .inc();
+    }
+
+    fn record_stored_file(&self, size_bytes: u64) {
+        self.stored_file_size.observe(size_bytes as f64);
+        self.stored_bytes.inc_by(size_bytes);
+    }
+
+    fn set_active_users(&self, count: i64) {
+        self.active_users.set(count);
+    }
+
+    async fn render(&self) -> String {
+        self.update_pool_gauges();
+
+        let mut buffer = Vec::new();
+        if let Err(e) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            tracing::error!("Failed to encode metrics: {}", e);
+        }
+
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
@@ -0,0 +1,99 @@
+use std::{
+    fmt,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+
+use tracing::{
+    Event, Level, Subscriber,
+    field::{Field, Visit},
+};
+use tracing_subscriber::{
+    Layer,
+    filter::{Filtered, Targets},
+    layer::Context,
+};
+
+use super::prometheus_metrics::PrometheusMetrics;
+
+/// Target of the events sqlx emits as each SQL statement completes.
+const QUERY_EVENT_TARGET: &str = "sqlx::query";
+
+static QUERY_METRICS: OnceLock<Arc<PrometheusMetrics>> = OnceLock::new();
+
+/// Tracing layer timing every SQL statement from the events sqlx emits, whatever the log level.
+/// Statements run before `record_to` are not counted.
+pub struct QueryMetricsLayer;
+
+impl QueryMetricsLayer {
+    /// The layer, listening to the statement events only.
+    pub fn filtered<S: Subscriber>() -> Filtered<Self, Targets, S> {
+        Self.with_filter(Targets::new().with_target(QUERY_EVENT_TARGET, Level::DEBUG))
+    }
+
+    pub fn record_to(metrics: Arc<PrometheusMetrics>) {
+        if QUERY_METRICS.set(metrics).is_err() {
+            tracing::warn!("SQL statements are already recorded to other metrics");
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for QueryMetricsLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let Some(metrics) = QUERY_METRICS.get() else {
+            return;
+        };
+
+        let mut statement = StatementFields::default();
+        event.record(&mut statement);
+
+        if let Some(elapsed_secs) = statement.elapsed_secs {
+            metrics.record_db_query(statement.kind(), Duration::from_secs_f64(elapsed_secs));
+        }
+    }
+}
+
+#[derive(Default)]
+struct StatementFields {
+    summary: String,
+    rows_returned: u64,
+    elapsed_secs: Option<f64>,
+}
+
+impl StatementFields {
+    /// `query` for statements returning rows, `exec` for the others.
+    fn kind(&self) -> &'static str {
+        let is_select = self
+            .summary
+            .get(..6)
+            .is_some_and(|verb| verb.eq_ignore_ascii_case("select"));
+
+        if is_select || self.rows_returned > 0 {
+            "query"
+        } else {
+            "exec"
+        }
+    }
+}
+
+impl Visit for StatementFields {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if field.name() == "elapsed_secs" {
+            self.elapsed_secs = Some(value);
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "rows_returned" {
+            self.rows_returned = value;
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "summary" {
+            self.summary = value.to_string();
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn fmt::Debug) {}
+}
@@ -1,6 +1,15 @@
 pub mod container;
 pub mod database;
+pub mod events;
+pub mod export;
 pub mod frontend;
+pub mod health;
+pub mod issue_trackers;
+pub mod mail;
+pub mod metrics;
+pub mod notifications;
 pub mod repositories;
 pub mod security;
 pub mod storage;
+pub mod tls;
+pub mod webhooks;
@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, Url};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        models::chat_integration::ChatMessage,
+        ports::chat_notifier_port::{ChatNotifierError, ChatNotifierPort},
+    },
+    infrastructure::security::public_addresses::{check_ip_host, outbound_client_builder},
+};
+
+#[derive(Clone)]
+pub struct HttpChatNotifier {
+    client: Client,
+    allow_private_networks: bool,
+}
+
+impl HttpChatNotifier {
+    /// Unless `allow_private_networks` is set, messages only reach public addresses. Redirects
+    /// are never followed.
+    #[instrument(name = "HttpChatNotifier::new", level = "debug")]
+    pub fn new(
+        timeout_seconds: u64,
+        allow_private_networks: bool,
+    ) -> Result<Self, ChatNotifierError> {
+        let client = outbound_client_builder(allow_private_networks)
+            .timeout(Duration::from_secs(timeout_seconds))
+            .user_agent(concat!("rebug/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| ChatNotifierError::ConfigurationError(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            allow_private_networks,
+        })
+    }
+
+    /// URLs holding an IP address are not resolved, so they are checked here.
+    fn check_address(&self, webhook_url: &str) -> Result<(), ChatNotifierError> {
+        if self.allow_private_networks {
+            return Ok(());
+        }
+
+        let url =
+            Url::parse(webhook_url).map_err(|e| ChatNotifierError::RequestFailed(e.to_string()))?;
+        check_ip_host(&url).map_err(|e| ChatNotifierError::RequestFailed(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl ChatNotifierPort for HttpChatNotifier {
+    #[instrument(skip(self, webhook_url, message), level = "debug")]
+    async fn post_message(
+        &self,
+        webhook_url: &str,
+        message: &ChatMessage,
+    ) -> Result<(), ChatNotifierError> {
+        self.check_address(webhook_url)?;
+
+        let response = self
+            .client
+            .post(webhook_url)
+            .json(message)
+            .send()
+            .await
+            .map_err(|e| ChatNotifierError::RequestFailed(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ChatNotifierError::Rejected(status.as_u16()));
+        }
+
+        Ok(())
+    }
+}
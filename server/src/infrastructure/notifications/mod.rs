@@ -0,0 +1 @@
+pub mod http_chat_notifier;
@@ -0,0 +1,130 @@
+use async_trait::async_trait;
+use sqlx::{Row, Sqlite, SqlitePool, query::Query, sqlite::SqliteArguments};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::audit::{AuditEvent, AuditEventFilter, CreateAuditEventParams},
+        repositories::{RepositoryResult, audit_event_repository::AuditEventRepository},
+    },
+    infrastructure::repositories::{
+        count_to_i32, entities::audit_event_entity::AuditEventEntity, from_sqlite_row,
+        map_sqlx_error, sqlite_timestamp,
+    },
+};
+
+const AUDIT_EVENT_COLUMNS: &str = "id, actor_id, action, target_type, target_id, ip_address, \
+     user_agent, changes, created_at";
+
+const FILTER: &str = "WHERE ($1 IS NULL OR actor_id = $1)
+     AND ($2 IS NULL OR action = $2)
+     AND ($3 IS NULL OR target_type = $3)
+     AND ($4 IS NULL OR target_id = $4)
+     AND ($5 IS NULL OR created_at >= $5)
+     AND ($6 IS NULL OR created_at < $6)";
+
+#[derive(Clone)]
+pub struct SqliteAuditEventRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAuditEventRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Binds the filter to the first six parameters of a query using `FILTER`.
+fn bind_filter<'q>(
+    query: Query<'q, Sqlite, SqliteArguments<'q>>,
+    filter: &AuditEventFilter,
+) -> Query<'q, Sqlite, SqliteArguments<'q>> {
+    query
+        .bind(filter.actor_id.map(Uuid::hyphenated))
+        .bind(filter.action)
+        .bind(filter.target_type)
+        .bind(filter.target_id.map(Uuid::hyphenated))
+        .bind(filter.from.map(sqlite_timestamp))
+        .bind(filter.to.map(sqlite_timestamp))
+}
+
+#[async_trait]
+impl AuditEventRepository for SqliteAuditEventRepository {
+    #[instrument(skip(self, params), fields(action = %params.action, target_id = ?params.target_id), level = "debug")]
+    async fn create_event(&self, params: CreateAuditEventParams) -> RepositoryResult<AuditEvent> {
+        let query = format!(
+            "INSERT INTO audit_events (id, actor_id, action, target_type, target_id, ip_address, \
+             user_agent, changes, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             RETURNING {AUDIT_EVENT_COLUMNS}"
+        );
+
+        sqlx::query(&query)
+            .bind(Uuid::new_v4().hyphenated())
+            .bind(params.actor_id.map(Uuid::hyphenated))
+            .bind(params.action)
+            .bind(params.target_type)
+            .bind(params.target_id.map(Uuid::hyphenated))
+            .bind(params.ip_address)
+            .bind(params.user_agent)
+            .bind(params.changes.to_string())
+            .bind(sqlite_timestamp(chrono::Utc::now()))
+            .try_map(from_sqlite_row::<AuditEventEntity>)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .map(AuditEventEntity::into)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_paginated(
+        &self,
+        filter: &AuditEventFilter,
+        page: i32,
+        per_page: i32,
+    ) -> RepositoryResult<Vec<AuditEvent>> {
+        let offset = (page - 1) * per_page;
+        let query = format!(
+            "SELECT {AUDIT_EVENT_COLUMNS} FROM audit_events {FILTER}
+             ORDER BY created_at DESC
+             LIMIT $7 OFFSET $8"
+        );
+
+        bind_filter(sqlx::query(&query), filter)
+            .bind(i64::from(per_page))
+            .bind(i64::from(offset))
+            .try_map(from_sqlite_row::<AuditEventEntity>)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .map(|entities| entities.into_iter().map(AuditEventEntity::into).collect())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn count(&self, filter: &AuditEventFilter) -> RepositoryResult<i32> {
+        let query = format!("SELECT COUNT(*) FROM audit_events {FILTER}");
+
+        bind_filter(sqlx::query(&query), filter)
+            .fetch_one(&self.pool)
+            .await
+            .and_then(|row| row.try_get::<i64, _>(0))
+            .map_err(map_sqlx_error)
+            .and_then(count_to_i32)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_all(&self, filter: &AuditEventFilter) -> RepositoryResult<Vec<AuditEvent>> {
+        let query = format!(
+            "SELECT {AUDIT_EVENT_COLUMNS} FROM audit_events {FILTER}
+             ORDER BY created_at ASC"
+        );
+
+        bind_filter(sqlx::query(&query), filter)
+            .try_map(from_sqlite_row::<AuditEventEntity>)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .map(|entities| entities.into_iter().map(AuditEventEntity::into).collect())
+    }
+}
@@ -1,29 +1,58 @@
 use async_trait::async_trait;
-use rbatis::RBatis;
+use sqlx::{SqliteConnection, SqlitePool};
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::{
     domain::{
-        models::board::Board,
+        models::{board::Board, board_export::BoardImport},
         repositories::{RepositoryResult, board_repository::BoardRepository},
     },
     infrastructure::repositories::{
-        entities::board_entity::BoardEntity, map_rbatis_error, queries::board_queries,
+        entities::{
+            board_entity::BoardEntity, report_entity::ReportEntity,
+            report_history_entity::ReportHistoryEntity,
+        },
+        from_sqlite_row, map_sqlx_error,
+        report_repository::{insert_report, insert_report_history},
+        sqlite_timestamp,
     },
 };
 
+const BOARD_COLUMNS: &str = "id, owner_id, name, description, is_default, created_at, updated_at";
+
 #[derive(Clone)]
 pub struct SqliteBoardRepository {
-    rb: RBatis,
+    pool: SqlitePool,
 }
 
 impl SqliteBoardRepository {
-    pub fn new(rb: RBatis) -> Self {
-        Self { rb }
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
     }
 }
 
+async fn insert_board(
+    conn: &mut SqliteConnection,
+    entity: BoardEntity,
+) -> Result<BoardEntity, sqlx::Error> {
+    sqlx::query(&format!(
+        "INSERT INTO boards (id, owner_id, name, description, is_default, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7)
+         RETURNING {BOARD_COLUMNS}"
+    ))
+    .bind(entity.id.hyphenated())
+    .bind(entity.owner_id.hyphenated())
+    .bind(entity.name)
+    .bind(entity.description)
+    .bind(entity.is_default)
+    .bind(sqlite_timestamp(entity.created_at))
+    .bind(sqlite_timestamp(entity.updated_at))
+    .try_map(from_sqlite_row::<BoardEntity>)
+    .fetch_one(conn)
+    .await
+}
+
 #[async_trait]
 impl BoardRepository for SqliteBoardRepository {
     #[instrument(skip(self), level = "debug")]
@@ -48,25 +77,104 @@ impl BoardRepository for SqliteBoardRepository {
 
         tracing::debug!(board_id = %entity.id, "Creating new board.");
 
-        board_queries::insert_board(&self.rb, entity)
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx_error)?;
+        insert_board(&mut conn, entity)
             .await
-            .map_err(map_rbatis_error)
+            .map_err(map_sqlx_error)
             .map(BoardEntity::into)
     }
 
     #[instrument(skip(self), level = "debug")]
     async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Board>> {
-        board_queries::find_by_id(&self.rb, id)
+        sqlx::query(&format!("SELECT {BOARD_COLUMNS} FROM boards WHERE id = $1"))
+            .bind(id.hyphenated())
+            .try_map(from_sqlite_row::<BoardEntity>)
+            .fetch_optional(&self.pool)
             .await
-            .map_err(map_rbatis_error)
+            .map_err(map_sqlx_error)
             .map(|opt_entity| opt_entity.map(BoardEntity::into))
     }
 
     #[instrument(skip(self), level = "debug")]
     async fn find_by_user_id(&self, user_id: Uuid) -> RepositoryResult<Vec<Board>> {
-        board_queries::find_by_owner_id(&self.rb, user_id)
+        sqlx::query(&format!(
+            "SELECT {BOARD_COLUMNS} FROM boards WHERE owner_id = $1 ORDER BY created_at ASC"
+        ))
+        .bind(user_id.hyphenated())
+        .try_map(from_sqlite_row::<BoardEntity>)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(BoardEntity::into).collect())
+    }
+
+    #[instrument(skip(self, import), fields(board_id = %import.board.id, report_count = import.reports.len()), level = "debug")]
+    async fn import_board(&self, import: BoardImport) -> RepositoryResult<Board> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        let board = insert_board(
+            &mut tx,
+            BoardEntity {
+                id: import.board.id,
+                name: import.board.name,
+                description: import.board.description,
+                owner_id: import.board.owner_id,
+                is_default: import.board.is_default,
+                created_at: import.board.created_at,
+                updated_at: import.board.updated_at,
+            },
+        )
+        .await
+        .map_err(map_sqlx_error)?;
+
+        for report in import.reports {
+            insert_report(
+                &mut tx,
+                ReportEntity {
+                    id: report.id,
+                    user_id: report.user_id,
+                    board_id: report.board_id,
+                    report_type: report.report_type,
+                    status: report.status,
+                    title: report.title,
+                    description: report.description,
+                    file_path: report.file_path,
+                    thumbnail_file_path: report.thumbnail_file_path,
+                    url: report.url,
+                    browser_name: report.browser_name,
+                    browser_version: report.browser_version,
+                    os_name: report.os_name,
+                    contact_email: report.contact_email,
+                    external_issue_key: report.external_issue_key,
+                    external_issue_url: report.external_issue_url,
+                    created_at: report.created_at,
+                    updated_at: report.updated_at,
+                },
+            )
             .await
-            .map_err(map_rbatis_error)
-            .map(|entities| entities.into_iter().map(BoardEntity::into).collect())
+            .map_err(map_sqlx_error)?;
+        }
+
+        for entry in import.history {
+            insert_report_history(
+                &mut tx,
+                ReportHistoryEntity {
+                    id: entry.id,
+                    report_id: entry.report_id,
+                    field: entry.field,
+                    old_value: entry.old_value,
+                    new_value: entry.new_value,
+                    source: entry.source,
+                    actor_id: entry.actor_id,
+                    details: entry.details,
+                    created_at: entry.created_at,
+                },
+            )
+            .await
+            .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(board.into())
     }
 }
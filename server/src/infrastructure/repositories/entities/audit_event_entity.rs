@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::audit::{AuditAction, AuditEvent, AuditTargetType};
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AuditEventEntity {
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub action: AuditAction,
+    pub target_type: AuditTargetType,
+    pub target_id: Option<Uuid>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub changes: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<AuditEventEntity> for AuditEvent {
+    fn from(entity: AuditEventEntity) -> Self {
+        Self {
+            id: entity.id,
+            actor_id: entity.actor_id,
+            action: entity.action,
+            target_type: entity.target_type,
+            target_id: entity.target_id,
+            ip_address: entity.ip_address,
+            user_agent: entity.user_agent,
+            // Only ever written from a serialized value.
+            changes: serde_json::from_str(&entity.changes).unwrap_or_default(),
+            created_at: entity.created_at,
+        }
+    }
+}
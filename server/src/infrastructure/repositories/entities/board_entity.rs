@@ -5,7 +5,7 @@ use crate::domain::models::board::Board;
 
 use super::bool_from_int;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct BoardEntity {
     pub id: Uuid,
     pub name: String,
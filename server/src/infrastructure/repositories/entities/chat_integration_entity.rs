@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::chat_integration::ChatIntegration;
+
+use super::bool_from_int;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ChatIntegrationEntity {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub webhook_url: String,
+    pub message_template: Option<String>,
+
+    #[serde(with = "bool_from_int")]
+    pub is_active: bool,
+
+    pub created_by: Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ChatIntegrationEntity> for ChatIntegration {
+    fn from(entity: ChatIntegrationEntity) -> Self {
+        Self {
+            id: entity.id,
+            board_id: entity.board_id,
+            webhook_url: entity.webhook_url,
+            message_template: entity.message_template,
+            is_active: entity.is_active,
+            created_by: entity.created_by,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::email::InboundMailbox;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct InboundMailboxEntity {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub token: String,
+    pub created_by: Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<InboundMailboxEntity> for InboundMailbox {
+    fn from(entity: InboundMailboxEntity) -> Self {
+        Self {
+            id: entity.id,
+            board_id: entity.board_id,
+            token: entity.token,
+            created_by: entity.created_by,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
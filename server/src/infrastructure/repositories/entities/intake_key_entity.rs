@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::intake_key::IntakeKey;
+
+use super::bool_from_int;
+
+const ORIGIN_SEPARATOR: char = ',';
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct IntakeKeyEntity {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub name: String,
+    pub public_key: String,
+    pub allowed_origins: String,
+
+    #[serde(with = "bool_from_int")]
+    pub is_active: bool,
+
+    pub created_by: Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub fn join_origins(origins: &[String]) -> String {
+    origins.join(&ORIGIN_SEPARATOR.to_string())
+}
+
+impl From<IntakeKeyEntity> for IntakeKey {
+    fn from(entity: IntakeKeyEntity) -> Self {
+        Self {
+            id: entity.id,
+            board_id: entity.board_id,
+            name: entity.name,
+            public_key: entity.public_key,
+            allowed_origins: entity
+                .allowed_origins
+                .split(ORIGIN_SEPARATOR)
+                .filter(|origin| !origin.is_empty())
+                .map(str::to_string)
+                .collect(),
+            is_active: entity.is_active,
+            created_by: entity.created_by,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::{invitation::Invitation, user::UserRole};
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct InvitationEntity {
+    pub id: Uuid,
+    pub email: String,
+    pub role: UserRole,
+    pub token_hash: String,
+    pub invited_by: Uuid,
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+    pub accepted_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub revoked_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<InvitationEntity> for Invitation {
+    fn from(entity: InvitationEntity) -> Self {
+        Self {
+            id: entity.id,
+            email: entity.email,
+            role: entity.role,
+            token_hash: entity.token_hash,
+            invited_by: entity.invited_by,
+            expires_at: entity.expires_at,
+            accepted_at: entity.accepted_at,
+            revoked_at: entity.revoked_at,
+            created_at: entity.created_at,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::issue_tracker::{IssueTrackerIntegration, IssueTrackerProvider};
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct IssueTrackerIntegrationEntity {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub provider: IssueTrackerProvider,
+    pub api_base_url: String,
+    pub repository: String,
+    pub access_token: String,
+    pub webhook_secret: String,
+    pub created_by: Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<IssueTrackerIntegrationEntity> for IssueTrackerIntegration {
+    fn from(entity: IssueTrackerIntegrationEntity) -> Self {
+        Self {
+            id: entity.id,
+            board_id: entity.board_id,
+            provider: entity.provider,
+            api_base_url: entity.api_base_url,
+            repository: entity.repository,
+            access_token: entity.access_token,
+            webhook_secret: entity.webhook_secret,
+            created_by: entity.created_by,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
@@ -1,4 +1,16 @@
+pub mod audit_event_entity;
 pub mod board_entity;
 pub mod bool_from_int;
+pub mod chat_integration_entity;
+pub mod inbound_mailbox_entity;
+pub mod intake_key_entity;
+pub mod invitation_entity;
+pub mod issue_tracker_integration_entity;
+pub mod notification_preference_entity;
+pub mod recovery_code_entity;
 pub mod report_entity;
+pub mod report_history_entity;
+pub mod text_enum;
 pub mod user_entity;
+pub mod webhook_delivery_entity;
+pub mod webhook_entity;
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::notification_preference::NotificationPreferences;
+
+use super::bool_from_int;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct NotificationPreferenceEntity {
+    pub user_id: Uuid,
+
+    #[serde(with = "bool_from_int")]
+    pub email_on_new_report: bool,
+    #[serde(with = "bool_from_int")]
+    pub email_on_status_change: bool,
+
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<NotificationPreferenceEntity> for NotificationPreferences {
+    fn from(entity: NotificationPreferenceEntity) -> Self {
+        Self {
+            user_id: entity.user_id,
+            email_on_new_report: entity.email_on_new_report,
+            email_on_status_change: entity.email_on_status_change,
+            updated_at: Some(entity.updated_at),
+        }
+    }
+}
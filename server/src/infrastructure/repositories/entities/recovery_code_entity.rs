@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::two_factor::RecoveryCode;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct RecoveryCodeEntity {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub code_hash: String,
+    pub used_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<RecoveryCodeEntity> for RecoveryCode {
+    fn from(entity: RecoveryCodeEntity) -> Self {
+        Self {
+            id: entity.id,
+            user_id: entity.user_id,
+            code_hash: entity.code_hash,
+            used_at: entity.used_at,
+            created_at: entity.created_at,
+        }
+    }
+}
@@ -1,14 +1,15 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::models::report::{Report, ReportType};
+use crate::domain::models::report::{Report, ReportStatus, ReportType};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct ReportEntity {
     pub id: Uuid,
     pub user_id: Uuid,
     pub board_id: Uuid,
     pub report_type: ReportType,
+    pub status: ReportStatus,
     pub title: String,
     pub description: Option<String>,
     pub file_path: String,
@@ -17,6 +18,9 @@ pub struct ReportEntity {
     pub browser_name: Option<String>,
     pub browser_version: Option<String>,
     pub os_name: Option<String>,
+    pub contact_email: Option<String>,
+    pub external_issue_key: Option<String>,
+    pub external_issue_url: Option<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -28,6 +32,7 @@ impl From<ReportEntity> for Report {
             user_id: entity.user_id,
             board_id: entity.board_id,
             report_type: entity.report_type,
+            status: entity.status,
             title: entity.title,
             description: entity.description,
             file_path: entity.file_path,
@@ -36,6 +41,9 @@ impl From<ReportEntity> for Report {
             browser_name: entity.browser_name,
             browser_version: entity.browser_version,
             os_name: entity.os_name,
+            contact_email: entity.contact_email,
+            external_issue_key: entity.external_issue_key,
+            external_issue_url: entity.external_issue_url,
             created_at: entity.created_at,
             updated_at: entity.updated_at,
         }
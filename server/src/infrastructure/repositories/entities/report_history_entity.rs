@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::report::{ReportChangeSource, ReportHistoryEntry};
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ReportHistoryEntity {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub source: ReportChangeSource,
+    pub actor_id: Option<Uuid>,
+    pub details: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ReportHistoryEntity> for ReportHistoryEntry {
+    fn from(entity: ReportHistoryEntity) -> Self {
+        Self {
+            id: entity.id,
+            report_id: entity.report_id,
+            field: entity.field,
+            old_value: entity.old_value,
+            new_value: entity.new_value,
+            source: entity.source,
+            actor_id: entity.actor_id,
+            details: entity.details,
+            created_at: entity.created_at,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use serde::{Serialize, de::DeserializeOwned};
+use sqlx::{Database, Decode, Encode, Type, encode::IsNull, error::BoxDynError};
+
+use crate::domain::models::{
+    audit::{AuditAction, AuditTargetType},
+    event::EventType,
+    issue_tracker::IssueTrackerProvider,
+    report::{ReportChangeSource, ReportStatus, ReportType},
+    user::UserRole,
+    webhook::WebhookDeliveryStatus,
+};
+
+// Enums are stored in TEXT columns under their serde name, so every backend holds the same values.
+macro_rules! text_enum {
+    ($($enum_type:ty),* $(,)?) => {$(
+        impl<DB: Database> Type<DB> for $enum_type
+        where
+            String: Type<DB>,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <String as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <String as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: Database> Encode<'q, DB> for $enum_type
+        where
+            String: Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+            ) -> Result<IsNull, BoxDynError> {
+                <String as Encode<DB>>::encode(to_text(self)?, buf)
+            }
+        }
+
+        impl<'r, DB: Database> Decode<'r, DB> for $enum_type
+        where
+            &'r str: Decode<'r, DB>,
+        {
+            fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                from_text(<&str as Decode<DB>>::decode(value)?)
+            }
+        }
+    )*};
+}
+
+text_enum!(
+    AuditAction,
+    AuditTargetType,
+    EventType,
+    IssueTrackerProvider,
+    ReportChangeSource,
+    ReportStatus,
+    ReportType,
+    UserRole,
+    WebhookDeliveryStatus,
+);
+
+fn to_text<T: Serialize>(value: &T) -> Result<String, BoxDynError> {
+    match serde_json::to_value(value)? {
+        serde_json::Value::String(text) => Ok(text),
+        value => Err(format!("{} is not stored as text", value).into()),
+    }
+}
+
+fn from_text<T: DeserializeOwned>(text: &str) -> Result<T, BoxDynError> {
+    Ok(serde_json::from_value(serde_json::Value::String(
+        text.to_string(),
+    ))?)
+}
@@ -3,14 +3,27 @@ use uuid::Uuid;
 
 use crate::domain::models::user::{User, UserRole};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use super::bool_from_int;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct UserEntity {
     pub id: Uuid,
     pub email: String,
     pub password_hash: String,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
+    pub avatar_url: Option<String>,
     pub role: UserRole,
+    pub totp_secret: Option<String>,
+
+    #[serde(with = "bool_from_int")]
+    pub totp_enabled: bool,
+
+    #[serde(with = "bool_from_int")]
+    pub is_active: bool,
+
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -23,7 +36,12 @@ impl From<UserEntity> for User {
             password_hash: entity.password_hash,
             first_name: entity.first_name,
             last_name: entity.last_name,
+            avatar_url: entity.avatar_url,
             role: entity.role,
+            totp_secret: entity.totp_secret,
+            two_factor_enabled: entity.totp_enabled,
+            is_active: entity.is_active,
+            deleted_at: entity.deleted_at,
             created_at: entity.created_at,
             updated_at: entity.updated_at,
         }
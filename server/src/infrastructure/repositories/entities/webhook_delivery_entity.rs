@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::{
+    event::EventType,
+    webhook::{WebhookDelivery, WebhookDeliveryStatus},
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct WebhookDeliveryEntity {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: EventType,
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<WebhookDeliveryEntity> for WebhookDelivery {
+    fn from(entity: WebhookDeliveryEntity) -> Self {
+        Self {
+            id: entity.id,
+            webhook_id: entity.webhook_id,
+            event_id: entity.event_id,
+            event_type: entity.event_type,
+            payload: entity.payload,
+            status: entity.status,
+            attempts: entity.attempts,
+            response_status: entity.response_status,
+            last_error: entity.last_error,
+            next_attempt_at: entity.next_attempt_at,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
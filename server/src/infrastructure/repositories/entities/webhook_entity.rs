@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::{event::EventType, webhook::Webhook};
+
+use super::bool_from_int;
+
+const EVENT_TYPE_SEPARATOR: char = ',';
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct WebhookEntity {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_types: String,
+
+    #[serde(with = "bool_from_int")]
+    pub is_active: bool,
+
+    pub created_by: Uuid,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub fn join_event_types(event_types: &[EventType]) -> String {
+    event_types
+        .iter()
+        .map(EventType::as_str)
+        .collect::<Vec<_>>()
+        .join(&EVENT_TYPE_SEPARATOR.to_string())
+}
+
+impl From<WebhookEntity> for Webhook {
+    fn from(entity: WebhookEntity) -> Self {
+        Self {
+            id: entity.id,
+            board_id: entity.board_id,
+            url: entity.url,
+            secret: entity.secret,
+            event_types: entity
+                .event_types
+                .split(EVENT_TYPE_SEPARATOR)
+                .filter_map(|event_type| event_type.parse().ok())
+                .collect(),
+            is_active: entity.is_active,
+            created_by: entity.created_by,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::{invitation::Invitation, user::UserRole},
+        repositories::{
+            RepositoryError, RepositoryResult, invitation_repository::InvitationRepository,
+        },
+    },
+    infrastructure::repositories::{
+        entities::invitation_entity::InvitationEntity, from_sqlite_row, map_sqlx_error,
+        sqlite_timestamp,
+    },
+};
+
+const INVITATION_COLUMNS: &str = "id, email, role, token_hash, invited_by, expires_at, \
+     accepted_at, revoked_at, created_at";
+
+#[derive(Clone)]
+pub struct SqliteInvitationRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteInvitationRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InvitationRepository for SqliteInvitationRepository {
+    #[instrument(skip(self, token_hash), fields(email = %email, role = %role), level = "debug")]
+    async fn create_invitation(
+        &self,
+        email: &str,
+        role: UserRole,
+        token_hash: &str,
+        invited_by: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> RepositoryResult<Invitation> {
+        let id = Uuid::new_v4();
+
+        tracing::debug!(invitation_id = %id, "Creating new invitation.");
+
+        sqlx::query(&format!(
+            "INSERT INTO user_invitations (id, email, role, token_hash, invited_by, expires_at, \
+             created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING {INVITATION_COLUMNS}"
+        ))
+        .bind(id.hyphenated())
+        .bind(email)
+        .bind(role)
+        .bind(token_hash)
+        .bind(invited_by.hyphenated())
+        .bind(sqlite_timestamp(expires_at))
+        .bind(sqlite_timestamp(Utc::now()))
+        .try_map(from_sqlite_row::<InvitationEntity>)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(InvitationEntity::into)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Invitation>> {
+        sqlx::query(&format!(
+            "SELECT {INVITATION_COLUMNS} FROM user_invitations WHERE id = $1"
+        ))
+        .bind(id.hyphenated())
+        .try_map(from_sqlite_row::<InvitationEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(InvitationEntity::into))
+    }
+
+    #[instrument(skip(self, token_hash), level = "debug")]
+    async fn find_by_token_hash(&self, token_hash: &str) -> RepositoryResult<Option<Invitation>> {
+        sqlx::query(&format!(
+            "SELECT {INVITATION_COLUMNS} FROM user_invitations WHERE token_hash = $1"
+        ))
+        .bind(token_hash)
+        .try_map(from_sqlite_row::<InvitationEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(InvitationEntity::into))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_open(&self) -> RepositoryResult<Vec<Invitation>> {
+        sqlx::query(&format!(
+            "SELECT {INVITATION_COLUMNS} FROM user_invitations
+             WHERE accepted_at IS NULL AND revoked_at IS NULL
+             ORDER BY created_at DESC"
+        ))
+        .try_map(from_sqlite_row::<InvitationEntity>)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(InvitationEntity::into).collect())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn mark_accepted(&self, id: Uuid) -> RepositoryResult<()> {
+        let exec_result = sqlx::query(
+            "UPDATE user_invitations SET accepted_at = $2
+             WHERE id = $1 AND accepted_at IS NULL AND revoked_at IS NULL",
+        )
+        .bind(id.hyphenated())
+        .bind(sqlite_timestamp(Utc::now()))
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if exec_result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn revoke(&self, id: Uuid) -> RepositoryResult<()> {
+        let exec_result = sqlx::query(
+            "UPDATE user_invitations SET revoked_at = $2
+             WHERE id = $1 AND accepted_at IS NULL AND revoked_at IS NULL",
+        )
+        .bind(id.hyphenated())
+        .bind(sqlite_timestamp(Utc::now()))
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if exec_result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn revoke_open_by_email(&self, email: &str) -> RepositoryResult<()> {
+        sqlx::query(
+            "UPDATE user_invitations SET revoked_at = $2
+             WHERE email = $1 AND accepted_at IS NULL AND revoked_at IS NULL",
+        )
+        .bind(email)
+        .bind(sqlite_timestamp(Utc::now()))
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|_| ())
+    }
+}
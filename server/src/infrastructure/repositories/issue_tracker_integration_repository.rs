@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::issue_tracker::{ConfigureIssueTrackerParams, IssueTrackerIntegration},
+        repositories::{
+            RepositoryError, RepositoryResult,
+            issue_tracker_integration_repository::IssueTrackerIntegrationRepository,
+        },
+    },
+    infrastructure::repositories::{
+        entities::issue_tracker_integration_entity::IssueTrackerIntegrationEntity, from_sqlite_row,
+        map_sqlx_error, sqlite_timestamp,
+    },
+};
+
+const ISSUE_TRACKER_INTEGRATION_COLUMNS: &str = "id, board_id, provider, api_base_url, \
+     repository, access_token, webhook_secret, created_by, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteIssueTrackerIntegrationRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteIssueTrackerIntegrationRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IssueTrackerIntegrationRepository for SqliteIssueTrackerIntegrationRepository {
+    #[instrument(skip(self, webhook_secret, params), fields(board_id = %board_id, provider = %params.provider), level = "debug")]
+    async fn upsert_for_board(
+        &self,
+        board_id: Uuid,
+        api_base_url: &str,
+        webhook_secret: &str,
+        params: ConfigureIssueTrackerParams,
+        created_by: Uuid,
+    ) -> RepositoryResult<IssueTrackerIntegration> {
+        // The webhook secret is kept when the integration is reconfigured.
+        sqlx::query(&format!(
+            "INSERT INTO issue_tracker_integrations (id, board_id, provider, api_base_url, \
+             repository, access_token, webhook_secret, created_by, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $9)
+             ON CONFLICT (board_id) DO UPDATE SET
+             provider = excluded.provider,
+             api_base_url = excluded.api_base_url,
+             repository = excluded.repository,
+             access_token = excluded.access_token,
+             updated_at = excluded.updated_at
+             RETURNING {ISSUE_TRACKER_INTEGRATION_COLUMNS}"
+        ))
+        .bind(Uuid::new_v4().hyphenated())
+        .bind(board_id.hyphenated())
+        .bind(params.provider)
+        .bind(api_base_url)
+        .bind(params.repository)
+        .bind(params.access_token)
+        .bind(webhook_secret)
+        .bind(created_by.hyphenated())
+        .bind(sqlite_timestamp(chrono::Utc::now()))
+        .try_map(from_sqlite_row::<IssueTrackerIntegrationEntity>)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(IssueTrackerIntegrationEntity::into)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<IssueTrackerIntegration>> {
+        sqlx::query(&format!(
+            "SELECT {ISSUE_TRACKER_INTEGRATION_COLUMNS} FROM issue_tracker_integrations \
+             WHERE id = $1"
+        ))
+        .bind(id.hyphenated())
+        .try_map(from_sqlite_row::<IssueTrackerIntegrationEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(IssueTrackerIntegrationEntity::into))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_board_id(
+        &self,
+        board_id: Uuid,
+    ) -> RepositoryResult<Option<IssueTrackerIntegration>> {
+        sqlx::query(&format!(
+            "SELECT {ISSUE_TRACKER_INTEGRATION_COLUMNS} FROM issue_tracker_integrations \
+             WHERE board_id = $1"
+        ))
+        .bind(board_id.hyphenated())
+        .try_map(from_sqlite_row::<IssueTrackerIntegrationEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(IssueTrackerIntegrationEntity::into))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn delete_by_board_id(&self, board_id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query("DELETE FROM issue_tracker_integrations WHERE board_id = $1")
+            .bind(board_id.hyphenated())
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -1,22 +1,69 @@
-use rbatis::Error as RbatisError;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::de::DeserializeOwned;
+use sqlx::{Column, Row, ValueRef, sqlite::SqliteRow};
 
 use crate::domain::repositories::RepositoryError;
 
+pub mod audit_event_repository;
 pub mod board_repository;
+pub mod chat_integration_repository;
+pub mod inbound_mailbox_repository;
+pub mod intake_key_repository;
+pub mod invitation_repository;
+pub mod issue_tracker_integration_repository;
+pub mod notification_preference_repository;
+pub mod postgres;
+pub mod recovery_code_repository;
 pub mod report_repository;
+pub mod settings_repository;
 pub mod user_repository;
+pub mod webhook_delivery_repository;
+pub mod webhook_repository;
 
 mod entities;
-pub mod queries;
 
-pub fn map_rbatis_error(e: RbatisError) -> RepositoryError {
-    // TODO: not found error ?
+// Transactions roll back when dropped, so returning early with `?` discards their statements.
+
+pub fn map_sqlx_error(e: sqlx::Error) -> RepositoryError {
     match e {
-        RbatisError::E(e) if e.contains("UNIQUE constraint failed") => {
+        sqlx::Error::Database(ref db_error) if db_error.is_unique_violation() => {
             RepositoryError::AlreadyExists
         }
         _ => RepositoryError::DatabaseError(e.to_string()),
     }
 }
 
-pub type RepositoryResult<T> = Result<T, RepositoryError>;
+fn count_to_i32(count: i64) -> Result<i32, RepositoryError> {
+    count
+        .try_into()
+        .map_err(|_| RepositoryError::DatabaseError("Count conversion error".into()))
+}
+
+/// Decodes a SQLite row through the serde implementation of the entity. SQLite stores UUIDs and
+/// timestamps as text and booleans as integers, which sqlx would not decode into the entity
+/// fields by itself.
+fn from_sqlite_row<T: DeserializeOwned>(row: SqliteRow) -> Result<T, sqlx::Error> {
+    let mut fields = serde_json::Map::with_capacity(row.len());
+
+    for column in row.columns() {
+        let index = column.ordinal();
+        let value = if row.try_get_raw(index)?.is_null() {
+            serde_json::Value::Null
+        } else {
+            match row.try_get_raw(index)?.type_info().to_string().as_str() {
+                "INTEGER" | "BOOLEAN" => row.try_get_unchecked::<i64, _>(index)?.into(),
+                "REAL" => row.try_get_unchecked::<f64, _>(index)?.into(),
+                _ => row.try_get_unchecked::<String, _>(index)?.into(),
+            }
+        };
+        fields.insert(column.name().to_string(), value);
+    }
+
+    serde_json::from_value(serde_json::Value::Object(fields))
+        .map_err(|e| sqlx::Error::Decode(e.into()))
+}
+
+/// Timestamp in the RFC 3339 form the SQLite database already holds, ending with `Z`.
+fn sqlite_timestamp(date_time: DateTime<Utc>) -> String {
+    date_time.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::notification_preference::{
+            NotificationPreferences, UpdateNotificationPreferencesParams,
+        },
+        repositories::{
+            RepositoryResult, notification_preference_repository::NotificationPreferenceRepository,
+        },
+    },
+    infrastructure::repositories::{
+        entities::notification_preference_entity::NotificationPreferenceEntity, from_sqlite_row,
+        map_sqlx_error, sqlite_timestamp,
+    },
+};
+
+#[derive(Clone)]
+pub struct SqliteNotificationPreferenceRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteNotificationPreferenceRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationPreferenceRepository for SqliteNotificationPreferenceRepository {
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<NotificationPreferences>> {
+        sqlx::query(
+            "SELECT user_id, email_on_new_report, email_on_status_change, updated_at
+             FROM notification_preferences
+             WHERE user_id = $1",
+        )
+        .bind(user_id.hyphenated())
+        .try_map(from_sqlite_row::<NotificationPreferenceEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(NotificationPreferenceEntity::into))
+    }
+
+    #[instrument(skip(self, params), level = "debug")]
+    async fn upsert(
+        &self,
+        user_id: Uuid,
+        params: UpdateNotificationPreferencesParams,
+    ) -> RepositoryResult<NotificationPreferences> {
+        sqlx::query(
+            "INSERT INTO notification_preferences (user_id, email_on_new_report, \
+             email_on_status_change, updated_at)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT (user_id) DO UPDATE SET
+             email_on_new_report = excluded.email_on_new_report,
+             email_on_status_change = excluded.email_on_status_change,
+             updated_at = excluded.updated_at
+             RETURNING user_id, email_on_new_report, email_on_status_change, updated_at",
+        )
+        .bind(user_id.hyphenated())
+        .bind(params.email_on_new_report)
+        .bind(params.email_on_status_change)
+        .bind(sqlite_timestamp(chrono::Utc::now()))
+        .try_map(from_sqlite_row::<NotificationPreferenceEntity>)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(NotificationPreferenceEntity::into)
+    }
+}
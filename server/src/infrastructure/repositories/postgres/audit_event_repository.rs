@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, Postgres, postgres::PgArguments, query::QueryAs};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::audit::{AuditEvent, AuditEventFilter, CreateAuditEventParams},
+        repositories::{RepositoryResult, audit_event_repository::AuditEventRepository},
+    },
+    infrastructure::repositories::{
+        count_to_i32, entities::audit_event_entity::AuditEventEntity, map_sqlx_error,
+    },
+};
+
+const AUDIT_EVENT_COLUMNS: &str = "id, actor_id, action, target_type, target_id, ip_address, \
+     user_agent, changes, created_at";
+
+const FILTER: &str = "WHERE ($1::UUID IS NULL OR actor_id = $1)
+     AND ($2::TEXT IS NULL OR action = $2)
+     AND ($3::TEXT IS NULL OR target_type = $3)
+     AND ($4::UUID IS NULL OR target_id = $4)
+     AND ($5::TIMESTAMPTZ IS NULL OR created_at >= $5)
+     AND ($6::TIMESTAMPTZ IS NULL OR created_at < $6)";
+
+#[derive(Clone)]
+pub struct PostgresAuditEventRepository {
+    pool: PgPool,
+}
+
+impl PostgresAuditEventRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Binds the filter to the first six parameters of a query using `FILTER`.
+fn bind_filter<'q, O>(
+    query: QueryAs<'q, Postgres, O, PgArguments>,
+    filter: &AuditEventFilter,
+) -> QueryAs<'q, Postgres, O, PgArguments> {
+    query
+        .bind(filter.actor_id)
+        .bind(filter.action)
+        .bind(filter.target_type)
+        .bind(filter.target_id)
+        .bind(filter.from)
+        .bind(filter.to)
+}
+
+#[async_trait]
+impl AuditEventRepository for PostgresAuditEventRepository {
+    #[instrument(skip(self, params), fields(action = %params.action, target_id = ?params.target_id), level = "debug")]
+    async fn create_event(&self, params: CreateAuditEventParams) -> RepositoryResult<AuditEvent> {
+        let query = format!(
+            "INSERT INTO audit_events (id, actor_id, action, target_type, target_id, ip_address, \
+             user_agent, changes, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             RETURNING {AUDIT_EVENT_COLUMNS}"
+        );
+
+        sqlx::query_as::<_, AuditEventEntity>(&query)
+            .bind(Uuid::new_v4())
+            .bind(params.actor_id)
+            .bind(params.action)
+            .bind(params.target_type)
+            .bind(params.target_id)
+            .bind(params.ip_address)
+            .bind(params.user_agent)
+            .bind(params.changes.to_string())
+            .bind(chrono::Utc::now())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .map(AuditEventEntity::into)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_paginated(
+        &self,
+        filter: &AuditEventFilter,
+        page: i32,
+        per_page: i32,
+    ) -> RepositoryResult<Vec<AuditEvent>> {
+        let offset = (page - 1) * per_page;
+        let query = format!(
+            "SELECT {AUDIT_EVENT_COLUMNS} FROM audit_events {FILTER}
+             ORDER BY created_at DESC
+             LIMIT $7 OFFSET $8"
+        );
+
+        bind_filter(sqlx::query_as::<_, AuditEventEntity>(&query), filter)
+            .bind(i64::from(per_page))
+            .bind(i64::from(offset))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .map(|entities| entities.into_iter().map(AuditEventEntity::into).collect())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn count(&self, filter: &AuditEventFilter) -> RepositoryResult<i32> {
+        let query = format!("SELECT COUNT(*) FROM audit_events {FILTER}");
+
+        bind_filter(sqlx::query_as::<_, (i64,)>(&query), filter)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .and_then(|(count,)| count_to_i32(count))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_all(&self, filter: &AuditEventFilter) -> RepositoryResult<Vec<AuditEvent>> {
+        let query = format!(
+            "SELECT {AUDIT_EVENT_COLUMNS} FROM audit_events {FILTER}
+             ORDER BY created_at ASC"
+        );
+
+        bind_filter(sqlx::query_as::<_, AuditEventEntity>(&query), filter)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .map(|entities| entities.into_iter().map(AuditEventEntity::into).collect())
+    }
+}
@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::{board::Board, board_export::BoardImport},
+        repositories::{RepositoryResult, board_repository::BoardRepository},
+    },
+    infrastructure::repositories::{
+        entities::{
+            board_entity::BoardEntity, report_entity::ReportEntity,
+            report_history_entity::ReportHistoryEntity,
+        },
+        map_sqlx_error,
+        postgres::report_repository::{insert_report, insert_report_history},
+    },
+};
+
+const BOARD_COLUMNS: &str = "id, owner_id, name, description, is_default, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct PostgresBoardRepository {
+    pool: PgPool,
+}
+
+impl PostgresBoardRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn insert_board_query(
+    entity: BoardEntity,
+) -> sqlx::query::QueryAs<'static, sqlx::Postgres, BoardEntity, sqlx::postgres::PgArguments> {
+    sqlx::query_as::<_, BoardEntity>(
+        "INSERT INTO boards (id, owner_id, name, description, is_default, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7)
+         RETURNING id, owner_id, name, description, is_default, created_at, updated_at",
+    )
+    .bind(entity.id)
+    .bind(entity.owner_id)
+    .bind(entity.name)
+    .bind(entity.description)
+    .bind(entity.is_default)
+    .bind(entity.created_at)
+    .bind(entity.updated_at)
+}
+
+#[async_trait]
+impl BoardRepository for PostgresBoardRepository {
+    #[instrument(skip(self), level = "debug")]
+    async fn create_board(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        owner_id: Uuid,
+        is_default: bool,
+    ) -> RepositoryResult<Board> {
+        let current_date_time = chrono::Utc::now();
+
+        let entity = BoardEntity {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            description: description.map(|d| d.to_string()),
+            owner_id,
+            is_default,
+            created_at: current_date_time,
+            updated_at: current_date_time,
+        };
+
+        tracing::debug!(board_id = %entity.id, "Creating new board.");
+
+        insert_board_query(entity)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .map(BoardEntity::into)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Board>> {
+        sqlx::query_as::<_, BoardEntity>(&format!(
+            "SELECT {BOARD_COLUMNS} FROM boards WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(BoardEntity::into))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_user_id(&self, user_id: Uuid) -> RepositoryResult<Vec<Board>> {
+        sqlx::query_as::<_, BoardEntity>(&format!(
+            "SELECT {BOARD_COLUMNS} FROM boards WHERE owner_id = $1 ORDER BY created_at ASC"
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(BoardEntity::into).collect())
+    }
+
+    #[instrument(skip(self, import), fields(board_id = %import.board.id, report_count = import.reports.len()), level = "debug")]
+    async fn import_board(&self, import: BoardImport) -> RepositoryResult<Board> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        let board = insert_board_query(BoardEntity {
+            id: import.board.id,
+            name: import.board.name,
+            description: import.board.description,
+            owner_id: import.board.owner_id,
+            is_default: import.board.is_default,
+            created_at: import.board.created_at,
+            updated_at: import.board.updated_at,
+        })
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        for report in import.reports {
+            insert_report(
+                &mut tx,
+                ReportEntity {
+                    id: report.id,
+                    user_id: report.user_id,
+                    board_id: report.board_id,
+                    report_type: report.report_type,
+                    status: report.status,
+                    title: report.title,
+                    description: report.description,
+                    file_path: report.file_path,
+                    thumbnail_file_path: report.thumbnail_file_path,
+                    url: report.url,
+                    browser_name: report.browser_name,
+                    browser_version: report.browser_version,
+                    os_name: report.os_name,
+                    contact_email: report.contact_email,
+                    external_issue_key: report.external_issue_key,
+                    external_issue_url: report.external_issue_url,
+                    created_at: report.created_at,
+                    updated_at: report.updated_at,
+                },
+            )
+            .await
+            .map_err(map_sqlx_error)?;
+        }
+
+        for entry in import.history {
+            insert_report_history(
+                &mut tx,
+                ReportHistoryEntity {
+                    id: entry.id,
+                    report_id: entry.report_id,
+                    field: entry.field,
+                    old_value: entry.old_value,
+                    new_value: entry.new_value,
+                    source: entry.source,
+                    actor_id: entry.actor_id,
+                    details: entry.details,
+                    created_at: entry.created_at,
+                },
+            )
+            .await
+            .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(board.into())
+    }
+}
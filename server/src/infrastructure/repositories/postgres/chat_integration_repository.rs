@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::chat_integration::{ChatIntegration, UpdateChatIntegrationParams},
+        repositories::{
+            RepositoryError, RepositoryResult,
+            chat_integration_repository::ChatIntegrationRepository,
+        },
+    },
+    infrastructure::repositories::{
+        entities::chat_integration_entity::ChatIntegrationEntity, map_sqlx_error,
+    },
+};
+
+const CHAT_INTEGRATION_COLUMNS: &str =
+    "id, board_id, webhook_url, message_template, is_active, created_by, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct PostgresChatIntegrationRepository {
+    pool: PgPool,
+}
+
+impl PostgresChatIntegrationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ChatIntegrationRepository for PostgresChatIntegrationRepository {
+    #[instrument(skip(self, webhook_url, message_template), fields(board_id = %board_id), level = "debug")]
+    async fn create_integration(
+        &self,
+        board_id: Uuid,
+        webhook_url: &str,
+        message_template: Option<&str>,
+        created_by: Uuid,
+    ) -> RepositoryResult<ChatIntegration> {
+        let current_date_time = chrono::Utc::now();
+        let id = Uuid::new_v4();
+
+        tracing::debug!(chat_integration_id = %id, "Creating new chat integration.");
+
+        sqlx::query_as::<_, ChatIntegrationEntity>(&format!(
+            "INSERT INTO chat_integrations (id, board_id, webhook_url, message_template, \
+             is_active, created_by, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, TRUE, $5, $6, $6)
+             RETURNING {CHAT_INTEGRATION_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(board_id)
+        .bind(webhook_url)
+        .bind(message_template)
+        .bind(created_by)
+        .bind(current_date_time)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(ChatIntegrationEntity::into)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<ChatIntegration>> {
+        sqlx::query_as::<_, ChatIntegrationEntity>(&format!(
+            "SELECT {CHAT_INTEGRATION_COLUMNS} FROM chat_integrations WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(ChatIntegrationEntity::into))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_board_id(&self, board_id: Uuid) -> RepositoryResult<Vec<ChatIntegration>> {
+        sqlx::query_as::<_, ChatIntegrationEntity>(&format!(
+            "SELECT {CHAT_INTEGRATION_COLUMNS} FROM chat_integrations
+             WHERE board_id = $1
+             ORDER BY created_at ASC"
+        ))
+        .bind(board_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| {
+            entities
+                .into_iter()
+                .map(ChatIntegrationEntity::into)
+                .collect()
+        })
+    }
+
+    #[instrument(skip(self, params), level = "debug")]
+    async fn update_integration(
+        &self,
+        id: Uuid,
+        params: UpdateChatIntegrationParams,
+    ) -> RepositoryResult<ChatIntegration> {
+        let integration = self
+            .find_by_id(id)
+            .await?
+            .ok_or(RepositoryError::NotFound)?;
+
+        let webhook_url = params.webhook_url.unwrap_or(integration.webhook_url);
+        let message_template = params
+            .message_template
+            .unwrap_or(integration.message_template);
+        let is_active = params.is_active.unwrap_or(integration.is_active);
+
+        sqlx::query_as::<_, ChatIntegrationEntity>(&format!(
+            "UPDATE chat_integrations
+             SET webhook_url = $2, message_template = $3, is_active = $4, updated_at = $5
+             WHERE id = $1
+             RETURNING {CHAT_INTEGRATION_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(webhook_url)
+        .bind(message_template)
+        .bind(is_active)
+        .bind(chrono::Utc::now())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(ChatIntegrationEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn delete_integration(&self, id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query("DELETE FROM chat_integrations WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::email::InboundMailbox,
+        repositories::{
+            RepositoryError, RepositoryResult, inbound_mailbox_repository::InboundMailboxRepository,
+        },
+    },
+    infrastructure::repositories::{
+        entities::inbound_mailbox_entity::InboundMailboxEntity, map_sqlx_error,
+    },
+};
+
+const INBOUND_MAILBOX_COLUMNS: &str = "id, board_id, token, created_by, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct PostgresInboundMailboxRepository {
+    pool: PgPool,
+}
+
+impl PostgresInboundMailboxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InboundMailboxRepository for PostgresInboundMailboxRepository {
+    #[instrument(skip(self, token), fields(board_id = %board_id), level = "debug")]
+    async fn upsert_for_board(
+        &self,
+        board_id: Uuid,
+        token: &str,
+        created_by: Uuid,
+    ) -> RepositoryResult<InboundMailbox> {
+        sqlx::query_as::<_, InboundMailboxEntity>(&format!(
+            "INSERT INTO inbound_mailboxes (id, board_id, token, created_by, created_at, \
+             updated_at)
+             VALUES ($1, $2, $3, $4, $5, $5)
+             ON CONFLICT (board_id) DO UPDATE SET
+             token = excluded.token,
+             updated_at = excluded.updated_at
+             RETURNING {INBOUND_MAILBOX_COLUMNS}"
+        ))
+        .bind(Uuid::new_v4())
+        .bind(board_id)
+        .bind(token)
+        .bind(created_by)
+        .bind(chrono::Utc::now())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(InboundMailboxEntity::into)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_board_id(&self, board_id: Uuid) -> RepositoryResult<Option<InboundMailbox>> {
+        sqlx::query_as::<_, InboundMailboxEntity>(&format!(
+            "SELECT {INBOUND_MAILBOX_COLUMNS} FROM inbound_mailboxes WHERE board_id = $1"
+        ))
+        .bind(board_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(InboundMailboxEntity::into))
+    }
+
+    #[instrument(skip(self, token), level = "debug")]
+    async fn find_by_token(&self, token: &str) -> RepositoryResult<Option<InboundMailbox>> {
+        sqlx::query_as::<_, InboundMailboxEntity>(&format!(
+            "SELECT {INBOUND_MAILBOX_COLUMNS} FROM inbound_mailboxes WHERE token = $1"
+        ))
+        .bind(token)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(InboundMailboxEntity::into))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn delete_by_board_id(&self, board_id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query("DELETE FROM inbound_mailboxes WHERE board_id = $1")
+            .bind(board_id)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
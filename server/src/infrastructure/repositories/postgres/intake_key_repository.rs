@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::intake_key::{IntakeKey, UpdateIntakeKeyParams},
+        repositories::{
+            RepositoryError, RepositoryResult, intake_key_repository::IntakeKeyRepository,
+        },
+    },
+    infrastructure::repositories::{
+        entities::intake_key_entity::{IntakeKeyEntity, join_origins},
+        map_sqlx_error,
+    },
+};
+
+const INTAKE_KEY_COLUMNS: &str = "id, board_id, name, public_key, allowed_origins, is_active, \
+     created_by, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct PostgresIntakeKeyRepository {
+    pool: PgPool,
+}
+
+impl PostgresIntakeKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IntakeKeyRepository for PostgresIntakeKeyRepository {
+    #[instrument(skip(self, public_key, allowed_origins), fields(board_id = %board_id), level = "debug")]
+    async fn create_key(
+        &self,
+        board_id: Uuid,
+        name: &str,
+        public_key: &str,
+        allowed_origins: &[String],
+        created_by: Uuid,
+    ) -> RepositoryResult<IntakeKey> {
+        let current_date_time = chrono::Utc::now();
+        let id = Uuid::new_v4();
+
+        tracing::debug!(intake_key_id = %id, "Creating new intake key.");
+
+        sqlx::query_as::<_, IntakeKeyEntity>(&format!(
+            "INSERT INTO intake_keys (id, board_id, name, public_key, allowed_origins, \
+             is_active, created_by, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, TRUE, $6, $7, $7)
+             RETURNING {INTAKE_KEY_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(board_id)
+        .bind(name)
+        .bind(public_key)
+        .bind(join_origins(allowed_origins))
+        .bind(created_by)
+        .bind(current_date_time)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(IntakeKeyEntity::into)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<IntakeKey>> {
+        sqlx::query_as::<_, IntakeKeyEntity>(&format!(
+            "SELECT {INTAKE_KEY_COLUMNS} FROM intake_keys WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(IntakeKeyEntity::into))
+    }
+
+    #[instrument(skip(self, public_key), level = "debug")]
+    async fn find_by_public_key(&self, public_key: &str) -> RepositoryResult<Option<IntakeKey>> {
+        sqlx::query_as::<_, IntakeKeyEntity>(&format!(
+            "SELECT {INTAKE_KEY_COLUMNS} FROM intake_keys WHERE public_key = $1"
+        ))
+        .bind(public_key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(IntakeKeyEntity::into))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_board_id(&self, board_id: Uuid) -> RepositoryResult<Vec<IntakeKey>> {
+        sqlx::query_as::<_, IntakeKeyEntity>(&format!(
+            "SELECT {INTAKE_KEY_COLUMNS} FROM intake_keys
+             WHERE board_id = $1
+             ORDER BY created_at ASC"
+        ))
+        .bind(board_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(IntakeKeyEntity::into).collect())
+    }
+
+    #[instrument(skip(self, params), level = "debug")]
+    async fn update_key(
+        &self,
+        id: Uuid,
+        params: UpdateIntakeKeyParams,
+    ) -> RepositoryResult<IntakeKey> {
+        let intake_key = self
+            .find_by_id(id)
+            .await?
+            .ok_or(RepositoryError::NotFound)?;
+
+        let name = params.name.unwrap_or(intake_key.name);
+        let allowed_origins = params.allowed_origins.unwrap_or(intake_key.allowed_origins);
+        let is_active = params.is_active.unwrap_or(intake_key.is_active);
+
+        sqlx::query_as::<_, IntakeKeyEntity>(&format!(
+            "UPDATE intake_keys
+             SET name = $2, allowed_origins = $3, is_active = $4, updated_at = $5
+             WHERE id = $1
+             RETURNING {INTAKE_KEY_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(name)
+        .bind(join_origins(&allowed_origins))
+        .bind(is_active)
+        .bind(chrono::Utc::now())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(IntakeKeyEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn delete_key(&self, id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query("DELETE FROM intake_keys WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::{invitation::Invitation, user::UserRole},
+        repositories::{
+            RepositoryError, RepositoryResult, invitation_repository::InvitationRepository,
+        },
+    },
+    infrastructure::repositories::{entities::invitation_entity::InvitationEntity, map_sqlx_error},
+};
+
+const INVITATION_COLUMNS: &str = "id, email, role, token_hash, invited_by, expires_at, \
+     accepted_at, revoked_at, created_at";
+
+#[derive(Clone)]
+pub struct PostgresInvitationRepository {
+    pool: PgPool,
+}
+
+impl PostgresInvitationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InvitationRepository for PostgresInvitationRepository {
+    #[instrument(skip(self, token_hash), fields(email = %email, role = %role), level = "debug")]
+    async fn create_invitation(
+        &self,
+        email: &str,
+        role: UserRole,
+        token_hash: &str,
+        invited_by: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> RepositoryResult<Invitation> {
+        let id = Uuid::new_v4();
+
+        tracing::debug!(invitation_id = %id, "Creating new invitation.");
+
+        sqlx::query_as::<_, InvitationEntity>(&format!(
+            "INSERT INTO user_invitations (id, email, role, token_hash, invited_by, expires_at, \
+             created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING {INVITATION_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(email)
+        .bind(role)
+        .bind(token_hash)
+        .bind(invited_by)
+        .bind(expires_at)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(InvitationEntity::into)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Invitation>> {
+        sqlx::query_as::<_, InvitationEntity>(&format!(
+            "SELECT {INVITATION_COLUMNS} FROM user_invitations WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(InvitationEntity::into))
+    }
+
+    #[instrument(skip(self, token_hash), level = "debug")]
+    async fn find_by_token_hash(&self, token_hash: &str) -> RepositoryResult<Option<Invitation>> {
+        sqlx::query_as::<_, InvitationEntity>(&format!(
+            "SELECT {INVITATION_COLUMNS} FROM user_invitations WHERE token_hash = $1"
+        ))
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(InvitationEntity::into))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_open(&self) -> RepositoryResult<Vec<Invitation>> {
+        sqlx::query_as::<_, InvitationEntity>(&format!(
+            "SELECT {INVITATION_COLUMNS} FROM user_invitations
+             WHERE accepted_at IS NULL AND revoked_at IS NULL
+             ORDER BY created_at DESC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(InvitationEntity::into).collect())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn mark_accepted(&self, id: Uuid) -> RepositoryResult<()> {
+        let exec_result = sqlx::query(
+            "UPDATE user_invitations SET accepted_at = $2
+             WHERE id = $1 AND accepted_at IS NULL AND revoked_at IS NULL",
+        )
+        .bind(id)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if exec_result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn revoke(&self, id: Uuid) -> RepositoryResult<()> {
+        let exec_result = sqlx::query(
+            "UPDATE user_invitations SET revoked_at = $2
+             WHERE id = $1 AND accepted_at IS NULL AND revoked_at IS NULL",
+        )
+        .bind(id)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        if exec_result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn revoke_open_by_email(&self, email: &str) -> RepositoryResult<()> {
+        sqlx::query(
+            "UPDATE user_invitations SET revoked_at = $2
+             WHERE email = $1 AND accepted_at IS NULL AND revoked_at IS NULL",
+        )
+        .bind(email)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|_| ())
+    }
+}
@@ -0,0 +1,14 @@
+pub mod audit_event_repository;
+pub mod board_repository;
+pub mod chat_integration_repository;
+pub mod inbound_mailbox_repository;
+pub mod intake_key_repository;
+pub mod invitation_repository;
+pub mod issue_tracker_integration_repository;
+pub mod notification_preference_repository;
+pub mod recovery_code_repository;
+pub mod report_repository;
+pub mod settings_repository;
+pub mod user_repository;
+pub mod webhook_delivery_repository;
+pub mod webhook_repository;
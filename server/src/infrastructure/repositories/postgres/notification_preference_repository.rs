@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::notification_preference::{
+            NotificationPreferences, UpdateNotificationPreferencesParams,
+        },
+        repositories::{
+            RepositoryResult, notification_preference_repository::NotificationPreferenceRepository,
+        },
+    },
+    infrastructure::repositories::{
+        entities::notification_preference_entity::NotificationPreferenceEntity, map_sqlx_error,
+    },
+};
+
+#[derive(Clone)]
+pub struct PostgresNotificationPreferenceRepository {
+    pool: PgPool,
+}
+
+impl PostgresNotificationPreferenceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationPreferenceRepository for PostgresNotificationPreferenceRepository {
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> RepositoryResult<Option<NotificationPreferences>> {
+        sqlx::query_as::<_, NotificationPreferenceEntity>(
+            "SELECT user_id, email_on_new_report, email_on_status_change, updated_at
+             FROM notification_preferences
+             WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(NotificationPreferenceEntity::into))
+    }
+
+    #[instrument(skip(self, params), level = "debug")]
+    async fn upsert(
+        &self,
+        user_id: Uuid,
+        params: UpdateNotificationPreferencesParams,
+    ) -> RepositoryResult<NotificationPreferences> {
+        sqlx::query_as::<_, NotificationPreferenceEntity>(
+            "INSERT INTO notification_preferences (user_id, email_on_new_report, \
+             email_on_status_change, updated_at)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT (user_id) DO UPDATE SET
+             email_on_new_report = excluded.email_on_new_report,
+             email_on_status_change = excluded.email_on_status_change,
+             updated_at = excluded.updated_at
+             RETURNING user_id, email_on_new_report, email_on_status_change, updated_at",
+        )
+        .bind(user_id)
+        .bind(params.email_on_new_report)
+        .bind(params.email_on_status_change)
+        .bind(chrono::Utc::now())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(NotificationPreferenceEntity::into)
+    }
+}
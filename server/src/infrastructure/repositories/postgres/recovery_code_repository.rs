@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::two_factor::RecoveryCode,
+        repositories::{
+            RepositoryError, RepositoryResult, recovery_code_repository::RecoveryCodeRepository,
+        },
+    },
+    infrastructure::repositories::{
+        entities::recovery_code_entity::RecoveryCodeEntity, map_sqlx_error,
+    },
+};
+
+#[derive(Clone)]
+pub struct PostgresRecoveryCodeRepository {
+    pool: PgPool,
+}
+
+impl PostgresRecoveryCodeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RecoveryCodeRepository for PostgresRecoveryCodeRepository {
+    #[instrument(skip(self, code_hashes), fields(user_id = %user_id, count = code_hashes.len()), level = "debug")]
+    async fn replace_codes(
+        &self,
+        user_id: Uuid,
+        code_hashes: Vec<String>,
+    ) -> RepositoryResult<Vec<RecoveryCode>> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        sqlx::query("DELETE FROM user_recovery_codes WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        let current_date_time = chrono::Utc::now();
+        let mut codes = Vec::with_capacity(code_hashes.len());
+        for code_hash in code_hashes {
+            let entity = sqlx::query_as::<_, RecoveryCodeEntity>(
+                "INSERT INTO user_recovery_codes (id, user_id, code_hash, created_at)
+                 VALUES ($1, $2, $3, $4)
+                 RETURNING id, user_id, code_hash, used_at, created_at",
+            )
+            .bind(Uuid::new_v4())
+            .bind(user_id)
+            .bind(code_hash)
+            .bind(current_date_time)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+            codes.push(entity.into());
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(codes)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_unused_by_user_id(&self, user_id: Uuid) -> RepositoryResult<Vec<RecoveryCode>> {
+        sqlx::query_as::<_, RecoveryCodeEntity>(
+            "SELECT id, user_id, code_hash, used_at, created_at
+             FROM user_recovery_codes
+             WHERE user_id = $1 AND used_at IS NULL",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(RecoveryCodeEntity::into).collect())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn mark_used(&self, id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query(
+            "UPDATE user_recovery_codes SET used_at = $2 WHERE id = $1 AND used_at IS NULL",
+        )
+        .bind(id)
+        .bind(chrono::Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        // Already used, e.g. by a concurrent login with the same code.
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn delete_by_user_id(&self, user_id: Uuid) -> RepositoryResult<()> {
+        sqlx::query("DELETE FROM user_recovery_codes WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .map(|_| ())
+    }
+}
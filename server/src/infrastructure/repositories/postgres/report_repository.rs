@@ -0,0 +1,305 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::report::{
+            CreateReportParams, Report, ReportHistoryEntry, ReportStatus, ReportStatusChange,
+        },
+        repositories::{RepositoryError, RepositoryResult, report_repository::ReportRepository},
+    },
+    infrastructure::repositories::{
+        count_to_i32,
+        entities::{report_entity::ReportEntity, report_history_entity::ReportHistoryEntity},
+        map_sqlx_error,
+    },
+};
+
+const REPORT_COLUMNS: &str = "id, user_id, board_id, report_type, status, title, description, \
+     file_path, thumbnail_file_path, url, browser_name, browser_version, os_name, contact_email, \
+     external_issue_key, external_issue_url, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct PostgresReportRepository {
+    pool: PgPool,
+}
+
+impl PostgresReportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+pub(super) async fn insert_report(
+    conn: &mut PgConnection,
+    entity: ReportEntity,
+) -> Result<ReportEntity, sqlx::Error> {
+    sqlx::query_as::<_, ReportEntity>(&format!(
+        "INSERT INTO reports (id, user_id, board_id, report_type, status, title, description, \
+         file_path, thumbnail_file_path, url, browser_name, browser_version, os_name, \
+         contact_email, external_issue_key, external_issue_url, created_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
+         RETURNING {REPORT_COLUMNS}"
+    ))
+    .bind(entity.id)
+    .bind(entity.user_id)
+    .bind(entity.board_id)
+    .bind(entity.report_type)
+    .bind(entity.status)
+    .bind(entity.title)
+    .bind(entity.description)
+    .bind(entity.file_path)
+    .bind(entity.thumbnail_file_path)
+    .bind(entity.url)
+    .bind(entity.browser_name)
+    .bind(entity.browser_version)
+    .bind(entity.os_name)
+    .bind(entity.contact_email)
+    .bind(entity.external_issue_key)
+    .bind(entity.external_issue_url)
+    .bind(entity.created_at)
+    .bind(entity.updated_at)
+    .fetch_one(conn)
+    .await
+}
+
+pub(super) async fn insert_report_history(
+    conn: &mut PgConnection,
+    entity: ReportHistoryEntity,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO report_history (id, report_id, field, old_value, new_value, source, \
+         actor_id, details, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+    )
+    .bind(entity.id)
+    .bind(entity.report_id)
+    .bind(entity.field)
+    .bind(entity.old_value)
+    .bind(entity.new_value)
+    .bind(entity.source)
+    .bind(entity.actor_id)
+    .bind(entity.details)
+    .bind(entity.created_at)
+    .execute(conn)
+    .await
+    .map(|_| ())
+}
+
+#[async_trait]
+impl ReportRepository for PostgresReportRepository {
+    async fn create_report(&self, params: CreateReportParams) -> RepositoryResult<Report> {
+        let current_date_time = chrono::Utc::now();
+
+        let entity = ReportEntity {
+            id: Uuid::new_v4(),
+            user_id: params.user_id,
+            board_id: params.board_id,
+            report_type: params.report_type,
+            status: ReportStatus::Open,
+            title: params.title,
+            description: params.description,
+            file_path: params.file_path,
+            thumbnail_file_path: params.thumbnail_file_path,
+            url: params.url,
+            browser_name: params.browser_name,
+            browser_version: params.browser_version,
+            os_name: params.os_name,
+            contact_email: params.contact_email,
+            external_issue_key: None,
+            external_issue_url: None,
+            created_at: current_date_time,
+            updated_at: current_date_time,
+        };
+
+        let mut conn = self.pool.acquire().await.map_err(map_sqlx_error)?;
+        insert_report(&mut conn, entity)
+            .await
+            .map_err(map_sqlx_error)
+            .map(ReportEntity::into)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Report>> {
+        sqlx::query_as::<_, ReportEntity>(&format!(
+            "SELECT {REPORT_COLUMNS} FROM reports WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(ReportEntity::into))
+    }
+
+    async fn find_recent_reports_by_board(
+        &self,
+        board_id: Uuid,
+        limit: usize,
+    ) -> RepositoryResult<Vec<Report>> {
+        sqlx::query_as::<_, ReportEntity>(&format!(
+            "SELECT {REPORT_COLUMNS} FROM reports
+             WHERE board_id = $1
+             ORDER BY created_at DESC
+             LIMIT $2"
+        ))
+        .bind(board_id)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(ReportEntity::into).collect())
+    }
+
+    async fn find_by_board_id_paginated(
+        &self,
+        board_id: Uuid,
+        page: i32,
+        per_page: i32,
+    ) -> RepositoryResult<Vec<Report>> {
+        let offset = (page - 1) * per_page;
+
+        sqlx::query_as::<_, ReportEntity>(&format!(
+            "SELECT {REPORT_COLUMNS} FROM reports
+             WHERE board_id = $1
+             ORDER BY created_at DESC
+             LIMIT $2 OFFSET $3"
+        ))
+        .bind(board_id)
+        .bind(i64::from(per_page))
+        .bind(i64::from(offset))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(ReportEntity::into).collect())
+    }
+
+    async fn find_all_by_board_id(&self, board_id: Uuid) -> RepositoryResult<Vec<Report>> {
+        sqlx::query_as::<_, ReportEntity>(&format!(
+            "SELECT {REPORT_COLUMNS} FROM reports
+             WHERE board_id = $1
+             ORDER BY created_at ASC"
+        ))
+        .bind(board_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(ReportEntity::into).collect())
+    }
+
+    async fn set_external_issue(
+        &self,
+        id: Uuid,
+        external_issue_key: &str,
+        external_issue_url: &str,
+    ) -> RepositoryResult<Report> {
+        sqlx::query_as::<_, ReportEntity>(&format!(
+            "UPDATE reports
+             SET external_issue_key = $2, external_issue_url = $3, updated_at = $4
+             WHERE id = $1
+             RETURNING {REPORT_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(external_issue_key)
+        .bind(external_issue_url)
+        .bind(chrono::Utc::now())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(ReportEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn count_by_board_id(&self, board_id: Uuid) -> RepositoryResult<i32> {
+        sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM reports WHERE board_id = $1")
+            .bind(board_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .and_then(count_to_i32)
+    }
+
+    async fn find_by_external_issue_key(
+        &self,
+        board_id: Uuid,
+        external_issue_key: &str,
+    ) -> RepositoryResult<Option<Report>> {
+        sqlx::query_as::<_, ReportEntity>(&format!(
+            "SELECT {REPORT_COLUMNS} FROM reports
+             WHERE board_id = $1 AND external_issue_key = $2"
+        ))
+        .bind(board_id)
+        .bind(external_issue_key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(ReportEntity::into))
+    }
+
+    async fn update_status(
+        &self,
+        id: Uuid,
+        change: ReportStatusChange,
+    ) -> RepositoryResult<Report> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        // Locks the row so that concurrent changes record the status they actually replaced.
+        let old_status: ReportStatus =
+            sqlx::query_scalar("SELECT status FROM reports WHERE id = $1 FOR UPDATE")
+                .bind(id)
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(map_sqlx_error)?
+                .ok_or(RepositoryError::NotFound)?;
+        let current_date_time = chrono::Utc::now();
+
+        let updated_report = sqlx::query_as::<_, ReportEntity>(&format!(
+            "UPDATE reports SET status = $2, updated_at = $3
+             WHERE id = $1
+             RETURNING {REPORT_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(change.status)
+        .bind(current_date_time)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        let history_entry = ReportHistoryEntity {
+            id: Uuid::new_v4(),
+            report_id: id,
+            field: "status".to_string(),
+            old_value: Some(old_status.to_string()),
+            new_value: Some(change.status.to_string()),
+            source: change.source,
+            actor_id: change.actor_id,
+            details: change.details,
+            created_at: current_date_time,
+        };
+        insert_report_history(&mut tx, history_entry)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(updated_report.into())
+    }
+
+    async fn find_history(&self, report_id: Uuid) -> RepositoryResult<Vec<ReportHistoryEntry>> {
+        sqlx::query_as::<_, ReportHistoryEntity>(
+            "SELECT id, report_id, field, old_value, new_value, source, actor_id, details, \
+             created_at
+             FROM report_history
+             WHERE report_id = $1
+             ORDER BY created_at ASC",
+        )
+        .bind(report_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| {
+            entities
+                .into_iter()
+                .map(ReportHistoryEntity::into)
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,393 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::user::{User, UserRole},
+        repositories::{RepositoryError, RepositoryResult, user_repository::UserRepository},
+    },
+    infrastructure::repositories::{
+        count_to_i32, entities::user_entity::UserEntity, map_sqlx_error,
+    },
+};
+
+const USER_COLUMNS: &str = "id, email, password_hash, first_name, last_name, avatar_url, role, \
+     totp_secret, totp_enabled, is_active, deleted_at, created_at, updated_at";
+
+const SEARCH_FILTER: &str = "WHERE deleted_at IS NULL
+     AND ($1::TEXT IS NULL OR email ILIKE $1 OR first_name ILIKE $1 OR last_name ILIKE $1)";
+
+#[derive(Clone)]
+pub struct PostgresUserRepository {
+    pool: PgPool,
+}
+
+impl PostgresUserRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn search_pattern(search: Option<&str>) -> Option<String> {
+    search
+        .map(str::trim)
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("%{}%", term))
+}
+
+#[async_trait]
+impl UserRepository for PostgresUserRepository {
+    #[instrument(skip(self, password_hash), fields(email = %email, role = %role), level = "debug")]
+    async fn create_user(
+        &self,
+        email: &str,
+        password_hash: &str,
+        first_name: Option<&str>,
+        last_name: Option<&str>,
+        role: UserRole,
+    ) -> RepositoryResult<User> {
+        let current_date_time = chrono::Utc::now();
+        let id = Uuid::new_v4();
+
+        tracing::debug!(user_id = %id, "Executing insert query for new user.");
+
+        sqlx::query_as::<_, UserEntity>(&format!(
+            "INSERT INTO users (id, email, password_hash, first_name, last_name, role, \
+             totp_enabled, is_active, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, FALSE, TRUE, $7, $7)
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(email)
+        .bind(password_hash)
+        .bind(first_name)
+        .bind(last_name)
+        .bind(role)
+        .bind(current_date_time)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(UserEntity::into)
+    }
+
+    #[instrument(skip(self), fields(id = %id), level = "debug")]
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<User>> {
+        sqlx::query_as::<_, UserEntity>(&format!("SELECT {USER_COLUMNS} FROM users WHERE id = $1"))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .map(|opt_entity| opt_entity.map(UserEntity::into))
+    }
+
+    #[instrument(skip(self), fields(email = %email), level = "debug")]
+    async fn find_by_email(&self, email: &str) -> RepositoryResult<Option<User>> {
+        sqlx::query_as::<_, UserEntity>(&format!(
+            "SELECT {USER_COLUMNS} FROM users WHERE email = $1"
+        ))
+        .bind(email)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(UserEntity::into))
+    }
+
+    #[instrument(skip(self, totp_secret), fields(id = %id, totp_enabled = %totp_enabled), level = "debug")]
+    async fn update_totp(
+        &self,
+        id: Uuid,
+        totp_secret: Option<&str>,
+        totp_enabled: bool,
+    ) -> RepositoryResult<User> {
+        sqlx::query_as::<_, UserEntity>(&format!(
+            "UPDATE users SET totp_secret = $2, totp_enabled = $3, updated_at = $4,
+             totp_last_time_step = CASE WHEN totp_secret IS NOT DISTINCT FROM $2
+                 THEN totp_last_time_step END
+             WHERE id = $1
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(totp_secret)
+        .bind(totp_enabled)
+        .bind(chrono::Utc::now())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(UserEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn record_totp_time_step(&self, id: Uuid, time_step: i64) -> RepositoryResult<bool> {
+        let result = sqlx::query(
+            "UPDATE users SET totp_last_time_step = $2
+             WHERE id = $1 AND (totp_last_time_step IS NULL OR totp_last_time_step < $2)",
+        )
+        .bind(id)
+        .bind(time_step)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_paginated(
+        &self,
+        search: Option<&str>,
+        page: i32,
+        per_page: i32,
+    ) -> RepositoryResult<Vec<User>> {
+        let offset = (page - 1) * per_page;
+
+        sqlx::query_as::<_, UserEntity>(&format!(
+            "SELECT {USER_COLUMNS} FROM users {SEARCH_FILTER}
+             ORDER BY created_at ASC
+             LIMIT $2 OFFSET $3"
+        ))
+        .bind(search_pattern(search))
+        .bind(i64::from(per_page))
+        .bind(i64::from(offset))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(UserEntity::into).collect())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn count(&self, search: Option<&str>) -> RepositoryResult<i32> {
+        sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM users {SEARCH_FILTER}"))
+            .bind(search_pattern(search))
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .and_then(count_to_i32)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn count_active(&self) -> RepositoryResult<i64> {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM users WHERE is_active = TRUE AND deleted_at IS NULL",
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+    }
+
+    #[instrument(skip(self, password_hash), fields(id = %id), level = "debug")]
+    async fn update_password_hash(&self, id: Uuid, password_hash: &str) -> RepositoryResult<()> {
+        let exec_result =
+            sqlx::query("UPDATE users SET password_hash = $2, updated_at = $3 WHERE id = $1")
+                .bind(id)
+                .bind(password_hash)
+                .bind(chrono::Utc::now())
+                .execute(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+
+        if exec_result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(id = %id), level = "debug")]
+    async fn update_profile(
+        &self,
+        id: Uuid,
+        email: &str,
+        first_name: Option<&str>,
+        last_name: Option<&str>,
+    ) -> RepositoryResult<User> {
+        sqlx::query_as::<_, UserEntity>(&format!(
+            "UPDATE users SET email = $2, first_name = $3, last_name = $4, updated_at = $5
+             WHERE id = $1
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(email)
+        .bind(first_name)
+        .bind(last_name)
+        .bind(chrono::Utc::now())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(UserEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), fields(id = %id), level = "debug")]
+    async fn update_avatar(&self, id: Uuid, avatar_url: Option<&str>) -> RepositoryResult<User> {
+        sqlx::query_as::<_, UserEntity>(&format!(
+            "UPDATE users SET avatar_url = $2, updated_at = $3
+             WHERE id = $1
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(avatar_url)
+        .bind(chrono::Utc::now())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(UserEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), fields(id = %id, role = %role), level = "debug")]
+    async fn update_role(&self, id: Uuid, role: UserRole) -> RepositoryResult<User> {
+        sqlx::query_as::<_, UserEntity>(&format!(
+            "UPDATE users SET role = $2, updated_at = $3
+             WHERE id = $1
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(role)
+        .bind(chrono::Utc::now())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(UserEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), fields(id = %id, is_active = %is_active), level = "debug")]
+    async fn update_active(&self, id: Uuid, is_active: bool) -> RepositoryResult<User> {
+        sqlx::query_as::<_, UserEntity>(&format!(
+            "UPDATE users SET is_active = $2, updated_at = $3
+             WHERE id = $1
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(is_active)
+        .bind(chrono::Utc::now())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(UserEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), fields(id = %id, new_owner_id = %new_owner_id), level = "debug")]
+    async fn delete_reassigning_content(
+        &self,
+        id: Uuid,
+        new_owner_id: Uuid,
+    ) -> RepositoryResult<()> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        let email: String = sqlx::query_scalar("SELECT email FROM users WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?
+            .ok_or(RepositoryError::NotFound)?;
+        let current_date_time = chrono::Utc::now();
+
+        sqlx::query(
+            "UPDATE boards
+             SET name = CASE
+                 WHEN EXISTS (
+                     SELECT 1 FROM boards AS existing
+                     WHERE existing.owner_id = $2 AND existing.name = boards.name
+                 ) THEN boards.name || ' (' || $3 || ')'
+                 ELSE boards.name
+             END,
+             owner_id = $2,
+             is_default = FALSE,
+             updated_at = $4
+             WHERE owner_id = $1",
+        )
+        .bind(id)
+        .bind(new_owner_id)
+        .bind(&email)
+        .bind(current_date_time)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+        sqlx::query("UPDATE reports SET user_id = $2, updated_at = $3 WHERE user_id = $1")
+            .bind(id)
+            .bind(new_owner_id)
+            .bind(current_date_time)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        // Nothing may reference the user anymore once it is deleted.
+        for statement in [
+            "UPDATE user_invitations SET invited_by = $2 WHERE invited_by = $1",
+            "UPDATE webhooks SET created_by = $2 WHERE created_by = $1",
+            "UPDATE chat_integrations SET created_by = $2 WHERE created_by = $1",
+            "UPDATE issue_tracker_integrations SET created_by = $2 WHERE created_by = $1",
+            "UPDATE intake_keys SET created_by = $2 WHERE created_by = $1",
+            "UPDATE inbound_mailboxes SET created_by = $2 WHERE created_by = $1",
+            "UPDATE report_history SET actor_id = $2 WHERE actor_id = $1",
+        ] {
+            sqlx::query(statement)
+                .bind(id)
+                .bind(new_owner_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(map_sqlx_error)?;
+        }
+        for statement in [
+            "DELETE FROM user_recovery_codes WHERE user_id = $1",
+            "DELETE FROM notification_preferences WHERE user_id = $1",
+            "DELETE FROM users WHERE id = $1",
+        ] {
+            sqlx::query(statement)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)
+    }
+
+    #[instrument(skip(self), fields(id = %id), level = "debug")]
+    async fn anonymize(&self, id: Uuid) -> RepositoryResult<()> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        let placeholder_email = format!("deleted-{}@deleted.invalid", id);
+        let exec_result = sqlx::query(
+            "UPDATE users
+             SET email = $2,
+             password_hash = '',
+             first_name = NULL,
+             last_name = NULL,
+             avatar_url = NULL,
+             role = 'User',
+             totp_secret = NULL,
+             totp_last_time_step = NULL,
+             totp_enabled = FALSE,
+             is_active = FALSE,
+             deleted_at = $3,
+             updated_at = $3
+             WHERE id = $1",
+        )
+        .bind(id)
+        .bind(&placeholder_email)
+        .bind(chrono::Utc::now())
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+        if exec_result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        for statement in [
+            "DELETE FROM user_recovery_codes WHERE user_id = $1",
+            "DELETE FROM notification_preferences WHERE user_id = $1",
+        ] {
+            sqlx::query(statement)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)
+    }
+}
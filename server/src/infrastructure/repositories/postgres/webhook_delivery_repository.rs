@@ -0,0 +1,181 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::{
+            event::EventType,
+            webhook::{WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus},
+        },
+        repositories::{
+            RepositoryError, RepositoryResult,
+            webhook_delivery_repository::WebhookDeliveryRepository,
+        },
+    },
+    infrastructure::repositories::{
+        count_to_i32, entities::webhook_delivery_entity::WebhookDeliveryEntity, map_sqlx_error,
+    },
+};
+
+const WEBHOOK_DELIVERY_COLUMNS: &str = "id, webhook_id, event_id, event_type, payload, status, \
+     attempts, response_status, last_error, next_attempt_at, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct PostgresWebhookDeliveryRepository {
+    pool: PgPool,
+}
+
+impl PostgresWebhookDeliveryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for PostgresWebhookDeliveryRepository {
+    #[instrument(skip(self, payload), fields(webhook_id = %webhook_id, event_type = %event_type), level = "debug")]
+    async fn create_delivery(
+        &self,
+        webhook_id: Uuid,
+        event_id: Uuid,
+        event_type: EventType,
+        payload: &str,
+        next_attempt_at: DateTime<Utc>,
+    ) -> RepositoryResult<WebhookDelivery> {
+        sqlx::query_as::<_, WebhookDeliveryEntity>(&format!(
+            "INSERT INTO webhook_deliveries (id, webhook_id, event_id, event_type, payload, \
+             status, attempts, next_attempt_at, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, 0, $7, $8, $8)
+             RETURNING {WEBHOOK_DELIVERY_COLUMNS}"
+        ))
+        .bind(Uuid::new_v4())
+        .bind(webhook_id)
+        .bind(event_id)
+        .bind(event_type)
+        .bind(payload)
+        .bind(WebhookDeliveryStatus::Pending)
+        .bind(next_attempt_at)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(WebhookDeliveryEntity::into)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<WebhookDelivery>> {
+        sqlx::query_as::<_, WebhookDeliveryEntity>(&format!(
+            "SELECT {WEBHOOK_DELIVERY_COLUMNS} FROM webhook_deliveries WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(WebhookDeliveryEntity::into))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_webhook_id_paginated(
+        &self,
+        webhook_id: Uuid,
+        page: i32,
+        per_page: i32,
+    ) -> RepositoryResult<Vec<WebhookDelivery>> {
+        let offset = (page - 1) * per_page;
+
+        sqlx::query_as::<_, WebhookDeliveryEntity>(&format!(
+            "SELECT {WEBHOOK_DELIVERY_COLUMNS} FROM webhook_deliveries
+             WHERE webhook_id = $1
+             ORDER BY created_at DESC
+             LIMIT $2 OFFSET $3"
+        ))
+        .bind(webhook_id)
+        .bind(i64::from(per_page))
+        .bind(i64::from(offset))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| {
+            entities
+                .into_iter()
+                .map(WebhookDeliveryEntity::into)
+                .collect()
+        })
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn count_by_webhook_id(&self, webhook_id: Uuid) -> RepositoryResult<i32> {
+        sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM webhook_deliveries WHERE webhook_id = $1",
+        )
+        .bind(webhook_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .and_then(count_to_i32)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn claim_due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i32,
+    ) -> RepositoryResult<Vec<WebhookDelivery>> {
+        sqlx::query_as::<_, WebhookDeliveryEntity>(&format!(
+            "UPDATE webhook_deliveries
+             SET next_attempt_at = $3
+             WHERE id IN (
+                 SELECT id FROM webhook_deliveries
+                 WHERE status = $1 AND next_attempt_at <= $2
+                 ORDER BY next_attempt_at
+                 LIMIT $4
+                 FOR UPDATE SKIP LOCKED
+             )
+             RETURNING {WEBHOOK_DELIVERY_COLUMNS}"
+        ))
+        .bind(WebhookDeliveryStatus::Pending)
+        .bind(now)
+        .bind(lease_until)
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| {
+            entities
+                .into_iter()
+                .map(WebhookDeliveryEntity::into)
+                .collect()
+        })
+    }
+
+    #[instrument(skip(self, attempt), fields(status = %attempt.status, attempts = attempt.attempts), level = "debug")]
+    async fn record_attempt(
+        &self,
+        id: Uuid,
+        attempt: WebhookDeliveryAttempt,
+    ) -> RepositoryResult<WebhookDelivery> {
+        sqlx::query_as::<_, WebhookDeliveryEntity>(&format!(
+            "UPDATE webhook_deliveries
+             SET status = $2, attempts = $3, response_status = $4, last_error = $5, \
+             next_attempt_at = $6, updated_at = $7
+             WHERE id = $1
+             RETURNING {WEBHOOK_DELIVERY_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(attempt.status)
+        .bind(attempt.attempts)
+        .bind(attempt.response_status)
+        .bind(attempt.last_error)
+        .bind(attempt.next_attempt_at)
+        .bind(Utc::now())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(WebhookDeliveryEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+}
@@ -1,3 +0,0 @@
-pub mod board_queries;
-pub mod report_queries;
-pub mod user_queries;
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::two_factor::RecoveryCode,
+        repositories::{
+            RepositoryError, RepositoryResult, recovery_code_repository::RecoveryCodeRepository,
+        },
+    },
+    infrastructure::repositories::{
+        entities::recovery_code_entity::RecoveryCodeEntity, from_sqlite_row, map_sqlx_error,
+        sqlite_timestamp,
+    },
+};
+
+#[derive(Clone)]
+pub struct SqliteRecoveryCodeRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteRecoveryCodeRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RecoveryCodeRepository for SqliteRecoveryCodeRepository {
+    #[instrument(skip(self, code_hashes), fields(user_id = %user_id, count = code_hashes.len()), level = "debug")]
+    async fn replace_codes(
+        &self,
+        user_id: Uuid,
+        code_hashes: Vec<String>,
+    ) -> RepositoryResult<Vec<RecoveryCode>> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        sqlx::query("DELETE FROM user_recovery_codes WHERE user_id = $1")
+            .bind(user_id.hyphenated())
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        let current_date_time = chrono::Utc::now();
+        let mut codes = Vec::with_capacity(code_hashes.len());
+        for code_hash in code_hashes {
+            let entity = sqlx::query(
+                "INSERT INTO user_recovery_codes (id, user_id, code_hash, created_at)
+                 VALUES ($1, $2, $3, $4)
+                 RETURNING id, user_id, code_hash, used_at, created_at",
+            )
+            .bind(Uuid::new_v4().hyphenated())
+            .bind(user_id.hyphenated())
+            .bind(code_hash)
+            .bind(sqlite_timestamp(current_date_time))
+            .try_map(from_sqlite_row::<RecoveryCodeEntity>)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+            codes.push(entity.into());
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)?;
+        Ok(codes)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_unused_by_user_id(&self, user_id: Uuid) -> RepositoryResult<Vec<RecoveryCode>> {
+        sqlx::query(
+            "SELECT id, user_id, code_hash, used_at, created_at
+             FROM user_recovery_codes
+             WHERE user_id = $1 AND used_at IS NULL",
+        )
+        .bind(user_id.hyphenated())
+        .try_map(from_sqlite_row::<RecoveryCodeEntity>)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(RecoveryCodeEntity::into).collect())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn mark_used(&self, id: Uuid) -> RepositoryResult<()> {
+        let result = sqlx::query(
+            "UPDATE user_recovery_codes SET used_at = $2 WHERE id = $1 AND used_at IS NULL",
+        )
+        .bind(id.hyphenated())
+        .bind(sqlite_timestamp(chrono::Utc::now()))
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        // Already used, e.g. by a concurrent login with the same code.
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn delete_by_user_id(&self, user_id: Uuid) -> RepositoryResult<()> {
+        sqlx::query("DELETE FROM user_recovery_codes WHERE user_id = $1")
+            .bind(user_id.hyphenated())
+            .execute(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+            .map(|_| ())
+    }
+}
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use tracing::instrument;
+
+use crate::{
+    domain::repositories::{RepositoryResult, settings_repository::SettingsRepository},
+    infrastructure::repositories::{map_sqlx_error, sqlite_timestamp},
+};
+
+#[derive(Clone)]
+pub struct SqliteSettingsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteSettingsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SettingsRepository for SqliteSettingsRepository {
+    #[instrument(skip(self), level = "debug")]
+    async fn get_value(&self, key: &str) -> RepositoryResult<Option<String>> {
+        sqlx::query_scalar("SELECT value FROM settings WHERE key = $1")
+            .bind(key)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn set_value(&self, key: &str, value: &str) -> RepositoryResult<()> {
+        sqlx::query(
+            "INSERT INTO settings (key, value, updated_at)
+             VALUES ($1, $2, $3)
+             ON CONFLICT (key) DO UPDATE SET
+             value = excluded.value,
+             updated_at = excluded.updated_at",
+        )
+        .bind(key)
+        .bind(value)
+        .bind(sqlite_timestamp(chrono::Utc::now()))
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|_| ())
+    }
+}
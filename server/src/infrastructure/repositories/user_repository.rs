@@ -1,29 +1,43 @@
 use async_trait::async_trait;
-use rbatis::RBatis;
+use sqlx::SqlitePool;
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::{
     domain::{
         models::user::{User, UserRole},
-        repositories::{RepositoryResult, user_repository::UserRepository},
+        repositories::{RepositoryError, RepositoryResult, user_repository::UserRepository},
     },
     infrastructure::repositories::{
-        entities::user_entity::UserEntity, map_rbatis_error, queries::user_queries,
+        count_to_i32, entities::user_entity::UserEntity, from_sqlite_row, map_sqlx_error,
+        sqlite_timestamp,
     },
 };
 
+const USER_COLUMNS: &str = "id, email, password_hash, first_name, last_name, avatar_url, role, \
+     totp_secret, totp_enabled, is_active, deleted_at, created_at, updated_at";
+
+const SEARCH_FILTER: &str = "WHERE deleted_at IS NULL
+     AND ($1 IS NULL OR email LIKE $1 OR first_name LIKE $1 OR last_name LIKE $1)";
+
 #[derive(Clone)]
 pub struct SqliteUserRepository {
-    rb: RBatis,
+    pool: SqlitePool,
 }
 
 impl SqliteUserRepository {
-    pub fn new(rb: RBatis) -> Self {
-        Self { rb }
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
     }
 }
 
+fn search_pattern(search: Option<&str>) -> Option<String> {
+    search
+        .map(str::trim)
+        .filter(|term| !term.is_empty())
+        .map(|term| format!("%{}%", term))
+}
+
 #[async_trait]
 impl UserRepository for SqliteUserRepository {
     #[instrument(skip(self, password_hash), fields(email = %email, role = %role), level = "debug")]
@@ -36,39 +50,355 @@ impl UserRepository for SqliteUserRepository {
         role: UserRole,
     ) -> RepositoryResult<User> {
         let current_date_time = chrono::Utc::now();
+        let id = Uuid::new_v4();
 
-        let entity = UserEntity {
-            id: Uuid::new_v4(),
-            email: email.to_string(),
-            password_hash: password_hash.to_string(),
-            first_name: first_name.map(|s| s.to_string()),
-            last_name: last_name.map(|s| s.to_string()),
-            role,
-            created_at: current_date_time,
-            updated_at: current_date_time,
-        };
+        tracing::debug!(user_id = %id, "Executing insert query for new user.");
 
-        tracing::debug!(user_id = %entity.id, "Executing insert query for new user.");
-
-        user_queries::insert_user(&self.rb, entity)
-            .await
-            .map_err(map_rbatis_error)
-            .map(UserEntity::into)
+        sqlx::query(&format!(
+            "INSERT INTO users (id, email, password_hash, first_name, last_name, role, \
+             totp_enabled, is_active, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, 0, 1, $7, $7)
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id.hyphenated())
+        .bind(email)
+        .bind(password_hash)
+        .bind(first_name)
+        .bind(last_name)
+        .bind(role)
+        .bind(sqlite_timestamp(current_date_time))
+        .try_map(from_sqlite_row::<UserEntity>)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(UserEntity::into)
     }
 
     #[instrument(skip(self), fields(id = %id), level = "debug")]
     async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<User>> {
-        user_queries::find_by_id(&self.rb, id)
+        sqlx::query(&format!("SELECT {USER_COLUMNS} FROM users WHERE id = $1"))
+            .bind(id.hyphenated())
+            .try_map(from_sqlite_row::<UserEntity>)
+            .fetch_optional(&self.pool)
             .await
-            .map_err(map_rbatis_error)
+            .map_err(map_sqlx_error)
             .map(|opt_entity| opt_entity.map(UserEntity::into))
     }
 
     #[instrument(skip(self), fields(email = %email), level = "debug")]
     async fn find_by_email(&self, email: &str) -> RepositoryResult<Option<User>> {
-        user_queries::find_by_email(&self.rb, email)
+        sqlx::query(&format!(
+            "SELECT {USER_COLUMNS} FROM users WHERE email = $1"
+        ))
+        .bind(email)
+        .try_map(from_sqlite_row::<UserEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(UserEntity::into))
+    }
+
+    #[instrument(skip(self, totp_secret), fields(id = %id, totp_enabled = %totp_enabled), level = "debug")]
+    async fn update_totp(
+        &self,
+        id: Uuid,
+        totp_secret: Option<&str>,
+        totp_enabled: bool,
+    ) -> RepositoryResult<User> {
+        sqlx::query(&format!(
+            "UPDATE users SET totp_secret = $2, totp_enabled = $3, updated_at = $4,
+             totp_last_time_step = CASE WHEN totp_secret IS $2 THEN totp_last_time_step END
+             WHERE id = $1
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id.hyphenated())
+        .bind(totp_secret)
+        .bind(totp_enabled)
+        .bind(sqlite_timestamp(chrono::Utc::now()))
+        .try_map(from_sqlite_row::<UserEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(UserEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn record_totp_time_step(&self, id: Uuid, time_step: i64) -> RepositoryResult<bool> {
+        let result = sqlx::query(
+            "UPDATE users SET totp_last_time_step = $2
+             WHERE id = $1 AND (totp_last_time_step IS NULL OR totp_last_time_step < $2)",
+        )
+        .bind(id.hyphenated())
+        .bind(time_step)
+        .execute(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_paginated(
+        &self,
+        search: Option<&str>,
+        page: i32,
+        per_page: i32,
+    ) -> RepositoryResult<Vec<User>> {
+        let offset = (page - 1) * per_page;
+
+        sqlx::query(&format!(
+            "SELECT {USER_COLUMNS} FROM users {SEARCH_FILTER}
+             ORDER BY created_at ASC
+             LIMIT $2 OFFSET $3"
+        ))
+        .bind(search_pattern(search))
+        .bind(i64::from(per_page))
+        .bind(i64::from(offset))
+        .try_map(from_sqlite_row::<UserEntity>)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(UserEntity::into).collect())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn count(&self, search: Option<&str>) -> RepositoryResult<i32> {
+        sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) FROM users {SEARCH_FILTER}"))
+            .bind(search_pattern(search))
+            .fetch_one(&self.pool)
             .await
-            .map_err(map_rbatis_error)
-            .map(|opt_entity| opt_entity.map(UserEntity::into))
+            .map_err(map_sqlx_error)
+            .and_then(count_to_i32)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn count_active(&self) -> RepositoryResult<i64> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE is_active = 1 AND deleted_at IS NULL")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(map_sqlx_error)
+    }
+
+    #[instrument(skip(self, password_hash), fields(id = %id), level = "debug")]
+    async fn update_password_hash(&self, id: Uuid, password_hash: &str) -> RepositoryResult<()> {
+        let exec_result =
+            sqlx::query("UPDATE users SET password_hash = $2, updated_at = $3 WHERE id = $1")
+                .bind(id.hyphenated())
+                .bind(password_hash)
+                .bind(sqlite_timestamp(chrono::Utc::now()))
+                .execute(&self.pool)
+                .await
+                .map_err(map_sqlx_error)?;
+
+        if exec_result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(id = %id), level = "debug")]
+    async fn update_profile(
+        &self,
+        id: Uuid,
+        email: &str,
+        first_name: Option<&str>,
+        last_name: Option<&str>,
+    ) -> RepositoryResult<User> {
+        sqlx::query(&format!(
+            "UPDATE users SET email = $2, first_name = $3, last_name = $4, updated_at = $5
+             WHERE id = $1
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id.hyphenated())
+        .bind(email)
+        .bind(first_name)
+        .bind(last_name)
+        .bind(sqlite_timestamp(chrono::Utc::now()))
+        .try_map(from_sqlite_row::<UserEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(UserEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), fields(id = %id), level = "debug")]
+    async fn update_avatar(&self, id: Uuid, avatar_url: Option<&str>) -> RepositoryResult<User> {
+        sqlx::query(&format!(
+            "UPDATE users SET avatar_url = $2, updated_at = $3
+             WHERE id = $1
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id.hyphenated())
+        .bind(avatar_url)
+        .bind(sqlite_timestamp(chrono::Utc::now()))
+        .try_map(from_sqlite_row::<UserEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(UserEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), fields(id = %id, role = %role), level = "debug")]
+    async fn update_role(&self, id: Uuid, role: UserRole) -> RepositoryResult<User> {
+        sqlx::query(&format!(
+            "UPDATE users SET role = $2, updated_at = $3
+             WHERE id = $1
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id.hyphenated())
+        .bind(role)
+        .bind(sqlite_timestamp(chrono::Utc::now()))
+        .try_map(from_sqlite_row::<UserEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(UserEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), fields(id = %id, is_active = %is_active), level = "debug")]
+    async fn update_active(&self, id: Uuid, is_active: bool) -> RepositoryResult<User> {
+        sqlx::query(&format!(
+            "UPDATE users SET is_active = $2, updated_at = $3
+             WHERE id = $1
+             RETURNING {USER_COLUMNS}"
+        ))
+        .bind(id.hyphenated())
+        .bind(is_active)
+        .bind(sqlite_timestamp(chrono::Utc::now()))
+        .try_map(from_sqlite_row::<UserEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(UserEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), fields(id = %id, new_owner_id = %new_owner_id), level = "debug")]
+    async fn delete_reassigning_content(
+        &self,
+        id: Uuid,
+        new_owner_id: Uuid,
+    ) -> RepositoryResult<()> {
+        let mut tx = self
+            .pool
+            .begin_with("BEGIN IMMEDIATE")
+            .await
+            .map_err(map_sqlx_error)?;
+
+        let email: String = sqlx::query_scalar("SELECT email FROM users WHERE id = $1")
+            .bind(id.hyphenated())
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?
+            .ok_or(RepositoryError::NotFound)?;
+        let current_date_time = chrono::Utc::now();
+
+        sqlx::query(
+            "UPDATE boards
+             SET name = CASE
+                 WHEN EXISTS (
+                     SELECT 1 FROM boards AS existing
+                     WHERE existing.owner_id = $2 AND existing.name = boards.name
+                 ) THEN boards.name || ' (' || $3 || ')'
+                 ELSE boards.name
+             END,
+             owner_id = $2,
+             is_default = 0,
+             updated_at = $4
+             WHERE owner_id = $1",
+        )
+        .bind(id.hyphenated())
+        .bind(new_owner_id.hyphenated())
+        .bind(&email)
+        .bind(sqlite_timestamp(current_date_time))
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+        sqlx::query("UPDATE reports SET user_id = $2, updated_at = $3 WHERE user_id = $1")
+            .bind(id.hyphenated())
+            .bind(new_owner_id.hyphenated())
+            .bind(sqlite_timestamp(current_date_time))
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        // Nothing may reference the user anymore once it is deleted.
+        for statement in [
+            "UPDATE user_invitations SET invited_by = $2 WHERE invited_by = $1",
+            "UPDATE webhooks SET created_by = $2 WHERE created_by = $1",
+            "UPDATE chat_integrations SET created_by = $2 WHERE created_by = $1",
+            "UPDATE issue_tracker_integrations SET created_by = $2 WHERE created_by = $1",
+            "UPDATE intake_keys SET created_by = $2 WHERE created_by = $1",
+            "UPDATE inbound_mailboxes SET created_by = $2 WHERE created_by = $1",
+            "UPDATE report_history SET actor_id = $2 WHERE actor_id = $1",
+        ] {
+            sqlx::query(statement)
+                .bind(id.hyphenated())
+                .bind(new_owner_id.hyphenated())
+                .execute(&mut *tx)
+                .await
+                .map_err(map_sqlx_error)?;
+        }
+        for statement in [
+            "DELETE FROM user_recovery_codes WHERE user_id = $1",
+            "DELETE FROM notification_preferences WHERE user_id = $1",
+            "DELETE FROM users WHERE id = $1",
+        ] {
+            sqlx::query(statement)
+                .bind(id.hyphenated())
+                .execute(&mut *tx)
+                .await
+                .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)
+    }
+
+    #[instrument(skip(self), fields(id = %id), level = "debug")]
+    async fn anonymize(&self, id: Uuid) -> RepositoryResult<()> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        let placeholder_email = format!("deleted-{}@deleted.invalid", id);
+        let exec_result = sqlx::query(
+            "UPDATE users
+             SET email = $2,
+             password_hash = '',
+             first_name = NULL,
+             last_name = NULL,
+             avatar_url = NULL,
+             role = 'User',
+             totp_secret = NULL,
+             totp_last_time_step = NULL,
+             totp_enabled = 0,
+             is_active = 0,
+             deleted_at = $3,
+             updated_at = $3
+             WHERE id = $1",
+        )
+        .bind(id.hyphenated())
+        .bind(&placeholder_email)
+        .bind(sqlite_timestamp(chrono::Utc::now()))
+        .execute(&mut *tx)
+        .await
+        .map_err(map_sqlx_error)?;
+        if exec_result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        for statement in [
+            "DELETE FROM user_recovery_codes WHERE user_id = $1",
+            "DELETE FROM notification_preferences WHERE user_id = $1",
+        ] {
+            sqlx::query(statement)
+                .bind(id.hyphenated())
+                .execute(&mut *tx)
+                .await
+                .map_err(map_sqlx_error)?;
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)
     }
 }
@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::{
+            event::EventType,
+            webhook::{UpdateWebhookParams, Webhook},
+        },
+        repositories::{RepositoryError, RepositoryResult, webhook_repository::WebhookRepository},
+    },
+    infrastructure::repositories::{
+        entities::webhook_entity::{WebhookEntity, join_event_types},
+        from_sqlite_row, map_sqlx_error, sqlite_timestamp,
+    },
+};
+
+const WEBHOOK_COLUMNS: &str =
+    "id, board_id, url, secret, event_types, is_active, created_by, created_at, updated_at";
+
+#[derive(Clone)]
+pub struct SqliteWebhookRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteWebhookRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for SqliteWebhookRepository {
+    #[instrument(skip(self, secret), fields(board_id = %board_id), level = "debug")]
+    async fn create_webhook(
+        &self,
+        board_id: Uuid,
+        url: &str,
+        secret: &str,
+        event_types: &[EventType],
+        created_by: Uuid,
+    ) -> RepositoryResult<Webhook> {
+        let current_date_time = chrono::Utc::now();
+        let id = Uuid::new_v4();
+
+        tracing::debug!(webhook_id = %id, "Creating new webhook.");
+
+        sqlx::query(&format!(
+            "INSERT INTO webhooks (id, board_id, url, secret, event_types, is_active, \
+             created_by, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, 1, $6, $7, $7)
+             RETURNING {WEBHOOK_COLUMNS}"
+        ))
+        .bind(id.hyphenated())
+        .bind(board_id.hyphenated())
+        .bind(url)
+        .bind(secret)
+        .bind(join_event_types(event_types))
+        .bind(created_by.hyphenated())
+        .bind(sqlite_timestamp(current_date_time))
+        .try_map(from_sqlite_row::<WebhookEntity>)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(WebhookEntity::into)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_id(&self, id: Uuid) -> RepositoryResult<Option<Webhook>> {
+        sqlx::query(&format!(
+            "SELECT {WEBHOOK_COLUMNS} FROM webhooks WHERE id = $1"
+        ))
+        .bind(id.hyphenated())
+        .try_map(from_sqlite_row::<WebhookEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|opt_entity| opt_entity.map(WebhookEntity::into))
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn find_by_board_id(&self, board_id: Uuid) -> RepositoryResult<Vec<Webhook>> {
+        sqlx::query(&format!(
+            "SELECT {WEBHOOK_COLUMNS} FROM webhooks
+             WHERE board_id = $1
+             ORDER BY created_at ASC"
+        ))
+        .bind(board_id.hyphenated())
+        .try_map(from_sqlite_row::<WebhookEntity>)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(map_sqlx_error)
+        .map(|entities| entities.into_iter().map(WebhookEntity::into).collect())
+    }
+
+    #[instrument(skip(self, params), level = "debug")]
+    async fn update_webhook(
+        &self,
+        id: Uuid,
+        params: UpdateWebhookParams,
+    ) -> RepositoryResult<Webhook> {
+        let webhook = self
+            .find_by_id(id)
+            .await?
+            .ok_or(RepositoryError::NotFound)?;
+
+        let url = params.url.unwrap_or(webhook.url);
+        let secret = params.secret.unwrap_or(webhook.secret);
+        let event_types = params.event_types.unwrap_or(webhook.event_types);
+        let is_active = params.is_active.unwrap_or(webhook.is_active);
+
+        sqlx::query(&format!(
+            "UPDATE webhooks
+             SET url = $2, secret = $3, event_types = $4, is_active = $5, updated_at = $6
+             WHERE id = $1
+             RETURNING {WEBHOOK_COLUMNS}"
+        ))
+        .bind(id.hyphenated())
+        .bind(url)
+        .bind(secret)
+        .bind(join_event_types(&event_types))
+        .bind(is_active)
+        .bind(sqlite_timestamp(chrono::Utc::now()))
+        .try_map(from_sqlite_row::<WebhookEntity>)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(map_sqlx_error)?
+        .map(WebhookEntity::into)
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn delete_webhook(&self, id: Uuid) -> RepositoryResult<()> {
+        let mut tx = self.pool.begin().await.map_err(map_sqlx_error)?;
+
+        sqlx::query("DELETE FROM webhook_deliveries WHERE webhook_id = $1")
+            .bind(id.hyphenated())
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        let exec_result = sqlx::query("DELETE FROM webhooks WHERE id = $1")
+            .bind(id.hyphenated())
+            .execute(&mut *tx)
+            .await
+            .map_err(map_sqlx_error)?;
+        if exec_result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        tx.commit().await.map_err(map_sqlx_error)
+    }
+}
@@ -0,0 +1,12 @@
+use rand::{Rng, distr::Alphanumeric};
+
+const INBOUND_MAILBOX_TOKEN_LENGTH: usize = 24;
+
+/// Mailbox tokens are the local part of an email address, which mail servers may lowercase.
+pub fn generate_inbound_mailbox_token() -> String {
+    rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(INBOUND_MAILBOX_TOKEN_LENGTH)
+        .map(|byte| char::from(byte).to_ascii_lowercase())
+        .collect()
+}
@@ -0,0 +1,15 @@
+use rand::{Rng, distr::Alphanumeric};
+
+const INTAKE_KEY_PREFIX: &str = "rbk_";
+const INTAKE_KEY_LENGTH: usize = 32;
+
+/// Intake keys are public, the prefix only makes them recognizable in the widget snippets.
+pub fn generate_intake_key() -> String {
+    let random_part: String = rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(INTAKE_KEY_LENGTH)
+        .map(char::from)
+        .collect();
+
+    format!("{}{}", INTAKE_KEY_PREFIX, random_part)
+}
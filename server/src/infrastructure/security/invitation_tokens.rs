@@ -0,0 +1,17 @@
+use rand::{Rng, distr::Alphanumeric};
+use sha2::{Digest, Sha256};
+
+const INVITATION_TOKEN_LENGTH: usize = 40;
+
+pub fn generate_invitation_token() -> String {
+    rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(INVITATION_TOKEN_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+/// Invitation tokens are random and high-entropy, so a fast digest is enough to store them.
+pub fn hash_invitation_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.trim().as_bytes()))
+}
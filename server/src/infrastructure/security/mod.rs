@@ -1 +1,9 @@
+pub mod inbound_mailbox_tokens;
+pub mod intake_keys;
+pub mod invitation_tokens;
 pub mod password_hasher;
+pub mod public_addresses;
+pub mod rate_limiter;
+pub mod recovery_codes;
+pub mod totp;
+pub mod webhook_signature;
@@ -1,4 +1,13 @@
-use bcrypt::{DEFAULT_COST, hash, verify};
+use argon2::{
+    Algorithm, Argon2, Params, PasswordHash, PasswordHasher, PasswordVerifier, Version,
+    password_hash::SaltString,
+};
+
+use crate::config::app_config::APP_CONFIG;
+
+const ARGON2_PREFIX: &str = "$argon2";
+const BCRYPT_PREFIXES: [&str; 3] = ["$2a$", "$2b$", "$2y$"];
+const SALT_LENGTH: usize = 16;
 
 #[derive(Debug, thiserror::Error)]
 pub enum PasswordError {
@@ -12,10 +21,161 @@ pub enum PasswordError {
 
 pub type PasswordResult<T> = Result<T, PasswordError>;
 
-pub fn hash_password(password: &str) -> PasswordResult<String> {
-    hash(password, DEFAULT_COST).map_err(|e| PasswordError::HashingError(e.to_string()))
+/// Hashes the password with Argon2id, off the async executor.
+pub async fn hash_password(password: &str) -> PasswordResult<String> {
+    let password = password.to_string();
+
+    tokio::task::spawn_blocking(move || hash_with_argon2(&password))
+        .await
+        .map_err(|e| PasswordError::HashingError(e.to_string()))?
+}
+
+/// Verifies the password against an Argon2 or a legacy bcrypt hash, off the async executor.
+pub async fn verify_password(password: &str, hashed_password: &str) -> PasswordResult<bool> {
+    let password = password.to_string();
+    let hashed_password = hashed_password.to_string();
+
+    tokio::task::spawn_blocking(move || verify_blocking(&password, &hashed_password))
+        .await
+        .map_err(|e| PasswordError::VerificationError(e.to_string()))?
+}
+
+/// Whether the hash should be replaced: it is not Argon2id, or was made with other parameters.
+pub fn needs_rehash(hashed_password: &str) -> bool {
+    let Ok(parsed_hash) = PasswordHash::new(hashed_password) else {
+        return true;
+    };
+
+    if parsed_hash.algorithm != Algorithm::Argon2id.ident() {
+        return true;
+    }
+
+    match (Params::try_from(&parsed_hash), argon2_params()) {
+        (Ok(current), Ok(configured)) => {
+            current.m_cost() != configured.m_cost()
+                || current.t_cost() != configured.t_cost()
+                || current.p_cost() != configured.p_cost()
+        }
+        _ => true,
+    }
+}
+
+fn argon2_params() -> PasswordResult<Params> {
+    Params::new(
+        APP_CONFIG.argon2_memory_cost_kib,
+        APP_CONFIG.argon2_time_cost,
+        APP_CONFIG.argon2_parallelism,
+        None,
+    )
+    .map_err(|e| PasswordError::HashingError(format!("Invalid Argon2 parameters: {}", e)))
+}
+
+fn argon2() -> PasswordResult<Argon2<'static>> {
+    Ok(Argon2::new(
+        Algorithm::Argon2id,
+        Version::V0x13,
+        argon2_params()?,
+    ))
+}
+
+fn hash_with_argon2(password: &str) -> PasswordResult<String> {
+    let salt = SaltString::encode_b64(&rand::random::<[u8; SALT_LENGTH]>())
+        .map_err(|e| PasswordError::HashingError(e.to_string()))?;
+
+    argon2()?
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| PasswordError::HashingError(e.to_string()))
+}
+
+fn verify_blocking(password: &str, hashed_password: &str) -> PasswordResult<bool> {
+    if hashed_password.starts_with(ARGON2_PREFIX) {
+        let parsed_hash = PasswordHash::new(hashed_password)
+            .map_err(|e| PasswordError::VerificationError(e.to_string()))?;
+
+        // The parameters embedded in the hash take precedence over the configured ones.
+        return match Argon2::default().verify_password(password.as_bytes(), &parsed_hash) {
+            Ok(()) => Ok(true),
+            Err(argon2::password_hash::Error::Password) => Ok(false),
+            Err(e) => Err(PasswordError::VerificationError(e.to_string())),
+        };
+    }
+
+    if BCRYPT_PREFIXES
+        .iter()
+        .any(|prefix| hashed_password.starts_with(prefix))
+    {
+        return bcrypt::verify(password, hashed_password)
+            .map_err(|e| PasswordError::VerificationError(e.to_string()));
+    }
+
+    Err(PasswordError::VerificationError(
+        "Unsupported password hash format".to_string(),
+    ))
 }
 
-pub fn verify_password(password: &str, hashed_password: &str) -> PasswordResult<bool> {
-    verify(password, hashed_password).map_err(|e| PasswordError::VerificationError(e.to_string()))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSWORD: &str = "correct horse battery staple";
+
+    fn argon2id_hash(memory_cost_kib: u32, time_cost: u32) -> String {
+        let salt = SaltString::encode_b64(&[7; SALT_LENGTH]).unwrap();
+        let params = Params::new(memory_cost_kib, time_cost, 1, None).unwrap();
+
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password(PASSWORD.as_bytes(), &salt)
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn verifies_legacy_bcrypt_hashes() {
+        let hash = bcrypt::hash(PASSWORD, 4).unwrap();
+
+        for hash in [hash.clone(), hash.replacen("$2b$", "$2a$", 1)] {
+            assert!(verify_password(PASSWORD, &hash).await.unwrap(), "{}", hash);
+            assert!(!verify_password("wrong password", &hash).await.unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn hashes_with_argon2id_and_verifies() {
+        let hash = hash_password(PASSWORD).await.unwrap();
+
+        assert!(hash.starts_with("$argon2id$"), "{}", hash);
+        assert!(verify_password(PASSWORD, &hash).await.unwrap());
+        assert!(!verify_password("wrong password", &hash).await.unwrap());
+        assert!(!needs_rehash(&hash));
+    }
+
+    #[tokio::test]
+    async fn rehashes_bcrypt_and_outdated_argon2_hashes() {
+        let outdated = argon2id_hash(8, 1);
+
+        assert!(needs_rehash(&bcrypt::hash(PASSWORD, 4).unwrap()));
+        assert!(needs_rehash(&outdated));
+        assert!(needs_rehash(&argon2id_hash(
+            APP_CONFIG.argon2_memory_cost_kib,
+            APP_CONFIG.argon2_time_cost + 1
+        )));
+        // Hashes made with other parameters still verify until they are replaced.
+        assert!(verify_password(PASSWORD, &outdated).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_hash_formats() {
+        for hash in ["$1$salt$hash", "$5$rounds=5000$salt$hash", PASSWORD, ""] {
+            assert!(
+                matches!(
+                    verify_password(PASSWORD, hash).await,
+                    Err(PasswordError::VerificationError(_))
+                ),
+                "{}",
+                hash
+            );
+            assert!(needs_rehash(hash), "{}", hash);
+        }
+    }
 }
@@ -0,0 +1,271 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+};
+
+use reqwest::{
+    ClientBuilder, Url,
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PublicAddressError {
+    #[error("Not an absolute http(s) URL")]
+    InvalidUrl,
+    #[error("Cannot resolve {0}")]
+    Unresolvable(String),
+    #[error("{0} is not a public address")]
+    NotPublic(IpAddr),
+}
+
+/// Whether the address is reachable on the internet, as opposed to loopback, private, link-local
+/// and other special-purpose ranges. Requests sent on behalf of users, such as webhooks, must not
+/// reach the services running next to the server.
+pub fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ipv4(ip),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        || first == 0
+        // Shared address space, used by carrier-grade NAT.
+        || (first == 100 && (64..128).contains(&second))
+        // IETF protocol assignments.
+        || (first == 192 && second == 0 && ip.octets()[2] == 0)
+        // Benchmarking.
+        || (first == 198 && (18..20).contains(&second))
+        // Reserved.
+        || first >= 240)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first_segment = ip.segments()[0];
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        || ip.is_unique_local()
+        || ip.is_unicast_link_local()
+        // Deprecated site-local addresses.
+        || (first_segment & 0xffc0) == 0xfec0
+        // Documentation.
+        || (first_segment == 0x2001 && ip.segments()[1] == 0x0db8)
+        // IPv4-translated addresses, which can reach any IPv4 network through NAT64.
+        || (first_segment == 0x0064 && ip.segments()[1] == 0xff9b))
+}
+
+/// Addresses of the host, all of them public. A host with any other address is refused
+/// altogether, rather than filtered, since the address used for a connection is not known in
+/// advance.
+pub async fn resolve_public_addresses(
+    host: &str,
+    port: u16,
+) -> Result<Vec<SocketAddr>, PublicAddressError> {
+    let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|_| PublicAddressError::Unresolvable(host.to_string()))?
+        .collect();
+
+    if addresses.is_empty() {
+        return Err(PublicAddressError::Unresolvable(host.to_string()));
+    }
+    if let Some(address) = addresses
+        .iter()
+        .find(|address| !is_public_address(address.ip()))
+    {
+        return Err(PublicAddressError::NotPublic(address.ip()));
+    }
+
+    Ok(addresses)
+}
+
+/// DNS resolver for HTTP clients, failing the connection to hosts with non-public addresses. It
+/// is checked again on each request, so that a host cannot be repointed once validated. URLs
+/// holding an IP address skip the resolver and must be checked with `is_public_address`.
+pub struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            // The connector sets the port of the URL on each address.
+            let addresses = resolve_public_addresses(name.as_str(), 0).await?;
+            Ok(Box::new(addresses.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Checks a URL given by a user before it is stored: it must be an absolute http(s) URL and,
+/// unless private networks are allowed, its host must resolve to public addresses only.
+pub async fn check_public_url(
+    url: &str,
+    allow_private_networks: bool,
+) -> Result<(), PublicAddressError> {
+    let url = Url::parse(url).map_err(|_| PublicAddressError::InvalidUrl)?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(PublicAddressError::InvalidUrl);
+    }
+    let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+        return Err(PublicAddressError::InvalidUrl);
+    };
+
+    if allow_private_networks {
+        return Ok(());
+    }
+    resolve_public_addresses(host.trim_start_matches('[').trim_end_matches(']'), port).await?;
+
+    Ok(())
+}
+
+/// Client for requests sent to URLs given by users. Unless `allow_private_networks` is set, they
+/// only reach public addresses. Redirects are never followed, since they could lead anywhere.
+pub fn outbound_client_builder(allow_private_networks: bool) -> ClientBuilder {
+    let builder = reqwest::Client::builder().redirect(redirect::Policy::none());
+    if allow_private_networks {
+        builder
+    } else {
+        builder.dns_resolver(Arc::new(PublicAddressResolver))
+    }
+}
+
+/// Checks the host of a URL holding an IP address, which the resolver never sees. Run before
+/// each request sent with an `outbound_client_builder` client.
+pub fn check_ip_host(url: &Url) -> Result<(), PublicAddressError> {
+    match url
+        .host_str()
+        .unwrap_or_default()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+    {
+        Ok(ip) if !is_public_address(ip) => Err(PublicAddressError::NotPublic(ip)),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_public_addresses() {
+        for ip in [
+            "93.184.215.14",
+            "8.8.8.8",
+            "2606:4700::1111",
+            "::ffff:1.1.1.1",
+        ] {
+            assert!(is_public_address(ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[test]
+    fn rejects_internal_addresses() {
+        let cases = [
+            "0.0.0.0",
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "192.0.0.8",
+            "198.18.0.1",
+            "224.0.0.1",
+            "255.255.255.255",
+            "::",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "fec0::1",
+            "ff02::1",
+            "::ffff:127.0.0.1",
+            "::ffff:169.254.169.254",
+            "64:ff9b::a9fe:a9fe",
+        ];
+
+        for ip in cases {
+            assert!(!is_public_address(ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[tokio::test]
+    async fn refuses_hosts_resolving_to_internal_addresses() {
+        for host in ["127.0.0.1", "::1", "localhost"] {
+            assert!(
+                matches!(
+                    resolve_public_addresses(host, 80).await,
+                    Err(PublicAddressError::NotPublic(_))
+                ),
+                "{}",
+                host
+            );
+        }
+        assert!(matches!(
+            resolve_public_addresses("1.1.1.1", 443).await,
+            Ok(addresses) if addresses == [SocketAddr::from(([1, 1, 1, 1], 443))]
+        ));
+    }
+
+    #[tokio::test]
+    async fn checks_urls_given_by_users() {
+        for url in ["ftp://1.1.1.1/", "not a url", "mailto:a@b.c"] {
+            assert!(
+                matches!(
+                    check_public_url(url, false).await,
+                    Err(PublicAddressError::InvalidUrl)
+                ),
+                "{}",
+                url
+            );
+        }
+        for url in [
+            "http://127.0.0.1:8080/hook",
+            "https://[::1]/",
+            "http://10.0.0.1/",
+        ] {
+            assert!(
+                matches!(
+                    check_public_url(url, false).await,
+                    Err(PublicAddressError::NotPublic(_))
+                ),
+                "{}",
+                url
+            );
+            assert!(check_public_url(url, true).await.is_ok(), "{}", url);
+        }
+        assert!(
+            check_public_url("https://1.1.1.1/hook", false)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn checks_ip_hosts() {
+        for url in [
+            "http://169.254.169.254/",
+            "http://[::1]:3000/",
+            "http://[::ffff:10.0.0.1]/",
+        ] {
+            assert!(check_ip_host(&Url::parse(url).unwrap()).is_err(), "{}", url);
+        }
+        for url in ["https://1.1.1.1/", "https://example.com/"] {
+            assert!(check_ip_host(&Url::parse(url).unwrap()).is_ok(), "{}", url);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    net::{IpAddr, Ipv6Addr},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Above this many tracked clients, expired windows are dropped before counting a new hit.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// IPv6 clients usually get a whole /64 and can pick any address in it.
+const IPV6_CLIENT_PREFIX_LENGTH: u32 = 64;
+
+/// Key a client IP address is counted under: the address itself for IPv4, its /64 network for
+/// IPv6, so that rotating addresses within the network does not reset the limit.
+pub fn client_network(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V4(_) => ip,
+        IpAddr::V6(ipv6) => match ipv6.to_ipv4_mapped() {
+            Some(ipv4) => IpAddr::V4(ipv4),
+            None => {
+                let mask = u128::MAX << (128 - IPV6_CLIENT_PREFIX_LENGTH);
+                IpAddr::V6(Ipv6Addr::from(u128::from(ipv6) & mask))
+            }
+        },
+    }
+}
+
+/// Allows up to `max_hits` per client in fixed time windows, in memory.
+pub struct RateLimiter<K> {
+    max_hits: u32,
+    window: Duration,
+    windows: Mutex<HashMap<K, (Instant, u32)>>,
+}
+
+impl<K: Eq + Hash> RateLimiter<K> {
+    pub fn new(max_hits: u32, window: Duration) -> Self {
+        Self {
+            max_hits,
+            window,
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Counts a hit for the client, and returns whether it is within the limit.
+    pub fn check(&self, key: K) -> bool {
+        let now = Instant::now();
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+
+        if windows.len() >= PRUNE_THRESHOLD {
+            windows.retain(|_, (started_at, _)| now.duration_since(*started_at) < self.window);
+        }
+
+        let (started_at, hits) = windows.entry(key).or_insert((now, 0));
+        if now.duration_since(*started_at) >= self.window {
+            *started_at = now;
+            *hits = 0;
+        }
+
+        if *hits >= self.max_hits {
+            return false;
+        }
+        *hits += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_hits_per_key() {
+        let rate_limiter = RateLimiter::new(2, Duration::from_secs(60));
+
+        assert!(rate_limiter.check("a"));
+        assert!(rate_limiter.check("a"));
+        assert!(!rate_limiter.check("a"));
+        assert!(rate_limiter.check("b"));
+    }
+
+    #[test]
+    fn starts_a_new_window_once_expired() {
+        let rate_limiter = RateLimiter::new(1, Duration::ZERO);
+
+        assert!(rate_limiter.check("a"));
+        assert!(rate_limiter.check("a"));
+    }
+
+    #[test]
+    fn groups_ipv6_clients_by_network() {
+        let first: IpAddr = "2001:db8:1:2:aaaa::1".parse().unwrap();
+        let second: IpAddr = "2001:db8:1:2:ffff:ffff:ffff:ffff".parse().unwrap();
+        let other_network: IpAddr = "2001:db8:1:3::1".parse().unwrap();
+
+        assert_eq!(client_network(first), client_network(second));
+        assert_ne!(client_network(first), client_network(other_network));
+        assert_eq!(
+            client_network(first),
+            "2001:db8:1:2::".parse::<IpAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn keeps_ipv4_clients_apart() {
+        let ipv4: IpAddr = "203.0.113.7".parse().unwrap();
+        let mapped: IpAddr = "::ffff:203.0.113.7".parse().unwrap();
+
+        assert_eq!(client_network(ipv4), ipv4);
+        assert_eq!(client_network(mapped), ipv4);
+        assert_ne!(
+            client_network("203.0.113.8".parse().unwrap()),
+            client_network(ipv4)
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use rand::{Rng, distr::Alphanumeric};
+use sha2::{Digest, Sha256};
+
+const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_LENGTH: usize = 10;
+
+/// Generates a fresh set of recovery codes, formatted as `xxxxx-xxxxx`.
+pub fn generate_recovery_codes() -> Vec<String> {
+    let mut rng = rand::rng();
+
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let raw: String = (&mut rng)
+                .sample_iter(&Alphanumeric)
+                .take(RECOVERY_CODE_LENGTH)
+                .map(|c| char::from(c).to_ascii_lowercase())
+                .collect();
+            format!("{}-{}", &raw[..5], &raw[5..])
+        })
+        .collect()
+}
+
+/// Recovery codes are random and high-entropy, so a fast digest is enough to store them.
+pub fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .trim()
+        .chars()
+        .filter(|c| *c != '-')
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+
+    hex::encode(Sha256::digest(normalized.as_bytes()))
+}
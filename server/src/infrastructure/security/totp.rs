@@ -0,0 +1,130 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use totp_rs::{Algorithm, Secret, TOTP};
+
+#[derive(Debug, thiserror::Error)]
+pub enum TotpError {
+    #[error("Invalid TOTP secret: {0}")]
+    InvalidSecret(String),
+    #[error("Failed to read system time: {0}")]
+    ClockError(String),
+}
+
+pub type TotpResult<T> = Result<T, TotpError>;
+
+const TOTP_DIGITS: usize = 6;
+const TOTP_STEP_SECONDS: u64 = 30;
+// Accept the previous and next time step to tolerate small clock drifts. Steps are checked one at
+// a time, the TOTP itself has no skew.
+const TOTP_SKEW: u8 = 1;
+
+fn build_totp(secret_base32: &str, issuer: &str, account_name: &str) -> TotpResult<TOTP> {
+    let secret = Secret::Encoded(secret_base32.to_string())
+        .to_bytes()
+        .map_err(|e| TotpError::InvalidSecret(format!("{:?}", e)))?;
+
+    TOTP::new(
+        Algorithm::SHA1,
+        TOTP_DIGITS,
+        0,
+        TOTP_STEP_SECONDS,
+        secret,
+        Some(issuer.to_string()),
+        account_name.to_string(),
+    )
+    .map_err(|e| TotpError::InvalidSecret(e.to_string()))
+}
+
+/// Generates a new random secret, base32 encoded.
+pub fn generate_secret() -> String {
+    Secret::generate_secret().to_encoded().to_string()
+}
+
+/// Builds the `otpauth://` URI that authenticator apps scan as a QR code.
+pub fn provisioning_uri(
+    secret_base32: &str,
+    issuer: &str,
+    account_name: &str,
+) -> TotpResult<String> {
+    Ok(build_totp(secret_base32, issuer, account_name)?.get_url())
+}
+
+/// Returns the time step of the code when it is valid, so that callers can refuse a code that
+/// was already used.
+pub fn verify_code(
+    secret_base32: &str,
+    issuer: &str,
+    account_name: &str,
+    code: &str,
+) -> TotpResult<Option<u64>> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| TotpError::ClockError(e.to_string()))?;
+
+    verify_code_at(secret_base32, issuer, account_name, code, now.as_secs())
+}
+
+fn verify_code_at(
+    secret_base32: &str,
+    issuer: &str,
+    account_name: &str,
+    code: &str,
+    time: u64,
+) -> TotpResult<Option<u64>> {
+    let totp = build_totp(secret_base32, issuer, account_name)?;
+    let current_step = time / TOTP_STEP_SECONDS;
+    let first_step = current_step.saturating_sub(TOTP_SKEW as u64);
+    let last_step = current_step + TOTP_SKEW as u64;
+
+    Ok((first_step..=last_step).find(|step| totp.check(code.trim(), step * TOTP_STEP_SECONDS)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "JBSWY3DPEHPK3PXPJBSWY3DPEHPK3PXP";
+    const TIME: u64 = 1_700_000_000;
+
+    fn code_at(time: u64) -> String {
+        build_totp(SECRET, "Rebug", "jane@example.com")
+            .unwrap()
+            .generate(time)
+    }
+
+    fn verify(code: &str) -> Option<u64> {
+        verify_code_at(SECRET, "Rebug", "jane@example.com", code, TIME).unwrap()
+    }
+
+    #[test]
+    fn returns_the_time_step_of_a_valid_code() {
+        let current_step = TIME / TOTP_STEP_SECONDS;
+
+        assert_eq!(verify(&code_at(TIME)), Some(current_step));
+        assert_eq!(verify(&format!(" {} ", code_at(TIME))), Some(current_step));
+        assert_eq!(
+            verify(&code_at(TIME - TOTP_STEP_SECONDS)),
+            Some(current_step - 1)
+        );
+        assert_eq!(
+            verify(&code_at(TIME + TOTP_STEP_SECONDS)),
+            Some(current_step + 1)
+        );
+    }
+
+    #[test]
+    fn rejects_codes_outside_the_window() {
+        assert_eq!(verify(&code_at(TIME - 2 * TOTP_STEP_SECONDS)), None);
+        assert_eq!(verify(&code_at(TIME + 2 * TOTP_STEP_SECONDS)), None);
+        assert_eq!(verify(""), None);
+        assert_eq!(verify("abcdef"), None);
+    }
+
+    #[test]
+    fn rejects_invalid_secrets() {
+        assert!(matches!(
+            verify_code_at("not base32!", "Rebug", "jane@example.com", "123456", TIME),
+            Err(TotpError::InvalidSecret(_))
+        ));
+    }
+}
@@ -0,0 +1,102 @@
+use hmac::{Hmac, Mac};
+use rand::{Rng, distr::Alphanumeric};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+const WEBHOOK_SECRET_LENGTH: usize = 32;
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+pub fn generate_webhook_secret() -> String {
+    rand::rng()
+        .sample_iter(&Alphanumeric)
+        .take(WEBHOOK_SECRET_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+/// Signs `<timestamp>.<payload>` with HMAC-SHA256, formatted as `sha256=<hex>` for the signature
+/// header. Receivers compare the timestamp with their clock, so that a captured request cannot be
+/// replayed later on.
+pub fn sign_payload(secret: &str, timestamp: i64, payload: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(payload.as_bytes());
+
+    format!(
+        "{}{}",
+        SIGNATURE_PREFIX,
+        hex::encode(mac.finalize().into_bytes())
+    )
+}
+
+/// Checks a `sha256=<hex>` signature header against the payload, in constant time.
+pub fn verify_signature(secret: &str, payload: &[u8], signature: &str) -> bool {
+    let Some(signature) = signature
+        .strip_prefix(SIGNATURE_PREFIX)
+        .and_then(|hex_signature| hex::decode(hex_signature).ok())
+    else {
+        return false;
+    };
+
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(payload);
+    mac.verify_slice(&signature).is_ok()
+}
+
+/// Compares a token sent by a third party with the expected secret, in constant time.
+pub fn verify_token(secret: &str, token: &str) -> bool {
+    !secret.is_empty() && bool::from(secret.as_bytes().ct_eq(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAYLOAD: &str = r#"{"event":"report.created"}"#;
+
+    #[test]
+    fn signs_the_timestamp_with_the_payload() {
+        assert_eq!(
+            sign_payload("secret", 1_700_000_000, PAYLOAD),
+            "sha256=3b26063c5ec060cdf53ebb39ca40d6c8d727f899af46d304f94e08f63cc6ed7a"
+        );
+        assert_ne!(
+            sign_payload("secret", 1_700_000_000, PAYLOAD),
+            sign_payload("secret", 1_700_000_001, PAYLOAD)
+        );
+    }
+
+    #[test]
+    fn verifies_signatures() {
+        let signature = format!(
+            "sha256={}",
+            hex::encode(
+                Hmac::<Sha256>::new_from_slice(b"secret")
+                    .unwrap()
+                    .chain_update(PAYLOAD)
+                    .finalize()
+                    .into_bytes()
+            )
+        );
+
+        assert!(verify_signature("secret", PAYLOAD.as_bytes(), &signature));
+        assert!(!verify_signature("other", PAYLOAD.as_bytes(), &signature));
+        assert!(!verify_signature("secret", b"{}", &signature));
+        assert!(!verify_signature("secret", PAYLOAD.as_bytes(), "sha256=zz"));
+        assert!(!verify_signature(
+            "secret",
+            PAYLOAD.as_bytes(),
+            &signature[7..]
+        ));
+    }
+
+    #[test]
+    fn verifies_tokens() {
+        assert!(verify_token("token", "token"));
+        assert!(!verify_token("token", "tokens"));
+        assert!(!verify_token("", ""));
+    }
+}
@@ -1,12 +1,15 @@
 use async_trait::async_trait;
 use bytes::Bytes;
 use chrono::Utc;
-use mime_guess::MimeGuess;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 use tracing::instrument;
+use uuid::Uuid;
 
-use crate::domain::ports::storage_port::{StorageError, StoragePort, StorageResult};
+use crate::domain::{
+    models::media::MediaType,
+    ports::storage_port::{StorageError, StoragePort, StorageResult},
+};
 
 #[derive(Clone)]
 pub struct FileSystemStorage {
@@ -38,42 +41,43 @@ impl FileSystemStorage {
         })
     }
 
-    fn validate_file(&self, file_name: &str, data: &Bytes) -> StorageResult<()> {
-        let guess = MimeGuess::from_path(file_name).first_or_octet_stream();
-
-        match guess.type_().as_str() {
-            "image" => {
-                if data.len() > Self::MAX_IMAGE_SIZE {
-                    return Err(StorageError::ValidationError(format!(
-                        "Image file size exceeds the limit of {}MB",
-                        Self::MAX_IMAGE_SIZE / (1024 * 1024)
-                    )));
-                }
-            }
-            "video" => {
-                if data.len() > Self::MAX_VIDEO_SIZE {
-                    return Err(StorageError::ValidationError(format!(
-                        "Video file size exceeds the limit of {}MB",
-                        Self::MAX_VIDEO_SIZE / (1024 * 1024)
-                    )));
-                }
-            }
-            _ => {
-                return Err(StorageError::ValidationError(
-                    "Unsupported file type. Only images and videos are allowed.".to_string(),
-                ));
-            }
+    /// Type of the file, sniffed from its content: the name given by the client is not trusted.
+    fn validate_file(&self, data: &Bytes) -> StorageResult<MediaType> {
+        let media_type = MediaType::sniff(data).ok_or_else(|| {
+            StorageError::ValidationError(
+                "Unsupported file type. Only PNG, JPEG, GIF and WebP images and MP4, QuickTime \
+                 and WebM videos are allowed."
+                    .to_string(),
+            )
+        })?;
+
+        let (max_size, kind) = if media_type.is_video() {
+            (Self::MAX_VIDEO_SIZE, "Video")
+        } else {
+            (Self::MAX_IMAGE_SIZE, "Image")
+        };
+        if data.len() > max_size {
+            return Err(StorageError::ValidationError(format!(
+                "{} file size exceeds the limit of {}MB",
+                kind,
+                max_size / (1024 * 1024)
+            )));
         }
 
-        Ok(())
+        Ok(media_type)
     }
 
-    fn get_file_extension(&self, file_name: &str) -> Result<String, StorageError> {
-        Path::new(file_name)
-            .extension()
-            .and_then(|ext| ext.to_str())
-            .map(|s| s.to_string())
-            .ok_or_else(|| StorageError::ValidationError("File extension not found".to_string()))
+    /// Path of a file from its public URL, which must point right into the upload directory.
+    fn resolve_path(&self, file_identifier: &str) -> StorageResult<PathBuf> {
+        let file_name = file_identifier
+            .strip_prefix(&self.base_url)
+            .map(|path| path.trim_start_matches('/'))
+            .filter(|file_name| {
+                !file_name.is_empty() && !file_name.contains(['/', '\\']) && *file_name != ".."
+            })
+            .ok_or(StorageError::NotFound)?;
+
+        Ok(Path::new(&self.upload_directory).join(file_name))
     }
 
     fn get_public_url(&self, file_path: &str) -> String {
@@ -85,12 +89,13 @@ impl FileSystemStorage {
 impl StoragePort for FileSystemStorage {
     #[instrument(skip(self, data), fields(original_file_name = %original_file_name, file_size = data.len()), level = "debug")]
     async fn save_file(&self, original_file_name: &str, data: Bytes) -> StorageResult<String> {
-        self.validate_file(original_file_name, &data)?;
+        let media_type = self.validate_file(&data)?;
 
         let unique_file_name = format!(
-            "{}.{}",
+            "{}-{}.{}",
             Utc::now().timestamp_millis(),
-            self.get_file_extension(original_file_name)?
+            Uuid::new_v4().simple(),
+            media_type.extension()
         );
 
         let full_path = Path::new(&self.upload_directory).join(&unique_file_name);
@@ -103,4 +108,24 @@ impl StoragePort for FileSystemStorage {
 
         Ok(self.get_public_url(&unique_file_name))
     }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn retrieve_file(&self, file_identifier: &str) -> StorageResult<Bytes> {
+        let full_path = self.resolve_path(file_identifier)?;
+        match fs::read(&full_path).await {
+            Ok(data) => Ok(Bytes::from(data)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(StorageError::NotFound),
+            Err(e) => Err(StorageError::RetrievalFailed(e.to_string())),
+        }
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn delete_file(&self, file_identifier: &str) -> StorageResult<()> {
+        let full_path = self.resolve_path(file_identifier)?;
+        match fs::remove_file(&full_path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(StorageError::NotFound),
+            Err(e) => Err(StorageError::IoError(e)),
+        }
+    }
 }
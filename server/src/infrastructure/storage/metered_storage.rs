@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+
+use crate::domain::ports::{
+    metrics_port::MetricsPort,
+    storage_port::{StoragePort, StorageResult},
+};
+
+/// Storage recording the size of every file it saves.
+pub struct MeteredStorage {
+    inner: Arc<dyn StoragePort>,
+    metrics: Arc<dyn MetricsPort>,
+}
+
+impl MeteredStorage {
+    pub fn new(inner: Arc<dyn StoragePort>, metrics: Arc<dyn MetricsPort>) -> Self {
+        Self { inner, metrics }
+    }
+}
+
+#[async_trait]
+impl StoragePort for MeteredStorage {
+    async fn save_file(&self, file_name: &str, data: Bytes) -> StorageResult<String> {
+        let size_bytes = data.len() as u64;
+        let file_identifier = self.inner.save_file(file_name, data).await?;
+        self.metrics.record_stored_file(size_bytes);
+
+        Ok(file_identifier)
+    }
+
+    async fn retrieve_file(&self, file_identifier: &str) -> StorageResult<Bytes> {
+        self.inner.retrieve_file(file_identifier).await
+    }
+
+    async fn delete_file(&self, file_identifier: &str) -> StorageResult<()> {
+        self.inner.delete_file(file_identifier).await
+    }
+}
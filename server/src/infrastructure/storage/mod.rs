@@ -1 +1,2 @@
 pub mod file_system_storage;
+pub mod metered_storage;
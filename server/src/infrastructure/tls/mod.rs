@@ -0,0 +1 @@
+pub mod server_certificate;
@@ -0,0 +1,125 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use axum_server::tls_rustls::RustlsConfig;
+use tokio::time::MissedTickBehavior;
+use tokio_rustls::rustls::{
+    ServerConfig,
+    crypto::ring,
+    pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+};
+
+/// Certificate chain and private key the server presents over HTTPS, read from PEM files.
+///
+/// The files are checked periodically and reloaded when either changes, so that a renewed
+/// certificate is picked up without a restart. Connections already open keep the previous one.
+pub struct ServerCertificate {
+    cert_path: PathBuf,
+    key_path: PathBuf,
+    rustls_config: RustlsConfig,
+}
+
+impl ServerCertificate {
+    pub async fn load(cert_path: &str, key_path: &str) -> io::Result<Self> {
+        let cert_path = PathBuf::from(cert_path);
+        let key_path = PathBuf::from(key_path);
+        let server_config = read_server_config(&cert_path, &key_path).await?;
+
+        Ok(Self {
+            cert_path,
+            key_path,
+            rustls_config: RustlsConfig::from_config(Arc::new(server_config)),
+        })
+    }
+
+    /// Configuration of the listener, updated in place on each reload.
+    pub fn rustls_config(&self) -> RustlsConfig {
+        self.rustls_config.clone()
+    }
+
+    /// Reloads the certificate whenever the modification time of one of the files changes,
+    /// checking every `period`. An invalid pair, e.g. a certificate written before its key, is
+    /// logged and the current certificate kept until the next change.
+    pub async fn watch(self, period: Duration) {
+        let mut last_modified = self.modified_times().await;
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            let modified = self.modified_times().await;
+            if modified == last_modified {
+                continue;
+            }
+            last_modified = modified;
+
+            match read_server_config(&self.cert_path, &self.key_path).await {
+                Ok(server_config) => {
+                    self.rustls_config
+                        .reload_from_config(Arc::new(server_config));
+                    tracing::info!(
+                        "Reloaded the TLS certificate from {}",
+                        self.cert_path.display()
+                    );
+                }
+                Err(e) => tracing::error!(
+                    "Failed to reload the TLS certificate, keeping the current one: {}",
+                    e
+                ),
+            }
+        }
+    }
+
+    async fn modified_times(&self) -> (Option<SystemTime>, Option<SystemTime>) {
+        (
+            modified_time(&self.cert_path).await,
+            modified_time(&self.key_path).await,
+        )
+    }
+}
+
+async fn modified_time(path: &Path) -> Option<SystemTime> {
+    tokio::fs::metadata(path).await.ok()?.modified().ok()
+}
+
+async fn read_server_config(cert_path: &Path, key_path: &Path) -> io::Result<ServerConfig> {
+    let cert_pem = read_file(cert_path).await?;
+    let key_pem = read_file(key_path).await?;
+
+    let cert_chain = CertificateDer::pem_slice_iter(&cert_pem)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| invalid_data(cert_path, e))?;
+    if cert_chain.is_empty() {
+        return Err(invalid_data(cert_path, "no certificate found"));
+    }
+    let key = PrivateKeyDer::from_pem_slice(&key_pem).map_err(|e| invalid_data(key_path, e))?;
+
+    let mut server_config = ServerConfig::builder_with_provider(Arc::new(ring::default_provider()))
+        .with_safe_default_protocol_versions()
+        .map_err(|e| io::Error::other(e.to_string()))?
+        .with_no_client_auth()
+        .with_single_cert(cert_chain, key)
+        .map_err(|e| invalid_data(key_path, e))?;
+    server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+    Ok(server_config)
+}
+
+async fn read_file(path: &Path) -> io::Result<Vec<u8>> {
+    tokio::fs::read(path)
+        .await
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+}
+
+fn invalid_data(path: &Path, error: impl std::fmt::Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: {}", path.display(), error),
+    )
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, Url, header};
+use tracing::instrument;
+
+use crate::{
+    domain::ports::webhook_sender_port::{WebhookRequest, WebhookSendError, WebhookSenderPort},
+    infrastructure::security::public_addresses::{check_ip_host, outbound_client_builder},
+};
+
+const EVENT_HEADER: &str = "X-Rebug-Event";
+const DELIVERY_HEADER: &str = "X-Rebug-Delivery";
+const TIMESTAMP_HEADER: &str = "X-Rebug-Timestamp";
+const SIGNATURE_HEADER: &str = "X-Rebug-Signature-256";
+
+#[derive(Clone)]
+pub struct HttpWebhookSender {
+    client: Client,
+    allow_private_networks: bool,
+}
+
+impl HttpWebhookSender {
+    /// Unless `allow_private_networks` is set, requests only reach public addresses. Redirects
+    /// are never followed, since they could lead anywhere.
+    #[instrument(name = "HttpWebhookSender::new", level = "debug")]
+    pub fn new(
+        timeout_seconds: u64,
+        allow_private_networks: bool,
+    ) -> Result<Self, WebhookSendError> {
+        let client = outbound_client_builder(allow_private_networks)
+            .timeout(Duration::from_secs(timeout_seconds))
+            .user_agent(concat!("rebug-webhooks/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| WebhookSendError::ConfigurationError(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            allow_private_networks,
+        })
+    }
+
+    /// URLs holding an IP address are not resolved, so they are checked here.
+    fn check_address(&self, url: &str) -> Result<(), WebhookSendError> {
+        if self.allow_private_networks {
+            return Ok(());
+        }
+
+        let url = Url::parse(url).map_err(|e| WebhookSendError::RequestFailed(e.to_string()))?;
+        check_ip_host(&url).map_err(|e| WebhookSendError::RequestFailed(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl WebhookSenderPort for HttpWebhookSender {
+    #[instrument(skip(self, request), fields(delivery_id = %request.delivery_id, event_type = %request.event_type), level = "debug")]
+    async fn send(&self, request: WebhookRequest) -> Result<u16, WebhookSendError> {
+        self.check_address(&request.url)?;
+
+        let response = self
+            .client
+            .post(&request.url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, request.event_type.as_str())
+            .header(DELIVERY_HEADER, request.delivery_id.to_string())
+            .header(TIMESTAMP_HEADER, request.timestamp.to_string())
+            .header(SIGNATURE_HEADER, request.signature)
+            .body(request.body)
+            .send()
+            .await
+            .map_err(|e| WebhookSendError::RequestFailed(e.to_string()))?;
+
+        Ok(response.status().as_u16())
+    }
+}
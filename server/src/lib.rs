@@ -1,5 +1,6 @@
 pub mod api;
 pub mod application;
+pub mod cli;
 pub mod config;
 pub mod domain;
 pub mod infrastructure;
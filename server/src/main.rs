@@ -1,33 +1,127 @@
-use std::net::SocketAddr;
+use std::{net::SocketAddr, time::Duration};
 
-use axum::Router;
+use axum::{
+    Router,
+    http::{HeaderMap, HeaderValue, StatusCode, Uri, header, uri::Authority},
+    middleware,
+    response::{IntoResponse, Redirect, Response},
+};
+use axum_server::Handle;
 use rebug::{
-    api::{routers::get_api_routes, state::AppState},
-    config::app_config::APP_CONFIG,
-    domain::models::user::UserRole,
+    api::{
+        metrics::track_http_metrics,
+        routers::{get_api_routes, get_root_routes},
+        state::AppState,
+    },
+    cli::{Command, USAGE, commands},
+    config::app_config::{APP_CONFIG, DatabaseBackend, is_development_run_mode, run_mode},
+    domain::{
+        models::{audit::AuditContext, user::UserRole},
+        ports::health_probe_port::HealthProbePort,
+    },
     infrastructure::{
-        container::service_container::ServiceContainer, database::sqlite::Sqlite, frontend,
+        container::service_container::ServiceContainer, database::connection::DatabaseConnection,
+        frontend, health::storage_probes::StorageWritableProbe, mail::smtp_listener::SmtpListener,
+        metrics::query_metrics_layer::QueryMetricsLayer,
+        tls::server_certificate::ServerCertificate,
     },
 };
+use tokio::time::{Instant, MissedTickBehavior};
 use tower_http::{
     services::ServeDir,
+    set_header::SetResponseHeader,
     trace::{self, TraceLayer},
 };
 use tracing::Level;
-use tracing_subscriber::{EnvFilter, FmtSubscriber};
+use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, util::SubscriberInitExt};
 
 const DEFAULT_TRACING_FILTER: &str = "info,rebug=trace";
+/// Administrative commands print their own output.
+const COMMAND_TRACING_FILTER: &str = "warn";
+const UPLOADED_FILES_CSP: &str = "default-src 'none'; sandbox";
+/// How often due webhook retries are looked for, bounding how late they are sent.
+const WEBHOOK_RETRY_POLL_INTERVAL: Duration = Duration::from_secs(10);
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    init_tracing();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = Command::parse(&args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+    if command == Command::Help {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    if command == Command::Serve {
+        init_tracing(DEFAULT_TRACING_FILTER);
+    } else {
+        init_tracing(COMMAND_TRACING_FILTER);
+    }
 
-    let sqlite_connection = &Sqlite::new(APP_CONFIG.database_url.clone()).await?;
+    let database = &DatabaseConnection::new(APP_CONFIG.database_url.clone()).await?;
 
-    let container = ServiceContainer::new(sqlite_connection).await?;
+    let container = ServiceContainer::new(database).await?;
 
-    run_migrations(sqlite_connection).await?;
+    if command.runs_migrations() {
+        run_migrations(database).await?;
+    }
+
+    match command {
+        Command::Serve => serve(container).await,
+        Command::Migrate => commands::migrate(database),
+        Command::CreateUser {
+            email,
+            first_name,
+            last_name,
+            role,
+            password,
+        } => {
+            commands::create_user(
+                &container,
+                &email,
+                first_name.as_deref(),
+                last_name.as_deref(),
+                role,
+                password,
+            )
+            .await
+        }
+        Command::ResetPassword { email, password } => {
+            commands::reset_password(&container, &email, password).await
+        }
+        Command::Promote { email, role } => commands::promote(&container, &email, role).await,
+        Command::ListUsers { search } => commands::list_users(&container, search.as_deref()).await,
+        Command::Backup { destination } => {
+            commands::backup(&container, destination.as_deref()).await
+        }
+        Command::ImportBoard {
+            archive,
+            dry_run,
+            preserve_ids,
+            owner_email,
+        } => {
+            commands::import_board(
+                &container,
+                &archive,
+                dry_run,
+                preserve_ids,
+                owner_email.as_deref(),
+            )
+            .await
+        }
+        Command::CheckConfig => commands::check_config(&container).await,
+        Command::Help => Ok(()),
+    }
+}
+
+async fn serve(container: ServiceContainer) -> Result<(), Box<dyn std::error::Error>> {
+    check_upload_directory().await?;
     setup_initial_admin(&container).await?;
+    start_smtp_listener(&container).await?;
+    start_backup_schedule(&container);
+    start_webhook_retries(&container);
 
     let app_state = AppState::new(container);
     let router = build_router(app_state);
@@ -35,88 +129,192 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     start_server(router).await
 }
 
-fn init_tracing() {
-    let subscriber = FmtSubscriber::builder()
-        .with_env_filter(
-            EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| EnvFilter::new(DEFAULT_TRACING_FILTER)),
-        )
-        .finish();
-
-    tracing::subscriber::set_global_default(subscriber)
-        .expect("Setting default tracing subscriber failed");
+fn init_tracing(default_filter: &str) {
+    let env_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_filter));
 
-    fast_log::init(fast_log::Config::new().console()).expect("Failed to initialize fast_log");
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_filter(env_filter))
+        .with(QueryMetricsLayer::filtered())
+        .init();
 }
 
-async fn run_migrations(sqlite_connection: &Sqlite) -> Result<(), Box<dyn std::error::Error>> {
+async fn run_migrations(database: &DatabaseConnection) -> Result<(), Box<dyn std::error::Error>> {
     tracing::info!("Running database migrations...");
-    sqlx::migrate!()
-        .run(&sqlite_connection.get_pool())
+    database
+        .run_migrations()
         .await
-        .expect("Failed to run database migrations");
+        .map_err(|e| format!("Failed to run database migrations: {}", e))?;
     tracing::info!("Database migrations completed.");
 
     Ok(())
 }
 
+/// Uploads are written to the upload directory: one the server cannot write to stops it at
+/// startup, rather than failing the first upload.
+async fn check_upload_directory() -> Result<(), Box<dyn std::error::Error>> {
+    StorageWritableProbe::new(&APP_CONFIG.upload_directory)
+        .probe()
+        .await
+        .map_err(|e| {
+            format!(
+                "upload_directory '{}' is not writable: {}",
+                APP_CONFIG.upload_directory, e
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Creates the default admin on the first start, when the instance has no user yet. Accounts
+/// are managed with the other commands afterwards.
 async fn setup_initial_admin(
     container: &ServiceContainer,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    match container
-        .user_service
-        .get_user_by_email(&APP_CONFIG.default_admin_email)
-        .await
+    let (_, user_count) = container.user_service.list_users(None, 1, 1).await?;
+    if user_count > 0 {
+        return Ok(());
+    }
+    if let Some(problem) = APP_CONFIG.default_admin_password_problem()
+        && !is_development_run_mode()
     {
-        Ok(_) => {
-            tracing::info!(
-                "Default admin user with email '{}' already exists.",
-                APP_CONFIG.default_admin_email
-            );
+        return Err(format!(
+            "Unsafe configuration for the '{}' run mode: {}",
+            run_mode(),
+            problem
+        )
+        .into());
+    }
+
+    container
+        .user_onboarding_service
+        .onboard_user(
+            &APP_CONFIG.default_admin_email,
+            &APP_CONFIG.default_admin_password,
+            Some(&APP_CONFIG.default_admin_first_name),
+            Some(&APP_CONFIG.default_admin_last_name),
+            UserRole::Admin,
+            &AuditContext::system(),
+        )
+        .await
+        .map_err(|e| format!("Failed to create default admin user: {}", e))?;
+
+    tracing::warn!(
+        "No user found: created the default admin '{}'. Change its password, e.g. with \
+         `rebug reset-password {}`.",
+        APP_CONFIG.default_admin_email,
+        APP_CONFIG.default_admin_email
+    );
+
+    Ok(())
+}
+
+async fn start_smtp_listener(
+    container: &ServiceContainer,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !APP_CONFIG.inbound_smtp_enabled {
+        return Ok(());
+    }
+
+    let smtp_listener = SmtpListener::bind(
+        &APP_CONFIG.inbound_smtp_bind_address,
+        APP_CONFIG.inbound_email_domain.clone(),
+        (APP_CONFIG.max_body_size_mb * 1024 * 1024) as usize,
+        container.inbound_email_service.clone(),
+    )
+    .await?;
+
+    tracing::info!(
+        "Receiving inbound emails on {}",
+        APP_CONFIG.inbound_smtp_bind_address
+    );
+    tokio::spawn(smtp_listener.run());
+
+    Ok(())
+}
+
+/// Takes a snapshot every `backup_interval_hours`, starting one interval after startup so that
+/// restarts do not pile up snapshots. PostgreSQL databases are backed up with their own tools.
+fn start_backup_schedule(container: &ServiceContainer) {
+    if APP_CONFIG.backup_interval_hours == 0 {
+        return;
+    }
+    if APP_CONFIG.database_backend() == DatabaseBackend::Postgres {
+        tracing::info!("Scheduled backups are disabled with PostgreSQL, use pg_dump instead.");
+        return;
+    }
+
+    let period = Duration::from_secs(APP_CONFIG.backup_interval_hours * 3600);
+    let backup_service = container.backup_service.clone();
+
+    tracing::info!(
+        "Backing up the database every {} hour(s) to {}",
+        APP_CONFIG.backup_interval_hours,
+        APP_CONFIG.backup_directory
+    );
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval_at(Instant::now() + period, period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            if let Err(e) = backup_service.create_snapshot().await {
+                tracing::error!("Scheduled backup failed: {}", e);
+            }
         }
-        Err(e) => {
-            if let rebug::application::services::user_service::UserServiceError::UserNotFound = e {
-                tracing::info!(
-                    "Default admin user with email '{}' not found. Creating...",
-                    APP_CONFIG.default_admin_email
-                );
-                container
-                    .user_onboarding_service
-                    .onboard_user(
-                        &APP_CONFIG.default_admin_email,
-                        &APP_CONFIG.default_admin_password,
-                        Some(&APP_CONFIG.default_admin_first_name),
-                        Some(&APP_CONFIG.default_admin_last_name),
-                        UserRole::Admin,
-                    )
-                    .await
-                    .map_err(|create_err| {
-                        format!("Failed to create default admin user: {}", create_err)
-                    })?;
-                tracing::info!(
-                    "Default admin user '{}' created successfully.",
-                    APP_CONFIG.default_admin_email
-                );
-            } else {
-                tracing::error!(
-                    "Error checking for default admin user: {}. Admin not created.",
-                    e
-                );
-                return Err(Box::new(e));
+    });
+}
+
+/// Attempts the webhook deliveries that are due: those left pending by a previous run at
+/// startup, then the retries as they come due.
+fn start_webhook_retries(container: &ServiceContainer) {
+    let webhook_service = container.webhook_service.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(WEBHOOK_RETRY_POLL_INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            if let Err(e) = webhook_service.deliver_due_deliveries().await {
+                tracing::error!("Failed to resume due webhook deliveries: {}", e);
             }
         }
-    }
+    });
+}
 
-    Ok(())
+/// Uploaded files are served from the app origin, so they are only ever downloaded or embedded:
+/// never sniffed into another type nor rendered as a document able to run scripts.
+fn uploaded_files_service() -> SetResponseHeader<
+    SetResponseHeader<SetResponseHeader<ServeDir, HeaderValue>, HeaderValue>,
+    HeaderValue,
+> {
+    let service = ServeDir::new(&APP_CONFIG.upload_directory);
+    let service = SetResponseHeader::overriding(
+        service,
+        header::CONTENT_DISPOSITION,
+        HeaderValue::from_static("attachment"),
+    );
+    let service = SetResponseHeader::overriding(
+        service,
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+    SetResponseHeader::overriding(
+        service,
+        header::CONTENT_SECURITY_POLICY,
+        HeaderValue::from_static(UPLOADED_FILES_CSP),
+    )
 }
 
 fn build_router(app_state: AppState) -> Router {
-    let uploaded_files_service = ServeDir::new(&APP_CONFIG.upload_directory);
-
     Router::new()
         .nest("/api", get_api_routes())
-        .nest_service("/uploads", uploaded_files_service)
+        .merge(get_root_routes())
+        .nest_service("/uploads", uploaded_files_service())
         .fallback(frontend::frontend_service::frontend_handler)
+        .layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            track_http_metrics,
+        ))
         .layer(
             TraceLayer::new_for_http()
                 .make_span_with(trace::DefaultMakeSpan::new().level(Level::INFO)),
@@ -125,22 +323,87 @@ fn build_router(app_state: AppState) -> Router {
 }
 
 async fn start_server(router: Router) -> Result<(), Box<dyn std::error::Error>> {
-    let addr = SocketAddr::from(([127, 0, 0, 1], APP_CONFIG.server_port));
+    let addr = APP_CONFIG.server_address();
+    let make_service = router.into_make_service_with_connect_info::<SocketAddr>();
 
-    tracing::info!("Starting server on {}", addr);
+    if !APP_CONFIG.tls_enabled() {
+        tracing::info!("Starting server on http://{}", addr);
 
-    let listener = tokio::net::TcpListener::bind(&addr).await?;
+        let listener = tokio::net::TcpListener::bind(&addr).await?;
+        axum::serve(listener, make_service)
+            .with_graceful_shutdown(shutdown_signal())
+            .await?;
 
-    let graceful_shutdown = async {
-        tokio::signal::ctrl_c()
-            .await
-            .expect("Failed to install CTRL+C signal handler");
-        tracing::info!("Received shutdown signal, shutting down gracefully...");
-    };
+        return Ok(());
+    }
+
+    let certificate = ServerCertificate::load(&APP_CONFIG.tls_cert_path, &APP_CONFIG.tls_key_path)
+        .await
+        .map_err(|e| format!("Failed to load the TLS certificate: {}", e))?;
+    let rustls_config = certificate.rustls_config();
+    tokio::spawn(certificate.watch(Duration::from_secs(APP_CONFIG.tls_reload_interval_seconds)));
 
-    axum::serve(listener, router)
-        .with_graceful_shutdown(graceful_shutdown)
+    start_https_redirect().await?;
+
+    let handle = Handle::new();
+    let shutdown_handle = handle.clone();
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        shutdown_handle.graceful_shutdown(None);
+    });
+
+    tracing::info!("Starting server on https://{}", addr);
+    axum_server::bind_rustls(addr, rustls_config)
+        .handle(handle)
+        .serve(make_service)
         .await?;
 
     Ok(())
 }
+
+async fn shutdown_signal() {
+    tokio::signal::ctrl_c()
+        .await
+        .expect("Failed to install CTRL+C signal handler");
+    tracing::info!("Received shutdown signal, shutting down gracefully...");
+}
+
+/// Answers plain HTTP on `http_redirect_port` with a redirection to the same URL over HTTPS.
+async fn start_https_redirect() -> Result<(), Box<dyn std::error::Error>> {
+    if APP_CONFIG.http_redirect_port == 0 {
+        return Ok(());
+    }
+
+    let addr = SocketAddr::new(
+        APP_CONFIG.server_address().ip(),
+        APP_CONFIG.http_redirect_port,
+    );
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+
+    tracing::info!("Redirecting http://{} to HTTPS", addr);
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, Router::new().fallback(redirect_to_https)).await {
+            tracing::error!("HTTPS redirect listener stopped: {}", e);
+        }
+    });
+
+    Ok(())
+}
+
+async fn redirect_to_https(headers: HeaderMap, uri: Uri) -> Response {
+    let Some(host) = headers
+        .get(header::HOST)
+        .and_then(|host| host.to_str().ok())
+        .and_then(|host| host.parse::<Authority>().ok())
+    else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+
+    let authority = match APP_CONFIG.server_port {
+        443 => host.host().to_string(),
+        port => format!("{}:{}", host.host(), port),
+    };
+    let path_and_query = uri.path_and_query().map_or("/", |p| p.as_str());
+
+    Redirect::permanent(&format!("https://{}{}", authority, path_and_query)).into_response()
+}